@@ -41,6 +41,9 @@ async fn main() -> Result<(), Box<dyn Error>> {
             Auth::Bearer { .. } => {
                 println!("   - 認証: Bearer");
             }
+            Auth::PersonalAccessToken { .. } => {
+                println!("   - 認証: パーソナルアクセストークン");
+            }
         }
     }
 
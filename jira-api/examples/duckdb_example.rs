@@ -24,9 +24,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("[INFO] JIRA DuckDBストアの使用例");
     println!("================================");
 
-    // 設定をロード
+    // 設定をロード（検索で取得するフィールドは設定側でまとめて指定）
     let config = JiraConfig::from_env()
-        .map_err(|_| "環境変数が設定されていません。README.mdを参照してください。")?;
+        .map_err(|_| "環境変数が設定されていません。README.mdを参照してください。")?
+        .default_search_fields(vec![
+            "key".to_string(),
+            "summary".to_string(),
+            "status".to_string(),
+            "priority".to_string(),
+            "issuetype".to_string(),
+            "reporter".to_string(),
+            "assignee".to_string(),
+            "created".to_string(),
+            "updated".to_string(),
+            "project".to_string(),
+            "description".to_string(),
+        ]);
 
     let client = JiraClient::new(config)?;
     println!("[OK] JIRAクライアント準備完了");
@@ -46,19 +59,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n[2] JIRAからIssueを取得してDuckDBに保存");
 
     let jql_query = "ORDER BY created DESC";
-    let search_params = jira_api::SearchParams::new().max_results(100).fields(vec![
-        "key".to_string(),
-        "summary".to_string(),
-        "status".to_string(),
-        "priority".to_string(),
-        "issuetype".to_string(),
-        "reporter".to_string(),
-        "assignee".to_string(),
-        "created".to_string(),
-        "updated".to_string(),
-        "project".to_string(),
-        "description".to_string(),
-    ]);
+    let search_params = jira_api::SearchParams::new().max_results(100);
 
     match client.search_issues(jql_query, search_params).await {
         Ok(result) => {
@@ -648,8 +649,13 @@ async fn create_comprehensive_demo_data(
             reporter,
             created,
             updated,
+            resolution: None,
+            time_tracking: None,
+            votes: None,
+            watches: None,
             resolution_date: None,
             project: Some(project_obj),
+            parent: None,
             custom_fields: HashMap::new(),
         };
 
@@ -659,6 +665,7 @@ async fn create_comprehensive_demo_data(
             fields,
             self_url: "http://example.com".to_string(),
             changelog: None,
+            rendered_fields: None,
         };
 
         demo_issues.push(issue);
@@ -136,12 +136,17 @@ fn create_mock_issues(count: usize) -> Vec<Issue> {
             reporter,
             created: Utc::now() - Duration::days((i % 30) as i64),
             updated: Utc::now() - Duration::hours((i % 24) as i64),
+            resolution: None,
+            time_tracking: None,
+            votes: None,
+            watches: None,
             resolution_date: if i % 4 == 0 {
                 Some(Utc::now() - Duration::hours((i % 12) as i64))
             } else {
                 None
             },
             project: Some(project),
+            parent: None,
             custom_fields: {
                 let mut fields = HashMap::new();
                 fields.insert(
@@ -159,6 +164,7 @@ fn create_mock_issues(count: usize) -> Vec<Issue> {
             fields,
             self_url: format!("https://mock.example.com/issue/{}", 30000 + i),
             changelog: None,
+            rendered_fields: None,
         };
 
         issues.push(issue);
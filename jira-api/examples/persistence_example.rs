@@ -423,8 +423,13 @@ async fn create_demo_data(store: &mut JsonStore) -> Result<(), Box<dyn std::erro
             reporter,
             created: Utc::now() - chrono::Duration::days(i as i64),
             updated: Utc::now() - chrono::Duration::hours(i as i64),
+            resolution: None,
+            time_tracking: None,
+            votes: None,
+            watches: None,
             resolution_date: None,
             project: Some(project),
+            parent: None,
             custom_fields: HashMap::new(),
         };
 
@@ -434,6 +439,7 @@ async fn create_demo_data(store: &mut JsonStore) -> Result<(), Box<dyn std::erro
             fields,
             self_url: "http://example.com".to_string(),
             changelog: None,
+            rendered_fields: None,
         };
 
         demo_issues.push(issue);
@@ -0,0 +1,78 @@
+/// JIRA Issueのステータス遷移（トランジション）の使用例
+///
+/// Issueで実行可能なトランジションの一覧を取得し、名前で選んで実行します
+///
+/// 実行前に環境変数を設定してください：
+/// export JIRA_URL=https://your-instance.atlassian.net
+/// export JIRA_USER=your-email@example.com
+/// export JIRA_API_TOKEN=your-api-token
+///
+/// 実行方法：
+/// cargo run --example transition_example -- <Issueキー> <トランジション名>
+/// （例: cargo run --example transition_example -- TEST-1 "Start Progress"）
+///
+/// トランジション名を省略すると、実行可能なトランジションの一覧のみ表示します。
+use dotenv::dotenv;
+use jira_api::{JiraClient, JiraConfig};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    dotenv().ok();
+
+    println!("[INFO] JIRA ステータス遷移の使用例");
+    println!("===============================");
+
+    let mut args = std::env::args().skip(1);
+    let issue_key = args
+        .next()
+        .ok_or("Issueキーを指定してください（例: TEST-1）")?;
+    let transition_name = args.next();
+
+    // 設定をロード
+    let config = JiraConfig::from_env()
+        .map_err(|_| "環境変数が設定されていません。README.mdを参照してください。")?;
+
+    let client = JiraClient::new(config)?;
+    println!("[OK] JIRAクライアント準備完了");
+
+    // 1. 実行可能なトランジションの一覧
+    println!("\n[1] {} で実行可能なトランジション", issue_key);
+    let transitions = client.get_transitions(&issue_key).await?;
+    for transition in &transitions {
+        println!(
+            "     {} : {} -> {}",
+            transition.id, transition.name, transition.to_status.name
+        );
+    }
+
+    let Some(transition_name) = transition_name else {
+        println!("\nトランジション名を指定すると、そのトランジションを実行します。");
+        return Ok(());
+    };
+
+    // 2. 名前で選んで実行（大文字小文字は区別しない）
+    println!("\n[2] トランジション「{}」を実行", transition_name);
+    let Some(transition) = transitions
+        .iter()
+        .find(|t| t.name.eq_ignore_ascii_case(&transition_name))
+    else {
+        println!(
+            "   [ERROR] {} では「{}」を実行できません",
+            issue_key, transition_name
+        );
+        return Ok(());
+    };
+
+    match client
+        .transition_issue(&issue_key, &transition.id, None)
+        .await
+    {
+        Ok(()) => println!(
+            "   [OK] {} のステータスを {} に変更しました",
+            issue_key, transition.to_status.name
+        ),
+        Err(e) => println!("   [ERROR] エラー: {}", e),
+    }
+
+    Ok(())
+}
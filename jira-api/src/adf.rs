@@ -0,0 +1,365 @@
+//! Atlassian Document Format（ADF）の組み立てと変換
+//!
+//! JIRA Cloud REST API v3では、説明やコメントの本文をADFのJSONで送受信します。
+//! [`text_to_adf`]と[`markdown_to_adf`]は文字列からADF文書を組み立て、
+//! [`adf_to_plain_text`]はADF文書を表示用のプレーンテキストに戻します。
+
+use serde_json::{Value, json};
+
+/// プレーンテキストをADF文書に変換する
+///
+/// 空行で区切られた部分をそれぞれ段落とし、段落内の改行は`hardBreak`になります。
+/// 空の文字列は段落を持たない文書になります。
+pub fn text_to_adf(text: &str) -> Value {
+    let paragraphs: Vec<Value> = split_blocks(text)
+        .iter()
+        .map(|lines| paragraph(lines.iter().map(|line| vec![text_node(line, false)])))
+        .collect();
+    doc(paragraphs)
+}
+
+/// 簡易的なMarkdownをADF文書に変換する
+///
+/// 以下の記法に対応します。それ以外はプレーンテキストとして扱います。
+/// - 空行による段落の区切り
+/// - `- `または`* `で始まる行の箇条書き
+/// - `**太字**`
+pub fn markdown_to_adf(markdown: &str) -> Value {
+    let mut content = Vec::new();
+
+    for lines in split_blocks(markdown) {
+        let mut text_lines: Vec<&str> = Vec::new();
+        let mut list_items: Vec<&str> = Vec::new();
+
+        for line in lines {
+            match bullet_item(line) {
+                Some(item) => {
+                    if !text_lines.is_empty() {
+                        content.push(paragraph(text_lines.drain(..).map(parse_inline)));
+                    }
+                    list_items.push(item);
+                }
+                None => {
+                    if !list_items.is_empty() {
+                        content.push(bullet_list(list_items.drain(..)));
+                    }
+                    text_lines.push(line);
+                }
+            }
+        }
+
+        if !text_lines.is_empty() {
+            content.push(paragraph(text_lines.into_iter().map(parse_inline)));
+        }
+        if !list_items.is_empty() {
+            content.push(bullet_list(list_items.into_iter()));
+        }
+    }
+
+    doc(content)
+}
+
+/// ADF文書をプレーンテキストに変換する
+///
+/// 段落などのブロックは空行で、`hardBreak`は改行で区切ります。箇条書きの項目は`- `、
+/// 番号付きリストの項目は`1. `を先頭に付けて1行ずつ出力します。
+/// メンション・絵文字・リンクカードは表示名や短縮名、URLで置き換えます。
+/// 未知のノード（パネルや引用など）は子ノードを辿って本文を取り出すため、内容は失われません。
+/// 書式（太字など）は無視し、文字列の値はそのまま返します。
+pub fn adf_to_plain_text(adf: &Value) -> String {
+    match adf {
+        Value::String(text) => text.clone(),
+        Value::Object(_) if adf["type"] == "doc" => blocks_to_text(&adf["content"]),
+        Value::Object(_) => block_to_text(adf),
+        _ => String::new(),
+    }
+}
+
+/// 空行で区切られたブロックごとに行を分割する
+fn split_blocks(text: &str) -> Vec<Vec<&str>> {
+    let mut blocks = Vec::new();
+    let mut current = Vec::new();
+
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+
+    blocks
+}
+
+fn bullet_item(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    trimmed
+        .strip_prefix("- ")
+        .or_else(|| trimmed.strip_prefix("* "))
+}
+
+/// `**`で囲まれた部分を太字のテキストノードに変換する
+///
+/// 閉じられていない`**`はそのまま文字として残します。
+fn parse_inline(line: &str) -> Vec<Value> {
+    let mut nodes = Vec::new();
+    let mut rest = line;
+
+    while let Some(start) = rest.find("**") {
+        let Some(len) = rest[start + 2..].find("**") else {
+            break;
+        };
+        if start > 0 {
+            nodes.push(text_node(&rest[..start], false));
+        }
+        let bold = &rest[start + 2..start + 2 + len];
+        if !bold.is_empty() {
+            nodes.push(text_node(bold, true));
+        }
+        rest = &rest[start + 2 + len + 2..];
+    }
+    if !rest.is_empty() {
+        nodes.push(text_node(rest, false));
+    }
+
+    nodes
+}
+
+fn doc(content: Vec<Value>) -> Value {
+    json!({ "type": "doc", "version": 1, "content": content })
+}
+
+/// 行ごとのインラインノードを`hardBreak`でつないで段落にする
+fn paragraph(lines: impl Iterator<Item = Vec<Value>>) -> Value {
+    let mut content = Vec::new();
+    for (i, nodes) in lines.enumerate() {
+        if i > 0 {
+            content.push(json!({ "type": "hardBreak" }));
+        }
+        content.extend(nodes);
+    }
+    json!({ "type": "paragraph", "content": content })
+}
+
+fn bullet_list<'a>(items: impl Iterator<Item = &'a str>) -> Value {
+    let items: Vec<Value> = items
+        .map(|item| {
+            json!({
+                "type": "listItem",
+                "content": [paragraph(std::iter::once(parse_inline(item)))]
+            })
+        })
+        .collect();
+    json!({ "type": "bulletList", "content": items })
+}
+
+fn text_node(text: &str, bold: bool) -> Value {
+    if bold {
+        json!({ "type": "text", "text": text, "marks": [{ "type": "strong" }] })
+    } else {
+        json!({ "type": "text", "text": text })
+    }
+}
+
+fn blocks_to_text(content: &Value) -> String {
+    content
+        .as_array()
+        .map(|blocks| {
+            blocks
+                .iter()
+                .map(block_to_text)
+                .filter(|text| !text.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n\n")
+        })
+        .unwrap_or_default()
+}
+
+fn block_to_text(node: &Value) -> String {
+    match node["type"].as_str() {
+        Some("text") => node["text"].as_str().unwrap_or_default().to_string(),
+        Some("hardBreak") => "\n".to_string(),
+        Some("mention") => attr_text(node, &["text"]),
+        Some("emoji") => attr_text(node, &["text", "shortName"]),
+        Some("inlineCard") => attr_text(node, &["url"]),
+        Some("bulletList") => list_to_text(node, |_| "- ".to_string()),
+        Some("orderedList") => list_to_text(node, |i| format!("{}. ", i + 1)),
+        Some("paragraph") | Some("heading") | Some("codeBlock") => inline_to_text(&node["content"]),
+        _ => blocks_to_text(&node["content"]),
+    }
+}
+
+/// ノードの`attrs`から、指定した順に最初に見つかった文字列を取り出す
+fn attr_text(node: &Value, keys: &[&str]) -> String {
+    keys.iter()
+        .find_map(|key| node["attrs"][*key].as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn inline_to_text(content: &Value) -> String {
+    content
+        .as_array()
+        .map(|nodes| nodes.iter().map(block_to_text).collect())
+        .unwrap_or_default()
+}
+
+fn list_to_text(list: &Value, marker: impl Fn(usize) -> String) -> String {
+    list["content"]
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    let text = blocks_to_text(&item["content"]).replace("\n\n", "\n");
+                    format!("{}{}", marker(i), text)
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_to_adf_round_trip() {
+        // 空行で段落が分かれ、段落内の改行がhardBreakになり、プレーンテキストに戻せることをテスト
+        let text = "First line\nsecond line\n\nNext paragraph";
+        let adf = text_to_adf(text);
+
+        assert_eq!(adf["type"], "doc");
+        assert_eq!(adf["version"], 1);
+        assert_eq!(adf["content"].as_array().unwrap().len(), 2);
+        assert_eq!(adf["content"][0]["content"][1]["type"], "hardBreak");
+
+        // JSON文字列を経由しても同じ文書として読み込める
+        let parsed: Value = serde_json::from_str(&adf.to_string()).unwrap();
+        assert_eq!(parsed, adf);
+        assert_eq!(adf_to_plain_text(&parsed), text);
+
+        assert_eq!(
+            text_to_adf(""),
+            json!({ "type": "doc", "version": 1, "content": [] })
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_adf_lists_and_bold() {
+        // 箇条書きと太字が変換され、プレーンテキストに戻せることをテスト
+        let markdown = "Release **notes**:\n- fixed **crash**\n* added export\n\nThanks";
+        let adf = markdown_to_adf(markdown);
+        let content = adf["content"].as_array().unwrap();
+
+        assert_eq!(content.len(), 3);
+        assert_eq!(content[0]["type"], "paragraph");
+        assert_eq!(content[0]["content"][1]["text"], "notes");
+        assert_eq!(content[0]["content"][1]["marks"][0]["type"], "strong");
+        assert_eq!(content[1]["type"], "bulletList");
+        assert_eq!(content[1]["content"].as_array().unwrap().len(), 2);
+        assert_eq!(content[1]["content"][0]["type"], "listItem");
+        assert_eq!(content[2]["content"][0]["text"], "Thanks");
+
+        let parsed: Value = serde_json::from_str(&adf.to_string()).unwrap();
+        assert_eq!(
+            adf_to_plain_text(&parsed),
+            "Release notes:\n\n- fixed crash\n- added export\n\nThanks"
+        );
+
+        // 閉じられていない**は文字として残る
+        assert_eq!(adf_to_plain_text(&markdown_to_adf("a ** b")), "a ** b");
+    }
+
+    #[test]
+    fn test_adf_to_plain_text_inline_nodes() {
+        // メンション・絵文字・リンクカードが表示用の文字列に置き換わることをテスト
+        let adf = json!({
+            "type": "doc",
+            "version": 1,
+            "content": [{
+                "type": "paragraph",
+                "content": [
+                    { "type": "mention", "attrs": { "id": "557058:abc", "text": "@Alice" } },
+                    { "type": "text", "text": " please check " },
+                    { "type": "inlineCard", "attrs": { "url": "https://example.com/runbook" } },
+                    { "type": "text", "text": " " },
+                    { "type": "emoji", "attrs": { "shortName": ":warning:" } }
+                ]
+            }]
+        });
+
+        assert_eq!(
+            adf_to_plain_text(&adf),
+            "@Alice please check https://example.com/runbook :warning:"
+        );
+    }
+
+    #[test]
+    fn test_adf_to_plain_text_nested_and_unknown_nodes() {
+        // 見出し・番号付きリスト・コードブロックが変換され、未知のノードは子を辿って本文を残すことをテスト
+        let adf = json!({
+            "type": "doc",
+            "version": 1,
+            "content": [
+                {
+                    "type": "heading",
+                    "attrs": { "level": 2 },
+                    "content": [{ "type": "text", "text": "Steps" }]
+                },
+                {
+                    "type": "orderedList",
+                    "content": [
+                        {
+                            "type": "listItem",
+                            "content": [{
+                                "type": "paragraph",
+                                "content": [{ "type": "text", "text": "Open settings" }]
+                            }]
+                        },
+                        {
+                            "type": "listItem",
+                            "content": [{
+                                "type": "paragraph",
+                                "content": [{ "type": "text", "text": "Click save" }]
+                            }]
+                        }
+                    ]
+                },
+                {
+                    "type": "codeBlock",
+                    "attrs": { "language": "sh" },
+                    "content": [{ "type": "text", "text": "make test\nmake lint" }]
+                },
+                {
+                    "type": "panel",
+                    "attrs": { "panelType": "warning" },
+                    "content": [{
+                        "type": "blockquote",
+                        "content": [{
+                            "type": "paragraph",
+                            "content": [{ "type": "text", "text": "Do not deploy on Friday" }]
+                        }]
+                    }]
+                },
+                { "type": "rule" }
+            ]
+        });
+
+        assert_eq!(
+            adf_to_plain_text(&adf),
+            "Steps\n\n1. Open settings\n2. Click save\n\nmake test\nmake lint\n\nDo not deploy on Friday"
+        );
+
+        // 文書以外の値
+        assert_eq!(adf_to_plain_text(&json!("plain")), "plain");
+        assert_eq!(adf_to_plain_text(&Value::Null), "");
+    }
+}
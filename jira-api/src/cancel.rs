@@ -0,0 +1,50 @@
+//! 長時間の処理を途中で止めるためのキャンセルトークン
+//!
+//! 全件検索や同期はページ単位でリクエストを繰り返すため、ページの間で
+//! [`CancellationToken::is_cancelled`]を確認し、キャンセルされていればそこまでの結果を返して終了します。
+//! 実行中のリクエストは中断しません。
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// キャンセルトークン
+///
+/// クローンしたトークンはキャンセル状態を共有するため、処理に渡したトークンを
+/// 別のタスク（Ctrl-Cのハンドラーなど）から[`CancellationToken::cancel`]で止められます。
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// キャンセルされていないトークンを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// キャンセルを要求する
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// キャンセルが要求されているかどうか
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancellation_token_shared_between_clones() {
+        // クローン間でキャンセル状態が共有されることをテスト
+        let token = CancellationToken::new();
+        let shared = token.clone();
+        assert!(!shared.is_cancelled());
+
+        token.cancel();
+        assert!(shared.is_cancelled());
+    }
+}
@@ -1,31 +1,208 @@
 use crate::error::Result;
 use base64::Engine;
+use futures::{Stream, StreamExt, stream};
 use reqwest::{Client, header};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::{debug, trace};
 use url::Url;
 
+/// traceログに出力するレスポンスプレビューの最大文字数
+const RESPONSE_PREVIEW_CHARS: usize = 500;
+
+/// 認証方式
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum Auth {
+    /// ユーザー名（メールアドレス）とAPIトークンによるBasic認証（JIRA Cloud）
     Basic { username: String, api_token: String },
+    /// Bearerトークン認証
     Bearer { token: String },
+    /// パーソナルアクセストークン認証（JIRA Server / Data Center推奨）
+    ///
+    /// `Authorization: Bearer <token>`として送信します。
+    /// PATをユーザー名が空のBasic認証で渡すと`:<token>`がエンコードされて認証に失敗するため、
+    /// Data Centerではこちらを使用してください。
+    PersonalAccessToken { token: String },
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct JiraConfig {
     pub base_url: String,
     pub auth: Auth,
+    /// ホストごとに保持するアイドル接続の最大数（Noneの場合はreqwestのデフォルト: 無制限）
+    ///
+    /// 多数のタスクから並行してリクエストする場合、上限を設けるとソケットの使い過ぎを防げます。
+    /// 並行数と同程度（例: 同期の並行数が20なら20）が目安です。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool_max_idle_per_host: Option<usize>,
+    /// アイドル接続を保持する時間（Noneの場合はreqwestのデフォルト: 90秒）
+    ///
+    /// サーバーやロードバランサーのkeep-aliveタイムアウトより短くすると、
+    /// 切断済みの接続を再利用して失敗するのを避けられます。
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "duration_secs"
+    )]
+    pub pool_idle_timeout: Option<std::time::Duration>,
+    /// リクエスト全体のタイムアウト（Noneの場合は[`DEFAULT_TIMEOUT`]）
+    ///
+    /// タイムアウトしたリクエストは[`crate::Error::Timeout`]になります。
+    /// 設定ファイルでは秒数（例: `30`や`1.5`）で指定します。
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "duration_secs"
+    )]
+    pub timeout: Option<std::time::Duration>,
+    /// 接続確立までのタイムアウト（Noneの場合は個別に設定せず、`timeout`のみが適用される）
+    ///
+    /// 到達できないホストへの接続を、リクエスト全体のタイムアウトより早く打ち切るために使用します。
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "duration_secs"
+    )]
+    pub connect_timeout: Option<std::time::Duration>,
+    /// 429・503を受け取った場合に再送する最大回数（デフォルト: 3）
+    ///
+    /// 再送までの待機時間は`Retry-After`ヘッダーに従い、ヘッダーが無い場合は指数バックオフになります。
+    /// 0にすると再送せず、429は直ちに[`crate::Error::RateLimited`]になります。
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// 送信するUser-Agent（Noneの場合は[`DEFAULT_USER_AGENT`]）
+    ///
+    /// Atlassianのサポートや監査ツールで連携元を識別できるよう、アプリ名とバージョンを指定します。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+    /// `search_issues`で`SearchParams::fields`が未指定の場合に取得するフィールド
+    ///
+    /// チームで標準的に取得するフィールドを1か所で設定するためのものです。
+    /// `SearchParams::fields`を指定した場合はそちらが優先されます。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_search_fields: Option<Vec<String>>,
 }
 
 impl JiraConfig {
+    /// 設定を作成し、URLと認証情報を検証する
+    ///
+    /// 以下の場合は`InvalidConfiguration`エラーを返します。
+    /// - URLとして解釈できない、またはスキームがhttp/httpsでない
+    /// - Basic認証のユーザー名またはAPIトークンが空
+    /// - Bearer認証またはパーソナルアクセストークンのトークンが空
     pub fn new(base_url: impl Into<String>, auth: Auth) -> Result<Self> {
-        let base_url = base_url.into();
+        let config = Self::new_unchecked(base_url, auth);
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// 検証を行わずに設定を作成する
+    ///
+    /// プロキシ経由の特殊なURLなど、`new()`の検証を通らない設定を意図的に使う場合のためのものです。
+    pub fn new_unchecked(base_url: impl Into<String>, auth: Auth) -> Self {
+        Self {
+            base_url: base_url.into(),
+            auth,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: DEFAULT_MAX_RETRIES,
+            user_agent: None,
+            default_search_fields: None,
+        }
+    }
+
+    /// ホストごとに保持するアイドル接続の最大数を設定
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// アイドル接続を保持する時間を設定
+    pub fn pool_idle_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// リクエスト全体のタイムアウトを設定
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// 接続確立までのタイムアウトを設定
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// 429・503を受け取った場合に再送する最大回数を設定
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// 送信するUser-Agentを設定
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// 検索時にデフォルトで取得するフィールドを設定
+    pub fn default_search_fields(mut self, fields: Vec<String>) -> Self {
+        self.default_search_fields = Some(fields);
+        self
+    }
 
+    /// URLと認証情報を検証する
+    pub fn validate(&self) -> Result<()> {
         // Validate URL
-        let _ = Url::parse(&base_url).map_err(|_| {
+        let url = Url::parse(&self.base_url).map_err(|_| {
             crate::error::Error::InvalidConfiguration("Invalid base URL".to_string())
         })?;
 
-        Ok(Self { base_url, auth })
+        // "localhost:8080"のようにスキームが無いURLは"localhost"がスキームとして解釈される
+        if url.scheme() != "http" && url.scheme() != "https" {
+            return Err(crate::error::Error::InvalidConfiguration(format!(
+                "Base URL must use http or https: {}",
+                self.base_url
+            )));
+        }
+
+        match &self.auth {
+            Auth::Basic {
+                username,
+                api_token,
+            } => {
+                if username.trim().is_empty() {
+                    return Err(crate::error::Error::InvalidConfiguration(
+                        "Username must not be empty (use Auth::PersonalAccessToken for Data Center personal access tokens)".to_string(),
+                    ));
+                }
+                if api_token.trim().is_empty() {
+                    return Err(crate::error::Error::InvalidConfiguration(
+                        "API token must not be empty".to_string(),
+                    ));
+                }
+            }
+            Auth::Bearer { token } => {
+                if token.trim().is_empty() {
+                    return Err(crate::error::Error::InvalidConfiguration(
+                        "Bearer token must not be empty".to_string(),
+                    ));
+                }
+            }
+            Auth::PersonalAccessToken { token } => {
+                if token.trim().is_empty() {
+                    return Err(crate::error::Error::InvalidConfiguration(
+                        "Personal access token must not be empty".to_string(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
     }
 
     pub fn from_env() -> Result<Self> {
@@ -58,10 +235,188 @@ impl JiraConfig {
     }
 }
 
-#[derive(Debug, Clone)]
+/// 健全性チェックの失敗をレポートに記録する
+fn record_health_error(
+    report: &mut crate::models::HealthReport,
+    check: &str,
+    error: &crate::error::Error,
+) {
+    // HTTPレスポンスを受信できていればサーバーには到達している
+    if matches!(error, crate::error::Error::ApiError { .. }) {
+        report.reachable = true;
+    }
+    report.errors.push(format!("{}: {}", check, error));
+}
+
+/// `Retry-After`ヘッダーから再送までの待機時間を求める
+///
+/// 秒数とHTTP日付の両方に対応し、過去の日付は待機なしとして扱います。
+/// ヘッダーが無い、または解釈できない場合は`None`を返します。
+fn retry_after(
+    headers: &header::HeaderMap,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<std::time::Duration> {
+    let value = headers.get(header::RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(std::time::Duration::from_secs(secs));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&chrono::Utc) - now)
+            .to_std()
+            .unwrap_or(std::time::Duration::ZERO),
+    )
+}
+
+/// ページ単位の検索ストリームで、ページの取得に失敗した場合の動作
+#[derive(Debug, Clone, Copy)]
+enum PageErrorMode {
+    /// デフォルトの[`crate::RetryPolicy`]で再試行し、それでも失敗したらエラーを1件返して終了する
+    RetryThenStop,
+    /// 再試行せずにエラーを1件返し、失敗したページを飛ばせる場合は次のページへ進む
+    Continue,
+}
+
+/// 送信時に再送済みのレート制限・サービス停止（429・503）のエラーかどうか
+fn is_throttled(error: &crate::Error) -> bool {
+    matches!(
+        error,
+        crate::Error::RateLimited { .. } | crate::Error::ApiError { status: 503, .. }
+    )
+}
+
+/// ログ用にレスポンスの先頭部分を取り出す（文字の途中で切らない）
+fn response_preview(text: &str) -> String {
+    match text.char_indices().nth(RESPONSE_PREVIEW_CHARS) {
+        Some((end, _)) => format!("{}...", &text[..end]),
+        None => text.to_string(),
+    }
+}
+
+/// 一括トランジションの同時実行数
+const BULK_TRANSITION_CONCURRENCY: usize = 4;
+
+/// キー指定の一括取得で1回の検索に含めるキーの数
+const ISSUES_BY_KEYS_BATCH_SIZE: usize = 50;
+
+/// キー指定の一括取得で、検索に失敗したバッチを1件ずつ取得する際の同時実行数
+const ISSUES_BY_KEYS_FALLBACK_CONCURRENCY: usize = 4;
+
+/// `Option<Duration>`を秒数としてシリアライズする
+///
+/// 整数秒は整数、それ以外は小数で出力します。
+mod duration_secs {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::time::Duration;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum DurationRepr {
+        Secs(u64),
+        FractionalSecs(f64),
+    }
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(duration) if duration.subsec_nanos() == 0 => {
+                duration.as_secs().serialize(serializer)
+            }
+            Some(duration) => duration.as_secs_f64().serialize(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Option::<DurationRepr>::deserialize(deserializer)?
+            .map(|repr| match repr {
+                DurationRepr::Secs(secs) => Ok(Duration::from_secs(secs)),
+                DurationRepr::FractionalSecs(secs) => {
+                    Duration::try_from_secs_f64(secs).map_err(serde::de::Error::custom)
+                }
+            })
+            .transpose()
+    }
+}
+
+/// 全件検索で`maxResults`が指定されていない場合のページサイズ
+const DEFAULT_SEARCH_PAGE_SIZE: u32 = 50;
+
+/// 429・503を受け取った場合に再送する回数のデフォルト
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+fn default_max_retries() -> u32 {
+    DEFAULT_MAX_RETRIES
+}
+
+/// 429・503の再送で`Retry-After`に従って待機する時間の上限
+///
+/// これより長い待機を指示された場合は再送せず、呼び出し元に返します。
+const MAX_RETRY_AFTER: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// タイムアウトが設定されていない場合のリクエスト全体のタイムアウト
+pub const DEFAULT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// User-Agentが設定されていない場合に送信する値（`jira-api/<バージョン>`）
+pub const DEFAULT_USER_AGENT: &str =
+    concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// [`JiraClient::as_user`]で操作を行うユーザーを指定するヘッダー
+pub const ACTOR_HEADER: &str = "X-Actor-Account-Id";
+
+/// 送信直前のリクエストを変更するインターセプター
+///
+/// 相関IDヘッダーの付与、リクエスト署名、監査ログなどに使用します。
+pub type RequestInterceptor = Arc<dyn Fn(&mut reqwest::Request) + Send + Sync>;
+
+/// クライアントのリクエスト統計
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClientMetrics {
+    /// 送信したリクエスト数
+    pub request_count: u64,
+    /// 受信したレスポンスボディの合計バイト数
+    pub response_bytes: u64,
+    /// 失敗したリクエスト数（通信エラーと非2xxレスポンス）
+    pub error_count: u64,
+}
+
+/// リクエスト統計のカウンター（クローンしたクライアント間で共有）
+#[derive(Debug, Default)]
+struct MetricsCounters {
+    request_count: AtomicU64,
+    response_bytes: AtomicU64,
+    error_count: AtomicU64,
+}
+
+#[derive(Clone)]
 pub struct JiraClient {
     pub(crate) client: Client,
     pub(crate) config: Arc<JiraConfig>,
+    pub(crate) interceptors: Vec<RequestInterceptor>,
+    metrics: Arc<MetricsCounters>,
+    capabilities: Arc<std::sync::RwLock<Option<crate::models::ServerCapabilities>>>,
+    retry_budget: Option<Arc<crate::RetryBudget>>,
+    metadata_cache: Option<Arc<crate::metadata_cache::MetadataCache>>,
+    clock: Arc<dyn crate::Clock>,
+}
+
+impl std::fmt::Debug for JiraClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JiraClient")
+            .field("client", &self.client)
+            .field("config", &self.config)
+            .field("interceptors", &self.interceptors.len())
+            .field("metrics", &self.metrics())
+            .field("capabilities", &self.capabilities())
+            .field("retry_budget", &self.retry_budget)
+            .field("metadata_cache", &self.metadata_cache)
+            .finish()
+    }
 }
 
 impl JiraClient {
@@ -92,7 +447,7 @@ impl JiraClient {
                     })?,
                 );
             }
-            Auth::Bearer { token } => {
+            Auth::Bearer { token } | Auth::PersonalAccessToken { token } => {
                 headers.insert(
                     header::AUTHORIZATION,
                     header::HeaderValue::from_str(&format!("Bearer {}", token)).map_err(|_| {
@@ -102,44 +457,241 @@ impl JiraClient {
             }
         }
 
-        let client = Client::builder()
+        let user_agent = config.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT);
+        let mut builder = Client::builder()
             .default_headers(headers)
-            .build()
-            .map_err(|e| {
-                crate::error::Error::Unexpected(format!("Failed to build HTTP client: {}", e))
-            })?;
+            .user_agent(user_agent);
+        if let Some(max) = config.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = config.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        builder = builder.timeout(config.timeout.unwrap_or(DEFAULT_TIMEOUT));
+        if let Some(timeout) = config.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+
+        let client = builder.build().map_err(|e| {
+            crate::error::Error::Unexpected(format!("Failed to build HTTP client: {}", e))
+        })?;
 
         Ok(Self {
             client,
             config: Arc::new(config),
+            interceptors: Vec::new(),
+            metrics: Arc::new(MetricsCounters::default()),
+            capabilities: Arc::new(std::sync::RwLock::new(None)),
+            retry_budget: None,
+            metadata_cache: None,
+            clock: Arc::new(crate::SystemClock),
         })
     }
 
+    /// リクエストインターセプターを追加
+    ///
+    /// 追加したインターセプターは登録順に、各リクエストの送信直前に呼び出されます。
+    pub fn with_request_interceptor(mut self, interceptor: RequestInterceptor) -> Self {
+        self.interceptors.push(interceptor);
+        self
+    }
+
+    /// 指定ユーザーとして操作するクライアントを作成する
+    ///
+    /// アプリが利用者に代わって操作する場合のため、全てのリクエストに[`ACTOR_HEADER`]で
+    /// アカウントIDを付与するクライアントを返します。元のクライアントは変更されないため、
+    /// 1つのクライアントから複数のユーザーのコンテキストを作成できます。
+    /// 接続プール・統計・キャッシュは元のクライアントと共有します。
+    pub fn as_user(&self, account_id: &str) -> Result<Self> {
+        let value = header::HeaderValue::from_str(account_id).map_err(|_| {
+            crate::error::Error::InvalidInput(format!("Invalid account ID: {}", account_id))
+        })?;
+
+        Ok(self.clone().with_request_interceptor(Arc::new(
+            move |request: &mut reqwest::Request| {
+                request.headers_mut().insert(ACTOR_HEADER, value.clone());
+            },
+        )))
+    }
+
+    /// クライアント全体で共有する再試行の予算を設定
+    ///
+    /// このクライアントとクローンからの再試行は全てこの予算からトークンを消費し、
+    /// 予算が尽きている間は再試行せずにエラーを返します。
+    pub fn with_retry_budget(mut self, budget: crate::RetryBudget) -> Self {
+        self.retry_budget = Some(Arc::new(budget));
+        self
+    }
+
+    /// メタデータ取得APIのキャッシュを有効にする
+    ///
+    /// `get_priorities()`、`get_issue_types()`、`get_fields()`、`get_status_categories()`の
+    /// レスポンスを`ttl`の間保存し、期限切れ後の呼び出しで再取得します。
+    /// キャッシュはクローンしたクライアント間で共有されます。
+    pub fn with_metadata_cache(mut self, ttl: std::time::Duration) -> Self {
+        self.metadata_cache = Some(Arc::new(crate::metadata_cache::MetadataCache::new(ttl)));
+        self
+    }
+
+    /// 現在時刻の取得元を設定
+    ///
+    /// メタデータキャッシュの有効期限の判定に使われます。デフォルトはシステム時刻です。
+    pub fn with_clock(mut self, clock: Arc<dyn crate::Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// メタデータキャッシュを全て破棄する
+    ///
+    /// 管理画面でフィールドを追加した直後など、有効期限を待たずに再取得したい場合に使用します。
+    pub fn clear_metadata_cache(&self) {
+        if let Some(cache) = &self.metadata_cache {
+            cache.clear();
+        }
+    }
+
     pub fn config(&self) -> &JiraConfig {
         &self.config
     }
 
+    /// このクライアント（およびクローン）で送信したリクエストの統計を取得
+    pub fn metrics(&self) -> ClientMetrics {
+        ClientMetrics {
+            request_count: self.metrics.request_count.load(Ordering::Relaxed),
+            response_bytes: self.metrics.response_bytes.load(Ordering::Relaxed),
+            error_count: self.metrics.error_count.load(Ordering::Relaxed),
+        }
+    }
+
+    /// リクエストを送信し、429・503の場合は待機してから再送する
+    ///
+    /// 待機時間は`Retry-After`ヘッダー（秒数またはHTTP日付）に従い、ヘッダーが無い場合は
+    /// デフォルトの[`crate::RetryPolicy`]と同じ指数バックオフになります。
+    /// [`JiraConfig::max_retries`]回再送しても429の場合は[`crate::Error::RateLimited`]を返し、
+    /// 503の場合は最後のレスポンスをそのまま返します（呼び出し元で`ApiError`になります）。
+    /// `Retry-After`が[`MAX_RETRY_AFTER`]を超える場合や、再試行の予算が尽きた場合も再送をやめます。
+    /// 429・503の再送はここでのみ行い、[`crate::RetryPolicy`]による再試行の対象にはしません。
+    async fn send(&self, builder: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let backoff = crate::RetryPolicy::new();
+        let mut attempt = 0;
+
+        loop {
+            // ボディがストリームの場合は複製できないため、再送せずにそのまま送信する
+            let Some(request) = builder.try_clone() else {
+                return self.send_once(builder).await;
+            };
+            let response = self.send_once(request).await?;
+
+            let status = response.status();
+            if status != reqwest::StatusCode::TOO_MANY_REQUESTS
+                && status != reqwest::StatusCode::SERVICE_UNAVAILABLE
+            {
+                return Ok(response);
+            }
+
+            let retry_after = retry_after(response.headers(), self.clock.now());
+            if attempt >= self.config.max_retries
+                || retry_after.is_some_and(|wait| wait > MAX_RETRY_AFTER)
+                || !self.acquire_retry_token()
+            {
+                if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                    return Err(crate::error::Error::RateLimited { retry_after });
+                }
+                return Ok(response);
+            }
+
+            let delay = retry_after.unwrap_or_else(|| backoff.delay_for(attempt));
+            debug!(
+                url = %response.url(),
+                status = status.as_u16(),
+                attempt,
+                delay_ms = delay.as_millis() as u64,
+                "JIRA API throttled, retrying"
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// インターセプターを適用してリクエストを1回送信する
+    async fn send_once(&self, builder: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let mut request = builder.build()?;
+        for interceptor in &self.interceptors {
+            interceptor(&mut request);
+        }
+
+        self.metrics.request_count.fetch_add(1, Ordering::Relaxed);
+        let response = self.client.execute(request).await;
+        let failed = match &response {
+            Ok(response) => !response.status().is_success(),
+            Err(_) => true,
+        };
+        if failed {
+            self.metrics.error_count.fetch_add(1, Ordering::Relaxed);
+        }
+
+        Ok(response?)
+    }
+
+    /// レスポンスボディを読み込み、受信バイト数を記録する
+    async fn read_text(&self, response: reqwest::Response) -> Result<String> {
+        let text = response.text().await?;
+        self.metrics
+            .response_bytes
+            .fetch_add(text.len() as u64, Ordering::Relaxed);
+        Ok(text)
+    }
+
     pub(crate) async fn get<T>(&self, endpoint: &str) -> Result<T>
     where
         T: serde::de::DeserializeOwned,
     {
         let url = format!("{}{}", self.config.base_url, endpoint);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.send(self.client.get(&url)).await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
-            let message = response
-                .text()
+            let message = self
+                .read_text(response)
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
             return Err(crate::error::Error::ApiError { status, message });
         }
 
-        let data = response.json::<T>().await?;
+        // JSONのデコード失敗はpost()と同じくDeserializationErrorとして返す
+        let response_text = self.read_text(response).await?;
+        let data = serde_json::from_str::<T>(&response_text).map_err(|e| {
+            crate::error::Error::DeserializationError(format!("JSON parse error: {}", e))
+        })?;
         Ok(data)
     }
 
+    /// メタデータキャッシュを経由してGETリクエストを送信する
+    ///
+    /// キャッシュが無効の場合は`get()`と同じです。
+    async fn get_metadata<T>(&self, endpoint: &str) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let Some(cache) = &self.metadata_cache else {
+            return self.get(endpoint).await;
+        };
+
+        let value = match cache.get(endpoint, self.clock.now()) {
+            Some(value) => value,
+            None => {
+                let value: serde_json::Value = self.get(endpoint).await?;
+                cache.insert(endpoint, value.clone(), self.clock.now());
+                value
+            }
+        };
+
+        serde_json::from_value(value).map_err(|e| {
+            crate::error::Error::DeserializationError(format!("JSON parse error: {}", e))
+        })
+    }
+
     pub(crate) async fn post<T, B>(&self, endpoint: &str, body: &B) -> Result<T>
     where
         T: serde::de::DeserializeOwned,
@@ -147,53 +699,220 @@ impl JiraClient {
     {
         let url = format!("{}{}", self.config.base_url, endpoint);
 
-        let response = self.client.post(&url).json(body).send().await?;
+        let response = self.send(self.client.post(&url).json(body)).await?;
 
         let status = response.status();
-        println!("=== JIRA API Response ===");
-        println!("Status: {}", status);
 
         if !status.is_success() {
-            let message = response
-                .text()
+            let message = self
+                .read_text(response)
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
-            println!("Error Response: {}", message);
-            println!("=========================");
+            debug!(
+                endpoint,
+                status = status.as_u16(),
+                bytes = message.len(),
+                "JIRA API error response"
+            );
+            trace!(endpoint, body = %response_preview(&message), "JIRA API error response body");
             return Err(crate::error::Error::ApiError {
                 status: status.as_u16(),
                 message,
             });
         }
 
-        // レスポンステキストを取得してログ出力
-        let response_text = response.text().await?;
-        println!("Response Length: {} bytes", response_text.len());
-
-        // レスポンステキストの最初の500文字を表示（デバッグ用）
-        let preview = if response_text.len() > 500 {
-            format!("{}...", &response_text[..500])
-        } else {
-            response_text.clone()
-        };
-        println!("Response Preview:\n{}", preview);
-        println!("=========================");
+        // レスポンスの内容（Issueの本文など）はtraceレベルでのみ出力する
+        let response_text = self.read_text(response).await?;
+        debug!(
+            endpoint,
+            status = status.as_u16(),
+            bytes = response_text.len(),
+            "JIRA API response"
+        );
+        trace!(endpoint, preview = %response_preview(&response_text), "JIRA API response preview");
 
         // JSONをパースして返す
         let data = serde_json::from_str::<T>(&response_text).map_err(|e| {
-            println!("JSON Parse Error: {}", e);
-            println!("Full Response Text:\n{}", response_text);
-            crate::error::Error::SerializationError(format!("JSON parse error: {}", e))
+            debug!(endpoint, bytes = response_text.len(), error = %e, "JIRA API response parse error");
+            crate::error::Error::DeserializationError(format!("JSON parse error: {}", e))
         })?;
 
         Ok(data)
     }
 
+    /// レスポンスボディを使用しないPOSTリクエストを送信する
+    pub(crate) async fn post_no_content<B>(&self, endpoint: &str, body: &B) -> Result<()>
+    where
+        B: serde::Serialize,
+    {
+        let url = format!("{}{}", self.config.base_url, endpoint);
+
+        let response = self.send(self.client.post(&url).json(body)).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = self
+                .read_text(response)
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(crate::error::Error::ApiError { status, message });
+        }
+
+        Ok(())
+    }
+
+    /// レスポンスボディを使用しないPUTリクエストを送信する
+    pub(crate) async fn put_no_content<B>(&self, endpoint: &str, body: &B) -> Result<()>
+    where
+        B: serde::Serialize,
+    {
+        let url = format!("{}{}", self.config.base_url, endpoint);
+
+        let response = self.send(self.client.put(&url).json(body)).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = self
+                .read_text(response)
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(crate::error::Error::ApiError { status, message });
+        }
+
+        Ok(())
+    }
+
+    /// ページング形式のエンドポイントから全ページを取得する
+    ///
+    /// `{ startAt, maxResults, total, isLast, values }`形式のレスポンスを返す
+    /// エンドポイントに対して、最後のページに到達するまで`startAt`を進めながら
+    /// リクエストを繰り返し、全ての`values`を結合して返します。
+    /// 最後のページかどうかは[`crate::models::Page::is_last_page`]で判定するため、
+    /// 途中のページが`maxResults`より少なくても`total`に達するまで取得を続けます。
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - クエリパラメータを含んでもよいエンドポイントパス
+    ///   （例: `/rest/api/3/project/TEST/version`）
+    pub async fn fetch_all_pages<T>(&self, endpoint: &str) -> Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let separator = if endpoint.contains('?') { '&' } else { '?' };
+        let mut values = Vec::new();
+        let mut start_at = 0u32;
+
+        loop {
+            let url = format!("{}{}startAt={}", endpoint, separator, start_at);
+            let page: crate::models::Page<T> = self.get(&url).await?;
+
+            let is_last = page.is_last_page();
+            start_at = page.next_start_at();
+            values.extend(page.values);
+
+            if is_last {
+                break;
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// JQLでIssueを検索する
+    ///
+    /// JQLは文字列または[`crate::JqlBuilder`]で指定できます。
+    /// `params`にフィールドが指定されていない場合は[`JiraConfig::default_search_fields`]を使用します。
+    /// `params`に並び順が指定されている場合、JQLのORDER BY句はその並び順で置き換えられます。
+    ///
+    /// エンドポイントは[`JiraClient::search_strategy`]に従って選択します。トークン方式の場合、
+    /// `total`はそのページまでの件数になり、2ページ目以降は
+    /// [`crate::models::SearchParams::next_page`]で前のページのトークンを引き継いで取得します
+    /// （トークン無しで`startAt`を指定するとエラーになります）。
     pub async fn search_issues(
+        &self,
+        jql: impl crate::jql::IntoJql,
+        params: crate::models::SearchParams,
+    ) -> Result<crate::models::SearchResult> {
+        let mut jql = jql.into_jql()?;
+        if !params.order_by.is_empty() {
+            jql = crate::jql::with_order_by(&jql, &params.order_by)?;
+        }
+
+        match self.search_strategy() {
+            crate::models::SearchStrategy::OffsetPaginated => {
+                self.post_offset_search(&jql, params).await
+            }
+            crate::models::SearchStrategy::TokenPaginated => {
+                let start_at = params.start_at.unwrap_or(0);
+                if start_at > 0 && params.next_page_token.is_none() {
+                    return Err(crate::Error::InvalidInput(
+                        "Token paginated search requires nextPageToken to fetch later pages"
+                            .to_string(),
+                    ));
+                }
+                let max_results = params.max_results;
+                let page = self.post_token_search(&jql, params).await?;
+                Ok(page.into_search_result(start_at, max_results))
+            }
+        }
+    }
+
+    /// JQLでIssueを検索する（トークン方式のページング）
+    ///
+    /// `/rest/api/3/search/jql`を使用します。このエンドポイントは`startAt`と`total`を返さないため、
+    /// 次のページは結果の[`crate::models::JqlSearchResult::next_page_token`]を
+    /// `params`に指定して取得します。
+    /// `params`にフィールドが指定されていない場合は[`JiraConfig::default_search_fields`]を使用します。
+    /// ページ間でIssueが重複・欠落しないよう、JQLにORDER BY句が無い場合は`ORDER BY key ASC`を付与します。
+    ///
+    /// [`JiraClient::detect_capabilities`]でオフセット方式のサーバーと判定済みの場合は
+    /// `/rest/api/3/search`を使用し、次ページの開始位置をトークンとして返します。
+    pub async fn search_issues_jql(
+        &self,
+        jql: &str,
+        params: crate::models::JqlSearchParams,
+    ) -> Result<crate::models::JqlSearchResult> {
+        let jql = crate::jql::with_stable_order(jql);
+        let offset_only = self.capabilities().is_some_and(|capabilities| {
+            capabilities.search_strategy == crate::models::SearchStrategy::OffsetPaginated
+        });
+
+        if offset_only {
+            let start_at = match params.next_page_token.as_deref() {
+                Some(token) => token.parse::<u32>().map_err(|_| {
+                    crate::Error::InvalidInput(format!("Invalid nextPageToken: {}", token))
+                })?,
+                None => 0,
+            };
+            let mut search_params = crate::models::SearchParams::new().start_at(start_at);
+            search_params.max_results = params.max_results;
+            search_params.fields = params.fields;
+
+            let page = self.post_offset_search(&jql, search_params).await?;
+            let has_more = page.has_more();
+            return Ok(crate::models::JqlSearchResult {
+                next_page_token: has_more.then(|| page.next_start_at().to_string()),
+                is_last: !has_more,
+                issues: page.issues,
+            });
+        }
+
+        let params = crate::models::SearchParams {
+            next_page_token: params.next_page_token,
+            max_results: params.max_results,
+            fields: params.fields,
+            ..Default::default()
+        };
+        self.post_token_search(&jql, params).await
+    }
+
+    /// オフセット方式の検索エンドポイントに1ページ分の検索を送信する
+    async fn post_offset_search(
         &self,
         jql: &str,
         params: crate::models::SearchParams,
     ) -> Result<crate::models::SearchResult> {
+        let endpoint = crate::models::SearchStrategy::OffsetPaginated.search_endpoint();
         let mut body = serde_json::json!({
             "jql": jql
         });
@@ -205,7 +924,10 @@ impl JiraClient {
         if let Some(max_results) = params.max_results {
             body["maxResults"] = max_results.into();
         }
-        if let Some(fields) = params.fields {
+        if let Some(fields) = params
+            .fields
+            .or_else(|| self.config.default_search_fields.clone())
+        {
             body["fields"] = fields.into();
         }
         if let Some(expand) = params.expand {
@@ -215,49 +937,337 @@ impl JiraClient {
             body["validateQuery"] = validate_query.into();
         }
 
-        // リクエストボディをログ出力
-        println!("=== JIRA API Request ===");
-        println!("URL: {}/rest/api/3/search", self.config.base_url);
-        println!("Request Body:");
-        if let Ok(pretty_body) = serde_json::to_string_pretty(&body) {
-            println!("{}", pretty_body);
-        } else {
-            println!("{:?}", body);
-        }
-        println!("========================");
-
-        self.post("/rest/api/3/search", &body).await
-    }
+        // JQLは検索語を含むことがあるため、リクエストボディはtraceレベルでのみ出力する
+        debug!(
+            endpoint,
+            start_at = ?params.start_at,
+            max_results = ?params.max_results,
+            "JIRA search request"
+        );
+        trace!(endpoint, body = %body, "JIRA search request body");
 
-    pub async fn get_projects(&self) -> Result<Vec<crate::models::Project>> {
-        self.get_projects_with_params(crate::models::ProjectParams::new())
-            .await
+        self.post(endpoint, &body).await
     }
 
-    pub async fn get_projects_with_params(
+    /// トークン方式の検索エンドポイントに1ページ分の検索を送信する
+    ///
+    /// このエンドポイントの`expand`はカンマ区切りの文字列で指定します。
+    async fn post_token_search(
         &self,
-        params: crate::models::ProjectParams,
-    ) -> Result<Vec<crate::models::Project>> {
-        let mut url = "/rest/api/3/project".to_string();
-        let mut query_params = Vec::new();
+        jql: &str,
+        params: crate::models::SearchParams,
+    ) -> Result<crate::models::JqlSearchResult> {
+        let endpoint = crate::models::SearchStrategy::TokenPaginated.search_endpoint();
+        let mut body = serde_json::json!({
+            "jql": jql
+        });
 
-        // クエリパラメータを構築
-        if let Some(expand) = params.expand {
-            query_params.push(format!("expand={}", expand.join(",")));
+        if let Some(max_results) = params.max_results {
+            body["maxResults"] = max_results.into();
         }
-        if let Some(recent) = params.recent {
-            query_params.push(format!("recent={}", recent));
+        if let Some(fields) = params
+            .fields
+            .or_else(|| self.config.default_search_fields.clone())
+        {
+            body["fields"] = fields.into();
         }
-        if let Some(properties) = params.properties {
-            query_params.push(format!("properties={}", properties.join(",")));
+        if let Some(expand) = params.expand {
+            body["expand"] = expand.join(",").into();
         }
-
-        if !query_params.is_empty() {
-            url.push('?');
-            url.push_str(&query_params.join("&"));
+        let has_token = params.next_page_token.is_some();
+        if let Some(token) = params.next_page_token {
+            body["nextPageToken"] = token.into();
         }
 
-        self.get(&url).await
+        debug!(
+            endpoint,
+            has_next_page_token = has_token,
+            max_results = ?params.max_results,
+            "JIRA search request"
+        );
+        trace!(endpoint, body = %body, "JIRA search request body");
+
+        self.post(endpoint, &body).await
+    }
+
+    /// JQLに一致するIssueを全ページ取得する
+    ///
+    /// 各ページはデフォルトの[`crate::RetryPolicy`]で再試行し、それでも失敗した場合はエラーを返します。
+    /// `params`の`maxResults`がページサイズになります。
+    pub async fn search_all_issues(
+        &self,
+        jql: impl crate::jql::IntoJql,
+        params: crate::models::SearchParams,
+    ) -> Result<Vec<crate::models::Issue>> {
+        let result = self
+            .search_all_issues_with_options(jql, params, crate::models::SearchAllOptions::new())
+            .await?;
+        Ok(result.issues)
+    }
+
+    /// JQLに一致するIssueをページごとに取得しながら1件ずつ返すストリームを作成する
+    ///
+    /// [`JiraClient::search_all_issues`]と異なり全件をメモリに溜めないため、
+    /// 大量のIssueを順に処理して破棄するエクスポートなどでメモリ使用量が一定に保たれます。
+    /// 次のページはストリームを読み進めたときに要求します。各ページはデフォルトの
+    /// [`crate::RetryPolicy`]で再試行し、それでも失敗した場合はエラーを1件返して終了します。
+    /// ORDER BY句が無い場合は`ORDER BY key ASC`を付与します。
+    pub fn search_issue_stream(
+        &self,
+        jql: &str,
+        params: crate::models::SearchParams,
+    ) -> impl Stream<Item = Result<crate::models::Issue>> + '_ {
+        self.paged_issue_stream(jql, params, PageErrorMode::RetryThenStop)
+    }
+
+    /// JQLに一致するIssueを`maxResults`単位で自動ページングしながら1件ずつ返すストリームを作成する
+    ///
+    /// [`JiraClient::search_issue_stream`]と異なりページの取得は再試行せず、失敗したページは
+    /// `Err`要素として返します。呼び出し側はそこで読むのをやめて中断するか、読み進めて継続できます。
+    /// 継続した場合、総件数が判明していれば失敗したページを飛ばして次のページを取得し、
+    /// 1ページ目で失敗した場合は総件数が分からないためそのまま終了します。
+    /// トークン方式の検索では失敗したページを飛ばせないため、どのページでもそのまま終了します。
+    /// ページの区切りは[`crate::IssueStream`]と同じく、`n`ページ目が`startAt = n * maxResults`に対応します。
+    /// ORDER BY句が無い場合は`ORDER BY key ASC`を付与します。
+    pub fn search_issues_stream(
+        &self,
+        jql: &str,
+        params: crate::models::SearchParams,
+    ) -> impl Stream<Item = Result<crate::models::Issue>> + '_ {
+        self.paged_issue_stream(jql, params, PageErrorMode::Continue)
+    }
+
+    /// ページ単位で検索しながらIssueを1件ずつ返すストリームを作成する
+    ///
+    /// ページの取得に失敗した場合の動作は`mode`で指定します。
+    fn paged_issue_stream(
+        &self,
+        jql: &str,
+        params: crate::models::SearchParams,
+        mode: PageErrorMode,
+    ) -> impl Stream<Item = Result<crate::models::Issue>> + '_ {
+        let jql = crate::jql::with_stable_order(jql);
+        let page_size = params.max_results.unwrap_or(DEFAULT_SEARCH_PAGE_SIZE);
+        let start_at = params.start_at.unwrap_or(0);
+        let first_page = params.start_at(start_at).max_results(page_size);
+        let (policy, can_skip) = match mode {
+            PageErrorMode::RetryThenStop => (crate::RetryPolicy::new(), false),
+            PageErrorMode::Continue => (
+                crate::RetryPolicy::no_retry(),
+                self.search_strategy() == crate::models::SearchStrategy::OffsetPaginated,
+            ),
+        };
+
+        // 状態は次ページの検索パラメータと判明済みの総件数（Noneの場合は取得完了）
+        let pages = stream::unfold(Some((first_page, None)), move |state| {
+            let jql = jql.clone();
+            let policy = policy.clone();
+            async move {
+                let (page_params, total): (crate::models::SearchParams, Option<u32>) = state?;
+
+                match self
+                    .search_page_with_retry(&jql, page_params.clone(), &policy)
+                    .await
+                {
+                    Ok(page) => {
+                        let next = page
+                            .has_more()
+                            .then(|| (page_params.next_page(&page), Some(page.total)));
+                        let issues: Vec<Result<crate::models::Issue>> =
+                            page.issues.into_iter().map(Ok).collect();
+                        Some((issues, next))
+                    }
+                    Err(e) => {
+                        let next_start_at = page_params.start_at.unwrap_or(0) + page_size;
+                        let next = total
+                            .filter(|&total| can_skip && next_start_at < total)
+                            .map(|total| (page_params.start_at(next_start_at), Some(total)));
+                        Some((vec![Err(e)], next))
+                    }
+                }
+            }
+        });
+
+        pages.flat_map(stream::iter)
+    }
+
+    /// 全プロジェクトで指定日時以降に更新されたIssueを取得する
+    ///
+    /// `updated >= "..." ORDER BY updated DESC`で検索し、全ページを取得して
+    /// 更新日時の新しい順に返します。「前回確認以降の変更」の一覧などに使用します。
+    /// JQLの日時は分単位のため、`since`の秒以下は切り捨てられます。
+    pub async fn recently_updated(
+        &self,
+        since: chrono::DateTime<chrono::Utc>,
+        params: crate::models::SearchParams,
+    ) -> Result<Vec<crate::models::Issue>> {
+        let jql = crate::JqlBuilder::new()
+            .updated_since(since)
+            .order_by("updated", crate::OrderDirection::Desc);
+        self.search_all_issues(jql, params).await
+    }
+
+    /// オプションを指定してJQLに一致するIssueを全ページ取得する
+    ///
+    /// 一時的なエラー（5xxなど）で失敗したページは`retry_policy`に従って再試行します
+    /// （429・503は送信時の再送のみで、`retry_policy`では再試行しません）。
+    /// `best_effort`が有効な場合、再試行しても取得できなかったページは飛ばして続行し、
+    /// その開始位置を[`crate::models::SearchAllResult::failed_offsets`]に記録します。
+    /// 件数の分からない最初のページが取得できない場合や、ページを飛ばせないトークン方式の検索では、
+    /// `best_effort`でもエラーを返します。
+    ///
+    /// `cancellation_token`がキャンセルされると、次のページを要求せずに
+    /// それまでに取得したIssueを`cancelled: true`で返します。
+    ///
+    /// 並び順が一定でないとページ間でIssueが重複・欠落するため、JQLにORDER BY句が無い場合は
+    /// `ORDER BY key ASC`を付与します。ORDER BY句がある場合はそのまま使用します。
+    pub async fn search_all_issues_with_options(
+        &self,
+        jql: impl crate::jql::IntoJql,
+        params: crate::models::SearchParams,
+        options: crate::models::SearchAllOptions,
+    ) -> Result<crate::models::SearchAllResult> {
+        let jql = crate::jql::with_stable_order(&jql.into_jql()?);
+        let page_size = params.max_results.unwrap_or(DEFAULT_SEARCH_PAGE_SIZE);
+        let can_skip = self.search_strategy() == crate::models::SearchStrategy::OffsetPaginated;
+        let start_at = params.start_at.unwrap_or(0);
+        let mut page_params = params.start_at(start_at).max_results(page_size);
+        let mut total: Option<u32> = None;
+        let mut result = crate::models::SearchAllResult::default();
+
+        loop {
+            if options
+                .cancellation_token
+                .as_ref()
+                .is_some_and(|token| token.is_cancelled())
+            {
+                result.cancelled = true;
+                break;
+            }
+
+            match self
+                .search_page_with_retry(&jql, page_params.clone(), &options.retry_policy)
+                .await
+            {
+                Ok(page) => {
+                    total = Some(page.total);
+                    let has_more = page.has_more();
+                    page_params = page_params.next_page(&page);
+                    result.issues.extend(page.issues);
+
+                    if !has_more {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    let Some(total) = total.filter(|_| options.best_effort && can_skip) else {
+                        return Err(e);
+                    };
+
+                    let start_at = page_params.start_at.unwrap_or(0);
+                    result.failed_offsets.push(start_at);
+                    page_params = page_params.start_at(start_at + page_size);
+                    if page_size == 0 || start_at + page_size >= total {
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// 1ページ分の検索を再試行ポリシーに従って実行する
+    ///
+    /// 429・503は送信時に[`JiraConfig::max_retries`]の範囲で再送済みのため、ここでは再試行しません。
+    /// 再試行の予算が設定されている場合、予算が尽きていればそれ以上再試行しません。
+    async fn search_page_with_retry(
+        &self,
+        jql: &str,
+        params: crate::models::SearchParams,
+        policy: &crate::RetryPolicy,
+    ) -> Result<crate::models::SearchResult> {
+        let mut attempt = 0;
+        loop {
+            match self.search_issues(jql, params.clone()).await {
+                Ok(page) => return Ok(page),
+                Err(e)
+                    if attempt < policy.max_retries
+                        && policy.is_retryable(&e)
+                        && !is_throttled(&e)
+                        && self.acquire_retry_token() =>
+                {
+                    tokio::time::sleep(policy.delay_for(attempt)).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// 再試行の予算からトークンを取得する（予算が無い場合は常に成功）
+    fn acquire_retry_token(&self) -> bool {
+        self.retry_budget
+            .as_ref()
+            .is_none_or(|budget| budget.try_acquire())
+    }
+
+    /// JQLに一致するIssueの件数のみを取得する
+    ///
+    /// `maxResults=0`で検索を実行し、レスポンスの`total`を返します。
+    /// Issue本体は取得しないため、ダッシュボードの件数表示などに適しています。
+    /// トークン方式の検索は総件数を返さないため、その場合は
+    /// `/rest/api/3/search/approximate-count`の件数（概算）を返します。
+    pub async fn count_matching(&self, jql: impl crate::jql::IntoJql) -> Result<u64> {
+        if self.search_strategy() == crate::models::SearchStrategy::TokenPaginated {
+            #[derive(serde::Deserialize)]
+            struct ApproximateCount {
+                count: u64,
+            }
+
+            let body = serde_json::json!({ "jql": jql.into_jql()? });
+            let result: ApproximateCount = self
+                .post("/rest/api/3/search/approximate-count", &body)
+                .await?;
+            return Ok(result.count);
+        }
+
+        let params = crate::models::SearchParams::new()
+            .start_at(0)
+            .max_results(0);
+        let search_result = self.search_issues(jql, params).await?;
+        Ok(search_result.total as u64)
+    }
+
+    pub async fn get_projects(&self) -> Result<Vec<crate::models::Project>> {
+        self.get_projects_with_params(crate::models::ProjectParams::new())
+            .await
+    }
+
+    pub async fn get_projects_with_params(
+        &self,
+        params: crate::models::ProjectParams,
+    ) -> Result<Vec<crate::models::Project>> {
+        let mut url = "/rest/api/3/project".to_string();
+        let mut query_params = Vec::new();
+
+        // クエリパラメータを構築
+        if let Some(expand) = params.expand {
+            query_params.push(format!("expand={}", expand.join(",")));
+        }
+        if let Some(recent) = params.recent {
+            query_params.push(format!("recent={}", recent));
+        }
+        if let Some(properties) = params.properties {
+            query_params.push(format!("properties={}", properties.join(",")));
+        }
+
+        if !query_params.is_empty() {
+            url.push('?');
+            url.push_str(&query_params.join("&"));
+        }
+
+        self.get(&url).await
     }
 
     /// JIRAの優先度一覧を取得する
@@ -289,7 +1299,7 @@ impl JiraClient {
     /// # }
     /// ```
     pub async fn get_priorities(&self) -> Result<Vec<crate::models::Priority>> {
-        self.get("/rest/api/3/priority").await
+        self.get_metadata("/rest/api/3/priority").await
     }
 
     /// JIRAの課題タイプ一覧を取得する
@@ -325,7 +1335,27 @@ impl JiraClient {
     /// # }
     /// ```
     pub async fn get_issue_types(&self) -> Result<Vec<crate::models::IssueType>> {
-        self.get("/rest/api/3/issuetype").await
+        self.get_metadata("/rest/api/3/issuetype").await
+    }
+
+    /// 指定プロジェクトで作成可能なIssueタイプ一覧を取得する
+    ///
+    /// `get_issue_types()`はインスタンス全体のIssueタイプを返すため、
+    /// 作成フォームなどプロジェクト単位で有効なタイプが必要な場合はこちらを使用します。
+    /// 結果はページングされているため、全ページを取得して結合します。
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - プロジェクトキーまたはプロジェクトID
+    pub async fn get_issue_types_for_project(
+        &self,
+        project_key: &str,
+    ) -> Result<Vec<crate::models::IssueType>> {
+        let endpoint = format!(
+            "/rest/api/3/issue/createmeta/{}/issuetypes",
+            urlencoding::encode(project_key)
+        );
+        self.fetch_all_pages(&endpoint).await
     }
 
     /// JIRAのフィールド一覧を取得する
@@ -361,7 +1391,44 @@ impl JiraClient {
     /// # }
     /// ```
     pub async fn get_fields(&self) -> Result<Vec<crate::models::Field>> {
-        self.get("/rest/api/3/field").await
+        self.get_metadata("/rest/api/3/field").await
+    }
+
+    /// カスタムフィールドの選択肢を全件取得する
+    ///
+    /// `context_id`を指定するとそのコンテキストの選択肢のみを返します。
+    /// `None`の場合はフィールドの全コンテキストを取得し、選択肢をコンテキスト順に
+    /// 重複を除いて返します。選択肢はページングされるため最後のページまで辿ります。
+    pub async fn get_custom_field_options(
+        &self,
+        field_id: &str,
+        context_id: Option<&str>,
+    ) -> Result<Vec<crate::models::FieldOption>> {
+        let field_id = urlencoding::encode(field_id);
+
+        let context_ids = match context_id {
+            Some(id) => vec![id.to_string()],
+            None => {
+                let endpoint = format!("/rest/api/3/field/{}/context", field_id);
+                let contexts: Vec<crate::models::FieldContext> =
+                    self.fetch_all_pages(&endpoint).await?;
+                contexts.into_iter().map(|c| c.id).collect()
+            }
+        };
+
+        let mut seen = std::collections::HashSet::new();
+        let mut options = Vec::new();
+        for context_id in context_ids {
+            let endpoint = format!(
+                "/rest/api/3/field/{}/context/{}/option",
+                field_id,
+                urlencoding::encode(&context_id)
+            );
+            let page: Vec<crate::models::FieldOption> = self.fetch_all_pages(&endpoint).await?;
+            options.extend(page.into_iter().filter(|o| seen.insert(o.id.clone())));
+        }
+
+        Ok(options)
     }
 
     /// JIRAのステータスカテゴリー一覧を取得する
@@ -397,7 +1464,7 @@ impl JiraClient {
     /// # }
     /// ```
     pub async fn get_status_categories(&self) -> Result<Vec<crate::models::StatusCategory>> {
-        self.get("/rest/api/3/statuscategory").await
+        self.get_metadata("/rest/api/3/statuscategory").await
     }
 
     /// JIRAでユーザーを検索する
@@ -441,6 +1508,423 @@ impl JiraClient {
         let url = format!("/rest/api/3/user/search?query={}", encoded_query);
         self.get(&url).await
     }
+
+    /// Issueプロパティの値を取得する
+    ///
+    /// `/rest/api/3/issue/{issueKey}/properties/{propertyKey}`のレスポンス
+    /// `{ key, value }`から`value`部分を返します。
+    pub async fn get_issue_property(
+        &self,
+        issue_key: &str,
+        property_key: &str,
+    ) -> Result<serde_json::Value> {
+        let url = format!(
+            "/rest/api/3/issue/{}/properties/{}",
+            urlencoding::encode(issue_key),
+            urlencoding::encode(property_key)
+        );
+        let mut property: serde_json::Value = self.get(&url).await?;
+        Ok(property
+            .get_mut("value")
+            .map(serde_json::Value::take)
+            .unwrap_or(serde_json::Value::Null))
+    }
+
+    /// Issueプロパティの値を設定する
+    ///
+    /// プロパティが存在しない場合は作成（201）、存在する場合は更新（200）されます。
+    pub async fn set_issue_property(
+        &self,
+        issue_key: &str,
+        property_key: &str,
+        value: &serde_json::Value,
+    ) -> Result<()> {
+        let url = format!(
+            "/rest/api/3/issue/{}/properties/{}",
+            urlencoding::encode(issue_key),
+            urlencoding::encode(property_key)
+        );
+        self.put_no_content(&url, value).await
+    }
+
+    /// サーバー情報（バージョン、デプロイ形態、ビルド番号）を取得する
+    pub async fn get_server_info(&self) -> Result<crate::models::ServerInfo> {
+        self.get("/rest/api/3/serverInfo").await
+    }
+
+    /// サーバー情報から利用可能な機能を判定し、クライアントに記録する
+    ///
+    /// 判定結果はクローンしたクライアント間で共有され、[`JiraClient::capabilities`]で参照できます。
+    pub async fn detect_capabilities(&self) -> Result<crate::models::ServerCapabilities> {
+        let capabilities = self.get_server_info().await?.capabilities();
+        *self.capabilities.write().unwrap() = Some(capabilities);
+        Ok(capabilities)
+    }
+
+    /// 検出済みの利用可能機能（未検出の場合はNone）
+    pub fn capabilities(&self) -> Option<crate::models::ServerCapabilities> {
+        *self.capabilities.read().unwrap()
+    }
+
+    /// Issue検索に使用するページング方式
+    ///
+    /// [`JiraClient::detect_capabilities`]で検出済みの場合はその結果を、
+    /// 未検出の場合はオフセット方式を返します。
+    pub fn search_strategy(&self) -> crate::models::SearchStrategy {
+        self.capabilities()
+            .map(|c| c.search_strategy)
+            .unwrap_or(crate::models::SearchStrategy::OffsetPaginated)
+    }
+
+    /// 認証ユーザーの情報を取得する
+    pub async fn get_myself(&self) -> Result<crate::models::User> {
+        self.get("/rest/api/3/myself").await
+    }
+
+    /// 接続設定が正しく動作するか確認する
+    ///
+    /// `/myself`（認証）、`/project`（読み取り権限）、`/serverInfo`（バージョン）を順に呼び出し、
+    /// 結果を`HealthReport`にまとめます。個々のチェックの失敗はエラーとして返さず、
+    /// レポートの`errors`に記録します。
+    pub async fn health_check(&self) -> Result<crate::models::HealthReport> {
+        let mut report = crate::models::HealthReport::default();
+
+        match self.get_myself().await {
+            Ok(user) => {
+                report.reachable = true;
+                report.authenticated = true;
+                report.account_display_name = Some(user.display_name);
+            }
+            Err(e) => record_health_error(&mut report, "myself", &e),
+        }
+
+        match self.get_projects().await {
+            Ok(_) => {
+                report.reachable = true;
+                report.can_read_projects = true;
+            }
+            Err(e) => record_health_error(&mut report, "project", &e),
+        }
+
+        match self.get_server_info().await {
+            Ok(info) => {
+                report.reachable = true;
+                report.jira_version = Some(info.version);
+            }
+            Err(e) => record_health_error(&mut report, "serverInfo", &e),
+        }
+
+        Ok(report)
+    }
+
+    /// Issueのコメントを全件取得する
+    ///
+    /// コメント一覧は`startAt`/`maxResults`/`total`形式でページングされるため、
+    /// [`Self::fetch_all_pages`]で`total`に達するまで取得します。
+    pub async fn get_comments(&self, issue_key: &str) -> Result<Vec<crate::models::Comment>> {
+        let endpoint = format!(
+            "/rest/api/3/issue/{}/comment",
+            urlencoding::encode(issue_key)
+        );
+        self.fetch_all_pages(&endpoint).await
+    }
+
+    /// Issueのワークログを全件取得する
+    ///
+    /// コメント一覧と同じく`total`に達するまでページを辿って取得します。
+    pub async fn get_worklogs(&self, issue_key: &str) -> Result<Vec<crate::models::Worklog>> {
+        let endpoint = format!(
+            "/rest/api/3/issue/{}/worklog",
+            urlencoding::encode(issue_key)
+        );
+        self.fetch_all_pages(&endpoint).await
+    }
+
+    /// Issueにコメントを追加する
+    ///
+    /// 本文はADF文書で指定し、変換せずにそのまま送信します。
+    /// プレーンテキストやMarkdownからは[`crate::text_to_adf`]・[`crate::markdown_to_adf`]で作成できます。
+    pub async fn add_comment(
+        &self,
+        issue_key: &str,
+        body: serde_json::Value,
+    ) -> Result<crate::models::Comment> {
+        self.add_comment_with_visibility(issue_key, body, None)
+            .await
+    }
+
+    /// 公開範囲を指定してIssueにコメントを追加する
+    ///
+    /// `visibility`を指定すると、そのロールまたはグループのメンバーのみが閲覧できます。
+    /// `None`の場合は`add_comment`と同じく制限なしで追加します。本文はADF文書で指定します。
+    /// 存在しないロールを指定した場合などの400エラーは、
+    /// [`crate::Error::jira_error_response`]でフィールドごとのエラーを取得できます。
+    pub async fn add_comment_with_visibility(
+        &self,
+        issue_key: &str,
+        body: serde_json::Value,
+        visibility: Option<crate::models::CommentVisibility>,
+    ) -> Result<crate::models::Comment> {
+        let url = format!(
+            "/rest/api/3/issue/{}/comment",
+            urlencoding::encode(issue_key)
+        );
+
+        let mut request_body = serde_json::json!({ "body": body });
+        if let Some(visibility) = visibility {
+            request_body["visibility"] = serde_json::to_value(visibility)?;
+        }
+
+        self.post(&url, &request_body).await
+    }
+
+    /// Issueを作成する
+    ///
+    /// 作成されたIssueのID・キー・URLを返します。
+    /// 必須フィールドの不足などでJIRAが400を返した場合は、レスポンスボディをそのまま載せた
+    /// `ApiError`を返します（[`crate::Error::jira_error_response`]でフィールドごとのエラーを取得できます）。
+    pub async fn create_issue(
+        &self,
+        input: crate::models::CreateIssueInput,
+    ) -> Result<crate::models::CreatedIssue> {
+        self.post("/rest/api/3/issue", &input.to_body()).await
+    }
+
+    /// 重複を確認してからIssueを作成する
+    ///
+    /// 通信エラー後の再送などで同じIssueが重複して作成されるのを防ぐため、
+    /// 先に`dedupe_jql`で検索し、一致するIssueがあれば作成せずにそのIssueを返します。
+    /// `dedupe_jql`には呼び出し側で付与した一意なラベルやフィールド値を指定してください
+    /// （例: `labels = "import-2024-0001"`）。
+    pub async fn create_issue_idempotent(
+        &self,
+        input: &crate::models::CreateIssueInput,
+        dedupe_jql: &str,
+    ) -> Result<crate::models::CreatedIssue> {
+        #[derive(serde::Deserialize)]
+        struct DedupeSearchResult {
+            issues: Vec<crate::models::CreatedIssue>,
+        }
+
+        let body = serde_json::json!({
+            "jql": dedupe_jql,
+            "maxResults": 1,
+            "fields": ["key"]
+        });
+        let existing: DedupeSearchResult = self
+            .post(self.search_strategy().search_endpoint(), &body)
+            .await?;
+        if let Some(issue) = existing.issues.into_iter().next() {
+            return Ok(issue);
+        }
+
+        self.create_issue(input.clone()).await
+    }
+
+    /// Issueで現在実行可能なトランジションの一覧を取得する
+    ///
+    /// 一覧はIssueの現在のステータスとワークフロー、ユーザーの権限によって変わります。
+    pub async fn get_transitions(&self, issue_key: &str) -> Result<Vec<crate::models::Transition>> {
+        #[derive(serde::Deserialize)]
+        struct TransitionsResponse {
+            transitions: Vec<crate::models::Transition>,
+        }
+
+        let url = format!(
+            "/rest/api/3/issue/{}/transitions",
+            urlencoding::encode(issue_key)
+        );
+        let response: TransitionsResponse = self.get(&url).await?;
+        Ok(response.transitions)
+    }
+
+    /// Issueのトランジションを実行する
+    ///
+    /// 存在しない、または現在のステータスから実行できないトランジションIDを指定した場合、
+    /// JIRAが返す400の[`crate::Error::ApiError`]をそのまま返します。
+    ///
+    /// # Arguments
+    ///
+    /// * `issue_key` - 対象のIssueキー
+    /// * `transition_id` - 実行するトランジションのID（[`JiraClient::get_transitions`]で取得）
+    /// * `fields` - トランジション画面で設定するフィールド（例: `{"resolution": {"name": "Done"}}`）
+    pub async fn transition_issue(
+        &self,
+        issue_key: &str,
+        transition_id: &str,
+        fields: Option<serde_json::Value>,
+    ) -> Result<()> {
+        let url = format!(
+            "/rest/api/3/issue/{}/transitions",
+            urlencoding::encode(issue_key)
+        );
+        let mut body = serde_json::json!({ "transition": { "id": transition_id } });
+        if let Some(fields) = fields {
+            body["fields"] = fields;
+        }
+        self.post_no_content(&url, &body).await
+    }
+
+    /// Issueを1件取得する
+    ///
+    /// キーはURLエンコードして`/rest/api/3/issue/{key}`に埋め込みます。
+    /// `params`で取得するフィールドや`expand`（`changelog`など）を指定できます。
+    /// 存在しないキーの場合は404の[`crate::Error::ApiError`]を返します。
+    pub async fn get_issue(
+        &self,
+        issue_key: &str,
+        params: crate::models::IssueParams,
+    ) -> Result<crate::models::Issue> {
+        self.get(&format!(
+            "/rest/api/3/issue/{}{}",
+            urlencoding::encode(issue_key),
+            params.to_query_string()
+        ))
+        .await
+    }
+
+    /// Issueの変更履歴（changelog）を全件取得する
+    ///
+    /// `expand=changelog`で埋め込まれるchangelogは件数が切り詰められるため、
+    /// 専用のエンドポイントで最後のページまで辿って取得します。
+    pub async fn get_issue_changelog(
+        &self,
+        issue_key: &str,
+    ) -> Result<Vec<crate::models::History>> {
+        let endpoint = format!(
+            "/rest/api/3/issue/{}/changelog",
+            urlencoding::encode(issue_key)
+        );
+        self.fetch_all_pages(&endpoint).await
+    }
+
+    /// Issueと解析済みの変更履歴をまとめて取得する
+    ///
+    /// `expand=changelog`でIssueを取得し、埋め込まれたchangelogが切り詰められている場合は
+    /// [`JiraClient::get_issue_changelog`]で全件を取得し直してから
+    /// [`crate::ChangelogParser`]で[`crate::IssueHistory`]に展開します。
+    /// 返すIssueの`changelog`も全件に置き換えます。
+    pub async fn get_issue_with_history(
+        &self,
+        issue_key: &str,
+    ) -> Result<(crate::models::Issue, Vec<crate::IssueHistory>)> {
+        let mut issue = self
+            .get_issue(issue_key, crate::models::IssueParams::new().changelog())
+            .await?;
+
+        let complete = issue
+            .changelog
+            .as_ref()
+            .is_some_and(|changelog| changelog.histories.len() as u32 >= changelog.total);
+        if !complete {
+            let histories = self.get_issue_changelog(&issue.key).await?;
+            let total = histories.len() as u32;
+            issue.changelog = Some(crate::models::Changelog {
+                start_at: 0,
+                max_results: total,
+                total,
+                histories,
+            });
+        }
+
+        let changelog_json = serde_json::to_value(&issue.changelog)?;
+        let histories =
+            crate::ChangelogParser::parse_changelog(&issue.id, &issue.key, &changelog_json)?;
+
+        Ok((issue, histories))
+    }
+
+    /// 複数のIssueをキーで一括取得する
+    ///
+    /// キーを最大50件ずつ`key IN (...)`で検索します。存在しないキーや権限の無いキーが
+    /// 含まれるとJIRAはバッチ全体をエラーにするため、検索に失敗したバッチは1件ずつ取得し直します。
+    /// 取得できたIssueは入力順に、取得できなかったキーはエラーとともに返します。
+    /// 検索には成功したが結果に含まれなかったキーは`NotFound`エラーになります。
+    pub async fn get_issues_by_keys(
+        &self,
+        keys: &[&str],
+    ) -> Result<(Vec<crate::models::Issue>, Vec<(String, crate::Error)>)> {
+        let mut unique_keys: Vec<&str> = Vec::new();
+        for key in keys {
+            if !unique_keys.contains(key) {
+                unique_keys.push(key);
+            }
+        }
+
+        let mut issues = Vec::new();
+        let mut failed = Vec::new();
+
+        for batch in unique_keys.chunks(ISSUES_BY_KEYS_BATCH_SIZE) {
+            let jql = crate::jql::in_list("key", batch);
+            let params = crate::models::SearchParams::new().max_results(batch.len() as u32);
+
+            match self.search_issues(jql, params).await {
+                Ok(result) => {
+                    for key in batch {
+                        match result.issues.iter().find(|issue| issue.key == *key) {
+                            Some(issue) => issues.push(issue.clone()),
+                            None => failed.push((
+                                key.to_string(),
+                                crate::Error::NotFound(format!("Issue {} not found", key)),
+                            )),
+                        }
+                    }
+                }
+                Err(_) => {
+                    let outcomes: Vec<(&str, Result<crate::models::Issue>)> = stream::iter(batch)
+                        .map(|key| async move {
+                            (
+                                *key,
+                                self.get_issue(key, crate::models::IssueParams::new()).await,
+                            )
+                        })
+                        .buffered(ISSUES_BY_KEYS_FALLBACK_CONCURRENCY)
+                        .collect()
+                        .await;
+                    for (key, outcome) in outcomes {
+                        match outcome {
+                            Ok(issue) => issues.push(issue),
+                            Err(e) => failed.push((key.to_string(), e)),
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok((issues, failed))
+    }
+
+    /// 複数Issueのトランジションを一括で実行する
+    ///
+    /// 一部のトランジションが失敗しても処理を中断せず、Issueキーごとの成否を
+    /// `BulkResult`に記録します。同時実行数は少数に制限され、結果は入力順に並びます。
+    ///
+    /// # Arguments
+    ///
+    /// * `transitions` - （Issueキー, トランジションID）のリスト
+    pub async fn transition_issues(
+        &self,
+        transitions: &[(String, String)],
+    ) -> Result<crate::models::BulkResult> {
+        let outcomes: Vec<(String, Result<()>)> = stream::iter(transitions)
+            .map(|(issue_key, transition_id)| async move {
+                let outcome = self.transition_issue(issue_key, transition_id, None).await;
+                (issue_key.clone(), outcome)
+            })
+            .buffered(BULK_TRANSITION_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut result = crate::models::BulkResult::new();
+        for (issue_key, outcome) in outcomes {
+            match outcome {
+                Ok(()) => result.add_success(issue_key),
+                Err(e) => result.add_failure(issue_key, e.to_string()),
+            }
+        }
+
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
@@ -536,7 +2020,83 @@ mod tests {
         }
     }
 
-    /// JiraConfig::from_env()が環境変数からBasic認証で設定を作成できることをテスト
+    /// JiraConfig::new()がhttp/https以外のURLを拒否することをテスト
+    ///
+    /// テスト内容:
+    /// - ftpスキームのURLがInvalidConfigurationになる
+    /// - スキームの無いURL（localhost:8080）がInvalidConfigurationになる
+    /// - httpのURLは受け付けられる
+    #[test]
+    fn test_jira_config_new_rejects_non_http_scheme() {
+        let auth = Auth::Basic {
+            username: "test@example.com".to_string(),
+            api_token: "test_token".to_string(),
+        };
+
+        for base_url in ["ftp://example.com", "localhost:8080"] {
+            let result = JiraConfig::new(base_url, auth.clone());
+            assert!(
+                matches!(result, Err(crate::error::Error::InvalidConfiguration(_))),
+                "{} should be rejected",
+                base_url
+            );
+        }
+
+        assert!(JiraConfig::new("http://localhost:8080", auth).is_ok());
+    }
+
+    /// JiraConfig::new()が空の認証情報を拒否することをテスト
+    ///
+    /// テスト内容:
+    /// - Basic認証の空のユーザー名がInvalidConfigurationになる
+    /// - Basic認証の空のAPIトークンがInvalidConfigurationになる
+    /// - Bearer認証の空のトークンがInvalidConfigurationになる
+    /// - パーソナルアクセストークンの空のトークンがInvalidConfigurationになる
+    #[test]
+    fn test_jira_config_new_rejects_empty_credentials() {
+        let base_url = "https://example.atlassian.net";
+        let invalid_auths = vec![
+            Auth::Basic {
+                username: "".to_string(),
+                api_token: "test_token".to_string(),
+            },
+            Auth::Basic {
+                username: "test@example.com".to_string(),
+                api_token: "".to_string(),
+            },
+            Auth::Bearer {
+                token: "   ".to_string(),
+            },
+            Auth::PersonalAccessToken {
+                token: "".to_string(),
+            },
+        ];
+
+        for auth in invalid_auths {
+            let result = JiraConfig::new(base_url, auth.clone());
+            assert!(
+                matches!(result, Err(crate::error::Error::InvalidConfiguration(_))),
+                "{:?} should be rejected",
+                auth
+            );
+        }
+    }
+
+    /// JiraConfig::new_unchecked()が検証を行わないことをテスト
+    #[test]
+    fn test_jira_config_new_unchecked_skips_validation() {
+        let config = JiraConfig::new_unchecked(
+            "localhost:8080",
+            Auth::Bearer {
+                token: "".to_string(),
+            },
+        );
+
+        assert_eq!(config.base_url, "localhost:8080");
+        assert!(config.validate().is_err());
+    }
+
+    /// JiraConfig::from_env()が環境変数からBasic認証で設定を作成できることをテスト
     ///
     /// テスト内容:
     /// - JIRA_URL, JIRA_USER, JIRA_API_TOKENが正しく読み込まれる
@@ -661,6 +2221,13 @@ mod tests {
                 username: "test@example.com".to_string(),
                 api_token: "test_token".to_string(),
             },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
         };
 
         // When: JiraClientを作成
@@ -672,6 +2239,389 @@ mod tests {
         assert_eq!(client.config().base_url, "https://example.atlassian.net");
     }
 
+    /// 接続プールの設定を指定してクライアントを作成し、並行リクエストを処理できることをテスト
+    ///
+    /// テスト内容:
+    /// - pool_max_idle_per_host / pool_idle_timeoutを指定してもJiraClientが作成される
+    /// - 設定は保存時に保持され、未指定の設定ファイルからも読み込める
+    /// - アイドル接続数より多い20件の並行リクエストがすべて成功する
+    #[tokio::test]
+    async fn test_jira_client_with_pool_settings() {
+        use futures::future::join_all;
+        use serde_json::json;
+        use std::time::Duration;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/priority"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(20)
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig::new(
+            mock_server.uri(),
+            Auth::Bearer {
+                token: "token".to_string(),
+            },
+        )
+        .unwrap()
+        .pool_max_idle_per_host(4)
+        .pool_idle_timeout(Duration::from_secs(30));
+
+        let restored: JiraConfig =
+            serde_json::from_value(serde_json::to_value(&config).unwrap()).unwrap();
+        assert_eq!(restored.pool_max_idle_per_host, Some(4));
+        assert_eq!(restored.pool_idle_timeout, Some(Duration::from_secs(30)));
+
+        let legacy: JiraConfig = serde_json::from_value(json!({
+            "base_url": "https://example.atlassian.net",
+            "auth": { "Bearer": { "token": "token" } }
+        }))
+        .unwrap();
+        assert_eq!(legacy.pool_max_idle_per_host, None);
+        assert_eq!(legacy.pool_idle_timeout, None);
+        assert_eq!(legacy.timeout, None);
+        assert_eq!(legacy.user_agent, None);
+        assert_eq!(legacy.default_search_fields, None);
+
+        let client = JiraClient::new(config).unwrap();
+        let results = join_all((0..20).map(|_| client.get_priorities())).await;
+        assert!(results.iter().all(|r| r.is_ok()));
+    }
+
+    /// タイムアウト設定が秒数として保存・読み込みできることをテスト
+    ///
+    /// テスト内容:
+    /// - timeout / connect_timeoutの値が秒数でシリアライズされる
+    /// - 整数・小数の秒数から読み込め、秒数以外の形式は拒否される
+    /// - 未設定の設定でもデフォルトのタイムアウトでJiraClientが作成される
+    #[test]
+    fn test_jira_config_timeouts_as_seconds() {
+        use serde_json::json;
+        use std::time::Duration;
+
+        let auth = Auth::Bearer {
+            token: "token".to_string(),
+        };
+        let config = JiraConfig::new_unchecked("https://example.atlassian.net", auth.clone())
+            .timeout(Duration::from_secs(30))
+            .connect_timeout(Duration::from_millis(1500));
+
+        let value = serde_json::to_value(&config).unwrap();
+        assert_eq!(value["timeout"], json!(30));
+        assert_eq!(value["connect_timeout"], json!(1.5));
+
+        let restored: JiraConfig = serde_json::from_value(value).unwrap();
+        assert_eq!(restored.timeout, Some(Duration::from_secs(30)));
+        assert_eq!(restored.connect_timeout, Some(Duration::from_millis(1500)));
+
+        let partial: JiraConfig = serde_json::from_value(json!({
+            "base_url": "https://example.atlassian.net",
+            "auth": { "Bearer": { "token": "token" } },
+            "pool_idle_timeout": 90,
+            "timeout": 10
+        }))
+        .unwrap();
+        assert_eq!(partial.pool_idle_timeout, Some(Duration::from_secs(90)));
+        assert_eq!(partial.timeout, Some(Duration::from_secs(10)));
+        assert_eq!(partial.connect_timeout, None);
+        assert_eq!(partial.max_retries, 3);
+
+        for timeout in [json!(-1.0), json!({ "secs": 90, "nanos": 0 })] {
+            let invalid = serde_json::from_value::<JiraConfig>(json!({
+                "base_url": "https://example.atlassian.net",
+                "auth": { "Bearer": { "token": "token" } },
+                "timeout": timeout
+            }));
+            assert!(invalid.is_err(), "{:?}", invalid);
+        }
+
+        let client = JiraClient::new(JiraConfig::new_unchecked(
+            "https://example.atlassian.net",
+            auth,
+        ));
+        assert!(client.is_ok());
+        assert_eq!(DEFAULT_TIMEOUT, Duration::from_secs(30));
+    }
+
+    /// 429を受け取った場合にRetry-Afterに従って再送し、最終的に成功することをテスト
+    ///
+    /// テスト内容:
+    /// - 429を2回返した後に200を返すサーバーへのリクエストが成功する
+    /// - Retry-Afterの秒数だけ待ってから再送する
+    /// - 再送の上限を超えて429が続くとRateLimitedエラーになる
+    #[tokio::test]
+    async fn test_rate_limited_request_is_retried() {
+        use serde_json::json;
+        use std::time::{Duration, Instant};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Given: 429を2回（Retry-After: 1秒、0秒）返した後に200を返すモックサーバー
+        let mock_server = MockServer::start().await;
+        for retry_after in ["1", "0"] {
+            Mock::given(method("GET"))
+                .and(path("/rest/api/3/priority"))
+                .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", retry_after))
+                .up_to_n_times(1)
+                .mount(&mock_server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/priority"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .mount(&mock_server)
+            .await;
+
+        let auth = Auth::Bearer {
+            token: "token".to_string(),
+        };
+        let client =
+            JiraClient::new(JiraConfig::new_unchecked(mock_server.uri(), auth.clone())).unwrap();
+
+        // When: リクエストを送信
+        let started = Instant::now();
+        let result = client.get_priorities().await;
+
+        // Then: 2回再送して成功する
+        assert!(result.is_ok(), "unexpected error: {:?}", result);
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 3);
+
+        // Given: 常に429を返すサーバーと、再送1回までの設定
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/priority"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .mount(&mock_server)
+            .await;
+        let config = JiraConfig::new_unchecked(mock_server.uri(), auth).max_retries(1);
+        let client = JiraClient::new(config).unwrap();
+
+        // When/Then: 上限を超えるとRateLimitedになる
+        let error = client.get_priorities().await.unwrap_err();
+        assert!(
+            matches!(
+                error,
+                crate::Error::RateLimited {
+                    retry_after: Some(Duration::ZERO)
+                }
+            ),
+            "unexpected error: {:?}",
+            error
+        );
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+    }
+
+    /// 429・503の再送が送信時の1か所でのみ行われることをテスト
+    ///
+    /// テスト内容:
+    /// - Retry-Afterが上限を超える429は待機せずにRateLimitedになる
+    /// - 全件検索で503が続くページは、max_retriesの再送のみでRetryPolicyでは再試行されない
+    #[tokio::test]
+    async fn test_throttled_request_is_retried_once_per_layer() {
+        use crate::models::{SearchAllOptions, SearchParams};
+        use std::time::{Duration, Instant};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let auth = Auth::Bearer {
+            token: "token".to_string(),
+        };
+
+        // Given: 1時間後の再送を指示する429を返すサーバー
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/priority"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "3600"))
+            .mount(&mock_server)
+            .await;
+        let client =
+            JiraClient::new(JiraConfig::new_unchecked(mock_server.uri(), auth.clone())).unwrap();
+
+        // When/Then: 待機せずに指示された待機時間とともにRateLimitedになる
+        let started = Instant::now();
+        let error = client.get_priorities().await.unwrap_err();
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(
+            matches!(
+                error,
+                crate::Error::RateLimited {
+                    retry_after: Some(wait)
+                } if wait == Duration::from_secs(3600)
+            ),
+            "unexpected error: {:?}",
+            error
+        );
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+
+        // Given: 常に503を返す検索エンドポイントと、再送1回までの設定
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "0"))
+            .mount(&mock_server)
+            .await;
+        let config = JiraConfig::new_unchecked(mock_server.uri(), auth).max_retries(1);
+        let client = JiraClient::new(config).unwrap();
+
+        // When: 再試行ポリシーを指定して全件検索
+        let options = SearchAllOptions::new()
+            .retry_policy(crate::RetryPolicy::new().initial_backoff(Duration::ZERO));
+        let result = client
+            .search_all_issues_with_options("project = TEST", SearchParams::new(), options)
+            .await;
+
+        // Then: 最初の送信と1回の再送のみでエラーになる
+        assert!(matches!(
+            result,
+            Err(crate::Error::ApiError { status: 503, .. })
+        ));
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+    }
+
+    /// Retry-Afterヘッダーの秒数とHTTP日付を待機時間に変換できることをテスト
+    #[test]
+    fn test_retry_after_header() {
+        use chrono::{TimeZone, Utc};
+        use std::time::Duration;
+
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let headers = |value: &str| {
+            let mut headers = header::HeaderMap::new();
+            headers.insert(header::RETRY_AFTER, value.parse().unwrap());
+            headers
+        };
+
+        assert_eq!(
+            retry_after(&headers("120"), now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            retry_after(&headers("Mon, 01 Jan 2024 00:00:30 GMT"), now),
+            Some(Duration::from_secs(30))
+        );
+        // 過去の日付は待機なし
+        assert_eq!(
+            retry_after(&headers("Sun, 31 Dec 2023 23:59:00 GMT"), now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(retry_after(&headers("soon"), now), None);
+        assert_eq!(retry_after(&header::HeaderMap::new(), now), None);
+    }
+
+    /// タイムアウトと接続失敗がそれぞれTimeout・Networkエラーに分類されることをテスト
+    ///
+    /// テスト内容:
+    /// - 応答の遅いサーバーへのリクエストが短いタイムアウトでTimeoutエラーになる
+    /// - 待ち受けていないポートへのリクエストがNetworkエラーになる
+    /// - どちらも再試行の対象になる
+    #[tokio::test]
+    async fn test_timeout_and_network_errors_are_classified() {
+        use crate::RetryPolicy;
+        use serde_json::json;
+        use std::time::Duration;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/priority"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!([]))
+                    .set_delay(Duration::from_secs(2)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let auth = Auth::Basic {
+            username: "test".to_string(),
+            api_token: "token".to_string(),
+        };
+        let config = JiraConfig::new_unchecked(mock_server.uri(), auth.clone())
+            .timeout(Duration::from_millis(50));
+        let client = JiraClient::new(config).unwrap();
+
+        let timeout_error = client.get_priorities().await.unwrap_err();
+        assert!(
+            matches!(timeout_error, crate::Error::Timeout(_)),
+            "unexpected error: {:?}",
+            timeout_error
+        );
+
+        // 一度確保したポートを解放し、接続を拒否させる
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let config = JiraConfig::new_unchecked(format!("http://127.0.0.1:{}", port), auth);
+        let client = JiraClient::new(config).unwrap();
+
+        let network_error = client.get_priorities().await.unwrap_err();
+        assert!(
+            matches!(network_error, crate::Error::Network(_)),
+            "unexpected error: {:?}",
+            network_error
+        );
+
+        let policy = RetryPolicy::new();
+        assert!(policy.is_retryable(&timeout_error));
+        assert!(policy.is_retryable(&network_error));
+        assert!(timeout_error.is_retryable());
+        assert!(network_error.is_retryable());
+    }
+
+    /// 設定したUser-Agentが送信され、未設定の場合はデフォルト値が送信されることをテスト
+    ///
+    /// テスト内容:
+    /// - JiraConfig::user_agent()で指定した値がUser-Agentヘッダーで送信される
+    /// - 未指定の場合はDEFAULT_USER_AGENTが送信される
+    #[tokio::test]
+    async fn test_user_agent_header() {
+        use serde_json::json;
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/priority"))
+            .and(header("user-agent", "ticket-viewer2/1.2.3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/priority"))
+            .and(header("user-agent", DEFAULT_USER_AGENT))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let auth = Auth::Basic {
+            username: "test".to_string(),
+            api_token: "token".to_string(),
+        };
+
+        let config = JiraConfig::new_unchecked(mock_server.uri(), auth.clone())
+            .user_agent("ticket-viewer2/1.2.3");
+        let client = JiraClient::new(config).unwrap();
+        client.get_priorities().await.unwrap();
+
+        let client = JiraClient::new(JiraConfig::new_unchecked(mock_server.uri(), auth)).unwrap();
+        client.get_priorities().await.unwrap();
+
+        assert!(DEFAULT_USER_AGENT.starts_with("jira-api/"));
+    }
+
     /// JiraClient::new()がBearer認証で正常にクライアントを作成できることをテスト
     ///
     /// テスト内容:
@@ -685,6 +2635,13 @@ mod tests {
             auth: Auth::Bearer {
                 token: "bearer_token_123".to_string(),
             },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
         };
 
         // When: JiraClientを作成
@@ -694,6 +2651,55 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    /// パーソナルアクセストークン認証でBearer形式のヘッダーが送信されることをテスト
+    ///
+    /// テスト内容:
+    /// - Authorizationヘッダーが`Bearer <token>`になる
+    /// - Basic認証のヘッダーとは異なる値になる
+    #[tokio::test]
+    async fn test_personal_access_token_sends_bearer_header() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/2/myself"))
+            .and(header("Authorization", "Bearer pat_token_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::PersonalAccessToken {
+                token: "pat_token_123".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+        let client = JiraClient::new(config).unwrap();
+
+        let result: Result<serde_json::Value> = client.get("/rest/api/2/myself").await;
+        assert!(result.is_ok());
+
+        // 同じトークンをユーザー名が空のBasic認証で渡した場合とは異なるヘッダーになる
+        let requests = mock_server.received_requests().await.unwrap();
+        let sent = requests[0].headers.get("authorization").unwrap();
+        let basic = format!(
+            "Basic {}",
+            base64::engine::general_purpose::STANDARD.encode(":pat_token_123")
+        );
+        assert_eq!(sent.to_str().unwrap(), "Bearer pat_token_123");
+        assert_ne!(sent.to_str().unwrap(), basic);
+    }
+
     /// JiraClientのget()メソッドが正常にHTTP GETリクエストを実行できることをテスト
     ///
     /// テスト内容:
@@ -731,6 +2737,13 @@ mod tests {
                 username: "test@example.com".to_string(),
                 api_token: "test_token".to_string(),
             },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
         };
 
         let client = JiraClient::new(config).unwrap();
@@ -771,6 +2784,13 @@ mod tests {
                 username: "test@example.com".to_string(),
                 api_token: "test_token".to_string(),
             },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
         };
 
         let client = JiraClient::new(config).unwrap();
@@ -798,6 +2818,7 @@ mod tests {
     #[tokio::test]
     async fn test_search_issues_success() {
         use crate::models::SearchParams;
+        use crate::testing::{mock_client, sample_issue_json, sample_search_response};
         use serde_json::json;
         use wiremock::matchers::{body_json, method, path};
         use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -805,43 +2826,7 @@ mod tests {
         // Given: モックサーバーを起動
         let mock_server = MockServer::start().await;
 
-        let response_body = json!({
-            "startAt": 0,
-            "maxResults": 50,
-            "total": 1,
-            "issues": [{
-                "id": "10000",
-                "key": "TEST-1",
-                "self": "https://example.atlassian.net/rest/api/3/issue/10000",
-                "fields": {
-                    "summary": "Test Issue",
-                    "issuetype": {
-                        "id": "1",
-                        "name": "Bug",
-                        "self": "https://example.atlassian.net/rest/api/3/issuetype/1"
-                    },
-                    "status": {
-                        "id": "1",
-                        "name": "To Do",
-                        "self": "https://example.atlassian.net/rest/api/3/status/1",
-                        "statusCategory": {
-                            "id": 2,
-                            "key": "new",
-                            "name": "To Do",
-                            "colorName": "blue-gray"
-                        }
-                    },
-                    "reporter": {
-                        "accountId": "557058:f58131cb",
-                        "displayName": "Test User",
-                        "emailAddress": "test@example.com",
-                        "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:f58131cb"
-                    },
-                    "created": "2024-01-01T00:00:00.000Z",
-                    "updated": "2024-01-02T00:00:00.000Z"
-                }
-            }]
-        });
+        let response_body = sample_search_response(vec![sample_issue_json("10000", "TEST-1")]);
 
         Mock::given(method("POST"))
             .and(path("/rest/api/3/search"))
@@ -854,15 +2839,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let config = JiraConfig {
-            base_url: mock_server.uri(),
-            auth: Auth::Basic {
-                username: "test@example.com".to_string(),
-                api_token: "test_token".to_string(),
-            },
-        };
-
-        let client = JiraClient::new(config).unwrap();
+        let client = mock_client(&mock_server);
         let params = SearchParams::new().start_at(0).max_results(50);
 
         // When: 検索を実行
@@ -878,45 +2855,221 @@ mod tests {
         assert_eq!(search_result.issues[0].key, "TEST-1");
     }
 
-    /// search_issues()が複雑なパラメータ（fields, expand, validateQuery）で正しく動作することをテスト
+    /// search_issues_jql()がnextPageTokenで次のページを取得できることをテスト
     ///
     /// テスト内容:
-    /// - fieldsパラメータが正しくリクエストボディに設定される
-    /// - expandパラメータが正しくリクエストボディに設定される
-    /// - validateQueryフラグが正しくリクエストボディに設定される
+    /// - 1ページ目はnextPageToken無しでPOST /rest/api/3/search/jqlに送信される
+    /// - 返されたnextPageTokenを指定すると2ページ目が取得できる
+    /// - 最後のページではisLastがtrueになり、has_more()がfalseになる
     #[tokio::test]
-    async fn test_search_issues_with_params() {
-        use crate::models::SearchParams;
+    async fn test_search_issues_jql_token_paging() {
+        use crate::models::JqlSearchParams;
+        use crate::testing::{mock_client, sample_issue_json};
         use serde_json::json;
         use wiremock::matchers::{body_json, method, path};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
-        // Given: フィールド指定とexpandを含む検索
+        // Given: 2ページ分のレスポンスを返すモックサーバー
         let mock_server = MockServer::start().await;
 
         Mock::given(method("POST"))
-            .and(path("/rest/api/3/search"))
+            .and(path("/rest/api/3/search/jql"))
             .and(body_json(json!({
-                "jql": "assignee = currentUser()",
-                "fields": ["summary", "status", "assignee"],
-                "expand": ["changelog"],
-                "validateQuery": true
+                "jql": "project = TEST ORDER BY key ASC",
+                "maxResults": 2
             })))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "startAt": 0,
-                "maxResults": 50,
-                "total": 0,
-                "issues": []
+                "issues": [
+                    sample_issue_json("10001", "TEST-1"),
+                    sample_issue_json("10002", "TEST-2")
+                ],
+                "nextPageToken": "page-2",
+                "isLast": false
             })))
             .mount(&mock_server)
             .await;
 
-        let config = JiraConfig {
-            base_url: mock_server.uri(),
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search/jql"))
+            .and(body_json(json!({
+                "jql": "project = TEST ORDER BY key ASC",
+                "maxResults": 2,
+                "nextPageToken": "page-2"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "issues": [sample_issue_json("10003", "TEST-3")],
+                "isLast": true
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(&mock_server);
+
+        // When: トークンを辿って全ページを取得
+        let mut keys = Vec::new();
+        let mut params = JqlSearchParams::new().max_results(2);
+        let mut pages = 0;
+        loop {
+            let page = client
+                .search_issues_jql("project = TEST", params.clone())
+                .await
+                .unwrap();
+            pages += 1;
+            keys.extend(page.issues.iter().map(|issue| issue.key.clone()));
+
+            if !page.has_more() {
+                assert!(page.is_last);
+                assert!(page.next_page_token.is_none());
+                break;
+            }
+            params = params.next_page_token(page.next_page_token.unwrap());
+        }
+
+        // Then: 2ページで全Issueが取得できる
+        assert_eq!(pages, 2);
+        assert_eq!(keys, vec!["TEST-1", "TEST-2", "TEST-3"]);
+    }
+
+    /// オフセット方式のサーバーと判定済みの場合、search_issues_jql()が/rest/api/3/searchを使用することをテスト
+    ///
+    /// テスト内容:
+    /// - ORDER BY句の無いJQLに`ORDER BY key ASC`が付与される
+    /// - 次ページの開始位置がトークンとして返され、指定するとstartAtとして送信される
+    /// - 不正なトークンはInvalidInputエラーになる
+    #[tokio::test]
+    async fn test_search_issues_jql_offset_fallback() {
+        use crate::models::JqlSearchParams;
+        use crate::testing::{mock_client, sample_issue_json};
+        use serde_json::json;
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Given: Serverのサーバー情報と、2ページ分のオフセット方式のレスポンス
+        let mock_server = MockServer::start().await;
+        mount_server_info(&mock_server, "Server").await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_json(json!({
+                "jql": "project = TEST ORDER BY key ASC",
+                "startAt": 0,
+                "maxResults": 2
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 0,
+                "maxResults": 2,
+                "total": 3,
+                "issues": [
+                    sample_issue_json("10001", "TEST-1"),
+                    sample_issue_json("10002", "TEST-2")
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_json(json!({
+                "jql": "project = TEST ORDER BY key ASC",
+                "startAt": 2,
+                "maxResults": 2
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 2,
+                "maxResults": 2,
+                "total": 3,
+                "issues": [sample_issue_json("10003", "TEST-3")]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(&mock_server);
+        client.detect_capabilities().await.unwrap();
+
+        // When: トークンを辿って全ページを取得
+        let first = client
+            .search_issues_jql("project = TEST", JqlSearchParams::new().max_results(2))
+            .await
+            .unwrap();
+        assert!(first.has_more());
+        assert_eq!(first.next_page_token.as_deref(), Some("2"));
+
+        let params = JqlSearchParams::new()
+            .max_results(2)
+            .next_page_token(first.next_page_token.clone().unwrap());
+        let second = client
+            .search_issues_jql("project = TEST", params)
+            .await
+            .unwrap();
+
+        // Then: 2ページ目が最後のページになる
+        assert!(second.is_last);
+        assert!(!second.has_more());
+        let keys: Vec<&str> = first
+            .issues
+            .iter()
+            .chain(&second.issues)
+            .map(|issue| issue.key.as_str())
+            .collect();
+        assert_eq!(keys, vec!["TEST-1", "TEST-2", "TEST-3"]);
+
+        let result = client
+            .search_issues_jql(
+                "project = TEST",
+                JqlSearchParams::new().next_page_token("not-a-number"),
+            )
+            .await;
+        assert!(matches!(result, Err(crate::Error::InvalidInput(_))));
+    }
+
+    /// search_issues()が複雑なパラメータ（fields, expand, validateQuery）で正しく動作することをテスト
+    ///
+    /// テスト内容:
+    /// - fieldsパラメータが正しくリクエストボディに設定される
+    /// - expandパラメータが正しくリクエストボディに設定される
+    /// - validateQueryフラグが正しくリクエストボディに設定される
+    #[tokio::test]
+    async fn test_search_issues_with_params() {
+        use crate::models::SearchParams;
+        use serde_json::json;
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Given: フィールド指定とexpandを含む検索
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_json(json!({
+                "jql": "assignee = currentUser()",
+                "fields": ["summary", "status", "assignee"],
+                "expand": ["changelog"],
+                "validateQuery": true
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 0,
+                "maxResults": 50,
+                "total": 0,
+                "issues": []
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
             auth: Auth::Basic {
                 username: "test@example.com".to_string(),
                 api_token: "test_token".to_string(),
             },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
         };
 
         let client = JiraClient::new(config).unwrap();
@@ -941,44 +3094,27 @@ mod tests {
         assert_eq!(search_result.issues.len(), 0);
     }
 
-    /// get_projects()が正常にプロジェクト一覧を取得できることをテスト
+    /// 不正なJSONレスポンスがget/postの両方で同じエラー種別になることをテスト
     ///
     /// テスト内容:
-    /// - GET /rest/api/3/projectエンドポイントに正しいリクエストが送信される
-    /// - レスポンスがVec<Project>に正しくデシリアライズされる
-    /// - 各プロジェクトの基本プロパティ（key, name）が正しく設定される
+    /// - GETリクエストで200の不正なボディがDeserializationErrorになる
+    /// - POSTリクエストで200の不正なボディがDeserializationErrorになる
     #[tokio::test]
-    async fn test_get_projects_success() {
-        use serde_json::json;
+    async fn test_malformed_response_maps_to_deserialization_error() {
         use wiremock::matchers::{method, path};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
-        // Given: プロジェクト一覧を返すモックサーバー
         let mock_server = MockServer::start().await;
 
-        let response_body = json!([
-            {
-                "id": "10000",
-                "key": "TEST",
-                "name": "Test Project",
-                "self": "https://example.atlassian.net/rest/api/3/project/10000",
-                "description": "This is a test project",
-                "projectTypeKey": "software",
-                "simplified": false
-            },
-            {
-                "id": "10001",
-                "key": "DEMO",
-                "name": "Demo Project",
-                "self": "https://example.atlassian.net/rest/api/3/project/10001",
-                "projectTypeKey": "business",
-                "simplified": true
-            }
-        ]);
-
         Mock::given(method("GET"))
             .and(path("/rest/api/3/project"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+            .respond_with(ResponseTemplate::new(200).set_body_string("not json"))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("{ invalid"))
             .mount(&mock_server)
             .await;
 
@@ -988,40 +3124,90 @@ mod tests {
                 username: "test@example.com".to_string(),
                 api_token: "test_token".to_string(),
             },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
         };
 
         let client = JiraClient::new(config).unwrap();
 
-        // When: プロジェクト一覧を取得
-        let result = client.get_projects().await;
+        let get_result = client.get_projects().await;
+        assert!(matches!(
+            get_result,
+            Err(crate::error::Error::DeserializationError(_))
+        ));
 
-        // Then: 成功し、プロジェクトリストが返る
-        assert!(result.is_ok());
-        let projects = result.unwrap();
-        assert_eq!(projects.len(), 2);
-        assert_eq!(projects[0].key, "TEST");
-        assert_eq!(projects[0].name, "Test Project");
-        assert_eq!(projects[1].key, "DEMO");
-        assert_eq!(projects[1].name, "Demo Project");
+        let post_result = client
+            .search_issues("project = TEST", crate::models::SearchParams::new())
+            .await;
+        assert!(matches!(
+            post_result,
+            Err(crate::error::Error::DeserializationError(_))
+        ));
     }
 
-    /// get_projects()が権限エラーを正しく処理できることをテスト
+    /// renderedFieldsを指定した検索でHTMLが取得できることをテスト
     ///
     /// テスト内容:
-    /// - 403 Forbiddenレスポンスが正しくApiErrorに変換される
-    /// - エラーメッセージが正しく抽出される
-    /// - ステータスコードが正しく保持される
+    /// - リクエストボディのexpandにrenderedFieldsが含まれる
+    /// - レスポンスのrenderedFieldsがIssueにデシリアライズされる
+    /// - rendered_description()でレンダリング済みHTMLを参照できる
     #[tokio::test]
-    async fn test_get_projects_error() {
-        use wiremock::matchers::{method, path};
+    async fn test_search_issues_with_rendered_fields() {
+        use serde_json::json;
+        use wiremock::matchers::{body_partial_json, method, path};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
-        // Given: エラーを返すモックサーバー
         let mock_server = MockServer::start().await;
 
-        Mock::given(method("GET"))
-            .and(path("/rest/api/3/project"))
-            .respond_with(ResponseTemplate::new(403).set_body_string("Insufficient permissions"))
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_partial_json(json!({ "expand": ["renderedFields"] })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 0,
+                "maxResults": 50,
+                "total": 1,
+                "issues": [{
+                    "id": "10000",
+                    "key": "TEST-1",
+                    "self": "https://example.atlassian.net/rest/api/3/issue/10000",
+                    "fields": {
+                        "summary": "Test Issue",
+                        "issuetype": {
+                            "id": "1",
+                            "name": "Bug",
+                            "self": "https://example.atlassian.net/rest/api/3/issuetype/1"
+                        },
+                        "status": {
+                            "id": "1",
+                            "name": "To Do",
+                            "self": "https://example.atlassian.net/rest/api/3/status/1",
+                            "statusCategory": {
+                                "id": 2,
+                                "key": "new",
+                                "name": "To Do",
+                                "colorName": "blue-gray"
+                            }
+                        },
+                        "reporter": {
+                            "accountId": "557058:f58131cb",
+                            "displayName": "Test User",
+                            "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:f58131cb"
+                        },
+                        "created": "2024-01-01T00:00:00.000Z",
+                        "updated": "2024-01-02T00:00:00.000Z"
+                    },
+                    "renderedFields": {
+                        "description": "<p>Rendered <b>HTML</b></p>",
+                        "environment": null
+                    }
+                }]
+            })))
+            .expect(1)
             .mount(&mock_server)
             .await;
 
@@ -1031,183 +3217,196 @@ mod tests {
                 username: "test@example.com".to_string(),
                 api_token: "test_token".to_string(),
             },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
         };
 
         let client = JiraClient::new(config).unwrap();
+        let params = crate::models::SearchParams::new().rendered_fields();
+        let result = client
+            .search_issues("project = TEST", params)
+            .await
+            .unwrap();
 
-        // When: プロジェクト一覧を取得
-        let result = client.get_projects().await;
-
-        // Then: エラーが返される
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            crate::error::Error::ApiError { status, message } => {
-                assert_eq!(status, 403);
-                assert_eq!(message, "Insufficient permissions");
-            }
-            _ => panic!("Expected ApiError"),
-        }
+        let issue = &result.issues[0];
+        assert!(issue.rendered_fields.is_some());
+        assert_eq!(
+            issue.rendered_description(),
+            Some("<p>Rendered <b>HTML</b></p>")
+        );
     }
 
-    /// get_projects_with_params()がexpandパラメータで詳細情報付きプロジェクト取得できることをテスト
+    /// SearchParams::order_by()の並び順がJQLに付与されることをテスト
     ///
     /// テスト内容:
-    /// - expandクエリパラメータが正しくURLに追加される
-    /// - 複数のexpand値がカンマ区切りで結合される
-    /// - GET /rest/api/3/project?expand=lead,descriptionが正しく送信される
+    /// - ORDER BYの無いJQLに`ORDER BY created DESC`が付与される
+    /// - JQLに既存のORDER BYがある場合は重複せずに置き換えられる
     #[tokio::test]
-    async fn test_get_projects_with_expand() {
-        use crate::models::ProjectParams;
-        use serde_json::json;
-        use wiremock::matchers::{method, path, query_param};
+    async fn test_search_issues_with_order_by() {
+        use crate::OrderDirection;
+        use crate::models::SearchParams;
+        use crate::testing::{mock_client, sample_search_response};
+        use wiremock::matchers::{method, path};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
-        // Given: expandパラメータ付きのモックサーバー
         let mock_server = MockServer::start().await;
 
-        let response_body = json!([{
-            "id": "10000",
-            "key": "TEST",
-            "name": "Test Project with Details",
-            "self": "https://example.atlassian.net/rest/api/3/project/10000",
-            "description": "Detailed test project description",
-            "lead": {
-                "accountId": "557058:f58131cb",
-                "displayName": "Project Lead",
-                "emailAddress": "lead@example.com",
-                "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:f58131cb"
-            }
-        }]);
-
-        Mock::given(method("GET"))
-            .and(path("/rest/api/3/project"))
-            .and(query_param("expand", "lead,description"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(sample_search_response(Vec::new())),
+            )
             .mount(&mock_server)
             .await;
 
-        let config = JiraConfig {
-            base_url: mock_server.uri(),
-            auth: Auth::Basic {
-                username: "test@example.com".to_string(),
-                api_token: "test_token".to_string(),
-            },
-        };
-
-        let client = JiraClient::new(config).unwrap();
-        let params =
-            ProjectParams::new().expand(vec!["lead".to_string(), "description".to_string()]);
-
-        // When: expandパラメータ付きでプロジェクト取得
-        let result = client.get_projects_with_params(params).await;
+        let client = mock_client(&mock_server);
+        let params = SearchParams::new().order_by("created", OrderDirection::Desc);
+        client
+            .search_issues("project = TEST", params.clone())
+            .await
+            .unwrap();
+        client
+            .search_issues("project = TEST ORDER BY key ASC", params)
+            .await
+            .unwrap();
 
-        // Then: 成功し、詳細情報付きプロジェクトが返る
-        assert!(result.is_ok());
-        let projects = result.unwrap();
-        assert_eq!(projects.len(), 1);
-        assert_eq!(projects[0].name, "Test Project with Details");
+        let sent_jql: Vec<String> = mock_server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| {
+                let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+                body["jql"].as_str().unwrap().to_string()
+            })
+            .collect();
         assert_eq!(
-            projects[0].description,
-            Some("Detailed test project description".to_string())
+            sent_jql,
+            vec![
+                "project = TEST ORDER BY created DESC",
+                "project = TEST ORDER BY created DESC",
+            ]
         );
-        assert!(projects[0].lead.is_some());
     }
 
-    /// get_projects_with_params()がrecentパラメータで最近のプロジェクト取得できることをテスト
+    /// JiraConfig::default_search_fieldsが検索のフィールド指定に使われることをテスト
     ///
     /// テスト内容:
-    /// - recentクエリパラメータが正しくURLに追加される
-    /// - 数値パラメータが正しく文字列に変換される
-    /// - GET /rest/api/3/project?recent=5が正しく送信される
+    /// - SearchParamsにフィールドが無い場合はデフォルトのフィールドが送信される
+    /// - SearchParamsにフィールドがある場合はそちらが優先される
     #[tokio::test]
-    async fn test_get_projects_with_recent() {
-        use crate::models::ProjectParams;
+    async fn test_search_issues_default_search_fields() {
+        use crate::models::SearchParams;
+        use crate::testing::sample_search_response;
         use serde_json::json;
-        use wiremock::matchers::{method, path, query_param};
+        use wiremock::matchers::{method, path};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
-        // Given: recentパラメータ付きのモックサーバー
         let mock_server = MockServer::start().await;
 
-        Mock::given(method("GET"))
-            .and(path("/rest/api/3/project"))
-            .and(query_param("recent", "5"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(sample_search_response(Vec::new())),
+            )
             .mount(&mock_server)
             .await;
 
-        let config = JiraConfig {
-            base_url: mock_server.uri(),
-            auth: Auth::Basic {
-                username: "test@example.com".to_string(),
-                api_token: "test_token".to_string(),
+        let config = JiraConfig::new_unchecked(
+            mock_server.uri(),
+            Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
             },
-        };
-
+        )
+        .default_search_fields(vec!["summary".to_string(), "status".to_string()]);
         let client = JiraClient::new(config).unwrap();
-        let params = ProjectParams::new().recent(5);
 
-        // When: recentパラメータ付きでプロジェクト取得
-        let result = client.get_projects_with_params(params).await;
+        client
+            .search_issues("project = TEST", SearchParams::new())
+            .await
+            .unwrap();
+        client
+            .search_issues(
+                "project = TEST",
+                SearchParams::new().fields(vec!["key".to_string()]),
+            )
+            .await
+            .unwrap();
 
-        // Then: 成功する
-        assert!(result.is_ok());
+        let sent_fields: Vec<serde_json::Value> = mock_server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| {
+                let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+                body["fields"].clone()
+            })
+            .collect();
+        assert_eq!(
+            sent_fields,
+            vec![json!(["summary", "status"]), json!(["key"])]
+        );
     }
 
-    /// get_projects_with_params()が複数パラメータで正しくクエリ文字列を構築することをテスト
+    /// expand=namesを指定した検索でフィールドの表示名が取得できることをテスト
     ///
     /// テスト内容:
-    /// - 複数のクエリパラメータが&で結合される
-    /// - expand、recent、propertiesが同時に設定される
-    /// - クエリ文字列の順序が正しい
+    /// - リクエストボディのexpandにnamesが含まれる
+    /// - レスポンスのnamesがフィールドIDから表示名への対応として取得できる
     #[tokio::test]
-    async fn test_get_projects_with_multiple_params() {
-        use crate::models::ProjectParams;
+    async fn test_search_issues_with_names() {
+        use crate::testing::{mock_client, sample_issue_json, sample_search_response};
         use serde_json::json;
-        use wiremock::matchers::{method, path, query_param};
+        use wiremock::matchers::{body_partial_json, method, path};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
-        // Given: 複数パラメータ付きのモックサーバー
         let mock_server = MockServer::start().await;
 
-        Mock::given(method("GET"))
-            .and(path("/rest/api/3/project"))
-            .and(query_param("expand", "lead,description"))
-            .and(query_param("recent", "10"))
-            .and(query_param("properties", "*all"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        let mut response = sample_search_response(vec![sample_issue_json("10001", "TEST-1")]);
+        response["names"] = json!({
+            "summary": "Summary",
+            "customfield_10016": "Story Points"
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_partial_json(json!({ "expand": ["names"] })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .expect(1)
             .mount(&mock_server)
             .await;
 
-        let config = JiraConfig {
-            base_url: mock_server.uri(),
-            auth: Auth::Basic {
-                username: "test@example.com".to_string(),
-                api_token: "test_token".to_string(),
-            },
-        };
-
-        let client = JiraClient::new(config).unwrap();
-        let params = ProjectParams::new()
-            .expand(vec!["lead".to_string(), "description".to_string()])
-            .recent(10)
-            .properties(vec!["*all".to_string()]);
+        let client = mock_client(&mock_server);
+        let params = crate::models::SearchParams::new().names().names();
+        assert_eq!(params.expand, Some(vec!["names".to_string()]));
 
-        // When: 複数パラメータ付きでプロジェクト取得
-        let result = client.get_projects_with_params(params).await;
+        let result = client
+            .search_issues("project = TEST", params)
+            .await
+            .unwrap();
 
-        // Then: 成功する
-        assert!(result.is_ok());
+        assert_eq!(result.names.as_ref().map(|n| n.len()), Some(2));
+        assert_eq!(
+            result.field_display_name("customfield_10016"),
+            Some("Story Points")
+        );
+        assert_eq!(result.field_display_name("customfield_99999"), None);
     }
 
-    /// get_priorities()が正常に優先度一覧を取得できることをテスト
+    /// get_issue_property()が保存された値を返すことをテスト
     ///
     /// テスト内容:
-    /// - /rest/api/3/priorityエンドポイントに正しくGETリクエストが送信される
-    /// - レスポンスが正しくPriority構造体にデシリアライズされる
-    /// - 複数の優先度が含まれる場合の動作確認
+    /// - プロパティのエンドポイントにGETリクエストが送信される
+    /// - レスポンスのvalue部分が返される
     #[tokio::test]
-    async fn test_get_priorities() {
+    async fn test_get_issue_property() {
         use serde_json::json;
         use wiremock::matchers::{method, path};
         use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -1215,232 +3414,1650 @@ mod tests {
         let mock_server = MockServer::start().await;
 
         Mock::given(method("GET"))
-            .and(path("/rest/api/3/priority"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
-                {
-                    "id": "1",
-                    "name": "Highest",
-                    "self": "https://example.atlassian.net/rest/api/3/priority/1",
-                    "description": "This problem will block progress.",
-                    "iconUrl": "https://example.atlassian.net/images/icons/priorities/highest.svg",
-                    "statusColor": "#cd1316"
-                },
-                {
-                    "id": "2",
-                    "name": "High",
-                    "self": "https://example.atlassian.net/rest/api/3/priority/2",
-                    "description": "Serious problem that could block progress.",
-                    "iconUrl": "https://example.atlassian.net/images/icons/priorities/high.svg",
-                    "statusColor": "#d04437"
-                },
-                {
-                    "id": "3",
-                    "name": "Medium",
-                    "self": "https://example.atlassian.net/rest/api/3/priority/3",
-                    "description": "Has the potential to affect progress.",
-                    "iconUrl": "https://example.atlassian.net/images/icons/priorities/medium.svg",
-                    "statusColor": "#f79232"
-                }
-            ])))
+            .and(path("/rest/api/3/issue/TEST-1/properties/app.metadata"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "key": "app.metadata",
+                "value": { "score": 42, "tags": ["a", "b"] }
+            })))
+            .expect(1)
             .mount(&mock_server)
             .await;
 
         let config = JiraConfig {
             base_url: mock_server.uri(),
             auth: Auth::Basic {
-                username: "test".to_string(),
-                api_token: "token".to_string(),
+                username: "test@example.com".to_string(),
+                api_token: "test_token".to_string(),
             },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
         };
 
         let client = JiraClient::new(config).unwrap();
-        let result = client.get_priorities().await;
-
-        assert!(result.is_ok());
-        let priorities = result.unwrap();
-        assert_eq!(priorities.len(), 3);
-
-        assert_eq!(priorities[0].id, "1");
-        assert_eq!(priorities[0].name, "Highest");
-        assert_eq!(
-            priorities[0].description,
-            Some("This problem will block progress.".to_string())
-        );
-
-        assert_eq!(priorities[1].id, "2");
-        assert_eq!(priorities[1].name, "High");
+        let value = client
+            .get_issue_property("TEST-1", "app.metadata")
+            .await
+            .unwrap();
 
-        assert_eq!(priorities[2].id, "3");
-        assert_eq!(priorities[2].name, "Medium");
+        assert_eq!(value, json!({ "score": 42, "tags": ["a", "b"] }));
     }
 
-    /// get_priorities()がHTTPエラーを適切に処理することをテスト
+    /// set_issue_property()が作成(201)・更新(200)の両方で成功することをテスト
     ///
     /// テスト内容:
-    /// - 404エラー時にNotFoundエラーが返される
-    /// - 認証エラー（401）時にAuthenticationErrorが返される
+    /// - 値がリクエストボディとしてPUTされる
+    /// - 201と200のどちらのレスポンスでもOkが返される
     #[tokio::test]
-    async fn test_get_priorities_error_handling() {
-        use wiremock::matchers::{method, path};
+    async fn test_set_issue_property() {
+        use serde_json::json;
+        use wiremock::matchers::{body_json, method, path};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
         let mock_server = MockServer::start().await;
 
-        Mock::given(method("GET"))
-            .and(path("/rest/api/3/priority"))
-            .respond_with(ResponseTemplate::new(404))
+        Mock::given(method("PUT"))
+            .and(path("/rest/api/3/issue/TEST-1/properties/created.prop"))
+            .and(body_json(json!({ "score": 1 })))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path("/rest/api/3/issue/TEST-1/properties/updated.prop"))
+            .and(body_json(json!({ "score": 2 })))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
             .mount(&mock_server)
             .await;
 
         let config = JiraConfig {
             base_url: mock_server.uri(),
             auth: Auth::Basic {
-                username: "test".to_string(),
-                api_token: "token".to_string(),
+                username: "test@example.com".to_string(),
+                api_token: "test_token".to_string(),
             },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
         };
 
         let client = JiraClient::new(config).unwrap();
-        let result = client.get_priorities().await;
 
-        assert!(result.is_err());
-        let error = result.unwrap_err();
-        assert!(matches!(error, crate::Error::ApiError { status: 404, .. }));
+        client
+            .set_issue_property("TEST-1", "created.prop", &json!({ "score": 1 }))
+            .await
+            .unwrap();
+        client
+            .set_issue_property("TEST-1", "updated.prop", &json!({ "score": 2 }))
+            .await
+            .unwrap();
     }
 
-    /// get_issue_types()が正常に課題タイプ一覧を取得できることをテスト
+    /// search_issues()にJqlBuilderと文字列のどちらを渡しても同じリクエストになることをテスト
     ///
     /// テスト内容:
-    /// - /rest/api/3/issuetypeエンドポイントに正しくGETリクエストが送信される
-    /// - レスポンスが正しくIssueType構造体にデシリアライズされる
-    /// - 複数の課題タイプが含まれる場合の動作確認
+    /// - ビルダーと&strで送信されるリクエストボディが一致する
+    /// - 組み立てに失敗したビルダーはリクエストを送信せずにInvalidInputになる
     #[tokio::test]
-    async fn test_get_issue_types() {
-        use serde_json::json;
+    async fn test_search_issues_accepts_jql_builder() {
+        use crate::jql::{JqlBuilder, OrderDirection};
+        use crate::models::SearchParams;
+        use crate::testing::{mock_client, sample_search_response};
         use wiremock::matchers::{method, path};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
         let mock_server = MockServer::start().await;
 
-        Mock::given(method("GET"))
-            .and(path("/rest/api/3/issuetype"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
-                {
-                    "id": "10000",
-                    "name": "Task",
-                    "self": "https://example.atlassian.net/rest/api/3/issuetype/10000",
-                    "description": "A task represents work that needs to be done.",
-                    "iconUrl": "https://example.atlassian.net/images/icons/issuetypes/task.png",
-                    "subtask": false
-                },
-                {
-                    "id": "10001",
-                    "name": "Bug",
-                    "self": "https://example.atlassian.net/rest/api/3/issuetype/10001",
-                    "description": "A bug is a problem in the system.",
-                    "iconUrl": "https://example.atlassian.net/images/icons/issuetypes/bug.png",
-                    "subtask": false
-                },
-                {
-                    "id": "10002",
-                    "name": "Subtask",
-                    "self": "https://example.atlassian.net/rest/api/3/issuetype/10002",
-                    "description": "A subtask for a parent issue.",
-                    "iconUrl": "https://example.atlassian.net/images/icons/issuetypes/subtask.png",
-                    "subtask": true
-                }
-            ])))
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(sample_search_response(Vec::new())),
+            )
+            .expect(2)
             .mount(&mock_server)
             .await;
 
-        let config = JiraConfig {
-            base_url: mock_server.uri(),
-            auth: Auth::Basic {
-                username: "test".to_string(),
-                api_token: "token".to_string(),
-            },
-        };
-
-        let client = JiraClient::new(config).unwrap();
-        let result = client.get_issue_types().await;
+        let client = mock_client(&mock_server);
+        let builder = JqlBuilder::new()
+            .project("TEST")
+            .order_by("created", OrderDirection::Desc);
 
-        assert!(result.is_ok());
-        let issue_types = result.unwrap();
-        assert_eq!(issue_types.len(), 3);
+        client
+            .search_issues(builder, SearchParams::new().max_results(10))
+            .await
+            .unwrap();
+        client
+            .search_issues(
+                "project = \"TEST\" ORDER BY created DESC",
+                SearchParams::new().max_results(10),
+            )
+            .await
+            .unwrap();
 
-        assert_eq!(issue_types[0].id, "10000");
-        assert_eq!(issue_types[0].name, "Task");
-        assert_eq!(issue_types[0].subtask, Some(false));
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 2);
+        let from_builder: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        let from_str: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(from_builder, from_str);
 
-        assert_eq!(issue_types[1].id, "10001");
-        assert_eq!(issue_types[1].name, "Bug");
-        assert_eq!(issue_types[1].subtask, Some(false));
+        // 不正なビルダーはリクエストを送信しない
+        let result = client
+            .search_issues(JqlBuilder::new().status_in(&[]), SearchParams::new())
+            .await;
+        assert!(matches!(result, Err(crate::error::Error::InvalidInput(_))));
+    }
 
-        assert_eq!(issue_types[2].id, "10002");
-        assert_eq!(issue_types[2].name, "Subtask");
-        assert_eq!(issue_types[2].subtask, Some(true));
+    /// 全件検索のテスト用に、startAtに応じたページを返すモックを登録する
+    ///
+    /// 5件のIssueを2件ずつ返す（startAt=0, 2, 4）。
+    async fn mount_search_pages(mock_server: &wiremock::MockServer) {
+        use crate::testing::{sample_issue_json, sample_search_response};
+        use serde_json::json;
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        for start_at in [0u32, 2, 4] {
+            let issues = (start_at..(start_at + 2).min(5))
+                .map(|i| sample_issue_json(&(10000 + i).to_string(), &format!("TEST-{}", i + 1)))
+                .collect();
+            let mut body = sample_search_response(issues);
+            body["startAt"] = json!(start_at);
+            body["maxResults"] = json!(2);
+            body["total"] = json!(5);
+
+            Mock::given(method("POST"))
+                .and(path("/rest/api/3/search"))
+                .and(body_partial_json(json!({ "startAt": start_at })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(body))
+                .mount(mock_server)
+                .await;
+        }
     }
 
-    /// get_issue_types()がHTTPエラーを適切に処理することをテスト
+    /// search_issues_stream()がmaxResults単位で自動ページングして全件を返すことをテスト
     ///
     /// テスト内容:
-    /// - 404エラー時にNotFoundエラーが返される
+    /// - 3ページ（100件、100件、50件）に分かれた合計250件をcollectで取得できる
+    /// - 各ページのstartAtがmaxResults単位で進む
     #[tokio::test]
-    async fn test_get_issue_types_error_handling() {
-        use wiremock::matchers::{method, path};
+    async fn test_search_issues_stream_collects_all_pages() {
+        use crate::models::SearchParams;
+        use crate::testing::{mock_client, sample_issue_json};
+        use futures::StreamExt;
+        use serde_json::json;
+        use wiremock::matchers::{body_partial_json, method, path};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
+        // Given: 合計250件を100件ずつ返すモックサーバー
         let mock_server = MockServer::start().await;
+        for (start_at, count) in [(0u32, 100u32), (100, 100), (200, 50)] {
+            let issues: Vec<_> = (start_at..start_at + count)
+                .map(|i| sample_issue_json(&(10000 + i).to_string(), &format!("TEST-{}", i + 1)))
+                .collect();
+            Mock::given(method("POST"))
+                .and(path("/rest/api/3/search"))
+                .and(body_partial_json(
+                    json!({ "startAt": start_at, "maxResults": 100 }),
+                ))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "startAt": start_at,
+                    "maxResults": 100,
+                    "total": 250,
+                    "issues": issues
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+        let client = mock_client(&mock_server);
 
-        Mock::given(method("GET"))
-            .and(path("/rest/api/3/issuetype"))
-            .respond_with(ResponseTemplate::new(404))
+        // When: ストリームを全件collect
+        let results: Vec<_> = client
+            .search_issues_stream("project = TEST", SearchParams::new().max_results(100))
+            .collect()
+            .await;
+
+        // Then: 250件すべてが順に取得できる
+        assert_eq!(results.len(), 250);
+        let keys: Vec<String> = results.into_iter().map(|r| r.unwrap().key).collect();
+        assert_eq!(keys.first().map(String::as_str), Some("TEST-1"));
+        assert_eq!(keys.last().map(String::as_str), Some("TEST-250"));
+    }
+
+    /// search_issues_stream()がページのエラーをErr要素として返し、継続できることをテスト
+    ///
+    /// テスト内容:
+    /// - 2ページ目の失敗がErr要素として1件返される
+    /// - 読み進めると失敗したページを飛ばして3ページ目が取得される
+    #[tokio::test]
+    async fn test_search_issues_stream_yields_page_error() {
+        use crate::Error;
+        use crate::models::SearchParams;
+        use crate::testing::{mock_client, sample_issue_json};
+        use futures::StreamExt;
+        use serde_json::json;
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Given: 全5件のうち2ページ目（startAt=2）だけが失敗するモックサーバー
+        let mock_server = MockServer::start().await;
+        for (start_at, keys) in [(0u32, vec!["TEST-1", "TEST-2"]), (4, vec!["TEST-5"])] {
+            let issues: Vec<_> = keys
+                .into_iter()
+                .map(|key| sample_issue_json("10000", key))
+                .collect();
+            Mock::given(method("POST"))
+                .and(path("/rest/api/3/search"))
+                .and(body_partial_json(json!({ "startAt": start_at })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "startAt": start_at,
+                    "maxResults": 2,
+                    "total": 5,
+                    "issues": issues
+                })))
+                .mount(&mock_server)
+                .await;
+        }
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_partial_json(json!({ "startAt": 2 })))
+            .respond_with(ResponseTemplate::new(500))
             .mount(&mock_server)
             .await;
+        let client = mock_client(&mock_server);
 
-        let config = JiraConfig {
-            base_url: mock_server.uri(),
-            auth: Auth::Basic {
-                username: "test".to_string(),
-                api_token: "token".to_string(),
-            },
-        };
+        // When: エラーを含めて最後まで読み進める
+        let results: Vec<_> = client
+            .search_issues_stream("project = TEST", SearchParams::new().max_results(2))
+            .collect()
+            .await;
 
-        let client = JiraClient::new(config).unwrap();
-        let result = client.get_issue_types().await;
+        // Then: 失敗したページの位置にErrが入り、その後のページも取得される
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap().key, "TEST-1");
+        assert_eq!(results[1].as_ref().unwrap().key, "TEST-2");
+        assert!(matches!(
+            results[2],
+            Err(Error::ApiError { status: 500, .. })
+        ));
+        assert_eq!(results[3].as_ref().unwrap().key, "TEST-5");
+    }
 
-        assert!(result.is_err());
-        let error = result.unwrap_err();
-        assert!(matches!(error, crate::Error::ApiError { status: 404, .. }));
+    /// search_issue_stream()がsearch_all_issues()と同じIssueを順に返すことをテスト
+    ///
+    /// テスト内容:
+    /// - 3ページに分かれた検索結果をストリームで全件取得できる
+    /// - 並び順を含めてsearch_all_issues()の結果と一致する
+    /// - ページはストリームを読み進めたときに要求される
+    #[tokio::test]
+    async fn test_search_issue_stream_matches_search_all_issues() {
+        use crate::models::SearchParams;
+        use crate::testing::mock_client;
+        use futures::StreamExt;
+        use wiremock::MockServer;
+
+        let mock_server = MockServer::start().await;
+        mount_search_pages(&mock_server).await;
+        let client = mock_client(&mock_server);
+
+        // 最初の1件を読んだ時点では1ページ目のみ要求している
+        let mut stream = Box::pin(
+            client.search_issue_stream("project = TEST", SearchParams::new().max_results(2)),
+        );
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(first.key, "TEST-1");
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+
+        let mut streamed = vec![first.key];
+        while let Some(issue) = stream.next().await {
+            streamed.push(issue.unwrap().key);
+        }
+        drop(stream);
+
+        let all: Vec<String> = client
+            .search_all_issues("project = TEST", SearchParams::new().max_results(2))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|i| i.key)
+            .collect();
+
+        assert_eq!(
+            streamed,
+            vec!["TEST-1", "TEST-2", "TEST-3", "TEST-4", "TEST-5"]
+        );
+        assert_eq!(streamed, all);
     }
 
-    /// get_fields()が正常にフィールド一覧を取得できることをテスト
+    /// search_all_issues()が一時的に失敗したページを再試行して全件取得することをテスト
     ///
     /// テスト内容:
-    /// - /rest/api/3/fieldエンドポイントに正しくGETリクエストが送信される
-    /// - レスポンスが正しくField構造体にデシリアライズされる
-    /// - 複数のフィールドが含まれる場合の動作確認
+    /// - 2ページ目が一度だけ500を返しても、再試行で全てのIssueが返る
+    /// - 失敗したページのリクエストが再送される
     #[tokio::test]
-    async fn test_get_fields() {
+    async fn test_search_all_issues_retries_transient_failure() {
+        use crate::models::SearchParams;
+        use crate::testing::mock_client;
         use serde_json::json;
-        use wiremock::matchers::{method, path};
+        use std::time::Duration;
+        use wiremock::matchers::{body_partial_json, method, path};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
         let mock_server = MockServer::start().await;
 
-        Mock::given(method("GET"))
-            .and(path("/rest/api/3/field"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
-                {
-                    "id": "summary",
-                    "key": "summary",
-                    "name": "Summary",
-                    "custom": false,
-                    "orderable": true,
-                    "navigable": true,
-                    "searchable": true,
-                    "schema": {
-                        "type": "string",
+        // 2ページ目の最初のリクエストのみ500を返す
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_partial_json(json!({ "startAt": 2 })))
+            .respond_with(ResponseTemplate::new(500).set_body_string("Internal Server Error"))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+        mount_search_pages(&mock_server).await;
+
+        let client = mock_client(&mock_server);
+        let options = crate::models::SearchAllOptions::new()
+            .retry_policy(crate::RetryPolicy::new().initial_backoff(Duration::ZERO));
+
+        let result = client
+            .search_all_issues_with_options(
+                "project = TEST",
+                SearchParams::new().max_results(2),
+                options,
+            )
+            .await
+            .unwrap();
+
+        let keys: Vec<&str> = result.issues.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys, vec!["TEST-1", "TEST-2", "TEST-3", "TEST-4", "TEST-5"]);
+        assert!(result.is_complete());
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 4);
+    }
+
+    /// recently_updated()が更新日時の条件で全ページを取得することをテスト
+    ///
+    /// テスト内容:
+    /// - `updated >= "..." ORDER BY updated DESC`のJQLが送信される
+    /// - startAtを進めながら全ページのIssueが結合される
+    #[tokio::test]
+    async fn test_recently_updated() {
+        use crate::models::SearchParams;
+        use crate::testing::mock_client;
+        use chrono::{TimeZone, Utc};
+        use wiremock::MockServer;
+
+        let mock_server = MockServer::start().await;
+        mount_search_pages(&mock_server).await;
+
+        let client = mock_client(&mock_server);
+        let since = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 45).unwrap();
+        let issues = client
+            .recently_updated(since, SearchParams::new().max_results(2))
+            .await
+            .unwrap();
+
+        let keys: Vec<&str> = issues.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys, vec!["TEST-1", "TEST-2", "TEST-3", "TEST-4", "TEST-5"]);
+
+        let requests: Vec<serde_json::Value> = mock_server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| serde_json::from_slice(&request.body).unwrap())
+            .collect();
+        let start_ats: Vec<u64> = requests
+            .iter()
+            .map(|body| body["startAt"].as_u64().unwrap())
+            .collect();
+        assert_eq!(start_ats, vec![0, 2, 4]);
+        for body in &requests {
+            assert_eq!(
+                body["jql"],
+                r#"updated >= "2024-01-15 10:30" ORDER BY updated DESC"#
+            );
+        }
+    }
+
+    /// best effortモードで取得できなかったページを飛ばして続行することをテスト
+    ///
+    /// テスト内容:
+    /// - 再試行しても失敗するページの開始位置がfailed_offsetsに記録される
+    /// - 残りのページのIssueは返る
+    /// - best effortでない場合はエラーになる
+    #[tokio::test]
+    async fn test_search_all_issues_best_effort() {
+        use crate::models::SearchParams;
+        use crate::testing::mock_client;
+        use serde_json::json;
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        // 2ページ目は常に500を返す
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_partial_json(json!({ "startAt": 2 })))
+            .respond_with(ResponseTemplate::new(500).set_body_string("Internal Server Error"))
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+        mount_search_pages(&mock_server).await;
+
+        let client = mock_client(&mock_server);
+        let options = crate::models::SearchAllOptions::new()
+            .retry_policy(crate::RetryPolicy::no_retry())
+            .best_effort(true);
+
+        let result = client
+            .search_all_issues_with_options(
+                "project = TEST",
+                SearchParams::new().max_results(2),
+                options,
+            )
+            .await
+            .unwrap();
+
+        let keys: Vec<&str> = result.issues.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys, vec!["TEST-1", "TEST-2", "TEST-5"]);
+        assert_eq!(result.failed_offsets, vec![2]);
+        assert!(!result.is_complete());
+
+        let options =
+            crate::models::SearchAllOptions::new().retry_policy(crate::RetryPolicy::no_retry());
+        let result = client
+            .search_all_issues_with_options(
+                "project = TEST",
+                SearchParams::new().max_results(2),
+                options,
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(crate::error::Error::ApiError { status: 500, .. })
+        ));
+    }
+
+    /// search_all_issues()がORDER BYの無いJQLに安定した並び順を付与することをテスト
+    ///
+    /// テスト内容:
+    /// - ORDER BYが無いJQLには`ORDER BY key ASC`が付与される
+    /// - 既存のORDER BYはそのまま送信される
+    #[tokio::test]
+    async fn test_search_all_issues_appends_stable_order() {
+        use crate::models::SearchParams;
+        use crate::testing::{mock_client, sample_search_response};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(sample_search_response(Vec::new())),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(&mock_server);
+        client
+            .search_all_issues("project = TEST", SearchParams::new())
+            .await
+            .unwrap();
+        client
+            .search_all_issues("project = TEST ORDER BY updated DESC", SearchParams::new())
+            .await
+            .unwrap();
+
+        let sent_jql: Vec<String> = mock_server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| {
+                let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+                body["jql"].as_str().unwrap().to_string()
+            })
+            .collect();
+        assert_eq!(
+            sent_jql,
+            vec![
+                "project = TEST ORDER BY key ASC",
+                "project = TEST ORDER BY updated DESC",
+            ]
+        );
+    }
+
+    /// 再試行の予算が並行リクエスト全体の再試行回数を制限することをテスト
+    ///
+    /// テスト内容:
+    /// - 常に500を返すサーバーに4件の全件検索を並行して実行する
+    /// - 各検索は最大3回再試行するが、予算（2回分）を超えて再試行しない
+    #[tokio::test]
+    async fn test_retry_budget_limits_parallel_retries() {
+        use crate::models::{SearchAllOptions, SearchParams};
+        use crate::testing::mock_client;
+        use std::time::Duration;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("Internal Server Error"))
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(&mock_server).with_retry_budget(crate::RetryBudget::new(2, 0.0));
+        let policy = crate::RetryPolicy::new()
+            .max_retries(3)
+            .initial_backoff(Duration::ZERO);
+
+        let searches = (0..4).map(|_| {
+            let client = client.clone();
+            let options = SearchAllOptions::new().retry_policy(policy.clone());
+            async move {
+                client
+                    .search_all_issues_with_options("project = TEST", SearchParams::new(), options)
+                    .await
+            }
+        });
+        let results = futures::future::join_all(searches).await;
+
+        assert!(results.iter().all(|r| r.is_err()));
+        // 最初の試行4回 + 予算内の再試行2回
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 6);
+    }
+
+    /// キャンセル済みのトークンを渡すとページを要求せずに終了することをテスト
+    #[tokio::test]
+    async fn test_search_all_issues_cancelled() {
+        use crate::models::SearchParams;
+        use crate::testing::mock_client;
+        use wiremock::MockServer;
+
+        let mock_server = MockServer::start().await;
+        mount_search_pages(&mock_server).await;
+
+        let client = mock_client(&mock_server);
+        let token = crate::CancellationToken::new();
+        token.cancel();
+
+        let result = client
+            .search_all_issues_with_options(
+                "project = TEST",
+                SearchParams::new().max_results(2),
+                crate::models::SearchAllOptions::new().cancellation_token(token),
+            )
+            .await
+            .unwrap();
+
+        assert!(result.cancelled);
+        assert!(result.issues.is_empty());
+        assert!(!result.is_complete());
+        assert!(mock_server.received_requests().await.unwrap().is_empty());
+    }
+
+    /// get_issues_by_keys()が取得できないキーがあっても残りのIssueを返すことをテスト
+    ///
+    /// テスト内容:
+    /// - 存在しないキーを含むバッチの検索が400で失敗すると、1件ずつの取得に切り替わる
+    /// - 存在するキーのIssueは入力順に返り、存在しないキーはエラーとともに返る
+    #[tokio::test]
+    async fn test_get_issues_by_keys_partial_failure() {
+        use crate::testing::{mock_client, sample_issue_json};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(ResponseTemplate::new(400).set_body_string(
+                r#"{"errorMessages":["An issue with key 'TEST-404' does not exist for field 'key'."],"errors":{}}"#,
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        for (id, key) in [("10001", "TEST-1"), ("10002", "TEST-2")] {
+            Mock::given(method("GET"))
+                .and(path(format!("/rest/api/3/issue/{}", key)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(sample_issue_json(id, key)))
+                .mount(&mock_server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/TEST-404"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("Issue does not exist"))
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(&mock_server);
+        let (issues, failed) = client
+            .get_issues_by_keys(&["TEST-2", "TEST-404", "TEST-1", "TEST-2"])
+            .await
+            .unwrap();
+
+        let keys: Vec<&str> = issues.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys, vec!["TEST-2", "TEST-1"]);
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].0, "TEST-404");
+        assert!(matches!(
+            failed[0].1,
+            crate::Error::ApiError { status: 404, .. }
+        ));
+    }
+
+    /// create_issue()がIssueを作成し、ID・キー・URLを返すことをテスト
+    ///
+    /// テスト内容:
+    /// - POST /rest/api/3/issueにfields形式のボディが送信される
+    /// - プレーンテキストの説明がADF文書に変換される
+    /// - レスポンスがCreatedIssueにデシリアライズされる
+    #[tokio::test]
+    async fn test_create_issue_success() {
+        use crate::models::CreateIssueInput;
+        use crate::testing::mock_client;
+        use serde_json::json;
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Given: Issue作成APIのモック
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue"))
+            .and(body_partial_json(json!({
+                "fields": {
+                    "project": { "key": "TEST" },
+                    "issuetype": { "name": "Bug" },
+                    "summary": "Login fails",
+                    "description": { "type": "doc", "version": 1 },
+                    "customfield_10016": 5
+                }
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "id": "10100",
+                "key": "TEST-100",
+                "self": "https://example.atlassian.net/rest/api/3/issue/10100"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(&mock_server);
+        let input = CreateIssueInput::new("TEST", "Bug", "Login fails")
+            .description("Steps to reproduce")
+            .custom_field("customfield_10016", json!(5));
+
+        // When: Issueを作成
+        let created = client.create_issue(input).await.unwrap();
+
+        // Then: 作成されたIssueの参照が返る
+        assert_eq!(created.id, "10100");
+        assert_eq!(created.key, "TEST-100");
+        assert_eq!(
+            created.self_url,
+            "https://example.atlassian.net/rest/api/3/issue/10100"
+        );
+    }
+
+    /// create_issue()が必須フィールド不足の400エラー本文をApiErrorに載せることをテスト
+    ///
+    /// テスト内容:
+    /// - 400レスポンスがstatus 400のApiErrorになる
+    /// - エラー本文がそのままmessageに入り、フィールドごとのエラーを解析できる
+    #[tokio::test]
+    async fn test_create_issue_validation_error() {
+        use crate::models::CreateIssueInput;
+        use crate::testing::mock_client;
+        use serde_json::json;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Given: 必須フィールド不足で400を返すモック
+        let mock_server = MockServer::start().await;
+        let error_body = json!({
+            "errorMessages": [],
+            "errors": {
+                "customfield_10020": "Team is required."
+            }
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(&error_body))
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(&mock_server);
+
+        // When: 必須フィールドを指定せずに作成
+        let error = client
+            .create_issue(CreateIssueInput::new("TEST", "Story", "No team"))
+            .await
+            .unwrap_err();
+
+        // Then: エラー本文を含むApiErrorが返る
+        match &error {
+            crate::Error::ApiError { status, message } => {
+                assert_eq!(*status, 400);
+                assert_eq!(
+                    serde_json::from_str::<serde_json::Value>(message).unwrap(),
+                    error_body
+                );
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        let response = error.jira_error_response().unwrap();
+        assert_eq!(
+            response.errors.get("customfield_10020").map(String::as_str),
+            Some("Team is required.")
+        );
+    }
+
+    /// create_issue_idempotent()が重複確認の検索に一致した場合は作成しないことをテスト
+    ///
+    /// テスト内容:
+    /// - 検索結果が空の場合はIssueを作成する
+    /// - 2回目の呼び出しで検索に一致した場合は既存のキーを返し、作成リクエストを送信しない
+    #[tokio::test]
+    async fn test_create_issue_idempotent() {
+        use crate::models::CreateIssueInput;
+        use crate::testing::{mock_client, sample_issue_json, sample_search_response};
+        use serde_json::json;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(sample_search_response(Vec::new())),
+            )
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(sample_search_response(vec![
+                    sample_issue_json("10042", "TEST-42"),
+                ])),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "id": "10042",
+                "key": "TEST-42",
+                "self": "https://example.atlassian.net/rest/api/3/issue/10042"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(&mock_server);
+        let request = CreateIssueInput::new("TEST", "Task", "Import row 1")
+            .labels(vec!["import-0001".to_string()]);
+        let dedupe_jql = "labels = \"import-0001\"";
+
+        let created = client
+            .create_issue_idempotent(&request, dedupe_jql)
+            .await
+            .unwrap();
+        assert_eq!(created.key, "TEST-42");
+
+        let existing = client
+            .create_issue_idempotent(&request, dedupe_jql)
+            .await
+            .unwrap();
+        assert_eq!(existing.key, "TEST-42");
+        assert_eq!(existing.id, "10042");
+
+        let create_requests = mock_server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|r| r.url.path() == "/rest/api/3/issue")
+            .count();
+        assert_eq!(create_requests, 1);
+    }
+
+    /// add_comment()がADF文書をそのまま送信し、作成されたコメントを返すことをテスト
+    ///
+    /// テスト内容:
+    /// - POST /rest/api/3/issue/{key}/commentのbodyに指定したADF文書がそのまま入る
+    /// - レスポンスの作成者・作成日時・本文がCommentにデシリアライズされる
+    #[tokio::test]
+    async fn test_add_comment_sends_adf_body() {
+        use crate::testing::mock_client;
+        use serde_json::json;
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Given: 受け取った本文を含むコメントを返すモック
+        let mock_server = MockServer::start().await;
+        let doc = crate::adf::markdown_to_adf("**Released** in v1.2");
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/TEST-1/comment"))
+            .and(body_json(json!({ "body": doc })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "id": "10200",
+                "author": {
+                    "accountId": "557058:abc",
+                    "displayName": "Test User",
+                    "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:abc",
+                    "active": true
+                },
+                "body": doc,
+                "created": "2024-01-15T10:30:00.000Z",
+                "updated": "2024-01-15T10:30:00.000Z"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(&mock_server);
+
+        // When: ADF文書でコメントを追加
+        let comment = client.add_comment("TEST-1", doc.clone()).await.unwrap();
+
+        // Then: 作成されたコメントが返る
+        assert_eq!(comment.id, "10200");
+        assert_eq!(comment.body, doc);
+        assert_eq!(comment.author.unwrap().display_name, "Test User");
+        assert!(comment.created.is_some());
+        assert_eq!(comment.created, comment.updated);
+    }
+
+    /// add_comment_with_visibility()が公開範囲を指定した場合のみvisibilityを送信することをテスト
+    ///
+    /// テスト内容:
+    /// - 公開範囲を指定しない場合、リクエストボディにvisibilityが含まれない
+    /// - 公開範囲を指定した場合、{ type, value }がリクエストボディに含まれる
+    #[tokio::test]
+    async fn test_add_comment_with_visibility() {
+        use crate::models::CommentVisibility;
+        use crate::testing::mock_client;
+        use serde_json::json;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/TEST-1/comment"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "id": "10100",
+                "body": { "type": "doc", "version": 1, "content": [] }
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(&mock_server);
+
+        let comment = client
+            .add_comment("TEST-1", crate::adf::text_to_adf("Deployed"))
+            .await
+            .unwrap();
+        assert_eq!(comment.id, "10100");
+        client
+            .add_comment_with_visibility(
+                "TEST-1",
+                crate::adf::text_to_adf("Internal note"),
+                Some(CommentVisibility::role("Developers")),
+            )
+            .await
+            .unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let plain: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        let restricted: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+
+        assert!(plain.get("visibility").is_none());
+        assert_eq!(
+            plain["body"]["content"][0]["content"][0]["text"],
+            json!("Deployed")
+        );
+        assert_eq!(
+            restricted["visibility"],
+            json!({ "type": "role", "value": "Developers" })
+        );
+    }
+
+    /// 存在しないロールを指定した場合の400エラーからフィールドごとのエラーを取得できることをテスト
+    #[tokio::test]
+    async fn test_add_comment_unknown_role_error() {
+        use crate::models::CommentVisibility;
+        use crate::testing::mock_client;
+        use serde_json::json;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/TEST-1/comment"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "errorMessages": [],
+                "errors": {
+                    "commentLevel": "You are currently not a member of the project role: Unknown."
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(&mock_server);
+        let error = client
+            .add_comment_with_visibility(
+                "TEST-1",
+                crate::adf::text_to_adf("Internal note"),
+                Some(CommentVisibility::role("Unknown")),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            crate::error::Error::ApiError { status: 400, .. }
+        ));
+        let response = error.jira_error_response().unwrap();
+        assert!(
+            response
+                .errors
+                .get("commentLevel")
+                .is_some_and(|m| m.contains("Unknown"))
+        );
+    }
+
+    /// count_matching()がmaxResults=0で検索しtotalを返すことをテスト
+    ///
+    /// テスト内容:
+    /// - リクエストボディにmaxResults: 0が設定される
+    /// - レスポンスのtotalが件数として返される
+    #[tokio::test]
+    async fn test_count_matching() {
+        use serde_json::json;
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_json(json!({
+                "jql": "project = TEST",
+                "startAt": 0,
+                "maxResults": 0
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 0,
+                "maxResults": 0,
+                "total": 1234,
+                "issues": []
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test@example.com".to_string(),
+                api_token: "test_token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let count = client.count_matching("project = TEST").await.unwrap();
+
+        assert_eq!(count, 1234);
+    }
+
+    /// get_projects()が正常にプロジェクト一覧を取得できることをテスト
+    ///
+    /// テスト内容:
+    /// - GET /rest/api/3/projectエンドポイントに正しいリクエストが送信される
+    /// - レスポンスがVec<Project>に正しくデシリアライズされる
+    /// - 各プロジェクトの基本プロパティ（key, name）が正しく設定される
+    #[tokio::test]
+    async fn test_get_projects_success() {
+        use crate::testing::{mock_client, sample_project_json};
+        use serde_json::json;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Given: プロジェクト一覧を返すモックサーバー
+        let mock_server = MockServer::start().await;
+
+        let response_body = json!([
+            sample_project_json("10000", "TEST", "Test Project"),
+            sample_project_json("10001", "DEMO", "Demo Project"),
+        ]);
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(&mock_server);
+
+        // When: プロジェクト一覧を取得
+        let result = client.get_projects().await;
+
+        // Then: 成功し、プロジェクトリストが返る
+        assert!(result.is_ok());
+        let projects = result.unwrap();
+        assert_eq!(projects.len(), 2);
+        assert_eq!(projects[0].key, "TEST");
+        assert_eq!(projects[0].name, "Test Project");
+        assert_eq!(projects[1].key, "DEMO");
+        assert_eq!(projects[1].name, "Demo Project");
+    }
+
+    /// get_projects()が権限エラーを正しく処理できることをテスト
+    ///
+    /// テスト内容:
+    /// - 403 Forbiddenレスポンスが正しくApiErrorに変換される
+    /// - エラーメッセージが正しく抽出される
+    /// - ステータスコードが正しく保持される
+    #[tokio::test]
+    async fn test_get_projects_error() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Given: エラーを返すモックサーバー
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project"))
+            .respond_with(ResponseTemplate::new(403).set_body_string("Insufficient permissions"))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test@example.com".to_string(),
+                api_token: "test_token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+
+        // When: プロジェクト一覧を取得
+        let result = client.get_projects().await;
+
+        // Then: エラーが返される
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            crate::error::Error::ApiError { status, message } => {
+                assert_eq!(status, 403);
+                assert_eq!(message, "Insufficient permissions");
+            }
+            _ => panic!("Expected ApiError"),
+        }
+    }
+
+    /// get_projects_with_params()がexpandパラメータで詳細情報付きプロジェクト取得できることをテスト
+    ///
+    /// テスト内容:
+    /// - expandクエリパラメータが正しくURLに追加される
+    /// - 複数のexpand値がカンマ区切りで結合される
+    /// - GET /rest/api/3/project?expand=lead,descriptionが正しく送信される
+    #[tokio::test]
+    async fn test_get_projects_with_expand() {
+        use crate::models::ProjectParams;
+        use serde_json::json;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Given: expandパラメータ付きのモックサーバー
+        let mock_server = MockServer::start().await;
+
+        let response_body = json!([{
+            "id": "10000",
+            "key": "TEST",
+            "name": "Test Project with Details",
+            "self": "https://example.atlassian.net/rest/api/3/project/10000",
+            "description": "Detailed test project description",
+            "lead": {
+                "accountId": "557058:f58131cb",
+                "displayName": "Project Lead",
+                "emailAddress": "lead@example.com",
+                "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:f58131cb"
+            }
+        }]);
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project"))
+            .and(query_param("expand", "lead,description"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test@example.com".to_string(),
+                api_token: "test_token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let params =
+            ProjectParams::new().expand(vec!["lead".to_string(), "description".to_string()]);
+
+        // When: expandパラメータ付きでプロジェクト取得
+        let result = client.get_projects_with_params(params).await;
+
+        // Then: 成功し、詳細情報付きプロジェクトが返る
+        assert!(result.is_ok());
+        let projects = result.unwrap();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "Test Project with Details");
+        assert_eq!(
+            projects[0].description,
+            Some("Detailed test project description".to_string())
+        );
+        assert!(projects[0].lead.is_some());
+    }
+
+    /// get_projects_with_params()がrecentパラメータで最近のプロジェクト取得できることをテスト
+    ///
+    /// テスト内容:
+    /// - recentクエリパラメータが正しくURLに追加される
+    /// - 数値パラメータが正しく文字列に変換される
+    /// - GET /rest/api/3/project?recent=5が正しく送信される
+    #[tokio::test]
+    async fn test_get_projects_with_recent() {
+        use crate::models::ProjectParams;
+        use serde_json::json;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Given: recentパラメータ付きのモックサーバー
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project"))
+            .and(query_param("recent", "5"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test@example.com".to_string(),
+                api_token: "test_token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let params = ProjectParams::new().recent(5);
+
+        // When: recentパラメータ付きでプロジェクト取得
+        let result = client.get_projects_with_params(params).await;
+
+        // Then: 成功する
+        assert!(result.is_ok());
+    }
+
+    /// get_projects_with_params()が複数パラメータで正しくクエリ文字列を構築することをテスト
+    ///
+    /// テスト内容:
+    /// - 複数のクエリパラメータが&で結合される
+    /// - expand、recent、propertiesが同時に設定される
+    /// - クエリ文字列の順序が正しい
+    #[tokio::test]
+    async fn test_get_projects_with_multiple_params() {
+        use crate::models::ProjectParams;
+        use serde_json::json;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Given: 複数パラメータ付きのモックサーバー
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project"))
+            .and(query_param("expand", "lead,description"))
+            .and(query_param("recent", "10"))
+            .and(query_param("properties", "*all"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test@example.com".to_string(),
+                api_token: "test_token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let params = ProjectParams::new()
+            .expand(vec!["lead".to_string(), "description".to_string()])
+            .recent(10)
+            .properties(vec!["*all".to_string()]);
+
+        // When: 複数パラメータ付きでプロジェクト取得
+        let result = client.get_projects_with_params(params).await;
+
+        // Then: 成功する
+        assert!(result.is_ok());
+    }
+
+    /// get_priorities()が正常に優先度一覧を取得できることをテスト
+    ///
+    /// テスト内容:
+    /// - /rest/api/3/priorityエンドポイントに正しくGETリクエストが送信される
+    /// - レスポンスが正しくPriority構造体にデシリアライズされる
+    /// - 複数の優先度が含まれる場合の動作確認
+    #[tokio::test]
+    async fn test_get_priorities() {
+        use serde_json::json;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/priority"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {
+                    "id": "1",
+                    "name": "Highest",
+                    "self": "https://example.atlassian.net/rest/api/3/priority/1",
+                    "description": "This problem will block progress.",
+                    "iconUrl": "https://example.atlassian.net/images/icons/priorities/highest.svg",
+                    "statusColor": "#cd1316"
+                },
+                {
+                    "id": "2",
+                    "name": "High",
+                    "self": "https://example.atlassian.net/rest/api/3/priority/2",
+                    "description": "Serious problem that could block progress.",
+                    "iconUrl": "https://example.atlassian.net/images/icons/priorities/high.svg",
+                    "statusColor": "#d04437"
+                },
+                {
+                    "id": "3",
+                    "name": "Medium",
+                    "self": "https://example.atlassian.net/rest/api/3/priority/3",
+                    "description": "Has the potential to affect progress.",
+                    "iconUrl": "https://example.atlassian.net/images/icons/priorities/medium.svg",
+                    "statusColor": "#f79232"
+                }
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let result = client.get_priorities().await;
+
+        assert!(result.is_ok());
+        let priorities = result.unwrap();
+        assert_eq!(priorities.len(), 3);
+
+        assert_eq!(priorities[0].id, "1");
+        assert_eq!(priorities[0].name, "Highest");
+        assert_eq!(
+            priorities[0].description,
+            Some("This problem will block progress.".to_string())
+        );
+
+        assert_eq!(priorities[1].id, "2");
+        assert_eq!(priorities[1].name, "High");
+
+        assert_eq!(priorities[2].id, "3");
+        assert_eq!(priorities[2].name, "Medium");
+    }
+
+    /// get_priorities()がHTTPエラーを適切に処理することをテスト
+    ///
+    /// テスト内容:
+    /// - 404エラー時にNotFoundエラーが返される
+    /// - 認証エラー（401）時にAuthenticationErrorが返される
+    #[tokio::test]
+    async fn test_get_priorities_error_handling() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/priority"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let result = client.get_priorities().await;
+
+        assert!(result.is_err());
+        let error = result.unwrap_err();
+        assert!(matches!(error, crate::Error::ApiError { status: 404, .. }));
+    }
+
+    /// get_issue_types()が正常に課題タイプ一覧を取得できることをテスト
+    ///
+    /// テスト内容:
+    /// - /rest/api/3/issuetypeエンドポイントに正しくGETリクエストが送信される
+    /// - レスポンスが正しくIssueType構造体にデシリアライズされる
+    /// - 複数の課題タイプが含まれる場合の動作確認
+    #[tokio::test]
+    async fn test_get_issue_types() {
+        use serde_json::json;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issuetype"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {
+                    "id": "10000",
+                    "name": "Task",
+                    "self": "https://example.atlassian.net/rest/api/3/issuetype/10000",
+                    "description": "A task represents work that needs to be done.",
+                    "iconUrl": "https://example.atlassian.net/images/icons/issuetypes/task.png",
+                    "subtask": false
+                },
+                {
+                    "id": "10001",
+                    "name": "Bug",
+                    "self": "https://example.atlassian.net/rest/api/3/issuetype/10001",
+                    "description": "A bug is a problem in the system.",
+                    "iconUrl": "https://example.atlassian.net/images/icons/issuetypes/bug.png",
+                    "subtask": false
+                },
+                {
+                    "id": "10002",
+                    "name": "Subtask",
+                    "self": "https://example.atlassian.net/rest/api/3/issuetype/10002",
+                    "description": "A subtask for a parent issue.",
+                    "iconUrl": "https://example.atlassian.net/images/icons/issuetypes/subtask.png",
+                    "subtask": true
+                }
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let result = client.get_issue_types().await;
+
+        assert!(result.is_ok());
+        let issue_types = result.unwrap();
+        assert_eq!(issue_types.len(), 3);
+
+        assert_eq!(issue_types[0].id, "10000");
+        assert_eq!(issue_types[0].name, "Task");
+        assert_eq!(issue_types[0].subtask, Some(false));
+
+        assert_eq!(issue_types[1].id, "10001");
+        assert_eq!(issue_types[1].name, "Bug");
+        assert_eq!(issue_types[1].subtask, Some(false));
+
+        assert_eq!(issue_types[2].id, "10002");
+        assert_eq!(issue_types[2].name, "Subtask");
+        assert_eq!(issue_types[2].subtask, Some(true));
+    }
+
+    /// get_issue_types_for_project()がプロジェクト単位のIssueタイプ一覧を解析できることをテスト
+    ///
+    /// テスト内容:
+    /// - createmetaエンドポイントにプロジェクトキーが含まれる
+    /// - `issueTypes`に格納された要素が解析される
+    /// - 複数ページの結果が結合される
+    #[tokio::test]
+    async fn test_get_issue_types_for_project() {
+        use serde_json::json;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/createmeta/TEST/issuetypes"))
+            .and(query_param("startAt", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 0,
+                "maxResults": 2,
+                "total": 3,
+                "issueTypes": [
+                    {
+                        "id": "10000",
+                        "name": "Task",
+                        "self": "https://example.atlassian.net/rest/api/3/issuetype/10000",
+                        "description": "A task represents work that needs to be done.",
+                        "iconUrl": "https://example.atlassian.net/images/icons/issuetypes/task.png",
+                        "subtask": false
+                    },
+                    {
+                        "id": "10001",
+                        "name": "Bug",
+                        "self": "https://example.atlassian.net/rest/api/3/issuetype/10001",
+                        "subtask": false
+                    }
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/createmeta/TEST/issuetypes"))
+            .and(query_param("startAt", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 2,
+                "maxResults": 2,
+                "total": 3,
+                "issueTypes": [
+                    {
+                        "id": "10002",
+                        "name": "Subtask",
+                        "self": "https://example.atlassian.net/rest/api/3/issuetype/10002",
+                        "subtask": true
+                    }
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let issue_types = client.get_issue_types_for_project("TEST").await.unwrap();
+
+        let names: Vec<&str> = issue_types.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["Task", "Bug", "Subtask"]);
+        assert_eq!(issue_types[2].subtask, Some(true));
+    }
+
+    /// get_issue_types()がHTTPエラーを適切に処理することをテスト
+    ///
+    /// テスト内容:
+    /// - 404エラー時にNotFoundエラーが返される
+    #[tokio::test]
+    async fn test_get_issue_types_error_handling() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issuetype"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let result = client.get_issue_types().await;
+
+        assert!(result.is_err());
+        let error = result.unwrap_err();
+        assert!(matches!(error, crate::Error::ApiError { status: 404, .. }));
+    }
+
+    /// get_fields()が正常にフィールド一覧を取得できることをテスト
+    ///
+    /// テスト内容:
+    /// - /rest/api/3/fieldエンドポイントに正しくGETリクエストが送信される
+    /// - レスポンスが正しくField構造体にデシリアライズされる
+    /// - 複数のフィールドが含まれる場合の動作確認
+    #[tokio::test]
+    async fn test_get_fields() {
+        use serde_json::json;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/field"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {
+                    "id": "summary",
+                    "key": "summary",
+                    "name": "Summary",
+                    "custom": false,
+                    "orderable": true,
+                    "navigable": true,
+                    "searchable": true,
+                    "schema": {
+                        "type": "string",
                         "system": "summary"
                     }
                 },
@@ -1471,7 +5088,647 @@ mod tests {
                         "customId": 10001
                     }
                 }
-            ])))
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let result = client.get_fields().await;
+
+        assert!(result.is_ok());
+        let fields = result.unwrap();
+        assert_eq!(fields.len(), 3);
+
+        assert_eq!(fields[0].id, "summary");
+        assert_eq!(fields[0].name, "Summary");
+        assert_eq!(fields[0].custom, Some(false));
+
+        assert_eq!(fields[1].id, "issuetype");
+        assert_eq!(fields[1].name, "Issue Type");
+        assert_eq!(fields[1].custom, Some(false));
+
+        assert_eq!(fields[2].id, "customfield_10001");
+        assert_eq!(fields[2].name, "Story Points");
+        assert_eq!(fields[2].custom, Some(true));
+    }
+
+    /// メタデータキャッシュが有効期限内のget_fields()呼び出しを1回のリクエストにまとめることをテスト
+    ///
+    /// テスト内容:
+    /// - 有効期限内の2回目の呼び出しはリクエストを送信しない
+    /// - 有効期限が切れた後の呼び出しは再取得する
+    #[tokio::test]
+    async fn test_get_fields_metadata_cache() {
+        use crate::FixedClock;
+        use crate::testing::mock_client;
+        use chrono::{TimeZone, Utc};
+        use serde_json::json;
+        use std::time::Duration;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/field"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {"id": "summary", "key": "summary", "name": "Summary", "custom": false}
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let clock = FixedClock::new(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap());
+        let client = mock_client(&mock_server)
+            .with_metadata_cache(Duration::from_secs(300))
+            .with_clock(Arc::new(clock.clone()));
+
+        let first = client.get_fields().await.unwrap();
+        let second = client.get_fields().await.unwrap();
+        assert_eq!(first.len(), 1);
+        assert_eq!(second[0].id, "summary");
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+
+        clock.advance(chrono::Duration::seconds(301));
+        client.get_fields().await.unwrap();
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+    }
+
+    /// get_fields()がHTTPエラーを適切に処理することをテスト
+    ///
+    /// テスト内容:
+    /// - 404エラー時にNotFoundエラーが返される
+    #[tokio::test]
+    async fn test_get_fields_error_handling() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/field"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let result = client.get_fields().await;
+
+        assert!(result.is_err());
+        let error = result.unwrap_err();
+        assert!(matches!(error, crate::Error::ApiError { status: 404, .. }));
+    }
+
+    /// get_status_categories()が正常にステータスカテゴリー一覧を取得できることをテスト
+    ///
+    /// テスト内容:
+    /// - /rest/api/3/statuscategoryエンドポイントに正しくGETリクエストが送信される
+    /// - レスポンスが正しくStatusCategory構造体にデシリアライズされる
+    /// - 複数のステータスカテゴリーが含まれる場合の動作確認
+    #[tokio::test]
+    async fn test_get_status_categories() {
+        use serde_json::json;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/statuscategory"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {
+                    "id": 1,
+                    "key": "undefined",
+                    "name": "No Category",
+                    "colorName": "medium-gray"
+                },
+                {
+                    "id": 2,
+                    "key": "new",
+                    "name": "To Do",
+                    "colorName": "blue-gray"
+                },
+                {
+                    "id": 3,
+                    "key": "indeterminate",
+                    "name": "In Progress",
+                    "colorName": "yellow"
+                },
+                {
+                    "id": 4,
+                    "key": "done",
+                    "name": "Done",
+                    "colorName": "green"
+                }
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let result = client.get_status_categories().await;
+
+        assert!(result.is_ok());
+        let categories = result.unwrap();
+        assert_eq!(categories.len(), 4);
+
+        assert_eq!(categories[0].id, 1);
+        assert_eq!(categories[0].key, "undefined");
+        assert_eq!(categories[0].name, "No Category");
+        assert_eq!(categories[0].color_name, "medium-gray");
+
+        assert_eq!(categories[1].id, 2);
+        assert_eq!(categories[1].key, "new");
+        assert_eq!(categories[1].name, "To Do");
+
+        assert_eq!(categories[3].id, 4);
+        assert_eq!(categories[3].key, "done");
+        assert_eq!(categories[3].name, "Done");
+    }
+
+    /// get_status_categories()がHTTPエラーを適切に処理することをテスト
+    ///
+    /// テスト内容:
+    /// - 404エラー時にNotFoundエラーが返される
+    #[tokio::test]
+    async fn test_get_status_categories_error_handling() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/statuscategory"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let result = client.get_status_categories().await;
+
+        assert!(result.is_err());
+        let error = result.unwrap_err();
+        assert!(matches!(error, crate::Error::ApiError { status: 404, .. }));
+    }
+
+    /// search_users()が正常にユーザー検索を実行できることをテスト
+    ///
+    /// テスト内容:
+    /// - /rest/api/3/users/searchエンドポイントに正しくGETリクエストが送信される
+    /// - クエリパラメータが正しく設定される
+    /// - レスポンスが正しくUser構造体にデシリアライズされる
+    #[tokio::test]
+    async fn test_search_users() {
+        use serde_json::json;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/user/search"))
+            .and(query_param("query", "test"))
+            .respond_with(ResponseTemplate::new(200)
+                .set_body_json(json!([
+                    {
+                        "accountId": "557058:f58131cb-b67d-43c7-b30d-6b58d40bd077",
+                        "displayName": "Test User",
+                        "emailAddress": "test@example.com",
+                        "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:f58131cb",
+                        "active": true
+                    },
+                    {
+                        "accountId": "557058:a1b2c3d4-e5f6-g7h8-i9j0-k1l2m3n4o5p6",
+                        "displayName": "Another Test User",
+                        "emailAddress": "another@example.com", 
+                        "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:a1b2c3d4",
+                        "active": true
+                    }
+                ])))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let result = client.search_users("test").await;
+
+        assert!(result.is_ok());
+        let users = result.unwrap();
+        assert_eq!(users.len(), 2);
+
+        assert_eq!(
+            users[0].account_id,
+            "557058:f58131cb-b67d-43c7-b30d-6b58d40bd077"
+        );
+        assert_eq!(users[0].display_name, "Test User");
+        assert_eq!(users[0].email_address, Some("test@example.com".to_string()));
+
+        assert_eq!(
+            users[1].account_id,
+            "557058:a1b2c3d4-e5f6-g7h8-i9j0-k1l2m3n4o5p6"
+        );
+        assert_eq!(users[1].display_name, "Another Test User");
+    }
+
+    /// fetch_all_pages()が複数ページを順に取得して結合できることをテスト
+    ///
+    /// テスト内容:
+    /// - startAtを進めながら2ページ分のリクエストが送信される
+    /// - 全ページのvaluesが順序通りに結合される
+    #[tokio::test]
+    async fn test_fetch_all_pages_two_pages() {
+        use serde_json::json;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        #[derive(Debug, serde::Deserialize)]
+        struct Item {
+            id: String,
+        }
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/items"))
+            .and(query_param("startAt", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 0,
+                "maxResults": 2,
+                "total": 3,
+                "isLast": false,
+                "values": [{"id": "1"}, {"id": "2"}]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/items"))
+            .and(query_param("startAt", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 2,
+                "maxResults": 2,
+                "total": 3,
+                "isLast": true,
+                "values": [{"id": "3"}]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let items: Vec<Item> = client.fetch_all_pages("/rest/api/3/items").await.unwrap();
+
+        let ids: Vec<&str> = items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "2", "3"]);
+    }
+
+    /// search_users()が空のクエリを適切に処理することをテスト
+    ///
+    /// テスト内容:
+    /// - 空のクエリでも正常にリクエストが送信される
+    #[tokio::test]
+    async fn test_search_users_empty_query() {
+        use serde_json::json;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/user/search"))
+            .and(query_param("query", ""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let result = client.search_users("").await;
+
+        assert!(result.is_ok());
+        let users = result.unwrap();
+        assert_eq!(users.len(), 0);
+    }
+
+    /// search_users()がHTTPエラーを適切に処理することをテスト
+    ///
+    /// テスト内容:
+    /// - 404エラー時にNotFoundエラーが返される
+    #[tokio::test]
+    async fn test_search_users_error_handling() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/user/search"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let result = client.search_users("test").await;
+
+        assert!(result.is_err());
+        let error = result.unwrap_err();
+        assert!(matches!(error, crate::Error::ApiError { status: 404, .. }));
+    }
+
+    /// with_request_interceptor()で追加したヘッダーが送信されることをテスト
+    ///
+    /// テスト内容:
+    /// - インターセプターが送信直前のリクエストにヘッダーを追加できる
+    /// - 複数のインターセプターが登録順に適用される
+    #[tokio::test]
+    async fn test_request_interceptor_adds_header() {
+        use reqwest::header::HeaderValue;
+        use serde_json::json;
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        // インターセプターが付与したヘッダーを含むリクエストのみ成功する
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/user/search"))
+            .and(header("X-Correlation-Id", "corr-123"))
+            .and(header("X-Audit", "second"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config)
+            .unwrap()
+            .with_request_interceptor(Arc::new(|request: &mut reqwest::Request| {
+                request
+                    .headers_mut()
+                    .insert("X-Correlation-Id", HeaderValue::from_static("corr-123"));
+                request
+                    .headers_mut()
+                    .insert("X-Audit", HeaderValue::from_static("first"));
+            }))
+            .with_request_interceptor(Arc::new(|request: &mut reqwest::Request| {
+                request
+                    .headers_mut()
+                    .insert("X-Audit", HeaderValue::from_static("second"));
+            }));
+
+        let users = client.search_users("test").await.unwrap();
+        assert!(users.is_empty());
+    }
+
+    /// get_transitions()とtransition_issue()でトランジションを取得・実行できることをテスト
+    ///
+    /// テスト内容:
+    /// - GET /rest/api/3/issue/{key}/transitionsの一覧がTransitionのリストになる
+    /// - transition_issue()がトランジションIDとfieldsをPOSTする
+    /// - 存在しないトランジションIDに対する400がApiErrorとして返る
+    #[tokio::test]
+    async fn test_get_transitions_and_transition_issue() {
+        use crate::testing::mock_client;
+        use serde_json::json;
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Given: トランジション一覧と実行APIのモック
+        let mock_server = MockServer::start().await;
+        let status = |id: &str, name: &str, category: &str| {
+            json!({
+                "id": id,
+                "name": name,
+                "self": format!("https://example.atlassian.net/rest/api/3/status/{}", id),
+                "statusCategory": {
+                    "id": 4,
+                    "key": category,
+                    "name": name,
+                    "colorName": "yellow"
+                }
+            })
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/TEST-1/transitions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "expand": "transitions",
+                "transitions": [
+                    { "id": "21", "name": "Start Progress", "to": status("3", "In Progress", "indeterminate") },
+                    { "id": "31", "name": "Done", "to": status("10001", "Done", "done") }
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/TEST-1/transitions"))
+            .and(body_json(json!({
+                "transition": { "id": "31" },
+                "fields": { "resolution": { "name": "Done" } }
+            })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/TEST-1/transitions"))
+            .and(body_json(json!({ "transition": { "id": "999" } })))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "errorMessages": ["Transition id '999' is not valid for this issue."],
+                "errors": {}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(&mock_server);
+
+        // When: 一覧を取得し、名前で選んだトランジションを実行
+        let transitions = client.get_transitions("TEST-1").await.unwrap();
+        assert_eq!(transitions.len(), 2);
+        assert_eq!(transitions[0].to_status.name, "In Progress");
+        let done = transitions.iter().find(|t| t.name == "Done").unwrap();
+
+        let result = client
+            .transition_issue(
+                "TEST-1",
+                &done.id,
+                Some(json!({ "resolution": { "name": "Done" } })),
+            )
+            .await;
+
+        // Then: 成功する
+        assert!(result.is_ok(), "unexpected error: {:?}", result);
+
+        // 存在しないトランジションIDは400のApiErrorになる
+        let error = client
+            .transition_issue("TEST-1", "999", None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, crate::Error::ApiError { status: 400, .. }));
+        assert_eq!(
+            error.jira_error_response().unwrap().error_messages,
+            vec!["Transition id '999' is not valid for this issue."]
+        );
+    }
+
+    /// transition_issues()が失敗したIssueがあっても残りを実行し、結果を記録することをテスト
+    ///
+    /// テスト内容:
+    /// - 各IssueのトランジションAPIにトランジションIDが送信される
+    /// - 409を返したIssueは失敗として記録され、他のIssueは成功する
+    #[tokio::test]
+    async fn test_transition_issues_partial_failure() {
+        use serde_json::json;
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        for key in ["TEST-1", "TEST-3"] {
+            Mock::given(method("POST"))
+                .and(path(format!("/rest/api/3/issue/{}/transitions", key)))
+                .and(body_json(json!({ "transition": { "id": "31" } })))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/TEST-2/transitions"))
+            .respond_with(ResponseTemplate::new(409).set_body_string("Conflict"))
+            .expect(1)
             .mount(&mock_server)
             .await;
 
@@ -1481,275 +5738,811 @@ mod tests {
                 username: "test".to_string(),
                 api_token: "token".to_string(),
             },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
         };
 
         let client = JiraClient::new(config).unwrap();
-        let result = client.get_fields().await;
+        let transitions: Vec<(String, String)> = ["TEST-1", "TEST-2", "TEST-3"]
+            .iter()
+            .map(|key| (key.to_string(), "31".to_string()))
+            .collect();
+
+        let result = client.transition_issues(&transitions).await.unwrap();
+
+        assert_eq!(result.total(), 3);
+        assert!(!result.is_success());
+        assert_eq!(result.succeeded, vec!["TEST-1", "TEST-3"]);
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(result.failed[0].key, "TEST-2");
+        assert!(result.failed[0].error.contains("409"));
+    }
 
-        assert!(result.is_ok());
-        let fields = result.unwrap();
-        assert_eq!(fields.len(), 3);
+    /// metrics()がリクエスト数・受信バイト数・エラー数を集計することをテスト
+    ///
+    /// テスト内容:
+    /// - 成功したリクエストの件数とレスポンスボディのサイズが記録される
+    /// - 非2xxレスポンスはエラーとして数えられる
+    /// - クローンしたクライアントと統計が共有される
+    #[tokio::test]
+    async fn test_client_metrics() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
 
-        assert_eq!(fields[0].id, "summary");
-        assert_eq!(fields[0].name, "Summary");
-        assert_eq!(fields[0].custom, Some(false));
+        let mock_server = MockServer::start().await;
 
-        assert_eq!(fields[1].id, "issuetype");
-        assert_eq!(fields[1].name, "Issue Type");
-        assert_eq!(fields[1].custom, Some(false));
+        let priorities_body = r#"[{"id":"1","name":"High","self":"https://example.atlassian.net/rest/api/3/priority/1"}]"#;
+        let types_body = "[]";
 
-        assert_eq!(fields[2].id, "customfield_10001");
-        assert_eq!(fields[2].name, "Story Points");
-        assert_eq!(fields[2].custom, Some(true));
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/priority"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(priorities_body))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issuetype"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(types_body))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        assert_eq!(client.metrics(), ClientMetrics::default());
+
+        client.get_priorities().await.unwrap();
+        client.clone().get_issue_types().await.unwrap();
+
+        let metrics = client.metrics();
+        assert_eq!(metrics.request_count, 2);
+        assert_eq!(
+            metrics.response_bytes,
+            (priorities_body.len() + types_body.len()) as u64
+        );
+        assert_eq!(metrics.error_count, 0);
+
+        // モック未登録のパスは404となりエラーとして数えられる
+        assert!(client.get_fields().await.is_err());
+        let metrics = client.metrics();
+        assert_eq!(metrics.request_count, 3);
+        assert_eq!(metrics.error_count, 1);
     }
 
-    /// get_fields()がHTTPエラーを適切に処理することをテスト
+    /// health_check()が部分的な失敗を含めて各チェックの結果を記録することをテスト
     ///
     /// テスト内容:
-    /// - 404エラー時にNotFoundエラーが返される
+    /// - 認証に成功した場合はユーザー名とバージョンが記録される
+    /// - プロジェクト一覧の403はcan_read_projects=falseとエラーメッセージとして記録される
     #[tokio::test]
-    async fn test_get_fields_error_handling() {
+    async fn test_health_check_partial_failure() {
+        use serde_json::json;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/myself"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "accountId": "557058:f58131cb",
+                "displayName": "Test User",
+                "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:f58131cb"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project"))
+            .respond_with(ResponseTemplate::new(403).set_body_string("Forbidden"))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/serverInfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "baseUrl": "https://example.atlassian.net",
+                "version": "1001.0.0-SNAPSHOT",
+                "buildNumber": 100242,
+                "deploymentType": "Cloud"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = JiraConfig {
+            base_url: mock_server.uri(),
+            auth: Auth::Basic {
+                username: "test".to_string(),
+                api_token: "token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        };
+
+        let client = JiraClient::new(config).unwrap();
+        let report = client.health_check().await.unwrap();
+
+        assert!(report.reachable);
+        assert!(report.authenticated);
+        assert!(!report.can_read_projects);
+        assert_eq!(report.account_display_name.as_deref(), Some("Test User"));
+        assert_eq!(report.jira_version.as_deref(), Some("1001.0.0-SNAPSHOT"));
+        assert_eq!(report.errors.len(), 1);
+        assert!(report.errors[0].starts_with("project:"));
+        assert!(report.errors[0].contains("403"));
+        assert!(!report.is_healthy());
+    }
+
+    /// detect_capabilities()がデプロイ形態に応じて検索方式を選択することをテスト
+    ///
+    /// テスト内容:
+    /// - 未検出の場合はオフセット方式
+    /// - CloudとServerで異なる検索方式が選択される
+    #[tokio::test]
+    async fn test_detect_capabilities() {
+        use crate::models::{DeploymentType, SearchStrategy};
+        use serde_json::json;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mut strategies = Vec::new();
+        for (deployment_type, version) in [("Cloud", "1001.0.0-SNAPSHOT"), ("Server", "9.12.0")] {
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/rest/api/3/serverInfo"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "baseUrl": mock_server.uri(),
+                    "version": version,
+                    "deploymentType": deployment_type,
+                    "buildNumber": 100242
+                })))
+                .mount(&mock_server)
+                .await;
+
+            let config = JiraConfig {
+                base_url: mock_server.uri(),
+                auth: Auth::Basic {
+                    username: "test".to_string(),
+                    api_token: "token".to_string(),
+                },
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                timeout: None,
+                connect_timeout: None,
+                max_retries: 3,
+                user_agent: None,
+                default_search_fields: None,
+            };
+
+            let client = JiraClient::new(config).unwrap();
+            assert!(client.capabilities().is_none());
+            assert_eq!(client.search_strategy(), SearchStrategy::OffsetPaginated);
+
+            let capabilities = client.detect_capabilities().await.unwrap();
+            assert_eq!(client.capabilities(), Some(capabilities));
+            strategies.push((capabilities.deployment_type, client.search_strategy()));
+        }
+
+        assert_eq!(
+            strategies,
+            vec![
+                (DeploymentType::Cloud, SearchStrategy::TokenPaginated),
+                (DeploymentType::Server, SearchStrategy::OffsetPaginated),
+            ]
+        );
+    }
+
+    /// 指定したデプロイ形態のサーバー情報を返すモックを登録する
+    async fn mount_server_info(mock_server: &wiremock::MockServer, deployment_type: &str) {
+        use serde_json::json;
         use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/serverInfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "baseUrl": mock_server.uri(),
+                "version": "9.12.0",
+                "deploymentType": deployment_type,
+                "buildNumber": 100242
+            })))
+            .mount(mock_server)
+            .await;
+    }
+
+    /// Cloudを検出した後の検索がトークン方式のエンドポイントを使用することをテスト
+    ///
+    /// テスト内容:
+    /// - search_all_issues()が/rest/api/3/search/jqlをnextPageTokenで辿って全ページを取得する
+    /// - count_matching()が/rest/api/3/search/approximate-countの件数を返す
+    /// - オフセット方式のエンドポイントは呼び出されない
+    #[tokio::test]
+    async fn test_search_uses_token_endpoint_on_cloud() {
+        use crate::models::SearchParams;
+        use crate::testing::{mock_client, sample_issue_json, sample_search_response};
+        use serde_json::json;
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Given: Cloudのサーバー情報と、2ページ分のトークン方式のレスポンス
+        let mock_server = MockServer::start().await;
+        mount_server_info(&mock_server, "Cloud").await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search/jql"))
+            .and(body_json(json!({
+                "jql": "project = TEST ORDER BY key ASC",
+                "maxResults": 2
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "issues": [
+                    sample_issue_json("10001", "TEST-1"),
+                    sample_issue_json("10002", "TEST-2")
+                ],
+                "nextPageToken": "page-2",
+                "isLast": false
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search/jql"))
+            .and(body_json(json!({
+                "jql": "project = TEST ORDER BY key ASC",
+                "maxResults": 2,
+                "nextPageToken": "page-2"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "issues": [sample_issue_json("10003", "TEST-3")],
+                "isLast": true
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search/approximate-count"))
+            .and(body_json(json!({ "jql": "project = TEST" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "count": 3 })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(sample_search_response(vec![])))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(&mock_server);
+        client.detect_capabilities().await.unwrap();
+
+        // When: 全件取得と件数取得を実行
+        let issues = client
+            .search_all_issues("project = TEST", SearchParams::new().max_results(2))
+            .await
+            .unwrap();
+        let count = client.count_matching("project = TEST").await.unwrap();
+
+        // Then: トークンを辿って全Issueが取得され、件数は概算件数になる
+        let keys: Vec<&str> = issues.iter().map(|issue| issue.key.as_str()).collect();
+        assert_eq!(keys, vec!["TEST-1", "TEST-2", "TEST-3"]);
+        assert_eq!(count, 3);
+
+        // トークン無しで2ページ目を指定した場合はエラーになる
+        let result = client
+            .search_issues("project = TEST", SearchParams::new().start_at(2))
+            .await;
+        assert!(matches!(result, Err(crate::Error::InvalidInput(_))));
+    }
+
+    /// Server/Data Centerを検出した後の検索がオフセット方式のエンドポイントを使用することをテスト
+    ///
+    /// テスト内容:
+    /// - search_all_issues()が/rest/api/3/searchをstartAtで辿って全ページを取得する
+    /// - count_matching()がオフセット方式の検索結果のtotalを返す
+    /// - トークン方式のエンドポイントは呼び出されない
+    #[tokio::test]
+    async fn test_search_uses_offset_endpoint_on_server() {
+        use crate::models::SearchParams;
+        use crate::testing::{mock_client, sample_issue_json};
+        use serde_json::json;
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Given: Serverのサーバー情報と、2ページ分のオフセット方式のレスポンス
+        let mock_server = MockServer::start().await;
+        mount_server_info(&mock_server, "Server").await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_partial_json(json!({ "startAt": 0, "maxResults": 2 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 0,
+                "maxResults": 2,
+                "total": 3,
+                "issues": [
+                    sample_issue_json("10001", "TEST-1"),
+                    sample_issue_json("10002", "TEST-2")
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_partial_json(json!({ "startAt": 2, "maxResults": 2 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 2,
+                "maxResults": 2,
+                "total": 3,
+                "issues": [sample_issue_json("10003", "TEST-3")]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_partial_json(json!({ "startAt": 0, "maxResults": 0 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 0,
+                "maxResults": 0,
+                "total": 3,
+                "issues": []
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search/jql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "issues": [] })))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(&mock_server);
+        client.detect_capabilities().await.unwrap();
+
+        // When: 全件取得と件数取得を実行
+        let issues = client
+            .search_all_issues("project = TEST", SearchParams::new().max_results(2))
+            .await
+            .unwrap();
+        let count = client.count_matching("project = TEST").await.unwrap();
+
+        // Then: startAtを進めて全Issueが取得され、件数はtotalになる
+        let keys: Vec<&str> = issues.iter().map(|issue| issue.key.as_str()).collect();
+        assert_eq!(keys, vec!["TEST-1", "TEST-2", "TEST-3"]);
+        assert_eq!(count, 3);
+    }
+
+    /// get_comments()が途中の短いページを含めて全コメントを1回ずつ取得することをテスト
+    ///
+    /// テスト内容:
+    /// - 1ページ目がmaxResultsより少なくてもtotalに達するまで取得を続ける
+    /// - 最後のページが短くてもtotalに達した時点で取得を終了する
+    /// - 全コメントが重複なく順序通りに結合される
+    #[tokio::test]
+    async fn test_get_comments_follows_total_with_short_pages() {
+        use crate::testing::mock_client;
+        use serde_json::json;
+        use wiremock::matchers::{method, path, query_param};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
         let mock_server = MockServer::start().await;
 
+        let page = |start_at: u32, ids: &[&str]| {
+            json!({
+                "startAt": start_at,
+                "maxResults": 3,
+                "total": 5,
+                "comments": ids.iter().map(|id| json!({"id": id, "body": "text"})).collect::<Vec<_>>()
+            })
+        };
+
+        // 1ページ目はmaxResultsより少ない2件だけ返す
         Mock::given(method("GET"))
-            .and(path("/rest/api/3/field"))
-            .respond_with(ResponseTemplate::new(404))
+            .and(path("/rest/api/3/issue/TEST-1/comment"))
+            .and(query_param("startAt", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page(0, &["1", "2"])))
+            .expect(1)
             .mount(&mock_server)
             .await;
 
-        let config = JiraConfig {
-            base_url: mock_server.uri(),
-            auth: Auth::Basic {
-                username: "test".to_string(),
-                api_token: "token".to_string(),
-            },
-        };
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/TEST-1/comment"))
+            .and(query_param("startAt", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page(2, &["3", "4"])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
 
-        let client = JiraClient::new(config).unwrap();
-        let result = client.get_fields().await;
+        // 最後のページも短い
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/TEST-1/comment"))
+            .and(query_param("startAt", "4"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page(4, &["5"])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
 
-        assert!(result.is_err());
-        let error = result.unwrap_err();
-        assert!(matches!(error, crate::Error::ApiError { status: 404, .. }));
+        let client = mock_client(&mock_server);
+        let comments = client.get_comments("TEST-1").await.unwrap();
+
+        let ids: Vec<&str> = comments.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "2", "3", "4", "5"]);
     }
 
-    /// get_status_categories()が正常にステータスカテゴリー一覧を取得できることをテスト
+    /// get_custom_field_options()がページングされた選択肢を全件取得することをテスト
     ///
     /// テスト内容:
-    /// - /rest/api/3/statuscategoryエンドポイントに正しくGETリクエストが送信される
-    /// - レスポンスが正しくStatusCategory構造体にデシリアライズされる
-    /// - 複数のステータスカテゴリーが含まれる場合の動作確認
+    /// - コンテキスト指定時はそのコンテキストの選択肢を最後のページまで取得する
+    /// - コンテキスト未指定時はフィールドのコンテキスト一覧から選択肢を集める
+    /// - disabledの選択肢も含めて返す
     #[tokio::test]
-    async fn test_get_status_categories() {
+    async fn test_get_custom_field_options_paginated() {
+        use crate::testing::mock_client;
         use serde_json::json;
-        use wiremock::matchers::{method, path};
+        use wiremock::matchers::{method, path, query_param};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
         let mock_server = MockServer::start().await;
+        let options_path = "/rest/api/3/field/customfield_10010/context/10100/option";
 
         Mock::given(method("GET"))
-            .and(path("/rest/api/3/statuscategory"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
-                {
-                    "id": 1,
-                    "key": "undefined",
-                    "name": "No Category",
-                    "colorName": "medium-gray"
-                },
-                {
-                    "id": 2,
-                    "key": "new",
-                    "name": "To Do",
-                    "colorName": "blue-gray"
-                },
-                {
-                    "id": 3,
-                    "key": "indeterminate",
-                    "name": "In Progress",
-                    "colorName": "yellow"
-                },
-                {
-                    "id": 4,
-                    "key": "done",
-                    "name": "Done",
-                    "colorName": "green"
-                }
-            ])))
+            .and(path(options_path))
+            .and(query_param("startAt", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 0,
+                "maxResults": 2,
+                "total": 3,
+                "isLast": false,
+                "values": [
+                    {"id": "1", "value": "Backend", "disabled": false},
+                    {"id": "2", "value": "Frontend", "disabled": true}
+                ]
+            })))
             .mount(&mock_server)
             .await;
 
-        let config = JiraConfig {
-            base_url: mock_server.uri(),
-            auth: Auth::Basic {
-                username: "test".to_string(),
-                api_token: "token".to_string(),
-            },
-        };
-
-        let client = JiraClient::new(config).unwrap();
-        let result = client.get_status_categories().await;
+        Mock::given(method("GET"))
+            .and(path(options_path))
+            .and(query_param("startAt", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 2,
+                "maxResults": 2,
+                "total": 3,
+                "isLast": true,
+                "values": [{"id": "3", "value": "Mobile", "disabled": false}]
+            })))
+            .mount(&mock_server)
+            .await;
 
-        assert!(result.is_ok());
-        let categories = result.unwrap();
-        assert_eq!(categories.len(), 4);
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/field/customfield_10010/context"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 0,
+                "maxResults": 50,
+                "total": 1,
+                "isLast": true,
+                "values": [{"id": "10100", "name": "Default context", "isGlobalContext": true}]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
 
-        assert_eq!(categories[0].id, 1);
-        assert_eq!(categories[0].key, "undefined");
-        assert_eq!(categories[0].name, "No Category");
-        assert_eq!(categories[0].color_name, "medium-gray");
+        let client = mock_client(&mock_server);
 
-        assert_eq!(categories[1].id, 2);
-        assert_eq!(categories[1].key, "new");
-        assert_eq!(categories[1].name, "To Do");
+        let options = client
+            .get_custom_field_options("customfield_10010", Some("10100"))
+            .await
+            .unwrap();
+        let values: Vec<(&str, &str, bool)> = options
+            .iter()
+            .map(|o| (o.id.as_str(), o.value.as_str(), o.disabled))
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                ("1", "Backend", false),
+                ("2", "Frontend", true),
+                ("3", "Mobile", false)
+            ]
+        );
 
-        assert_eq!(categories[3].id, 4);
-        assert_eq!(categories[3].key, "done");
-        assert_eq!(categories[3].name, "Done");
+        // コンテキスト未指定でも同じ選択肢が得られる
+        let options = client
+            .get_custom_field_options("customfield_10010", None)
+            .await
+            .unwrap();
+        let ids: Vec<&str> = options.iter().map(|o| o.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "2", "3"]);
     }
 
-    /// get_status_categories()がHTTPエラーを適切に処理することをテスト
+    /// get_issue_with_history()が切り詰められたchangelogを全件取得して解析することをテスト
     ///
     /// テスト内容:
-    /// - 404エラー時にNotFoundエラーが返される
+    /// - expand=changelogで埋め込まれたchangelogがtotalより少ない場合は専用エンドポイントを辿る
+    /// - 2ページに分かれた変更履歴がすべて解析される
+    /// - 返すIssueのchangelogも全件に置き換わる
     #[tokio::test]
-    async fn test_get_status_categories_error_handling() {
-        use wiremock::matchers::{method, path};
+    async fn test_get_issue_with_history_pages_changelog() {
+        use crate::testing::{mock_client, sample_issue_json};
+        use serde_json::json;
+        use wiremock::matchers::{method, path, query_param};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
         let mock_server = MockServer::start().await;
 
+        let history = |id: &str, from: &str, to: &str| {
+            json!({
+                "id": id,
+                "author": {
+                    "accountId": "557058:f58131cb",
+                    "displayName": "Test User",
+                    "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:f58131cb"
+                },
+                "created": format!("2024-01-0{}T00:00:00.000Z", id),
+                "items": [{
+                    "field": "status",
+                    "fieldtype": "jira",
+                    "from": from,
+                    "fromString": from,
+                    "to": to,
+                    "toString": to
+                }]
+            })
+        };
+
+        // 埋め込みのchangelogは3件中1件のみ
+        let mut issue = sample_issue_json("10001", "TEST-1");
+        issue["changelog"] = json!({
+            "startAt": 0,
+            "maxResults": 1,
+            "total": 3,
+            "histories": [history("1", "To Do", "In Progress")]
+        });
         Mock::given(method("GET"))
-            .and(path("/rest/api/3/statuscategory"))
-            .respond_with(ResponseTemplate::new(404))
+            .and(path("/rest/api/3/issue/TEST-1"))
+            .and(query_param("expand", "changelog"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(issue))
+            .expect(1)
             .mount(&mock_server)
             .await;
 
-        let config = JiraConfig {
-            base_url: mock_server.uri(),
-            auth: Auth::Basic {
-                username: "test".to_string(),
-                api_token: "token".to_string(),
-            },
-        };
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/TEST-1/changelog"))
+            .and(query_param("startAt", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 0,
+                "maxResults": 2,
+                "total": 3,
+                "isLast": false,
+                "values": [
+                    history("1", "To Do", "In Progress"),
+                    history("2", "In Progress", "In Review")
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
 
-        let client = JiraClient::new(config).unwrap();
-        let result = client.get_status_categories().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/TEST-1/changelog"))
+            .and(query_param("startAt", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 2,
+                "maxResults": 2,
+                "total": 3,
+                "isLast": true,
+                "values": [history("3", "In Review", "Done")]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
 
-        assert!(result.is_err());
-        let error = result.unwrap_err();
-        assert!(matches!(error, crate::Error::ApiError { status: 404, .. }));
+        let client = mock_client(&mock_server);
+        let (issue, histories) = client.get_issue_with_history("TEST-1").await.unwrap();
+
+        assert_eq!(issue.key, "TEST-1");
+        assert_eq!(issue.changelog.as_ref().unwrap().histories.len(), 3);
+        assert_eq!(histories.len(), 3);
+
+        let transitions: Vec<(Option<&str>, Option<&str>)> = histories
+            .iter()
+            .map(|h| {
+                (
+                    h.from_display_value.as_deref(),
+                    h.to_display_value.as_deref(),
+                )
+            })
+            .collect();
+        assert!(transitions.contains(&(Some("In Review"), Some("Done"))));
+        assert!(
+            histories
+                .iter()
+                .all(|h| h.issue_key == "TEST-1" && h.issue_id == "10001")
+        );
     }
 
-    /// search_users()が正常にユーザー検索を実行できることをテスト
+    /// 検索のログがtracingで出力され、認証情報や本文が既定のレベルでは出力されないことをテスト
     ///
     /// テスト内容:
-    /// - /rest/api/3/users/searchエンドポイントに正しくGETリクエストが送信される
-    /// - クエリパラメータが正しく設定される
-    /// - レスポンスが正しくUser構造体にデシリアライズされる
+    /// - debugレベルではエンドポイント・ステータス・バイト数が出力される
+    /// - レスポンスのプレビューはtraceレベルでのみ出力される
+    /// - Authorizationヘッダーの値とAPIトークンはどのレベルでも出力されない
     #[tokio::test]
-    async fn test_search_users() {
-        use serde_json::json;
-        use wiremock::matchers::{method, path, query_param};
+    async fn test_search_issues_logs_with_tracing() {
+        use crate::models::SearchParams;
+        use crate::testing::{
+            TEST_API_TOKEN, mock_client, sample_issue_json, sample_search_response,
+        };
+        use base64::Engine;
+        use std::sync::{Arc, Mutex};
+        use tracing_subscriber::fmt::MakeWriter;
+        use wiremock::matchers::{method, path};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
-        let mock_server = MockServer::start().await;
-
-        Mock::given(method("GET"))
-            .and(path("/rest/api/3/user/search"))
-            .and(query_param("query", "test"))
-            .respond_with(ResponseTemplate::new(200)
-                .set_body_json(json!([
-                    {
-                        "accountId": "557058:f58131cb-b67d-43c7-b30d-6b58d40bd077",
-                        "displayName": "Test User",
-                        "emailAddress": "test@example.com",
-                        "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:f58131cb",
-                        "active": true
-                    },
-                    {
-                        "accountId": "557058:a1b2c3d4-e5f6-g7h8-i9j0-k1l2m3n4o5p6",
-                        "displayName": "Another Test User",
-                        "emailAddress": "another@example.com", 
-                        "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:a1b2c3d4",
-                        "active": true
-                    }
-                ])))
-            .mount(&mock_server)
-            .await;
+        /// 出力されたログを保持するライター
+        #[derive(Clone, Default)]
+        struct LogBuffer(Arc<Mutex<Vec<u8>>>);
 
-        let config = JiraConfig {
-            base_url: mock_server.uri(),
-            auth: Auth::Basic {
-                username: "test".to_string(),
-                api_token: "token".to_string(),
-            },
-        };
+        impl std::io::Write for LogBuffer {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
 
-        let client = JiraClient::new(config).unwrap();
-        let result = client.search_users("test").await;
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
 
-        assert!(result.is_ok());
-        let users = result.unwrap();
-        assert_eq!(users.len(), 2);
+        impl<'a> MakeWriter<'a> for LogBuffer {
+            type Writer = LogBuffer;
 
-        assert_eq!(
-            users[0].account_id,
-            "557058:f58131cb-b67d-43c7-b30d-6b58d40bd077"
-        );
-        assert_eq!(users[0].display_name, "Test User");
-        assert_eq!(users[0].email_address, Some("test@example.com".to_string()));
+            fn make_writer(&'a self) -> Self::Writer {
+                self.clone()
+            }
+        }
 
-        assert_eq!(
-            users[1].account_id,
-            "557058:a1b2c3d4-e5f6-g7h8-i9j0-k1l2m3n4o5p6"
-        );
-        assert_eq!(users[1].display_name, "Another Test User");
+        let mock_server = MockServer::start().await;
+        let issue = sample_issue_json("10001", "TEST-1");
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(sample_search_response(vec![issue])),
+            )
+            .mount(&mock_server)
+            .await;
+        let client = mock_client(&mock_server);
+
+        let credentials = base64::engine::general_purpose::STANDARD.encode(format!(
+            "{}:{}",
+            crate::testing::TEST_USERNAME,
+            TEST_API_TOKEN
+        ));
+
+        for level in [tracing::Level::DEBUG, tracing::Level::TRACE] {
+            let buffer = LogBuffer::default();
+            let subscriber = tracing_subscriber::fmt()
+                .with_max_level(level)
+                .with_ansi(false)
+                .with_writer(buffer.clone())
+                .finish();
+            let _guard = tracing::subscriber::set_default(subscriber);
+
+            client
+                .search_issues("project = TEST", SearchParams::new())
+                .await
+                .unwrap();
+
+            let logs = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+            assert!(logs.contains("/rest/api/3/search"), "{}", logs);
+            assert!(logs.contains("status=200"), "{}", logs);
+            assert!(logs.contains("bytes="), "{}", logs);
+            assert!(!logs.contains(&credentials), "{}", logs);
+            assert!(!logs.contains(TEST_API_TOKEN), "{}", logs);
+
+            // 本文はtraceレベルでのみ出力される
+            let has_preview = logs.contains(r#"preview={"issues":["#);
+            assert_eq!(has_preview, level == tracing::Level::TRACE, "{}", logs);
+        }
     }
 
-    /// search_users()が空のクエリを適切に処理することをテスト
+    /// get_issue()がキーとパラメータからURLを組み立ててIssueを取得することをテスト
     ///
     /// テスト内容:
-    /// - 空のクエリでも正常にリクエストが送信される
+    /// - fieldsとexpandがクエリパラメータとして送信される
+    /// - スラッシュなどを含むキーはURLエンコードされる
+    /// - 存在しないキーは404のApiErrorになる
     #[tokio::test]
-    async fn test_search_users_empty_query() {
-        use serde_json::json;
+    async fn test_get_issue_with_params() {
+        use crate::models::IssueParams;
+        use crate::testing::{mock_client, sample_issue_json};
         use wiremock::matchers::{method, path, query_param};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
         let mock_server = MockServer::start().await;
-
         Mock::given(method("GET"))
-            .and(path("/rest/api/3/user/search"))
-            .and(query_param("query", ""))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .and(path("/rest/api/3/issue/TEST-1"))
+            .and(query_param("fields", "summary,status"))
+            .and(query_param("expand", "changelog"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(sample_issue_json("10001", "TEST-1")),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/MISSING-1"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "errorMessages": ["Issue does not exist or you do not have permission to see it."],
+                "errors": {}
+            })))
             .mount(&mock_server)
             .await;
 
-        let config = JiraConfig {
-            base_url: mock_server.uri(),
-            auth: Auth::Basic {
-                username: "test".to_string(),
-                api_token: "token".to_string(),
-            },
-        };
+        let client = mock_client(&mock_server);
 
-        let client = JiraClient::new(config).unwrap();
-        let result = client.search_users("").await;
+        let params = IssueParams::new()
+            .fields(vec!["summary".to_string(), "status".to_string()])
+            .changelog();
+        let issue = client.get_issue("TEST-1", params).await.unwrap();
+        assert_eq!(issue.key, "TEST-1");
+        assert_eq!(issue.id, "10001");
 
-        assert!(result.is_ok());
-        let users = result.unwrap();
-        assert_eq!(users.len(), 0);
+        match client.get_issue("MISSING-1", IssueParams::new()).await {
+            Err(crate::Error::ApiError { status, .. }) => assert_eq!(status, 404),
+            other => panic!("expected 404 ApiError, got {:?}", other),
+        }
+
+        // スラッシュはエンコードされ、パスの区切りとして扱われない
+        let _ = client.get_issue("TEST/1", IssueParams::new()).await;
+        let requests = mock_server.received_requests().await.unwrap();
+        let last = requests.last().unwrap();
+        assert_eq!(last.url.path(), "/rest/api/3/issue/TEST%2F1");
     }
 
-    /// search_users()がHTTPエラーを適切に処理することをテスト
+    /// as_user()で作成したクライアントのみがアクターヘッダーを付与することをテスト
     ///
     /// テスト内容:
-    /// - 404エラー時にNotFoundエラーが返される
+    /// - as_user()のクライアントからのリクエストにアカウントIDのヘッダーが付く
+    /// - 元のクライアントからのリクエストにはヘッダーが付かない
+    /// - ヘッダーに使えないアカウントIDはInvalidInputエラーになる
     #[tokio::test]
-    async fn test_search_users_error_handling() {
+    async fn test_as_user_adds_actor_header() {
+        use crate::testing::mock_client;
+        use serde_json::json;
         use wiremock::matchers::{method, path};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -1757,23 +6550,41 @@ mod tests {
 
         Mock::given(method("GET"))
             .and(path("/rest/api/3/user/search"))
-            .respond_with(ResponseTemplate::new(404))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(3)
             .mount(&mock_server)
             .await;
 
-        let config = JiraConfig {
-            base_url: mock_server.uri(),
-            auth: Auth::Basic {
-                username: "test".to_string(),
-                api_token: "token".to_string(),
-            },
-        };
+        let client = mock_client(&mock_server);
+        let alice = client.as_user("557058:alice").unwrap();
+        let bob = client.as_user("557058:bob").unwrap();
 
-        let client = JiraClient::new(config).unwrap();
-        let result = client.search_users("test").await;
+        alice.search_users("test").await.unwrap();
+        client.search_users("test").await.unwrap();
+        bob.search_users("test").await.unwrap();
 
-        assert!(result.is_err());
-        let error = result.unwrap_err();
-        assert!(matches!(error, crate::Error::ApiError { status: 404, .. }));
+        let actors: Vec<Option<String>> = mock_server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| {
+                request
+                    .headers
+                    .get(ACTOR_HEADER)
+                    .map(|v| v.to_str().unwrap().to_string())
+            })
+            .collect();
+        assert_eq!(
+            actors,
+            vec![
+                Some("557058:alice".to_string()),
+                None,
+                Some("557058:bob".to_string()),
+            ]
+        );
+
+        let result = client.as_user("invalid\nid");
+        assert!(matches!(result, Err(crate::Error::InvalidInput(_))));
     }
 }
@@ -0,0 +1,81 @@
+//! 現在時刻の取得を抽象化するクロック
+//!
+//! 同期間隔の判定や時間ベースフィルターの範囲計算は現在時刻に依存します。
+//! `Utc::now()`を直接呼ぶ代わりに[`Clock`]を経由することで、
+//! テストでは[`FixedClock`]を使って時刻を自由に制御できます。
+
+use chrono::{DateTime, Duration, Utc};
+use std::sync::{Arc, Mutex};
+
+/// 現在時刻を提供するトレイト
+pub trait Clock: Send + Sync {
+    /// 現在時刻を取得
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// システム時刻を返すクロック（デフォルト）
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// 任意の時刻を返すクロック（テスト用）
+///
+/// クローンしたインスタンス間で時刻を共有するため、サービスに渡した後でも
+/// [`FixedClock::set`]や[`FixedClock::advance`]で時刻を進められます。
+#[derive(Debug, Clone)]
+pub struct FixedClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl FixedClock {
+    /// 指定した時刻で固定されたクロックを作成
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    /// 現在時刻を設定
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    /// 現在時刻を指定した時間だけ進める
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_fixed_clock_set_and_advance() {
+        // FixedClockの時刻がクローン間で共有され、設定・前進できることをテスト
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let clock = FixedClock::new(start);
+        let shared = clock.clone();
+
+        assert_eq!(shared.now(), start);
+
+        clock.advance(Duration::minutes(90));
+        assert_eq!(shared.now(), start + Duration::minutes(90));
+
+        let later = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+        clock.set(later);
+        assert_eq!(shared.now(), later);
+    }
+}
@@ -6,7 +6,7 @@ use std::path::{Path, PathBuf};
 use tokio::fs;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
-use crate::{Error, FilterConfig, JiraConfig};
+use crate::{Error, FilterConfig, JiraConfig, ProjectSyncStats};
 
 /// アプリケーション設定の抽象化トレイト
 #[async_trait]
@@ -35,6 +35,29 @@ pub trait ConfigStore: Send + Sync {
     /// アプリケーション設定を読み込み
     async fn load_app_config(&self) -> Result<Option<AppConfig>, Error>;
 
+    /// プロジェクトの同期ウォーターマーク（最後に同期したIssueの更新日時）を保存
+    async fn save_sync_watermark(
+        &mut self,
+        project_key: &str,
+        timestamp: DateTime<Utc>,
+    ) -> Result<(), Error>;
+
+    /// プロジェクトの同期ウォーターマークを読み込み
+    async fn load_sync_watermark(&self, project_key: &str) -> Result<Option<DateTime<Utc>>, Error>;
+
+    /// プロジェクトの同期統計履歴を保存（既存の履歴は置き換え）
+    async fn save_project_sync_history(
+        &mut self,
+        project_key: &str,
+        history: &[ProjectSyncStats],
+    ) -> Result<(), Error>;
+
+    /// プロジェクトの同期統計履歴を読み込み（古い順）
+    async fn load_project_sync_history(
+        &self,
+        project_key: &str,
+    ) -> Result<Vec<ProjectSyncStats>, Error>;
+
     /// 設定ストアを初期化
     async fn initialize(&mut self) -> Result<(), Error>;
 
@@ -147,6 +170,16 @@ impl FileConfigStore {
         self.config_dir.join("app_config.json")
     }
 
+    /// 同期ウォーターマークファイルのパスを取得
+    fn sync_watermarks_path(&self) -> PathBuf {
+        self.config_dir.join("sync_watermarks.json")
+    }
+
+    /// プロジェクト別同期統計履歴ファイルのパスを取得
+    fn project_sync_history_path(&self) -> PathBuf {
+        self.config_dir.join("project_sync_history.json")
+    }
+
     /// JSONファイルに書き込み
     async fn write_json_file<T>(&self, path: &Path, data: &T) -> Result<(), Error>
     where
@@ -277,6 +310,52 @@ impl ConfigStore for FileConfigStore {
         self.read_json_file(&path).await
     }
 
+    async fn save_sync_watermark(
+        &mut self,
+        project_key: &str,
+        timestamp: DateTime<Utc>,
+    ) -> Result<(), Error> {
+        let path = self.sync_watermarks_path();
+        let mut watermarks: HashMap<String, DateTime<Utc>> =
+            self.read_json_file(&path).await?.unwrap_or_default();
+
+        watermarks.insert(project_key.to_string(), timestamp);
+        self.write_json_file(&path, &watermarks).await
+    }
+
+    async fn load_sync_watermark(&self, project_key: &str) -> Result<Option<DateTime<Utc>>, Error> {
+        let path = self.sync_watermarks_path();
+        let watermarks: Option<HashMap<String, DateTime<Utc>>> = self.read_json_file(&path).await?;
+
+        Ok(watermarks.and_then(|w| w.get(project_key).copied()))
+    }
+
+    async fn save_project_sync_history(
+        &mut self,
+        project_key: &str,
+        history: &[ProjectSyncStats],
+    ) -> Result<(), Error> {
+        let path = self.project_sync_history_path();
+        let mut histories: HashMap<String, Vec<ProjectSyncStats>> =
+            self.read_json_file(&path).await?.unwrap_or_default();
+
+        histories.insert(project_key.to_string(), history.to_vec());
+        self.write_json_file(&path, &histories).await
+    }
+
+    async fn load_project_sync_history(
+        &self,
+        project_key: &str,
+    ) -> Result<Vec<ProjectSyncStats>, Error> {
+        let path = self.project_sync_history_path();
+        let histories: Option<HashMap<String, Vec<ProjectSyncStats>>> =
+            self.read_json_file(&path).await?;
+
+        Ok(histories
+            .and_then(|mut h| h.remove(project_key))
+            .unwrap_or_default())
+    }
+
     async fn initialize(&mut self) -> Result<(), Error> {
         // 設定ディレクトリとサブディレクトリを作成
         fs::create_dir_all(&self.config_dir)
@@ -490,6 +569,85 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_sync_watermark_save_and_load() {
+        // プロジェクト別の同期ウォーターマークの保存と読み込みが正しく動作することをテスト
+        let (mut store, temp_dir) = create_test_store().await;
+        store.initialize().await.unwrap();
+
+        // 未保存の場合はNone
+        assert!(store.load_sync_watermark("TEST").await.unwrap().is_none());
+
+        let first = Utc::now() - chrono::Duration::hours(1);
+        let second = Utc::now();
+        store.save_sync_watermark("TEST", first).await.unwrap();
+        store.save_sync_watermark("DEMO", first).await.unwrap();
+
+        // 上書き保存しても他プロジェクトの値は維持される
+        store.save_sync_watermark("TEST", second).await.unwrap();
+
+        // 別インスタンス（再起動相当）からも読み込めること
+        let reopened = FileConfigStore::new(temp_dir.path());
+        assert_eq!(
+            reopened.load_sync_watermark("TEST").await.unwrap(),
+            Some(second)
+        );
+        assert_eq!(
+            reopened.load_sync_watermark("DEMO").await.unwrap(),
+            Some(first)
+        );
+        assert!(
+            reopened
+                .load_sync_watermark("OTHER")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_project_sync_history_save_and_load() {
+        // プロジェクト別の同期統計履歴の保存と読み込みが正しく動作することをテスト
+        let (mut store, temp_dir) = create_test_store().await;
+        store.initialize().await.unwrap();
+
+        // 未保存の場合は空
+        assert!(
+            store
+                .load_project_sync_history("TEST")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let mut first = ProjectSyncStats::new("TEST".to_string());
+        first.synced_count = 1;
+        let mut second = ProjectSyncStats::new("TEST".to_string());
+        second.synced_count = 2;
+        store
+            .save_project_sync_history("TEST", &[first, second])
+            .await
+            .unwrap();
+        store
+            .save_project_sync_history("DEMO", &[ProjectSyncStats::new("DEMO".to_string())])
+            .await
+            .unwrap();
+
+        // 別インスタンス（再起動相当）からも順序を保って読み込めること
+        let reopened = FileConfigStore::new(temp_dir.path());
+        let history = reopened.load_project_sync_history("TEST").await.unwrap();
+        let counts: Vec<usize> = history.iter().map(|s| s.synced_count).collect();
+        assert_eq!(counts, vec![1, 2]);
+        assert_eq!(
+            reopened
+                .load_project_sync_history("DEMO")
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
     #[tokio::test]
     async fn test_config_store_clear() {
         // 設定ストアのクリアが正しく動作することをテスト
@@ -1,23 +1,90 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use duckdb::{Connection, params};
+use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use futures::{Stream, TryStreamExt, stream};
 use serde_json;
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use std::sync::{Arc, Mutex};
+use tokio::sync::Semaphore;
 use tokio::task;
+use tracing::warn;
 
 use crate::{
-    Error, FilterConfig, HistoryAuthor, HistoryFilter, HistoryStats, Issue, IssueFilter,
-    IssueHistory, PersistenceStore, SortOrder, StorageStats,
+    ChangelogParser, CustomFieldFilter, CustomFieldOperator, Error, FilterConfig, GroupBy,
+    HistoryAuthor, HistoryFilter, HistoryStats, Issue, IssueField, IssueFilter, IssueHistory,
+    IssueProjection, PersistenceStore, SortOrder, StorageStats, UNASSIGNED_BUCKET,
 };
 
+/// 履歴をストリームで読み込む際の1ページあたりの件数
+const HISTORY_STREAM_PAGE_SIZE: usize = 1000;
+
+/// DuckDBのLIMIT/OFFSETに指定できる値の上限（2^62未満）
+const MAX_SQL_LIMIT: u64 = (1 << 62) - 1;
+
+/// raw_jsonの圧縮方式を記録するメタデータのキー
+const RAW_COMPRESSION_METADATA_KEY: &str = "raw_json_compression";
+
+/// `optimize()`を推奨する断片化率のデフォルト閾値
+pub const DEFAULT_OPTIMIZE_THRESHOLD: f64 = 0.2;
+
+/// DuckDBストアのメンテナンス状況
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaintenanceReport {
+    /// Issue件数
+    pub issue_count: usize,
+    /// 履歴レコード件数
+    pub history_count: usize,
+    /// フィルター設定件数
+    pub filter_config_count: usize,
+    /// データベースファイルとWALファイルの合計サイズ（バイト、インメモリの場合は0）
+    pub database_size_bytes: u64,
+    /// 確保済みブロック数
+    pub total_blocks: u64,
+    /// 未使用ブロック数
+    pub free_blocks: u64,
+    /// 断片化率の概算（未使用ブロック数 / 確保済みブロック数）
+    pub fragmentation_ratio: f64,
+    /// `DEFAULT_OPTIMIZE_THRESHOLD`を基準に最適化が推奨されるかどうか
+    pub optimize_recommended: bool,
+}
+
+/// 読み取り用接続プールのデフォルトの接続数
+pub const DEFAULT_READ_POOL_SIZE: usize = 4;
+
 /// DuckDB形式のデータストア
+///
+/// 書き込みは1つの接続で直列に実行し、Issueの読み込み・件数取得・集計は
+/// 同じデータベースに接続した読み取り用の接続プールで並行に実行します。
 pub struct DuckDBStore {
     /// データベースファイルのパス
-    #[allow(dead_code)]
     db_path: PathBuf,
-    /// DuckDB接続（スレッドセーフ）
+    /// 書き込み用のDuckDB接続（スレッドセーフ）
     connection: Arc<Mutex<Connection>>,
+    /// 読み取り用の接続プール
+    read_pool: Arc<Mutex<Vec<Connection>>>,
+    /// 読み取り用接続の空き数
+    read_permits: Arc<Semaphore>,
+    /// `begin_transaction`で開始したトランザクション中かどうか
+    in_transaction: bool,
+    /// IssueのJSONをgzip圧縮して保存するかどうか
+    raw_compression: bool,
+}
+
+/// プールから借りた読み取り用接続（ドロップ時にプールへ戻す）
+struct PooledConnection {
+    pool: Arc<Mutex<Vec<Connection>>>,
+    connection: Option<Connection>,
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            self.pool.lock().unwrap().push(connection);
+        }
+    }
 }
 
 impl DuckDBStore {
@@ -27,10 +94,7 @@ impl DuckDBStore {
         let connection = Connection::open(&db_path)
             .map_err(|e| Error::DatabaseError(format!("Failed to open DuckDB: {}", e)))?;
 
-        Ok(Self {
-            db_path,
-            connection: Arc::new(Mutex::new(connection)),
-        })
+        Self::with_connection(db_path, connection, DEFAULT_READ_POOL_SIZE)
     }
 
     /// インメモリデータベースを作成
@@ -39,15 +103,143 @@ impl DuckDBStore {
             Error::DatabaseError(format!("Failed to create in-memory DuckDB: {}", e))
         })?;
 
+        Self::with_connection(
+            PathBuf::from(":memory:"),
+            connection,
+            DEFAULT_READ_POOL_SIZE,
+        )
+    }
+
+    /// 読み取り用接続プールの接続数を変更する
+    ///
+    /// 並行して実行できる読み込み処理の数になります。0を指定した場合は1として扱います。
+    pub fn with_read_pool_size(self, size: usize) -> Result<Self, Error> {
+        let connection = Arc::try_unwrap(self.connection)
+            .map_err(|_| Error::DatabaseError("Connection is still in use".to_string()))?
+            .into_inner()
+            .map_err(|e| Error::DatabaseError(format!("Connection lock poisoned: {}", e)))?;
+        drop(self.read_pool);
+
+        let mut store = Self::with_connection(self.db_path, connection, size)?;
+        store.raw_compression = self.raw_compression;
+        Ok(store)
+    }
+
+    /// IssueのJSONをgzip圧縮したBLOBとして保存するかどうかを設定する
+    ///
+    /// 有効にすると、以降に保存するIssueは`raw_json_gz`列に圧縮して保存し、
+    /// `raw_json`列にはカスタムフィールド条件の検索に必要なカスタムフィールドのみを残します。
+    /// 説明が約2KBのIssue 1,000件（`test_duckdb_store_raw_compression`のデータ）では、
+    /// データベースファイルが4,468,736バイトから3,944,448バイトへ約12%小さくなりました。
+    /// 説明の本文は検索用の`description_text`列に圧縮せず残り、DuckDB自体も列を圧縮するため、
+    /// JSONを単体でgzip圧縮した場合ほどは縮みません。
+    ///
+    /// 読み込み時は行ごとに圧縮の有無を判定して展開するため、設定を切り替えても
+    /// 既存のデータはそのまま読めます。設定は`initialize()`時にスキーマのメタデータへ記録されます。
+    pub fn with_raw_compression(mut self, enabled: bool) -> Self {
+        self.raw_compression = enabled;
+        self
+    }
+
+    /// スキーマのメタデータに記録されたIssueのJSONの圧縮設定を取得する
+    ///
+    /// 記録が無い場合は`None`を返します。`initialize()`前に呼び出した場合はエラーになります。
+    pub async fn recorded_raw_compression(&self) -> Result<Option<bool>, Error> {
+        self.run_read("Metadata read", |conn| {
+            let mut stmt = conn.prepare("SELECT value FROM store_metadata WHERE key = ?")?;
+            let mut rows = stmt.query(params![RAW_COMPRESSION_METADATA_KEY])?;
+            match rows.next()? {
+                Some(row) => {
+                    let value: String = row.get(0)?;
+                    Ok(Some(value == "gzip"))
+                }
+                None => Ok(None),
+            }
+        })
+        .await
+    }
+
+    /// 書き込み用の接続から読み取り用の接続を複製してストアを作成
+    fn with_connection(
+        db_path: PathBuf,
+        connection: Connection,
+        read_pool_size: usize,
+    ) -> Result<Self, Error> {
+        // query_rawで任意のSELECTを受け付けるため、ファイルの読み込みなど外部へのアクセスを無効にする
+        connection
+            .execute_batch("SET enable_external_access = false")
+            .map_err(|e| {
+                Error::DatabaseError(format!("Failed to disable external access: {}", e))
+            })?;
+
+        let read_pool_size = read_pool_size.max(1);
+        let readers = (0..read_pool_size)
+            .map(|_| connection.try_clone())
+            .collect::<duckdb::Result<Vec<_>>>()
+            .map_err(|e| Error::DatabaseError(format!("Failed to open read connection: {}", e)))?;
+
         Ok(Self {
-            db_path: PathBuf::from(":memory:"),
+            db_path,
             connection: Arc::new(Mutex::new(connection)),
+            read_pool: Arc::new(Mutex::new(readers)),
+            read_permits: Arc::new(Semaphore::new(read_pool_size)),
+            in_transaction: false,
+            raw_compression: false,
+        })
+    }
+
+    /// 読み取り用の接続で処理を実行する
+    ///
+    /// 空いている接続が無い場合は、他の読み込みが終わるまで待機します。
+    /// トランザクション中は未コミットの書き込みが見えるよう、書き込み用の接続で実行します。
+    async fn run_read<T, F>(&self, operation: &str, f: F) -> Result<T, Error>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Connection) -> duckdb::Result<T> + Send + 'static,
+    {
+        if self.in_transaction {
+            let conn = Arc::clone(&self.connection);
+            return task::spawn_blocking(move || f(&conn.lock().unwrap()))
+                .await
+                .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
+                .map_err(|e| Error::DatabaseError(format!("{} failed: {}", operation, e)));
+        }
+
+        let permit = Arc::clone(&self.read_permits)
+            .acquire_owned()
+            .await
+            .map_err(|e| Error::DatabaseError(format!("Read pool closed: {}", e)))?;
+        let pool = Arc::clone(&self.read_pool);
+
+        task::spawn_blocking(move || {
+            let _permit = permit;
+            let connection = pool.lock().unwrap().pop();
+            let reader = PooledConnection { pool, connection };
+            let conn = reader
+                .connection
+                .as_ref()
+                .expect("a permit guarantees an idle read connection");
+            f(conn)
         })
+        .await
+        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
+        .map_err(|e| Error::DatabaseError(format!("{} failed: {}", operation, e)))
+    }
+
+    /// 書き込み用の接続でトランザクション制御文を実行する
+    async fn execute_transaction_statement(&self, statement: &'static str) -> Result<(), Error> {
+        let conn = Arc::clone(&self.connection);
+
+        task::spawn_blocking(move || conn.lock().unwrap().execute_batch(statement))
+            .await
+            .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
+            .map_err(|e| Error::DatabaseError(format!("{} failed: {}", statement, e)))
     }
 
     /// データベーススキーマを初期化
     pub async fn initialize(&self) -> Result<(), Error> {
         let conn = Arc::clone(&self.connection);
+        let raw_compression = self.raw_compression;
 
         task::spawn_blocking(move || {
             let conn = conn.lock().unwrap();
@@ -69,12 +261,80 @@ impl DuckDBStore {
                     assignee_display_name VARCHAR,
                     created TIMESTAMP NOT NULL,
                     updated TIMESTAMP NOT NULL,
-                    raw_json TEXT NOT NULL
+                    raw_json TEXT NOT NULL,
+                    resolution_name VARCHAR,
+                    resolution_date TIMESTAMP,
+                    original_estimate_seconds BIGINT,
+                    remaining_estimate_seconds BIGINT,
+                    time_spent_seconds BIGINT,
+                    status_category_key VARCHAR,
+                    vote_count BIGINT,
+                    watch_count BIGINT,
+                    parent_key VARCHAR,
+                    description_text TEXT
                 )
                 "#,
                 params![],
             )?;
 
+            // 解決状況の列が無い既存データベースに列を追加
+            conn.execute("ALTER TABLE issues ADD COLUMN IF NOT EXISTS resolution_name VARCHAR", params![])?;
+            conn.execute("ALTER TABLE issues ADD COLUMN IF NOT EXISTS resolution_date TIMESTAMP", params![])?;
+
+            // 時間管理の列が無い既存データベースに列を追加
+            conn.execute("ALTER TABLE issues ADD COLUMN IF NOT EXISTS original_estimate_seconds BIGINT", params![])?;
+            conn.execute("ALTER TABLE issues ADD COLUMN IF NOT EXISTS remaining_estimate_seconds BIGINT", params![])?;
+            conn.execute("ALTER TABLE issues ADD COLUMN IF NOT EXISTS time_spent_seconds BIGINT", params![])?;
+
+            // ステータスカテゴリの列を追加し、既存の行はraw_jsonから埋める
+            conn.execute("ALTER TABLE issues ADD COLUMN IF NOT EXISTS status_category_key VARCHAR", params![])?;
+            conn.execute(
+                "UPDATE issues SET status_category_key = json_extract_string(raw_json, '$.fields.status.statusCategory.key') WHERE status_category_key IS NULL",
+                params![],
+            )?;
+
+            // 投票数・ウォッチャー数の列を追加し、既存の行はraw_jsonから埋める
+            conn.execute("ALTER TABLE issues ADD COLUMN IF NOT EXISTS vote_count BIGINT", params![])?;
+            conn.execute("ALTER TABLE issues ADD COLUMN IF NOT EXISTS watch_count BIGINT", params![])?;
+            conn.execute(
+                "UPDATE issues SET vote_count = TRY_CAST(json_extract_string(raw_json, '$.fields.votes.votes') AS BIGINT) WHERE vote_count IS NULL",
+                params![],
+            )?;
+            conn.execute(
+                "UPDATE issues SET watch_count = TRY_CAST(json_extract_string(raw_json, '$.fields.watches.watchCount') AS BIGINT) WHERE watch_count IS NULL",
+                params![],
+            )?;
+
+            // 圧縮したIssueのJSONの列を追加（NULLの行はraw_jsonに完全なJSONを持つ）
+            conn.execute("ALTER TABLE issues ADD COLUMN IF NOT EXISTS raw_json_gz BLOB", params![])?;
+
+            // 親Issueのキーの列を追加し、既存の行は保存済みのJSONから埋める
+            if !has_column(&conn, "issues", "parent_key")? {
+                conn.execute("ALTER TABLE issues ADD COLUMN parent_key VARCHAR", params![])?;
+                backfill_issue_column(&conn, "parent_key", |issue| {
+                    issue.parent_key().map(str::to_string)
+                })?;
+            }
+
+            // 説明のプレーンテキストの列を追加（本文検索はADFのJSONではなくこの列で照合する）
+            if !has_column(&conn, "issues", "description_text")? {
+                conn.execute("ALTER TABLE issues ADD COLUMN description_text TEXT", params![])?;
+                backfill_issue_column(&conn, "description_text", Issue::description_text)?;
+            }
+
+            // ストアの設定を記録するメタデータテーブル
+            conn.execute(
+                "CREATE TABLE IF NOT EXISTS store_metadata (key VARCHAR PRIMARY KEY, value VARCHAR NOT NULL)",
+                params![],
+            )?;
+            conn.execute(
+                "INSERT INTO store_metadata (key, value) VALUES (?, ?) ON CONFLICT (key) DO UPDATE SET value = EXCLUDED.value",
+                params![
+                    RAW_COMPRESSION_METADATA_KEY,
+                    if raw_compression { "gzip" } else { "none" }
+                ],
+            )?;
+
             // フィルター設定テーブルの作成
             conn.execute(
                 r#"
@@ -127,6 +387,8 @@ impl DuckDBStore {
             conn.execute("CREATE INDEX IF NOT EXISTS idx_issues_status_name ON issues(status_name)", params![])?;
             conn.execute("CREATE INDEX IF NOT EXISTS idx_issues_created ON issues(created)", params![])?;
             conn.execute("CREATE INDEX IF NOT EXISTS idx_issues_updated ON issues(updated)", params![])?;
+            conn.execute("CREATE INDEX IF NOT EXISTS idx_issues_resolution_name ON issues(resolution_name)", params![])?;
+            conn.execute("CREATE INDEX IF NOT EXISTS idx_issues_parent_key ON issues(parent_key)", params![])?;
 
             // 履歴テーブルのインデックス作成
             conn.execute("CREATE INDEX IF NOT EXISTS idx_history_issue_key ON issue_history(issue_key)", params![])?;
@@ -144,7 +406,239 @@ impl DuckDBStore {
         Ok(())
     }
 
-    /// DuckDBクエリでフィルター条件を構築（簡素化版）
+    /// Issueと埋め込みchangelogから生成した履歴を同一トランザクションで保存
+    ///
+    /// `expand=changelog`で取得したIssueの`changelog`を`ChangelogParser`で
+    /// `IssueHistory`に展開し、Issueのupsertと同じトランザクション内で
+    /// `issue_history`テーブルへ書き込みます。changelogを持つIssueについては
+    /// 既存の履歴を置き換えるため、同じIssueを再保存しても履歴は重複しません。
+    /// いずれかの書き込みに失敗した場合はロールバックされます。
+    ///
+    /// 戻り値は（保存したIssue数, 保存した履歴レコード数）です。
+    pub async fn save_issues_with_history(
+        &mut self,
+        issues: &[Issue],
+    ) -> Result<(usize, usize), Error> {
+        // changelogの解析はDB処理の前に行い、失敗時は何も書き込まない
+        let mut entries = Vec::with_capacity(issues.len());
+        for issue in issues {
+            let raw_json = encode_raw_json(issue, self.raw_compression)?;
+            let histories = match &issue.changelog {
+                Some(changelog) => {
+                    let changelog_json = serde_json::to_value(changelog)
+                        .map_err(|e| Error::SerializationError(e.to_string()))?;
+                    Some(ChangelogParser::parse_changelog(
+                        &issue.id,
+                        &issue.key,
+                        &changelog_json,
+                    )?)
+                }
+                None => None,
+            };
+            entries.push((issue.clone(), raw_json, histories));
+        }
+
+        let conn = Arc::clone(&self.connection);
+        let nested = self.in_transaction;
+
+        task::spawn_blocking(move || {
+            let conn = conn.lock().unwrap();
+
+            // 外側のトランザクション中はその一部として書き込む
+            if !nested {
+                conn.execute("BEGIN TRANSACTION", params![])?;
+            }
+
+            let result = (|| {
+                let mut issue_count = 0;
+                let mut history_count = 0;
+
+                for (issue, raw_json, histories) in &entries {
+                    upsert_issue(&conn, issue, raw_json)?;
+                    issue_count += 1;
+
+                    if let Some(histories) = histories {
+                        conn.execute(
+                            "DELETE FROM issue_history WHERE issue_id = ?",
+                            params![&issue.id],
+                        )?;
+                        for history in histories {
+                            insert_history(&conn, history)?;
+                            history_count += 1;
+                        }
+                    }
+                }
+
+                Ok::<(usize, usize), duckdb::Error>((issue_count, history_count))
+            })();
+
+            match result {
+                Ok(counts) => {
+                    if !nested {
+                        conn.execute("COMMIT", params![])?;
+                    }
+                    Ok(counts)
+                }
+                Err(e) => {
+                    if !nested {
+                        let _ = conn.execute("ROLLBACK", params![]);
+                    }
+                    Err(e)
+                }
+            }
+        })
+        .await
+        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
+        .map_err(|e| Error::DatabaseError(format!("Save with history operation failed: {}", e)))
+    }
+
+    /// データベース接続を閉じる
+    ///
+    /// 接続を明示的に解放し、未反映のデータをデータベースファイルに書き出します。
+    /// 実行中の処理が接続を保持している場合はエラーを返します。
+    pub async fn close(self) -> Result<(), Error> {
+        // 読み取り用の接続を先に解放する
+        drop(self.read_pool);
+
+        let connection = Arc::try_unwrap(self.connection)
+            .map_err(|_| Error::DatabaseError("Connection is still in use".to_string()))?
+            .into_inner()
+            .map_err(|e| Error::DatabaseError(format!("Connection lock poisoned: {}", e)))?;
+
+        task::spawn_blocking(move || connection.close().map_err(|(_, e)| e))
+            .await
+            .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
+            .map_err(|e| Error::DatabaseError(format!("Close operation failed: {}", e)))
+    }
+
+    /// メンテナンス状況を取得
+    ///
+    /// テーブルごとの行数、データベースのサイズ、未使用ブロックの割合から求めた
+    /// 断片化率の概算を返します。長時間稼働するサービスで`optimize()`を
+    /// 実行すべきか判断するために使用します。
+    pub async fn maintenance_report(&self) -> Result<MaintenanceReport, Error> {
+        let (issue_count, history_count, filter_config_count, total_blocks, free_blocks) = self
+            .run_read("Maintenance report", move |conn| {
+                let count_rows = |table: &str| -> duckdb::Result<usize> {
+                    let count: i64 = conn
+                        .prepare(&format!("SELECT COUNT(*) FROM {}", table))?
+                        .query_row(params![], |row| row.get(0))?;
+                    Ok(count as usize)
+                };
+
+                let issue_count = count_rows("issues")?;
+                let history_count = count_rows("issue_history")?;
+                let filter_config_count = count_rows("filter_configs")?;
+
+                // インメモリDBなどブロック情報が取得できない場合は断片化なしとして扱う
+                let (total_blocks, free_blocks) = conn
+                    .prepare(
+                        "SELECT total_blocks, free_blocks FROM pragma_database_size() LIMIT 1",
+                    )?
+                    .query_row(params![], |row| {
+                        let total: i64 = row.get(0)?;
+                        let free: i64 = row.get(1)?;
+                        Ok((total.max(0) as u64, free.max(0) as u64))
+                    })
+                    .unwrap_or((0, 0));
+
+                Ok::<_, duckdb::Error>((
+                    issue_count,
+                    history_count,
+                    filter_config_count,
+                    total_blocks,
+                    free_blocks,
+                ))
+            })
+            .await?;
+
+        let fragmentation_ratio = if total_blocks == 0 {
+            0.0
+        } else {
+            free_blocks as f64 / total_blocks as f64
+        };
+
+        Ok(MaintenanceReport {
+            issue_count,
+            history_count,
+            filter_config_count,
+            database_size_bytes: self.database_size_bytes(),
+            total_blocks,
+            free_blocks,
+            fragmentation_ratio,
+            optimize_recommended: fragmentation_ratio > DEFAULT_OPTIMIZE_THRESHOLD,
+        })
+    }
+
+    /// 断片化率が閾値を超えている場合のみ最適化を実行
+    ///
+    /// 最適化を実行した場合は`true`、閾値以下で何もしなかった場合は`false`を返します。
+    pub async fn optimize_if_needed(&mut self, threshold: f64) -> Result<bool, Error> {
+        let report = self.maintenance_report().await?;
+        if report.fragmentation_ratio <= threshold {
+            return Ok(false);
+        }
+
+        self.optimize().await?;
+        Ok(true)
+    }
+
+    /// 任意のSELECT文を実行し、各行を列名をキーとするJSONオブジェクトとして返す
+    ///
+    /// `load_issues`では表せない集計などに使用します（例:
+    /// `SELECT project_key, status_name, COUNT(*) AS issues FROM issues GROUP BY project_key, status_name`）。
+    /// 圧縮保存が有効な場合、`raw_json`列にはカスタムフィールドしか残らないため、
+    /// 集計には`status_name`や`priority_name`などの列を使用してください。
+    /// SELECT（またはWITH）で始まらない文、複数の文、INSERT・UPDATE・DELETEなどデータや
+    /// スキーマを変更するキーワードを含む文は`InvalidInput`エラーで拒否します。
+    /// ストアの接続は外部アクセスを無効にしているため、`read_csv`・`read_text`・`glob`などの
+    /// ファイルを読み込むテーブル関数は実行時に`DatabaseError`になります。
+    /// 同じ名前の列が複数ある場合は後の列の値になります。
+    pub async fn query_raw(&self, sql: &str) -> Result<Vec<serde_json::Value>, Error> {
+        ensure_select_query(sql)?;
+        let sql = sql.to_string();
+
+        self.run_read("Raw query", move |conn| {
+            let mut stmt = conn.prepare(&sql)?;
+            let mut rows = stmt.query(params![])?;
+            let columns = rows
+                .as_ref()
+                .map(|stmt| stmt.column_names())
+                .unwrap_or_default();
+
+            let mut results = Vec::new();
+            while let Some(row) = rows.next()? {
+                let mut object = serde_json::Map::new();
+                for (i, column) in columns.iter().enumerate() {
+                    let value: duckdb::types::Value = row.get(i)?;
+                    object.insert(column.clone(), duckdb_value_to_json(value));
+                }
+                results.push(serde_json::Value::Object(object));
+            }
+            Ok(results)
+        })
+        .await
+    }
+
+    /// データベースファイルとWALファイルの合計サイズ
+    fn database_size_bytes(&self) -> u64 {
+        if self.db_path == Path::new(":memory:") {
+            return 0;
+        }
+
+        let mut wal_path = self.db_path.clone().into_os_string();
+        wal_path.push(".wal");
+
+        [self.db_path.clone(), PathBuf::from(wal_path)]
+            .iter()
+            .filter_map(|path| std::fs::metadata(path).ok())
+            .map(|metadata| metadata.len())
+            .sum()
+    }
+
+    /// DuckDBクエリでフィルター条件を構築
+    ///
+    /// [`IssueFilter::matches`]と同じ条件で絞り込み、JsonStoreと同じ結果になるようにします。
     fn build_where_clause(&self, filter: &IssueFilter) -> (String, Vec<String>) {
         let mut conditions = Vec::new();
         let mut params = Vec::new();
@@ -172,10 +666,100 @@ impl DuckDBStore {
             }
         }
 
-        // サマリー検索
+        // 優先度・Issue種別・報告者でフィルタ
+        for (column, values) in [
+            ("priority_name", &filter.priorities),
+            ("issue_type_name", &filter.issue_types),
+            ("reporter_display_name", &filter.reporters),
+        ] {
+            if !values.is_empty() {
+                let placeholders: Vec<String> = values.iter().map(|_| "?".to_string()).collect();
+                conditions.push(format!("{} IN ({})", column, placeholders.join(", ")));
+                params.extend(values.iter().cloned());
+            }
+        }
+
+        // ラベルでフィルタ（いずれかのラベルを持つIssueに一致）
+        // ラベルはカスタムフィールドとして保持されるため、圧縮時もraw_jsonに残る
+        if !filter.labels.is_empty() {
+            let label_conditions: Vec<&str> = filter
+                .labels
+                .iter()
+                .map(|_| "list_contains(json_extract_string(raw_json, '$.fields.labels[*]'), ?)")
+                .collect();
+            conditions.push(format!("({})", label_conditions.join(" OR ")));
+            params.extend(filter.labels.iter().cloned());
+        }
+
+        // 担当者でフィルタ（未割り当ての指定とはORで組み合わせる）
+        let mut assignee_conditions = Vec::new();
+        if !filter.assignees.is_empty() {
+            let placeholders: Vec<String> =
+                filter.assignees.iter().map(|_| "?".to_string()).collect();
+            assignee_conditions.push(format!(
+                "assignee_display_name IN ({})",
+                placeholders.join(", ")
+            ));
+            for assignee in &filter.assignees {
+                params.push(assignee.clone());
+            }
+        }
+        match filter.assignee_unassigned {
+            Some(true) => assignee_conditions.push("assignee_display_name IS NULL".to_string()),
+            Some(false) => conditions.push("assignee_display_name IS NOT NULL".to_string()),
+            None => {}
+        }
+        if !assignee_conditions.is_empty() {
+            conditions.push(format!("({})", assignee_conditions.join(" OR ")));
+        }
+
+        // 親Issueでフィルタ
+        if !filter.parent_keys.is_empty() {
+            let placeholders: Vec<String> =
+                filter.parent_keys.iter().map(|_| "?".to_string()).collect();
+            conditions.push(format!("parent_key IN ({})", placeholders.join(", ")));
+            for key in &filter.parent_keys {
+                params.push(key.clone());
+            }
+        }
+
+        // 作成日時・更新日時の範囲でフィルタ（開始を含み終了を含まない）
+        if let Some(ref range) = filter.created_range {
+            conditions.push("created >= ? AND created < ?".to_string());
+            params.push(format_db_timestamp(&range.start));
+            params.push(format_db_timestamp(&range.end));
+        }
+        if let Some(ref range) = filter.updated_range {
+            conditions.push("updated >= ? AND updated < ?".to_string());
+            params.push(format_db_timestamp(&range.start));
+            params.push(format_db_timestamp(&range.end));
+        }
+
+        // サマリー検索（JsonStoreと同じく大文字小文字を区別しない部分一致）
         if let Some(ref text) = filter.summary_contains {
-            conditions.push("summary LIKE ?".to_string());
-            params.push(format!("%{}%", text));
+            conditions.push("contains(lower(summary), lower(?))".to_string());
+            params.push(text.clone());
+        }
+
+        // 説明検索（保存時にADFから取り出したプレーンテキストに対して照合する）
+        if let Some(ref text) = filter.description_contains {
+            conditions.push("contains(lower(description_text), lower(?))".to_string());
+            params.push(text.clone());
+        }
+
+        // 本文検索（サマリーまたは説明のプレーンテキスト）
+        if let Some(ref text) = filter.text_search {
+            conditions.push(
+                "(contains(lower(summary), lower(?)) OR contains(lower(description_text), lower(?)))"
+                    .to_string(),
+            );
+            params.push(text.clone());
+            params.push(text.clone());
+        }
+
+        // カスタムフィールドでフィルタ
+        for custom_filter in &filter.custom_field_filters {
+            conditions.push(build_custom_field_condition(custom_filter, &mut params));
         }
 
         let where_clause = if conditions.is_empty() {
@@ -189,16 +773,26 @@ impl DuckDBStore {
 
     /// ソート順をSQL ORDER BY句に変換
     fn build_order_clause(&self, sort_order: &SortOrder) -> String {
-        match sort_order {
-            SortOrder::CreatedAsc => "ORDER BY created ASC".to_string(),
-            SortOrder::CreatedDesc => "ORDER BY created DESC".to_string(),
-            SortOrder::UpdatedAsc => "ORDER BY updated ASC".to_string(),
-            SortOrder::UpdatedDesc => "ORDER BY updated DESC".to_string(),
-            SortOrder::KeyAsc => "ORDER BY issue_key ASC".to_string(),
-            SortOrder::KeyDesc => "ORDER BY issue_key DESC".to_string(),
-            SortOrder::PriorityAsc => "ORDER BY priority_name ASC NULLS LAST".to_string(),
-            SortOrder::PriorityDesc => "ORDER BY priority_name DESC NULLS LAST".to_string(),
-        }
+        let order = match sort_order {
+            SortOrder::CreatedAsc => "created ASC",
+            SortOrder::CreatedDesc => "created DESC",
+            SortOrder::UpdatedAsc => "updated ASC",
+            SortOrder::UpdatedDesc => "updated DESC",
+            SortOrder::KeyAsc => return "ORDER BY issue_key ASC".to_string(),
+            SortOrder::KeyDesc => return "ORDER BY issue_key DESC".to_string(),
+            SortOrder::PriorityAsc => "priority_name ASC NULLS LAST",
+            SortOrder::PriorityDesc => "priority_name DESC NULLS LAST",
+            SortOrder::AssigneeAsc => "assignee_display_name ASC NULLS LAST",
+            SortOrder::AssigneeDesc => "assignee_display_name DESC NULLS LAST",
+            SortOrder::ReporterAsc => "reporter_display_name ASC",
+            SortOrder::ReporterDesc => "reporter_display_name DESC",
+            SortOrder::StatusAsc => "status_name ASC",
+            SortOrder::StatusDesc => "status_name DESC",
+            SortOrder::TypeAsc => "issue_type_name ASC",
+            SortOrder::TypeDesc => "issue_type_name DESC",
+        };
+        // 同じ値の行の順序を固定し、LIMIT/OFFSETでのページングで重複や欠落が起きないようにする
+        format!("ORDER BY {}, issue_key ASC", order)
     }
 
     /// 履歴フィルター条件をSQL WHERE句に変換
@@ -244,12 +838,12 @@ impl DuckDBStore {
             }
         }
 
-        // 日時範囲でフィルタ（簡素化）
+        // 日時範囲でフィルタ（開始を含み終了を含まない）
         if let Some(ref date_range) = filter.date_range {
             conditions.push("change_timestamp >= ?".to_string());
-            conditions.push("change_timestamp <= ?".to_string());
-            params.push(date_range.start.format("%Y-%m-%d %H:%M:%S").to_string());
-            params.push(date_range.end.format("%Y-%m-%d %H:%M:%S").to_string());
+            conditions.push("change_timestamp < ?".to_string());
+            params.push(format_db_timestamp(&date_range.start));
+            params.push(format_db_timestamp(&date_range.end));
         }
 
         let where_clause = if conditions.is_empty() {
@@ -263,477 +857,773 @@ impl DuckDBStore {
 
     /// 履歴ソート順をSQL ORDER BY句に変換
     fn build_history_order_clause(&self, sort_order: &crate::HistorySortOrder) -> String {
+        // ページングしても順序が変わらないよう、同値の場合はhistory_idで並べる
         match sort_order {
-            crate::HistorySortOrder::TimestampAsc => "ORDER BY change_timestamp ASC".to_string(),
-            crate::HistorySortOrder::TimestampDesc => "ORDER BY change_timestamp DESC".to_string(),
-            crate::HistorySortOrder::IssueKey => "ORDER BY issue_key ASC".to_string(),
-            crate::HistorySortOrder::FieldName => "ORDER BY field_name ASC".to_string(),
-        }
-    }
-}
-
-#[async_trait]
-impl PersistenceStore for DuckDBStore {
-    async fn save_issues(&mut self, issues: &[Issue]) -> Result<usize, Error> {
-        let conn = Arc::clone(&self.connection);
-        let issues_clone = issues.to_vec();
-
-        task::spawn_blocking(move || {
-            let conn = conn.lock().unwrap();
-
-            // トランザクション開始
-            conn.execute("BEGIN TRANSACTION", params![])?;
-
-            let mut saved_count = 0;
-            for issue in &issues_clone {
-                let raw_json = match serde_json::to_string(issue) {
-                    Ok(json) => json,
-                    Err(_) => continue, // エラーの場合はスキップ
-                };
-
-                let result = conn.execute(
-                    r#"
-                    INSERT INTO issues
-                    (id, issue_key, summary, description, status_name, priority_name,
-                     issue_type_name, project_key, project_name, reporter_display_name,
-                     assignee_display_name, created, updated, raw_json)
-                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-                    ON CONFLICT (id) DO UPDATE SET
-                        issue_key = EXCLUDED.issue_key,
-                        summary = EXCLUDED.summary,
-                        description = EXCLUDED.description,
-                        status_name = EXCLUDED.status_name,
-                        priority_name = EXCLUDED.priority_name,
-                        issue_type_name = EXCLUDED.issue_type_name,
-                        project_key = EXCLUDED.project_key,
-                        project_name = EXCLUDED.project_name,
-                        reporter_display_name = EXCLUDED.reporter_display_name,
-                        assignee_display_name = EXCLUDED.assignee_display_name,
-                        created = EXCLUDED.created,
-                        updated = EXCLUDED.updated,
-                        raw_json = EXCLUDED.raw_json
-                    "#,
-                    params![
-                        &issue.id,
-                        &issue.key,
-                        &issue.fields.summary,
-                        issue.fields.description.as_ref().map(|d| d.to_string()),
-                        &issue.fields.status.name,
-                        issue.fields.priority.as_ref().map(|p| &p.name),
-                        &issue.fields.issue_type.name,
-                        issue.fields.project.as_ref().map(|p| &p.key),
-                        issue.fields.project.as_ref().map(|p| &p.name),
-                        &issue.fields.reporter.display_name,
-                        issue.fields.assignee.as_ref().map(|a| &a.display_name),
-                        &issue
-                            .fields
-                            .created
-                            .format("%Y-%m-%d %H:%M:%S%.f")
-                            .to_string(),
-                        &issue
-                            .fields
-                            .updated
-                            .format("%Y-%m-%d %H:%M:%S%.f")
-                            .to_string(),
-                        &raw_json
-                    ],
-                );
-
-                if result.is_ok() {
-                    saved_count += 1;
-                }
+            crate::HistorySortOrder::TimestampAsc => {
+                "ORDER BY change_timestamp ASC, history_id ASC".to_string()
             }
-
-            // トランザクションコミット
-            conn.execute("COMMIT", params![])?;
-
-            Ok::<usize, duckdb::Error>(saved_count)
-        })
-        .await
-        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
-        .map_err(|e| Error::DatabaseError(format!("Save operation failed: {}", e)))
+            crate::HistorySortOrder::TimestampDesc => {
+                "ORDER BY change_timestamp DESC, history_id ASC".to_string()
+            }
+            crate::HistorySortOrder::IssueKey => {
+                "ORDER BY issue_key ASC, history_id ASC".to_string()
+            }
+            crate::HistorySortOrder::FieldName => {
+                "ORDER BY field_name ASC, history_id ASC".to_string()
+            }
+        }
     }
 
-    async fn load_issues(&self, filter: &IssueFilter) -> Result<Vec<Issue>, Error> {
-        let conn = Arc::clone(&self.connection);
-        let (where_clause, filter_params) = self.build_where_clause(filter);
-        let order_clause = self.build_order_clause(&filter.sort_order);
+    /// 履歴データをオフセット付きで取得
+    async fn query_issue_history(
+        &self,
+        filter: &HistoryFilter,
+        offset: Option<usize>,
+    ) -> Result<Vec<IssueHistory>, Error> {
+        let (where_clause, filter_params) = self.build_history_where_clause(filter);
+        let order_clause = self.build_history_order_clause(&filter.sort_order);
 
-        let limit_clause = match (filter.offset, filter.limit) {
-            (Some(offset), Some(limit)) => format!("LIMIT {} OFFSET {}", limit, offset),
-            (None, Some(limit)) => format!("LIMIT {}", limit),
-            (Some(offset), None) => format!("OFFSET {}", offset),
-            (None, None) => "".to_string(),
-        };
+        let limit_clause = build_limit_clause(offset, filter.limit);
 
         let query = format!(
-            "SELECT raw_json FROM issues {} {} {}",
+            "SELECT issue_id, issue_key, change_id,
+                    strftime(change_timestamp, '%Y-%m-%d %H:%M:%S.%f') as change_timestamp_str,
+                    author_account_id, author_display_name, author_email, field_name, field_id, from_value,
+                    to_value, from_display_value, to_display_value,
+                    strftime(created_at, '%Y-%m-%d %H:%M:%S.%f') as created_at_str
+             FROM issue_history {} {} {}",
             where_clause, order_clause, limit_clause
         );
 
-        task::spawn_blocking(move || {
-            let conn = conn.lock().unwrap();
+        self.run_read("Load history operation", move |conn| {
             let mut stmt = conn.prepare(&query)?;
 
-            // パラメータを文字列リファレンスに変換
             let params_refs: Vec<&dyn duckdb::ToSql> = filter_params
                 .iter()
                 .map(|p| p as &dyn duckdb::ToSql)
                 .collect();
 
             let rows = stmt.query_map(params_refs.as_slice(), |row| {
-                let raw_json: String = row.get(0)?;
-                Ok(raw_json)
-            })?;
+                let issue_id: String = row.get(0)?;
+                let issue_key: String = row.get(1)?;
+                let change_id: String = row.get(2)?;
+                let timestamp_str: String = row.get(3)?;
+                let account_id: Option<String> = row.get(4)?;
+                let display_name: Option<String> = row.get(5)?;
+                let email: Option<String> = row.get(6)?;
+                let field_name: String = row.get(7)?;
+                let field_id: Option<String> = row.get(8)?;
+                let from_value: Option<String> = row.get(9)?;
+                let to_value: Option<String> = row.get(10)?;
+                let from_display: Option<String> = row.get(11)?;
+                let to_display: Option<String> = row.get(12)?;
+                let created_str: String = row.get(13)?;
 
-            let mut issues = Vec::new();
-            for row in rows {
-                let raw_json = row?;
-                if let Ok(issue) = serde_json::from_str::<Issue>(&raw_json) {
-                    issues.push(issue);
-                }
-            }
+                let change_timestamp = parse_history_timestamp(3, &timestamp_str)?;
+                let created_at = parse_history_timestamp(13, &created_str)?;
 
-            Ok::<Vec<Issue>, duckdb::Error>(issues)
-        })
-        .await
-        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
-        .map_err(|e| Error::DatabaseError(format!("Load operation failed: {}", e)))
-    }
+                let author =
+                    if let (Some(account_id), Some(display_name)) = (account_id, display_name) {
+                        Some(HistoryAuthor {
+                            account_id,
+                            display_name,
+                            email_address: email,
+                        })
+                    } else {
+                        None
+                    };
 
-    async fn load_all_issues(&self) -> Result<Vec<Issue>, Error> {
-        let filter = IssueFilter::new();
-        self.load_issues(&filter).await
-    }
+                let mut history =
+                    IssueHistory::new(issue_id, issue_key, change_id, change_timestamp, field_name);
 
-    async fn count_issues(&self, filter: &IssueFilter) -> Result<usize, Error> {
-        let conn = Arc::clone(&self.connection);
-        let (where_clause, filter_params) = self.build_where_clause(filter);
+                if let Some(author) = author {
+                    history = history.with_author(author);
+                }
 
-        let query = format!("SELECT COUNT(*) FROM issues {}", where_clause);
+                history = history.with_field_change(from_value, to_value, from_display, to_display);
 
-        task::spawn_blocking(move || {
-            let conn = conn.lock().unwrap();
-            let mut stmt = conn.prepare(&query)?;
+                if let Some(field_id) = field_id {
+                    history = history.with_field_id(field_id);
+                }
 
-            let params_refs: Vec<&dyn duckdb::ToSql> = filter_params
-                .iter()
-                .map(|p| p as &dyn duckdb::ToSql)
-                .collect();
+                // Set the created_at timestamp from database
+                history = history.with_created_at(created_at);
 
-            let count: i64 = stmt.query_row(params_refs.as_slice(), |row| row.get(0))?;
-            Ok::<usize, duckdb::Error>(count as usize)
+                Ok(history)
+            })?;
+
+            let mut histories = Vec::new();
+            for row in rows {
+                histories.push(row?);
+            }
+
+            Ok::<Vec<IssueHistory>, duckdb::Error>(histories)
         })
         .await
-        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
-        .map_err(|e| Error::DatabaseError(format!("Count operation failed: {}", e)))
     }
+}
 
-    async fn delete_issues(&mut self, issue_keys: &[String]) -> Result<usize, Error> {
-        let conn = Arc::clone(&self.connection);
-        let keys = issue_keys.to_vec();
+/// `query_raw`で拒否する、データやスキーマ・設定を変更するキーワード
+const NON_SELECT_KEYWORDS: [&str; 17] = [
+    "INSERT",
+    "UPDATE",
+    "DELETE",
+    "CREATE",
+    "DROP",
+    "ALTER",
+    "TRUNCATE",
+    "ATTACH",
+    "DETACH",
+    "COPY",
+    "EXPORT",
+    "IMPORT",
+    "INSTALL",
+    "LOAD",
+    "PRAGMA",
+    "VACUUM",
+    "CHECKPOINT",
+];
+
+/// SQLが単一のSELECT文（WITH句を含む）であることを確認する
+///
+/// 文字列リテラル・引用符付きの識別子・コメントの中は判定の対象外です。
+fn ensure_select_query(sql: &str) -> Result<(), Error> {
+    let mut words: Vec<String> = Vec::new();
+    let mut chars = sql.chars().peekable();
+    let mut word = String::new();
+    let mut statement_ended = false;
+
+    while let Some(c) = chars.next() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            if statement_ended {
+                return Err(Error::InvalidInput(
+                    "Only a single SELECT statement is allowed".to_string(),
+                ));
+            }
+            word.push(c.to_ascii_uppercase());
+            continue;
+        }
+        if !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
 
-        task::spawn_blocking(move || {
-            let conn = conn.lock().unwrap();
+        match c {
+            '\'' | '"' => {
+                // 引用符の連続（''）はエスケープとして読み飛ばされる
+                for next in chars.by_ref() {
+                    if next == c {
+                        break;
+                    }
+                }
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for next in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            ';' => statement_ended = true,
+            c if statement_ended && !c.is_whitespace() => {
+                return Err(Error::InvalidInput(
+                    "Only a single SELECT statement is allowed".to_string(),
+                ));
+            }
+            _ => {}
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
 
-            let placeholders: Vec<String> = keys.iter().map(|_| "?".to_string()).collect();
-            let query = format!(
-                "DELETE FROM issues WHERE issue_key IN ({})",
-                placeholders.join(", ")
-            );
+    match words.first().map(String::as_str) {
+        Some("SELECT") | Some("WITH") => {}
+        _ => {
+            return Err(Error::InvalidInput(format!(
+                "Only SELECT statements are allowed: {}",
+                sql.trim()
+            )));
+        }
+    }
+    if let Some(keyword) = words
+        .iter()
+        .find(|w| NON_SELECT_KEYWORDS.contains(&w.as_str()))
+    {
+        return Err(Error::InvalidInput(format!(
+            "{} is not allowed in a SELECT query",
+            keyword
+        )));
+    }
 
-            let params_refs: Vec<&dyn duckdb::ToSql> =
-                keys.iter().map(|k| k as &dyn duckdb::ToSql).collect();
+    Ok(())
+}
 
-            let deleted_count = conn.execute(&query, params_refs.as_slice())?;
-            Ok::<usize, duckdb::Error>(deleted_count)
-        })
-        .await
-        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
-        .map_err(|e| Error::DatabaseError(format!("Delete operation failed: {}", e)))
+/// DuckDBの値をJSONの値に変換する
+///
+/// 日時はRFC 3339形式、日付は`YYYY-MM-DD`、バイナリはバイト値の配列になります。
+/// JSONで表せない種類の値はデバッグ表記の文字列にします。
+fn duckdb_value_to_json(value: duckdb::types::Value) -> serde_json::Value {
+    use duckdb::types::Value;
+
+    match value {
+        Value::Null => serde_json::Value::Null,
+        Value::Boolean(b) => b.into(),
+        Value::TinyInt(n) => n.into(),
+        Value::SmallInt(n) => n.into(),
+        Value::Int(n) => n.into(),
+        Value::BigInt(n) => n.into(),
+        Value::UTinyInt(n) => n.into(),
+        Value::USmallInt(n) => n.into(),
+        Value::UInt(n) => n.into(),
+        Value::UBigInt(n) => n.into(),
+        // i64に収まらない値は精度を保つため文字列にする
+        Value::HugeInt(n) => i64::try_from(n)
+            .map(serde_json::Value::from)
+            .unwrap_or_else(|_| n.to_string().into()),
+        Value::Float(n) => n.into(),
+        Value::Double(n) => n.into(),
+        Value::Decimal(n) => n.to_string().into(),
+        Value::Text(text) | Value::Enum(text) => text.into(),
+        Value::Blob(bytes) => bytes.into(),
+        Value::Timestamp(unit, n) => chrono::DateTime::from_timestamp_micros(unit.to_micros(n))
+            .map(|dt| dt.to_rfc3339().into())
+            .unwrap_or(serde_json::Value::Null),
+        Value::Date32(days) => chrono::NaiveDate::from_ymd_opt(1970, 1, 1)
+            .and_then(|epoch| epoch.checked_add_signed(chrono::Duration::days(days.into())))
+            .map(|date| date.to_string().into())
+            .unwrap_or(serde_json::Value::Null),
+        Value::List(values) | Value::Array(values) => values
+            .into_iter()
+            .map(duckdb_value_to_json)
+            .collect::<Vec<_>>()
+            .into(),
+        other => format!("{:?}", other).into(),
     }
+}
 
-    async fn optimize(&mut self) -> Result<(), Error> {
-        let conn = Arc::clone(&self.connection);
+/// カスタムフィールド条件をraw_jsonに対する`json_extract`の条件に変換する
+///
+/// 評価は`CustomFieldFilter::matches`と同じ規則で行います。
+/// JSONパスはSQLに直接埋め込むため、フィールドIDに英数字と`_`以外が含まれる場合は
+/// 一致なしとして扱います。
+fn build_custom_field_condition(filter: &CustomFieldFilter, params: &mut Vec<String>) -> String {
+    if filter.field_id.is_empty()
+        || !filter
+            .field_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return "FALSE".to_string();
+    }
 
-        task::spawn_blocking(move || {
-            let conn = conn.lock().unwrap();
-            // DuckDBの最適化コマンド
-            conn.execute("VACUUM", params![])?;
-            conn.execute("ANALYZE", params![])?;
-            Ok::<(), duckdb::Error>(())
-        })
-        .await
-        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
-        .map_err(|e| Error::DatabaseError(format!("Optimize operation failed: {}", e)))?;
+    let path = format!("$.fields.{}", filter.field_id);
 
-        Ok(())
+    match (filter.operator, filter.value.as_f64()) {
+        (CustomFieldOperator::Eq, Some(number)) => {
+            params.push(number.to_string());
+            format!(
+                "TRY_CAST(json_extract_string(raw_json, '{}') AS DOUBLE) = CAST(? AS DOUBLE)",
+                path
+            )
+        }
+        (CustomFieldOperator::Gt, Some(number)) => {
+            params.push(number.to_string());
+            format!(
+                "TRY_CAST(json_extract_string(raw_json, '{}') AS DOUBLE) > CAST(? AS DOUBLE)",
+                path
+            )
+        }
+        (CustomFieldOperator::Lt, Some(number)) => {
+            params.push(number.to_string());
+            format!(
+                "TRY_CAST(json_extract_string(raw_json, '{}') AS DOUBLE) < CAST(? AS DOUBLE)",
+                path
+            )
+        }
+        // 数値以外との大小比較は常に不一致
+        (CustomFieldOperator::Gt | CustomFieldOperator::Lt, None) => "FALSE".to_string(),
+        (CustomFieldOperator::Eq, None) => {
+            // 値そのもの、または選択肢オブジェクトのvalue/nameと比較
+            let text = json_scalar_text(&filter.value);
+            params.extend([text.clone(), text.clone(), text]);
+            format!(
+                "(json_extract_string(raw_json, '{0}') = ? \
+                  OR json_extract_string(raw_json, '{0}.value') = ? \
+                  OR json_extract_string(raw_json, '{0}.name') = ?)",
+                path
+            )
+        }
+        (CustomFieldOperator::Contains, _) => {
+            // 文字列は部分一致、配列は要素（または要素のvalue/name）との一致
+            let text = json_scalar_text(&filter.value);
+            params.extend([text.clone(), text.clone(), text.clone(), text]);
+            format!(
+                "(CASE json_type(raw_json, '{0}') \
+                  WHEN 'VARCHAR' THEN contains(json_extract_string(raw_json, '{0}'), ?) \
+                  WHEN 'ARRAY' THEN list_contains(json_extract_string(raw_json, '{0}[*]'), ?) \
+                                 OR list_contains(json_extract_string(raw_json, '{0}[*].value'), ?) \
+                                 OR list_contains(json_extract_string(raw_json, '{0}[*].name'), ?) \
+                  ELSE FALSE END)",
+                path
+            )
+        }
     }
+}
 
-    async fn get_stats(&self) -> Result<StorageStats, Error> {
-        let conn = Arc::clone(&self.connection);
+/// JSONのスカラー値を`json_extract_string`の結果と比較できる文字列に変換する
+fn json_scalar_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
 
-        task::spawn_blocking(move || {
-            let conn = conn.lock().unwrap();
+/// 射影の項目に対応するSELECT式
+fn projection_column(field: &IssueField) -> &'static str {
+    match field {
+        IssueField::Id => "id",
+        IssueField::Key => "issue_key",
+        IssueField::Summary => "summary",
+        IssueField::Status => "status_name",
+        IssueField::Priority => "priority_name",
+        IssueField::IssueType => "issue_type_name",
+        IssueField::Project => "project_key",
+        IssueField::Reporter => "reporter_display_name",
+        IssueField::Assignee => "assignee_display_name",
+        IssueField::Created => "strftime(created, '%Y-%m-%d %H:%M:%S.%f')",
+        IssueField::Updated => "strftime(updated, '%Y-%m-%d %H:%M:%S.%f')",
+        IssueField::Resolution => "resolution_name",
+    }
+}
 
-            let mut stats = StorageStats::new();
+/// 取得した列の値を射影に設定する
+fn set_projection_value(
+    projection: &mut IssueProjection,
+    field: IssueField,
+    value: Option<String>,
+) {
+    match field {
+        IssueField::Id => projection.id = value,
+        IssueField::Key => projection.key = value,
+        IssueField::Summary => projection.summary = value,
+        IssueField::Status => projection.status = value,
+        IssueField::Priority => projection.priority = value,
+        IssueField::IssueType => projection.issue_type = value,
+        IssueField::Project => projection.project_key = value,
+        IssueField::Reporter => projection.reporter = value,
+        IssueField::Assignee => projection.assignee = value,
+        IssueField::Created => projection.created = value.as_deref().and_then(parse_db_timestamp),
+        IssueField::Updated => projection.updated = value.as_deref().and_then(parse_db_timestamp),
+        IssueField::Resolution => projection.resolution = value,
+    }
+}
 
-            // 総Issue数
-            let total: i64 = conn.prepare("SELECT COUNT(*) FROM issues")?
-                .query_row(params![], |row| row.get(0))?;
-            stats.total_issues = total as usize;
+/// LIMIT/OFFSET句を構築する
+///
+/// DuckDBが受け付けない大きな値は上限に丸め、上限以上のLIMITは制限なしとして扱います。
+/// 件数を超えるOFFSETはエラーにならず空の結果になります。
+fn build_limit_clause(offset: Option<usize>, limit: Option<usize>) -> String {
+    let clamp = |value: usize| (value as u64).min(MAX_SQL_LIMIT);
+    let offset = offset.map(clamp);
+    let limit = limit.map(clamp).filter(|&limit| limit < MAX_SQL_LIMIT);
+
+    match (offset, limit) {
+        (Some(offset), Some(limit)) => format!("LIMIT {} OFFSET {}", limit, offset),
+        (None, Some(limit)) => format!("LIMIT {}", limit),
+        (Some(offset), None) => format!("OFFSET {}", offset),
+        (None, None) => "".to_string(),
+    }
+}
 
-            // プロジェクト別統計
-            let mut stmt = conn.prepare("SELECT project_key, COUNT(*) FROM issues WHERE project_key IS NOT NULL GROUP BY project_key")?;
-            let project_rows = stmt.query_map(params![], |row| {
-                let key: String = row.get(0)?;
-                let count: i64 = row.get(1)?;
-                Ok((key, count as usize))
-            })?;
+/// `strftime`で文字列化したTIMESTAMP列をUTCの日時として解析する
+fn parse_db_timestamp(value: &str) -> Option<chrono::DateTime<Utc>> {
+    chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f")
+        .ok()
+        .map(|dt| dt.and_utc())
+}
 
-            for row in project_rows {
-                let (key, count) = row?;
-                stats.issues_by_project.insert(key, count);
-            }
+/// 履歴の日時列を解析する
+///
+/// 解析できない値は現在時刻などで補わず、行の変換エラーとして返します。
+fn parse_history_timestamp(index: usize, value: &str) -> duckdb::Result<chrono::DateTime<Utc>> {
+    parse_db_timestamp(value).ok_or_else(|| {
+        duckdb::Error::FromSqlConversionFailure(
+            index,
+            duckdb::types::Type::Text,
+            format!("invalid timestamp: {}", value).into(),
+        )
+    })
+}
 
-            // ステータス別統計
-            let mut stmt = conn.prepare("SELECT status_name, COUNT(*) FROM issues GROUP BY status_name")?;
-            let status_rows = stmt.query_map(params![], |row| {
-                let name: String = row.get(0)?;
-                let count: i64 = row.get(1)?;
-                Ok((name, count as usize))
-            })?;
+/// フィルター設定の日時列を復元する
+///
+/// 値が無い場合は`None`を返します。解析できない値は警告を出して`None`を返すため、
+/// 呼び出し側でフォールバック値を使用します。
+fn restore_filter_timestamp(
+    config_id: &str,
+    column: &str,
+    value: Option<&str>,
+) -> Option<chrono::DateTime<Utc>> {
+    let value = value?;
+    let parsed = parse_db_timestamp(value);
+    if parsed.is_none() {
+        warn!(
+            config_id,
+            column, value, "Failed to parse filter config timestamp; using fallback"
+        );
+    }
+    parsed
+}
 
-            for row in status_rows {
-                let (name, count) = row?;
-                stats.issues_by_status.insert(name, count);
-            }
+/// 日時をTIMESTAMP列の保存形式（UTC、マイクロ秒まで）に変換
+///
+/// DuckDBのTIMESTAMPはマイクロ秒精度のため、保存時と検索条件で同じ桁数に揃えて
+/// 境界の日時が一致するようにします。
+fn format_db_timestamp(datetime: &chrono::DateTime<Utc>) -> String {
+    datetime.format("%Y-%m-%d %H:%M:%S%.6f").to_string()
+}
 
-            // Issue種別別統計
-            let mut stmt = conn.prepare("SELECT issue_type_name, COUNT(*) FROM issues GROUP BY issue_type_name")?;
-            let type_rows = stmt.query_map(params![], |row| {
-                let name: String = row.get(0)?;
-                let count: i64 = row.get(1)?;
-                Ok((name, count as usize))
-            })?;
+/// 保存用に変換したIssueのJSON
+struct RawJson {
+    /// `raw_json`列の値（圧縮時はカスタムフィールドのみ）
+    text: String,
+    /// `raw_json_gz`列の値（圧縮しない場合は`None`）
+    compressed: Option<Vec<u8>>,
+}
 
-            for row in type_rows {
-                let (name, count) = row?;
-                stats.issues_by_type.insert(name, count);
-            }
+/// IssueをJSONに変換し、必要に応じてgzip圧縮する
+///
+/// 圧縮時も`raw_json`列に対するカスタムフィールド条件が使えるよう、
+/// カスタムフィールドのみを`fields`に持つJSONを`text`に残します。
+fn encode_raw_json(issue: &Issue, compress: bool) -> Result<RawJson, Error> {
+    let json =
+        serde_json::to_string(issue).map_err(|e| Error::SerializationError(e.to_string()))?;
+    if !compress {
+        return Ok(RawJson {
+            text: json,
+            compressed: None,
+        });
+    }
 
-            stats.last_updated = Utc::now();
-            stats.compression_ratio = 0.0; // DuckDBは自動圧縮
-            stats.index_count = 4; // 作成したインデックス数
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(json.as_bytes())?;
+    let compressed = encoder.finish()?;
 
-            Ok::<StorageStats, duckdb::Error>(stats)
-        })
-        .await
-        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
-        .map_err(|e| Error::DatabaseError(format!("Stats operation failed: {}", e)))
+    let text = serde_json::json!({ "fields": &issue.fields.custom_fields }).to_string();
+    Ok(RawJson {
+        text,
+        compressed: Some(compressed),
+    })
+}
+
+/// 保存されたIssueのJSONを取り出す（圧縮されている場合は展開する）
+fn decode_raw_json(text: String, compressed: Option<Vec<u8>>) -> std::io::Result<String> {
+    match compressed {
+        Some(bytes) => {
+            let mut json = String::new();
+            GzDecoder::new(&bytes[..]).read_to_string(&mut json)?;
+            Ok(json)
+        }
+        None => Ok(text),
     }
+}
 
-    async fn save_filter_config(&mut self, config: &FilterConfig) -> Result<(), Error> {
+/// テーブルに列が存在するかどうか
+fn has_column(conn: &Connection, table: &str, column: &str) -> duckdb::Result<bool> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM information_schema.columns WHERE table_name = ? AND column_name = ?",
+        params![table, column],
+        |row| row.get(0),
+    )?;
+    Ok(count > 0)
+}
+
+/// 追加した列を保存済みのIssueのJSONから埋める
+///
+/// 圧縮されたJSONはSQLから参照できないため、行ごとに展開してIssueに戻してから値を求めます。
+/// 読み込めない行と値が`None`の行はNULLのままにします。
+fn backfill_issue_column<F>(conn: &Connection, column: &str, value: F) -> duckdb::Result<()>
+where
+    F: Fn(&Issue) -> Option<String>,
+{
+    let rows = conn
+        .prepare("SELECT id, raw_json, raw_json_gz FROM issues")?
+        .query_map(params![], |row| {
+            let id: String = row.get(0)?;
+            let raw_json: String = row.get(1)?;
+            let raw_json_gz: Option<Vec<u8>> = row.get(2)?;
+            Ok((id, raw_json, raw_json_gz))
+        })?
+        .collect::<duckdb::Result<Vec<_>>>()?;
+
+    let update = format!("UPDATE issues SET {} = ? WHERE id = ?", column);
+    for (id, raw_json, raw_json_gz) in rows {
+        let Ok(raw_json) = decode_raw_json(raw_json, raw_json_gz) else {
+            continue;
+        };
+        let Ok(issue) = serde_json::from_str::<Issue>(&raw_json) else {
+            continue;
+        };
+        if let Some(value) = value(&issue) {
+            conn.execute(&update, params![value, id])?;
+        }
+    }
+    Ok(())
+}
+
+/// Issueを1件UPSERTする（トランザクションは呼び出し側で管理）
+fn upsert_issue(conn: &Connection, issue: &Issue, raw_json: &RawJson) -> duckdb::Result<usize> {
+    let time_tracking = issue.fields.time_tracking.as_ref();
+    conn.execute(
+        r#"
+        INSERT INTO issues
+        (id, issue_key, summary, description, status_name, priority_name,
+         issue_type_name, project_key, project_name, reporter_display_name,
+         assignee_display_name, created, updated, raw_json, resolution_name, resolution_date,
+         original_estimate_seconds, remaining_estimate_seconds, time_spent_seconds,
+         status_category_key, vote_count, watch_count, raw_json_gz, parent_key, description_text)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT (id) DO UPDATE SET
+            issue_key = EXCLUDED.issue_key,
+            summary = EXCLUDED.summary,
+            description = EXCLUDED.description,
+            status_name = EXCLUDED.status_name,
+            priority_name = EXCLUDED.priority_name,
+            issue_type_name = EXCLUDED.issue_type_name,
+            project_key = EXCLUDED.project_key,
+            project_name = EXCLUDED.project_name,
+            reporter_display_name = EXCLUDED.reporter_display_name,
+            assignee_display_name = EXCLUDED.assignee_display_name,
+            created = EXCLUDED.created,
+            updated = EXCLUDED.updated,
+            raw_json = EXCLUDED.raw_json,
+            resolution_name = EXCLUDED.resolution_name,
+            resolution_date = EXCLUDED.resolution_date,
+            original_estimate_seconds = EXCLUDED.original_estimate_seconds,
+            remaining_estimate_seconds = EXCLUDED.remaining_estimate_seconds,
+            time_spent_seconds = EXCLUDED.time_spent_seconds,
+            status_category_key = EXCLUDED.status_category_key,
+            vote_count = EXCLUDED.vote_count,
+            watch_count = EXCLUDED.watch_count,
+            raw_json_gz = EXCLUDED.raw_json_gz,
+            parent_key = EXCLUDED.parent_key,
+            description_text = EXCLUDED.description_text
+        "#,
+        params![
+            &issue.id,
+            &issue.key,
+            &issue.fields.summary,
+            issue.fields.description.as_ref().map(|d| d.to_string()),
+            &issue.fields.status.name,
+            issue.fields.priority.as_ref().map(|p| &p.name),
+            &issue.fields.issue_type.name,
+            issue.fields.project.as_ref().map(|p| &p.key),
+            issue.fields.project.as_ref().map(|p| &p.name),
+            &issue.fields.reporter.display_name,
+            issue.fields.assignee.as_ref().map(|a| &a.display_name),
+            format_db_timestamp(&issue.fields.created),
+            format_db_timestamp(&issue.fields.updated),
+            &raw_json.text,
+            issue.fields.resolution.as_ref().map(|r| &r.name),
+            issue
+                .fields
+                .resolution_date
+                .as_ref()
+                .map(format_db_timestamp),
+            time_tracking.and_then(|t| t.original_estimate_seconds),
+            time_tracking.and_then(|t| t.remaining_estimate_seconds),
+            time_tracking.and_then(|t| t.time_spent_seconds),
+            issue.status_category_key(),
+            issue.fields.votes.as_ref().map(|v| v.votes as i64),
+            issue.fields.watches.as_ref().map(|w| w.watch_count as i64),
+            raw_json.compressed.as_deref(),
+            issue.parent_key(),
+            issue.description_text(),
+        ],
+    )
+}
+
+/// 履歴レコードを1件INSERTする（トランザクションは呼び出し側で管理）
+fn insert_history(conn: &Connection, history: &IssueHistory) -> duckdb::Result<usize> {
+    conn.execute(
+        r#"
+        INSERT INTO issue_history
+        (issue_id, issue_key, change_id, change_timestamp, author_account_id,
+         author_display_name, author_email, field_name, field_id, from_value,
+         to_value, from_display_value, to_display_value, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        params![
+            &history.issue_id,
+            &history.issue_key,
+            &history.change_id,
+            &format_db_timestamp(&history.change_timestamp),
+            &history.author.as_ref().map(|a| &a.account_id),
+            &history.author.as_ref().map(|a| &a.display_name),
+            &history
+                .author
+                .as_ref()
+                .and_then(|a| a.email_address.as_ref()),
+            &history.field_name,
+            &history.field_id,
+            &history.from_value,
+            &history.to_value,
+            &history.from_display_value,
+            &history.to_display_value,
+            &format_db_timestamp(&history.created_at),
+        ],
+    )
+}
+
+#[async_trait]
+impl PersistenceStore for DuckDBStore {
+    async fn save_issues(&mut self, issues: &[Issue]) -> Result<usize, Error> {
         let conn = Arc::clone(&self.connection);
-        let config_clone = config.clone();
+        let issues_clone = issues.to_vec();
+        let nested = self.in_transaction;
+        let raw_compression = self.raw_compression;
 
         task::spawn_blocking(move || {
             let conn = conn.lock().unwrap();
 
-            let filter_json = match serde_json::to_string(&config_clone.filter) {
-                Ok(json) => json,
-                Err(_) => return Err(duckdb::Error::QueryReturnedNoRows),
-            };
+            // トランザクション開始（外側のトランザクション中はその一部として書き込む）
+            if !nested {
+                conn.execute("BEGIN TRANSACTION", params![])?;
+            }
 
-            conn.execute(
-                r#"
-                INSERT INTO filter_configs
-                (id, name, description, filter_json, created_at, updated_at, usage_count, last_used_at)
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
-                ON CONFLICT (id) DO UPDATE SET
-                    name = EXCLUDED.name,
-                    description = EXCLUDED.description,
-                    filter_json = EXCLUDED.filter_json,
-                    updated_at = EXCLUDED.updated_at,
-                    usage_count = EXCLUDED.usage_count,
-                    last_used_at = EXCLUDED.last_used_at
-                "#,
-                params![
-                    config_clone.id,
-                    config_clone.name,
-                    config_clone.description,
-                    filter_json,
-                    config_clone.created_at.format("%Y-%m-%d %H:%M:%S%.f").to_string(),
-                    config_clone.updated_at.format("%Y-%m-%d %H:%M:%S%.f").to_string(),
-                    config_clone.usage_count as i64,
-                    config_clone.last_used_at.map(|t| t.format("%Y-%m-%d %H:%M:%S%.f").to_string()),
-                ],
-            )?;
+            let mut saved_count = 0;
+            for issue in &issues_clone {
+                let raw_json = match encode_raw_json(issue, raw_compression) {
+                    Ok(json) => json,
+                    Err(_) => continue, // エラーの場合はスキップ
+                };
 
-            Ok::<(), duckdb::Error>(())
+                let result = upsert_issue(&conn, issue, &raw_json);
+
+                if result.is_ok() {
+                    saved_count += 1;
+                }
+            }
+
+            // トランザクションコミット
+            if !nested {
+                conn.execute("COMMIT", params![])?;
+            }
+
+            Ok::<usize, duckdb::Error>(saved_count)
         })
         .await
         .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
-        .map_err(|e| Error::DatabaseError(format!("Save filter config failed: {}", e)))?;
-
-        Ok(())
+        .map_err(|e| Error::DatabaseError(format!("Save operation failed: {}", e)))
     }
 
-    async fn load_filter_config(&self) -> Result<Option<FilterConfig>, Error> {
-        let conn = Arc::clone(&self.connection);
+    /// `save_issues`はキー単位でupsertするため、そのまま呼び出します。
+    async fn upsert_issues(&mut self, issues: &[Issue]) -> Result<usize, Error> {
+        self.save_issues(issues).await
+    }
 
-        task::spawn_blocking(move || {
-            let conn = conn.lock().unwrap();
+    async fn load_issues(&self, filter: &IssueFilter) -> Result<Vec<Issue>, Error> {
+        let (where_clause, filter_params) = self.build_where_clause(filter);
+        let order_clause = self.build_order_clause(&filter.sort_order);
 
-            let mut stmt = conn.prepare(
-                "SELECT id, name, description, filter_json, created_at, updated_at, usage_count, last_used_at
-                 FROM filter_configs ORDER BY updated_at DESC LIMIT 1"
-            )?;
+        let limit_clause = build_limit_clause(filter.offset, filter.limit);
 
-            let result = stmt.query_row(params![], |row| {
-                let id: String = row.get(0)?;
-                let name: String = row.get(1)?;
-                let description: Option<String> = row.get(2)?;
-                let filter_json: String = row.get(3)?;
-                // DuckDBでは異なる型として格納されているため、Stringで取得を試行
-                let created_at_str: String = match row.get::<_, String>(4) {
-                    Ok(s) => s,
-                    Err(_) => "2024-01-01 00:00:00".to_string(), // フォールバック
-                };
-                let updated_at_str: String = match row.get::<_, String>(5) {
-                    Ok(s) => s,
-                    Err(_) => "2024-01-01 00:00:00".to_string(), // フォールバック
-                };
-                let usage_count: i64 = row.get(6)?;
-                let last_used_at_str: Option<String> = row.get::<_, Option<String>>(7).unwrap_or(None);
+        let query = format!(
+            "SELECT raw_json, raw_json_gz FROM issues {} {} {}",
+            where_clause, order_clause, limit_clause
+        );
 
-                Ok((id, name, description, filter_json, created_at_str, updated_at_str, usage_count, last_used_at_str))
-            });
+        self.run_read("Load operation", move |conn| {
+            let mut stmt = conn.prepare(&query)?;
 
-            match result {
-                Ok((id, name, description, filter_json, _created_at_str, _updated_at_str, usage_count, _last_used_at_str)) => {
-                    let filter = match serde_json::from_str(&filter_json) {
-                        Ok(f) => f,
-                        Err(_) => return Err(duckdb::Error::QueryReturnedNoRows),
-                    };
-
-                    // 簡素化した日時解析（実際のプロダクションではより厳密に）
-                    let created_at = Utc::now(); // 簡素化
-                    let updated_at = Utc::now(); // 簡素化
-                    let last_used_at = None; // 簡素化
+            // パラメータを文字列リファレンスに変換
+            let params_refs: Vec<&dyn duckdb::ToSql> = filter_params
+                .iter()
+                .map(|p| p as &dyn duckdb::ToSql)
+                .collect();
 
-                    let config = FilterConfig {
-                        id,
-                        name,
-                        description,
-                        filter,
-                        created_at,
-                        updated_at,
-                        usage_count: usage_count as u32,
-                        last_used_at,
-                    };
+            let rows = stmt.query_map(params_refs.as_slice(), |row| {
+                let raw_json: String = row.get(0)?;
+                let raw_json_gz: Option<Vec<u8>> = row.get(1)?;
+                Ok((raw_json, raw_json_gz))
+            })?;
 
-                    Ok(Some(config))
+            let mut issues = Vec::new();
+            for row in rows {
+                let (raw_json, raw_json_gz) = row?;
+                let Ok(raw_json) = decode_raw_json(raw_json, raw_json_gz) else {
+                    continue;
+                };
+                if let Ok(issue) = serde_json::from_str::<Issue>(&raw_json) {
+                    issues.push(issue);
                 }
-                Err(duckdb::Error::QueryReturnedNoRows) => Ok(None),
-                Err(e) => Err(e),
             }
+
+            Ok::<Vec<Issue>, duckdb::Error>(issues)
         })
         .await
-        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
-        .map_err(|e| Error::DatabaseError(format!("Load filter config failed: {}", e)))
     }
 
-    async fn save_issue_history(&mut self, histories: &[IssueHistory]) -> Result<usize, Error> {
-        let conn = Arc::clone(&self.connection);
-        let histories_clone = histories.to_vec();
-
-        task::spawn_blocking(move || {
-            let conn = conn.lock().unwrap();
+    async fn load_all_issues(&self) -> Result<Vec<Issue>, Error> {
+        let filter = IssueFilter::new();
+        self.load_issues(&filter).await
+    }
 
-            // トランザクション開始
-            conn.execute("BEGIN TRANSACTION", params![])?;
+    async fn count_issues(&self, filter: &IssueFilter) -> Result<usize, Error> {
+        let (where_clause, filter_params) = self.build_where_clause(filter);
 
-            let mut saved_count = 0;
-            for history in &histories_clone {
-                let result = conn.execute(
-                    r#"
-                    INSERT INTO issue_history
-                    (issue_id, issue_key, change_id, change_timestamp, author_account_id,
-                     author_display_name, author_email, field_name, field_id, from_value,
-                     to_value, from_display_value, to_display_value, created_at)
-                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-                    "#,
-                    params![
-                        &history.issue_id,
-                        &history.issue_key,
-                        &history.change_id,
-                        &history
-                            .change_timestamp
-                            .format("%Y-%m-%d %H:%M:%S%.f")
-                            .to_string(),
-                        &history.author.as_ref().map(|a| &a.account_id),
-                        &history.author.as_ref().map(|a| &a.display_name),
-                        &history
-                            .author
-                            .as_ref()
-                            .and_then(|a| a.email_address.as_ref()),
-                        &history.field_name,
-                        &history.field_id,
-                        &history.from_value,
-                        &history.to_value,
-                        &history.from_display_value,
-                        &history.to_display_value,
-                        &history
-                            .created_at
-                            .format("%Y-%m-%d %H:%M:%S%.f")
-                            .to_string(),
-                    ],
-                );
+        let query = format!("SELECT COUNT(*) FROM issues {}", where_clause);
 
-                match result {
-                    Ok(_) => {
-                        saved_count += 1;
-                    }
-                    Err(e) => {
-                        eprintln!("Failed to insert history record: {:?}", e);
-                        // Continue with other records instead of failing completely
-                    }
-                }
-            }
+        self.run_read("Count operation", move |conn| {
+            let mut stmt = conn.prepare(&query)?;
 
-            // トランザクションコミット
-            conn.execute("COMMIT", params![])?;
+            let params_refs: Vec<&dyn duckdb::ToSql> = filter_params
+                .iter()
+                .map(|p| p as &dyn duckdb::ToSql)
+                .collect();
 
-            Ok::<usize, duckdb::Error>(saved_count)
+            let count: i64 = stmt.query_row(params_refs.as_slice(), |row| row.get(0))?;
+            Ok::<usize, duckdb::Error>(count as usize)
         })
         .await
-        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
-        .map_err(|e| Error::DatabaseError(format!("Save history operation failed: {}", e)))
     }
 
-    async fn load_issue_history(&self, filter: &HistoryFilter) -> Result<Vec<IssueHistory>, Error> {
-        let conn = Arc::clone(&self.connection);
-        let (where_clause, filter_params) = self.build_history_where_clause(filter);
-        let order_clause = self.build_history_order_clause(&filter.sort_order);
+    async fn group_counts(
+        &self,
+        filter: &IssueFilter,
+        by: GroupBy,
+    ) -> Result<Vec<(String, usize)>, Error> {
+        let (where_clause, filter_params) = self.build_where_clause(filter);
 
-        let limit_clause = match filter.limit {
-            Some(limit) => format!("LIMIT {}", limit),
-            None => "".to_string(),
+        let (column, missing_key) = match by {
+            GroupBy::Status => ("status_name", ""),
+            GroupBy::Project => ("project_key", ""),
+            GroupBy::Type => ("issue_type_name", ""),
+            GroupBy::Priority => ("priority_name", ""),
+            // 未割り当てはStorageStatsの担当者別統計と同じキーにまとめる
+            GroupBy::Assignee => ("assignee_display_name", UNASSIGNED_BUCKET),
+            GroupBy::StatusCategory => ("status_category_key", ""),
         };
 
         let query = format!(
-            "SELECT issue_id, issue_key, change_id,
-                    strftime(change_timestamp, '%Y-%m-%d %H:%M:%S.%f') as change_timestamp_str,
-                    author_account_id, author_display_name, author_email, field_name, field_id, from_value,
-                    to_value, from_display_value, to_display_value,
-                    strftime(created_at, '%Y-%m-%d %H:%M:%S.%f') as created_at_str
-             FROM issue_history {} {} {}",
-            where_clause, order_clause, limit_clause
+            "SELECT COALESCE({}, '{}') AS group_key, COUNT(*) AS issue_count
+             FROM issues {}
+             GROUP BY group_key
+             ORDER BY issue_count DESC, group_key ASC",
+            column, missing_key, where_clause
         );
 
-        task::spawn_blocking(move || {
-            let conn = conn.lock().unwrap();
+        self.run_read("Group count operation", move |conn| {
             let mut stmt = conn.prepare(&query)?;
 
             let params_refs: Vec<&dyn duckdb::ToSql> = filter_params
@@ -742,580 +1632,2732 @@ impl PersistenceStore for DuckDBStore {
                 .collect();
 
             let rows = stmt.query_map(params_refs.as_slice(), |row| {
-                let issue_id: String = row.get(0)?;
-                let issue_key: String = row.get(1)?;
-                let change_id: String = row.get(2)?;
-                let timestamp_str: String = row.get(3)?;
-                let account_id: Option<String> = row.get(4)?;
-                let display_name: Option<String> = row.get(5)?;
-                let email: Option<String> = row.get(6)?;
-                let field_name: String = row.get(7)?;
-                let field_id: Option<String> = row.get(8)?;
-                let from_value: Option<String> = row.get(9)?;
-                let to_value: Option<String> = row.get(10)?;
-                let from_display: Option<String> = row.get(11)?;
-                let to_display: Option<String> = row.get(12)?;
-                let created_str: String = row.get(13)?;
+                let key: String = row.get(0)?;
+                let count: i64 = row.get(1)?;
+                Ok((key, count as usize))
+            })?;
 
-                // Parse timestamps
-                let change_timestamp =
-                    chrono::DateTime::parse_from_str(&timestamp_str, "%Y-%m-%d %H:%M:%S%.f")
-                        .map(|dt| dt.with_timezone(&chrono::Utc))
-                        .unwrap_or_else(|_| chrono::Utc::now());
-                let created_at =
-                    chrono::DateTime::parse_from_str(&created_str, "%Y-%m-%d %H:%M:%S%.f")
-                        .map(|dt| dt.with_timezone(&chrono::Utc))
-                        .unwrap_or_else(|_| chrono::Utc::now());
+            let mut grouped = Vec::new();
+            for row in rows {
+                grouped.push(row?);
+            }
 
-                let author =
-                    if let (Some(account_id), Some(display_name)) = (account_id, display_name) {
-                        Some(HistoryAuthor {
-                            account_id,
-                            display_name,
-                            email_address: email,
-                        })
-                    } else {
-                        None
-                    };
+            Ok::<Vec<(String, usize)>, duckdb::Error>(grouped)
+        })
+        .await
+    }
 
-                let mut history =
-                    IssueHistory::new(issue_id, issue_key, change_id, change_timestamp, field_name);
+    async fn load_projection(
+        &self,
+        filter: &IssueFilter,
+        fields: &[IssueField],
+    ) -> Result<Vec<IssueProjection>, Error> {
+        let (where_clause, filter_params) = self.build_where_clause(filter);
+        let order_clause = self.build_order_clause(&filter.sort_order);
+        let limit_clause = build_limit_clause(filter.offset, filter.limit);
 
-                if let Some(author) = author {
-                    history = history.with_author(author);
-                }
+        // raw_jsonは読まず、要求された列のみを取得する
+        let columns: Vec<&str> = fields.iter().map(projection_column).collect();
+        let select_list = if columns.is_empty() {
+            "NULL".to_string()
+        } else {
+            columns.join(", ")
+        };
 
-                history = history.with_field_change(from_value, to_value, from_display, to_display);
+        let query = format!(
+            "SELECT {} FROM issues {} {} {}",
+            select_list, where_clause, order_clause, limit_clause
+        );
+        let fields = fields.to_vec();
 
-                if let Some(field_id) = field_id {
-                    history = history.with_field_id(field_id);
-                }
+        self.run_read("Projection operation", move |conn| {
+            let mut stmt = conn.prepare(&query)?;
 
-                // Set the created_at timestamp from database
-                history = history.with_created_at(created_at);
+            let params_refs: Vec<&dyn duckdb::ToSql> = filter_params
+                .iter()
+                .map(|p| p as &dyn duckdb::ToSql)
+                .collect();
 
-                Ok(history)
+            let rows = stmt.query_map(params_refs.as_slice(), |row| {
+                let mut projection = IssueProjection::default();
+                for (index, field) in fields.iter().enumerate() {
+                    let value: Option<String> = row.get(index)?;
+                    set_projection_value(&mut projection, *field, value);
+                }
+                Ok(projection)
             })?;
 
-            let mut histories = Vec::new();
+            let mut projections = Vec::new();
             for row in rows {
-                histories.push(row?);
+                projections.push(row?);
             }
 
-            Ok::<Vec<IssueHistory>, duckdb::Error>(histories)
+            Ok::<Vec<IssueProjection>, duckdb::Error>(projections)
+        })
+        .await
+    }
+
+    async fn delete_issues(&mut self, issue_keys: &[String]) -> Result<usize, Error> {
+        let conn = Arc::clone(&self.connection);
+        let keys = issue_keys.to_vec();
+
+        task::spawn_blocking(move || {
+            let conn = conn.lock().unwrap();
+
+            let placeholders: Vec<String> = keys.iter().map(|_| "?".to_string()).collect();
+            let query = format!(
+                "DELETE FROM issues WHERE issue_key IN ({})",
+                placeholders.join(", ")
+            );
+
+            let params_refs: Vec<&dyn duckdb::ToSql> =
+                keys.iter().map(|k| k as &dyn duckdb::ToSql).collect();
+
+            let deleted_count = conn.execute(&query, params_refs.as_slice())?;
+            Ok::<usize, duckdb::Error>(deleted_count)
         })
         .await
         .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
-        .map_err(|e| Error::DatabaseError(format!("Load history operation failed: {}", e)))
+        .map_err(|e| Error::DatabaseError(format!("Delete operation failed: {}", e)))
     }
 
-    async fn get_history_stats(&self) -> Result<HistoryStats, Error> {
+    async fn begin_transaction(&mut self) -> Result<(), Error> {
+        if self.in_transaction {
+            return Err(Error::InvalidInput(
+                "Transaction already in progress".to_string(),
+            ));
+        }
+
+        self.execute_transaction_statement("BEGIN TRANSACTION")
+            .await?;
+        self.in_transaction = true;
+        Ok(())
+    }
+
+    async fn commit_transaction(&mut self) -> Result<(), Error> {
+        if !self.in_transaction {
+            return Err(Error::InvalidInput(
+                "No transaction in progress".to_string(),
+            ));
+        }
+
+        self.in_transaction = false;
+        if let Err(e) = self.execute_transaction_statement("COMMIT").await {
+            let _ = self.execute_transaction_statement("ROLLBACK").await;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    async fn rollback_transaction(&mut self) -> Result<(), Error> {
+        if !self.in_transaction {
+            return Err(Error::InvalidInput(
+                "No transaction in progress".to_string(),
+            ));
+        }
+
+        self.in_transaction = false;
+        self.execute_transaction_statement("ROLLBACK").await
+    }
+
+    async fn optimize(&mut self) -> Result<(), Error> {
+        let conn = Arc::clone(&self.connection);
+
+        task::spawn_blocking(move || {
+            let conn = conn.lock().unwrap();
+            // DuckDBの最適化コマンド
+            conn.execute("VACUUM", params![])?;
+            conn.execute("ANALYZE", params![])?;
+            Ok::<(), duckdb::Error>(())
+        })
+        .await
+        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
+        .map_err(|e| Error::DatabaseError(format!("Optimize operation failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<(), Error> {
         let conn = Arc::clone(&self.connection);
 
         task::spawn_blocking(move || {
             let conn = conn.lock().unwrap();
+            // WALの内容をデータベースファイルに書き出す
+            conn.execute_batch("CHECKPOINT")
+        })
+        .await
+        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
+        .map_err(|e| Error::DatabaseError(format!("Flush operation failed: {}", e)))
+    }
 
-            let mut stats = HistoryStats::new();
+    async fn get_stats(&self) -> Result<StorageStats, Error> {
+        self.run_read("Stats operation", move |conn| {
+            let mut stats = StorageStats::new();
 
-            // 総変更数
-            let total: i64 = conn.prepare("SELECT COUNT(*) FROM issue_history")?
+            // 総Issue数
+            let total: i64 = conn.prepare("SELECT COUNT(*) FROM issues")?
                 .query_row(params![], |row| row.get(0))?;
-            stats.total_changes = total as usize;
+            stats.total_issues = total as usize;
 
-            // ユニークな課題数
-            let unique_issues: i64 = conn.prepare("SELECT COUNT(DISTINCT issue_key) FROM issue_history")?
-                .query_row(params![], |row| row.get(0))?;
-            stats.unique_issues = unique_issues as usize;
+            // プロジェクト別統計
+            let mut stmt = conn.prepare("SELECT project_key, COUNT(*) FROM issues WHERE project_key IS NOT NULL GROUP BY project_key")?;
+            let project_rows = stmt.query_map(params![], |row| {
+                let key: String = row.get(0)?;
+                let count: i64 = row.get(1)?;
+                Ok((key, count as usize))
+            })?;
 
-            // ユニークな変更者数
-            let unique_authors: i64 = conn.prepare(
-                "SELECT COUNT(DISTINCT author_account_id) FROM issue_history WHERE author_account_id IS NOT NULL"
-            )?.query_row(params![], |row| row.get(0))?;
-            stats.unique_authors = unique_authors as usize;
+            for row in project_rows {
+                let (key, count) = row?;
+                stats.issues_by_project.insert(key, count);
+            }
 
-            // フィールド別変更数
-            let mut stmt = conn.prepare("SELECT field_name, COUNT(*) FROM issue_history GROUP BY field_name")?;
-            let field_rows = stmt.query_map(params![], |row| {
-                let field_name: String = row.get(0)?;
+            // ステータス別統計
+            let mut stmt = conn.prepare("SELECT status_name, COUNT(*) FROM issues GROUP BY status_name")?;
+            let status_rows = stmt.query_map(params![], |row| {
+                let name: String = row.get(0)?;
                 let count: i64 = row.get(1)?;
-                Ok((field_name, count as usize))
+                Ok((name, count as usize))
             })?;
 
-            for row in field_rows {
-                let (field_name, count) = row?;
-                stats.field_change_counts.insert(field_name, count);
+            for row in status_rows {
+                let (name, count) = row?;
+                stats.issues_by_status.insert(name, count);
             }
 
-            // 最古・最新の変更日時（簡素化）
-            stats.oldest_change = None; // TODO: 実装
-            stats.newest_change = None; // TODO: 実装
-
-            Ok::<HistoryStats, duckdb::Error>(stats)
-        })
-        .await
-        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
-        .map_err(|e| Error::DatabaseError(format!("History stats operation failed: {}", e)))
+            // Issue種別別統計
+            let mut stmt = conn.prepare("SELECT issue_type_name, COUNT(*) FROM issues GROUP BY issue_type_name")?;
+            let type_rows = stmt.query_map(params![], |row| {
+                let name: String = row.get(0)?;
+                let count: i64 = row.get(1)?;
+                Ok((name, count as usize))
+            })?;
+
+            for row in type_rows {
+                let (name, count) = row?;
+                stats.issues_by_type.insert(name, count);
+            }
+
+            // 解決状況別統計
+            let mut stmt = conn.prepare("SELECT resolution_name, COUNT(*) FROM issues WHERE resolution_name IS NOT NULL GROUP BY resolution_name")?;
+            let resolution_rows = stmt.query_map(params![], |row| {
+                let name: String = row.get(0)?;
+                let count: i64 = row.get(1)?;
+                Ok((name, count as usize))
+            })?;
+
+            for row in resolution_rows {
+                let (name, count) = row?;
+                stats.issues_by_resolution.insert(name, count);
+            }
+
+            // 担当者別統計（未割り当ては専用のキーにまとめる）
+            let mut stmt = conn.prepare(&format!(
+                "SELECT COALESCE(assignee_display_name, '{}') AS assignee, COUNT(*) FROM issues GROUP BY assignee",
+                UNASSIGNED_BUCKET
+            ))?;
+            let assignee_rows = stmt.query_map(params![], |row| {
+                let name: String = row.get(0)?;
+                let count: i64 = row.get(1)?;
+                Ok((name, count as usize))
+            })?;
+
+            for row in assignee_rows {
+                let (name, count) = row?;
+                stats.issues_by_assignee.insert(name, count);
+            }
+
+            // 優先度別統計
+            let mut stmt = conn.prepare("SELECT priority_name, COUNT(*) FROM issues WHERE priority_name IS NOT NULL GROUP BY priority_name")?;
+            let priority_rows = stmt.query_map(params![], |row| {
+                let name: String = row.get(0)?;
+                let count: i64 = row.get(1)?;
+                Ok((name, count as usize))
+            })?;
+
+            for row in priority_rows {
+                let (name, count) = row?;
+                stats.issues_by_priority.insert(name, count);
+            }
+
+            stats.last_updated = Utc::now();
+            stats.compression_ratio = 0.0; // DuckDBは自動圧縮
+            stats.index_count = 5; // 作成したインデックス数
+
+            Ok::<StorageStats, duckdb::Error>(stats)
+        })
+        .await
+    }
+
+    async fn save_filter_config(&mut self, config: &FilterConfig) -> Result<(), Error> {
+        let conn = Arc::clone(&self.connection);
+        let config_clone = config.clone();
+
+        task::spawn_blocking(move || {
+            let conn = conn.lock().unwrap();
+
+            let filter_json = match serde_json::to_string(&config_clone.filter) {
+                Ok(json) => json,
+                Err(_) => return Err(duckdb::Error::QueryReturnedNoRows),
+            };
+
+            conn.execute(
+                r#"
+                INSERT INTO filter_configs
+                (id, name, description, filter_json, created_at, updated_at, usage_count, last_used_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                ON CONFLICT (id) DO UPDATE SET
+                    name = EXCLUDED.name,
+                    description = EXCLUDED.description,
+                    filter_json = EXCLUDED.filter_json,
+                    updated_at = EXCLUDED.updated_at,
+                    usage_count = EXCLUDED.usage_count,
+                    last_used_at = EXCLUDED.last_used_at
+                "#,
+                params![
+                    config_clone.id,
+                    config_clone.name,
+                    config_clone.description,
+                    filter_json,
+                    format_db_timestamp(&config_clone.created_at),
+                    format_db_timestamp(&config_clone.updated_at),
+                    config_clone.usage_count as i64,
+                    config_clone.last_used_at.as_ref().map(format_db_timestamp),
+                ],
+            )?;
+
+            Ok::<(), duckdb::Error>(())
+        })
+        .await
+        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
+        .map_err(|e| Error::DatabaseError(format!("Save filter config failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn load_filter_config(&self) -> Result<Option<FilterConfig>, Error> {
+        self.run_read("Load filter config", move |conn| {
+            let mut stmt = conn.prepare(
+                "SELECT id, name, description, filter_json,
+                        strftime(created_at, '%Y-%m-%d %H:%M:%S.%f'),
+                        strftime(updated_at, '%Y-%m-%d %H:%M:%S.%f'),
+                        usage_count,
+                        strftime(last_used_at, '%Y-%m-%d %H:%M:%S.%f')
+                 FROM filter_configs ORDER BY updated_at DESC LIMIT 1",
+            )?;
+
+            let result = stmt.query_row(params![], |row| {
+                let id: String = row.get(0)?;
+                let name: String = row.get(1)?;
+                let description: Option<String> = row.get(2)?;
+                let filter_json: String = row.get(3)?;
+                let created_at_str: Option<String> = row.get(4)?;
+                let updated_at_str: Option<String> = row.get(5)?;
+                let usage_count: i64 = row.get(6)?;
+                let last_used_at_str: Option<String> = row.get(7)?;
+
+                Ok((
+                    id,
+                    name,
+                    description,
+                    filter_json,
+                    created_at_str,
+                    updated_at_str,
+                    usage_count,
+                    last_used_at_str,
+                ))
+            });
+
+            match result {
+                Ok((
+                    id,
+                    name,
+                    description,
+                    filter_json,
+                    created_at_str,
+                    updated_at_str,
+                    usage_count,
+                    last_used_at_str,
+                )) => {
+                    let filter = match serde_json::from_str(&filter_json) {
+                        Ok(f) => f,
+                        Err(_) => return Err(duckdb::Error::QueryReturnedNoRows),
+                    };
+
+                    let created_at =
+                        restore_filter_timestamp(&id, "created_at", created_at_str.as_deref())
+                            .unwrap_or_else(Utc::now);
+                    let updated_at =
+                        restore_filter_timestamp(&id, "updated_at", updated_at_str.as_deref())
+                            .unwrap_or_else(Utc::now);
+                    let last_used_at =
+                        restore_filter_timestamp(&id, "last_used_at", last_used_at_str.as_deref());
+
+                    let config = FilterConfig {
+                        id,
+                        name,
+                        description,
+                        filter,
+                        created_at,
+                        updated_at,
+                        usage_count: usage_count as u32,
+                        last_used_at,
+                    };
+
+                    Ok(Some(config))
+                }
+                Err(duckdb::Error::QueryReturnedNoRows) => Ok(None),
+                Err(e) => Err(e),
+            }
+        })
+        .await
+    }
+
+    async fn touch_filter_config(&mut self, id: &str) -> Result<(), Error> {
+        let conn = Arc::clone(&self.connection);
+        let id = id.to_string();
+
+        let updated = task::spawn_blocking({
+            let id = id.clone();
+            move || {
+                let conn = conn.lock().unwrap();
+                let now = format_db_timestamp(&Utc::now());
+
+                conn.execute(
+                    "UPDATE filter_configs
+                     SET usage_count = usage_count + 1, last_used_at = ?, updated_at = ?
+                     WHERE id = ?",
+                    params![now, now, id],
+                )
+            }
+        })
+        .await
+        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
+        .map_err(|e| Error::DatabaseError(format!("Touch filter config failed: {}", e)))?;
+
+        if updated == 0 {
+            return Err(Error::NotFound(format!("Filter config not found: {}", id)));
+        }
+
+        Ok(())
+    }
+
+    async fn save_issue_history(&mut self, histories: &[IssueHistory]) -> Result<usize, Error> {
+        let conn = Arc::clone(&self.connection);
+        let histories_clone = histories.to_vec();
+        let nested = self.in_transaction;
+
+        task::spawn_blocking(move || {
+            let conn = conn.lock().unwrap();
+
+            // トランザクション開始（外側のトランザクション中はその一部として書き込む）
+            if !nested {
+                conn.execute("BEGIN TRANSACTION", params![])?;
+            }
+
+            let mut saved_count = 0;
+            for history in &histories_clone {
+                let result = insert_history(&conn, history);
+
+                match result {
+                    Ok(_) => {
+                        saved_count += 1;
+                    }
+                    Err(e) => {
+                        eprintln!("Failed to insert history record: {:?}", e);
+                        // Continue with other records instead of failing completely
+                    }
+                }
+            }
+
+            // トランザクションコミット
+            if !nested {
+                conn.execute("COMMIT", params![])?;
+            }
+
+            Ok::<usize, duckdb::Error>(saved_count)
+        })
+        .await
+        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
+        .map_err(|e| Error::DatabaseError(format!("Save history operation failed: {}", e)))
+    }
+
+    async fn load_issue_history(&self, filter: &HistoryFilter) -> Result<Vec<IssueHistory>, Error> {
+        self.query_issue_history(filter, None).await
+    }
+
+    fn stream_issue_history(
+        &self,
+        filter: HistoryFilter,
+    ) -> Pin<Box<dyn Stream<Item = Result<IssueHistory, Error>> + Send + '_>> {
+        // LIMIT/OFFSETで一定件数ずつ読み込み、1件ずつ返す
+        // 状態は次のオフセット（Noneの場合は読み込み完了）
+        let pages = stream::try_unfold(Some(0usize), move |offset| {
+            let filter = filter.clone();
+            async move {
+                let Some(offset) = offset else {
+                    return Ok::<_, Error>(None);
+                };
+
+                let page_size = match filter.limit {
+                    Some(limit) => HISTORY_STREAM_PAGE_SIZE.min(limit.saturating_sub(offset)),
+                    None => HISTORY_STREAM_PAGE_SIZE,
+                };
+                if page_size == 0 {
+                    return Ok(None);
+                }
+
+                let mut page_filter = filter;
+                page_filter.limit = Some(page_size);
+                let page = self.query_issue_history(&page_filter, Some(offset)).await?;
+
+                let next_offset = if page.len() < page_size {
+                    None
+                } else {
+                    Some(offset + page.len())
+                };
+                Ok(Some((page, next_offset)))
+            }
+        });
+
+        Box::pin(
+            pages
+                .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+                .try_flatten(),
+        )
+    }
+
+    async fn get_history_stats(&self) -> Result<HistoryStats, Error> {
+        self.run_read("History stats operation", move |conn| {
+            let mut stats = HistoryStats::new();
+
+            // 総変更数
+            let total: i64 = conn.prepare("SELECT COUNT(*) FROM issue_history")?
+                .query_row(params![], |row| row.get(0))?;
+            stats.total_changes = total as usize;
+
+            // ユニークな課題数
+            let unique_issues: i64 = conn.prepare("SELECT COUNT(DISTINCT issue_key) FROM issue_history")?
+                .query_row(params![], |row| row.get(0))?;
+            stats.unique_issues = unique_issues as usize;
+
+            // ユニークな変更者数
+            let unique_authors: i64 = conn.prepare(
+                "SELECT COUNT(DISTINCT author_account_id) FROM issue_history WHERE author_account_id IS NOT NULL"
+            )?.query_row(params![], |row| row.get(0))?;
+            stats.unique_authors = unique_authors as usize;
+
+            // フィールド別変更数
+            let mut stmt = conn.prepare("SELECT field_name, COUNT(*) FROM issue_history GROUP BY field_name")?;
+            let field_rows = stmt.query_map(params![], |row| {
+                let field_name: String = row.get(0)?;
+                let count: i64 = row.get(1)?;
+                Ok((field_name, count as usize))
+            })?;
+
+            for row in field_rows {
+                let (field_name, count) = row?;
+                stats.field_change_counts.insert(field_name, count);
+            }
+
+            // 最古・最新の変更日時（履歴が無い場合はどちらもNULLになる）
+            let (oldest, newest): (Option<String>, Option<String>) = conn
+                .prepare(
+                    "SELECT strftime(MIN(change_timestamp), '%Y-%m-%d %H:%M:%S.%f'), \
+                     strftime(MAX(change_timestamp), '%Y-%m-%d %H:%M:%S.%f') FROM issue_history",
+                )?
+                .query_row(params![], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            stats.oldest_change = oldest.as_deref().and_then(parse_db_timestamp);
+            stats.newest_change = newest.as_deref().and_then(parse_db_timestamp);
+
+            Ok::<HistoryStats, duckdb::Error>(stats)
+        })
+        .await
+    }
+
+    async fn delete_issue_history(&mut self, issue_keys: &[String]) -> Result<usize, Error> {
+        let conn = Arc::clone(&self.connection);
+        let keys = issue_keys.to_vec();
+
+        task::spawn_blocking(move || {
+            let conn = conn.lock().unwrap();
+
+            let placeholders: Vec<String> = keys.iter().map(|_| "?".to_string()).collect();
+            let query = format!(
+                "DELETE FROM issue_history WHERE issue_key IN ({})",
+                placeholders.join(", ")
+            );
+
+            let params_refs: Vec<&dyn duckdb::ToSql> =
+                keys.iter().map(|k| k as &dyn duckdb::ToSql).collect();
+
+            let deleted_count = conn.execute(&query, params_refs.as_slice())?;
+            Ok::<usize, duckdb::Error>(deleted_count)
+        })
+        .await
+        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
+        .map_err(|e| Error::DatabaseError(format!("Delete history operation failed: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{IssueFields, IssueType, Priority, Project, Status, StatusCategory, User};
+    use crate::testing::{
+        sample_custom_field_issues, sample_resolution_issues, sample_sort_issues,
+    };
+    use std::collections::{HashMap, HashSet};
+    use tempfile::TempDir;
+
+    fn create_test_issue(key: &str, project_key: &str, status: &str) -> Issue {
+        let status_category = StatusCategory {
+            id: 1,
+            key: "new".to_string(),
+            name: "新規".to_string(),
+            color_name: "blue-gray".to_string(),
+            self_url: Some("http://example.com".to_string()),
+        };
+
+        let issue_status = Status {
+            id: "1".to_string(),
+            name: status.to_string(),
+            description: None,
+            icon_url: None,
+            status_category,
+            self_url: "http://example.com".to_string(),
+        };
+
+        let issue_type = IssueType {
+            id: "1".to_string(),
+            name: "Task".to_string(),
+            description: None,
+            icon_url: None,
+            subtask: Some(false),
+            self_url: "http://example.com".to_string(),
+        };
+
+        let project = Project {
+            id: "1".to_string(),
+            key: project_key.to_string(),
+            name: format!("Project {}", project_key),
+            project_type_key: Some("software".to_string()),
+            description: None,
+            lead: None,
+            url: None,
+            simplified: None,
+            self_url: "http://example.com".to_string(),
+            avatar_urls: None,
+        };
+
+        let reporter = User {
+            account_id: "test_user".to_string(),
+            display_name: "Test User".to_string(),
+            email_address: Some("test@example.com".to_string()),
+            self_url: "http://example.com".to_string(),
+            avatar_urls: None,
+            active: Some(true),
+            time_zone: None,
+            account_type: None,
+        };
+
+        let fields = IssueFields {
+            summary: format!("Test issue {}", key),
+            description: Some(serde_json::Value::String("Test description".to_string())),
+            status: issue_status,
+            priority: Some(Priority {
+                id: "1".to_string(),
+                name: "Medium".to_string(),
+                description: None,
+                icon_url: None,
+                status_color: None,
+                self_url: "http://example.com".to_string(),
+            }),
+            issue_type,
+            assignee: None,
+            reporter,
+            created: Utc::now(),
+            updated: Utc::now(),
+            resolution: None,
+            time_tracking: None,
+            votes: None,
+            watches: None,
+            resolution_date: None,
+            project: Some(project),
+            parent: None,
+            custom_fields: HashMap::new(),
+        };
+
+        Issue {
+            id: key.to_string(),
+            key: key.to_string(),
+            fields,
+            self_url: "http://example.com".to_string(),
+            changelog: None,
+            rendered_fields: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_new() {
+        // DuckDBStore::new()で正しく作成されることをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let store = DuckDBStore::new(&db_path).unwrap();
+
+        assert_eq!(store.db_path, db_path);
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_new_in_memory() {
+        // DuckDBStore::new_in_memory()で正しく作成されることをテスト
+        let store = DuckDBStore::new_in_memory().unwrap();
+
+        assert_eq!(store.db_path, PathBuf::from(":memory:"));
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_initialize() {
+        // DuckDBStore::initialize()でスキーマが作成されることをテスト
+        let store = DuckDBStore::new_in_memory().unwrap();
+
+        store.initialize().await.unwrap();
+
+        // テーブルが作成されているかチェック（DuckDB specific）
+        let conn = Arc::clone(&store.connection);
+        let result = task::spawn_blocking(move || {
+            let conn = conn.lock().unwrap();
+            let count: i64 = conn.prepare("SELECT COUNT(*) FROM information_schema.tables WHERE table_name IN ('issues', 'filter_configs')")
+                .unwrap()
+                .query_row(params![], |row| row.get(0))
+                .unwrap();
+            count
+        }).await.unwrap();
+
+        assert_eq!(result, 2); // issuesとfilter_configsテーブル
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_export_csv() {
+        // DuckDBStoreからもフィルター条件に一致するIssueをCSVに書き出せることをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let mut issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "In Progress"),
+            create_test_issue("DEMO-1", "DEMO", "Done"),
+        ];
+        issues[1].fields.summary = "Export, with comma".to_string();
+        store.save_issues(&issues).await.unwrap();
+
+        let filter = IssueFilter::new()
+            .project_keys(vec!["TEST".to_string()])
+            .sort_order(SortOrder::KeyAsc);
+        let path = temp_dir.path().join("issues.csv");
+        assert_eq!(store.export_csv(&filter, &path).await.unwrap(), 2);
+
+        let mut reader = csv::Reader::from_path(&path).unwrap();
+        let rows: Vec<csv::StringRecord> = reader.records().map(|r| r.unwrap()).collect();
+        let keys: Vec<&str> = rows.iter().map(|row| &row[0]).collect();
+        assert_eq!(keys, vec!["TEST-1", "TEST-2"]);
+        assert_eq!(&rows[1][1], "Export, with comma");
+        assert_eq!(&rows[1][2], "In Progress");
+        assert_eq!(&rows[1][5], "TEST");
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_export_ndjson_with_tied_created() {
+        // 作成日時が全て同じでも、ページをまたいで重複や欠落なく書き出せることをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let created = Utc::now();
+        let issues: Vec<Issue> = (1..=1200)
+            .map(|i| {
+                let mut issue = create_test_issue(&format!("TEST-{}", i), "TEST", "Open");
+                issue.fields.created = created;
+                issue
+            })
+            .collect();
+        store.save_issues(&issues).await.unwrap();
+
+        let mut output = Vec::new();
+        let exported = store
+            .export_ndjson(&IssueFilter::new(), &mut output)
+            .await
+            .unwrap();
+        assert_eq!(exported, 1200);
+
+        let keys: HashSet<String> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<Issue>(line).unwrap().key)
+            .collect();
+        assert_eq!(keys.len(), 1200);
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_query_raw() {
+        // 任意のSELECT文で集計でき、データを変更する文は拒否されることをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "In Progress"),
+            create_test_issue("DEMO-1", "DEMO", "Done"),
+        ];
+        store.save_issues(&issues).await.unwrap();
+
+        // 列名をキーとするオブジェクトとして集計結果が返る
+        let rows = store
+            .query_raw(
+                "SELECT project_key, COUNT(*) AS issue_count FROM issues \
+                 GROUP BY project_key ORDER BY project_key",
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                serde_json::json!({ "project_key": "DEMO", "issue_count": 1 }),
+                serde_json::json!({ "project_key": "TEST", "issue_count": 2 }),
+            ]
+        );
+
+        // WITH句とコメントを使用でき、文字列リテラル内のキーワードは拒否されない
+        let rows = store
+            .query_raw(
+                "-- TEST-1のsummaryを取得\n\
+                 WITH t AS (SELECT issue_key, summary FROM issues WHERE issue_key = 'TEST-1') \
+                 SELECT summary, 'delete' AS word FROM t;",
+            )
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0]["summary"], "Test issue TEST-1");
+        assert_eq!(rows[0]["word"], "delete");
+
+        // 非SELECTの文は拒否され、データは変更されない
+        for sql in [
+            "DELETE FROM issues",
+            "  /* comment */ insert into issues (id) values ('x')",
+            "SELECT 1; DROP TABLE issues",
+            "WITH t AS (SELECT 1) DELETE FROM issues",
+            "UPDATE issues SET summary = 'x'",
+        ] {
+            let result = store.query_raw(sql).await;
+            assert!(
+                matches!(result, Err(Error::InvalidInput(_))),
+                "{} was not rejected",
+                sql
+            );
+        }
+        assert_eq!(store.load_all_issues().await.unwrap().len(), 3);
+
+        // ファイルを読み込むテーブル関数は外部アクセスが無効なため実行できない
+        let path = std::env::current_dir().unwrap().join("Cargo.toml");
+        let path = path.display();
+        for sql in [
+            format!("SELECT * FROM read_text('{}')", path),
+            format!("SELECT * FROM read_csv('{}')", path),
+            format!("SELECT * FROM glob('{}')", path),
+            format!("SELECT * FROM '{}'", path),
+        ] {
+            let result = store.query_raw(&sql).await;
+            assert!(
+                matches!(result, Err(Error::DatabaseError(_))),
+                "{} was not rejected: {:?}",
+                sql,
+                result
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_save_and_load_issues() {
+        // DuckDBStoreでIssueの保存と読み込みが正しく動作することをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "In Progress"),
+            create_test_issue("DEMO-1", "DEMO", "Done"),
+        ];
+
+        // 保存
+        let saved_count = store.save_issues(&issues).await.unwrap();
+        assert_eq!(saved_count, 3);
+
+        // 全件読み込み
+        let loaded_issues = store.load_all_issues().await.unwrap();
+        assert_eq!(loaded_issues.len(), 3);
+
+        // キーが正しく保存されているか確認
+        let mut loaded_keys: Vec<String> = loaded_issues.iter().map(|i| i.key.clone()).collect();
+        loaded_keys.sort();
+        assert_eq!(loaded_keys, vec!["DEMO-1", "TEST-1", "TEST-2"]);
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_filter_issues() {
+        // DuckDBStoreでIssueのフィルタリングが正しく動作することをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "In Progress"),
+            create_test_issue("DEMO-1", "DEMO", "Done"),
+        ];
+
+        store.save_issues(&issues).await.unwrap();
+
+        // プロジェクトでフィルタ
+        let filter = IssueFilter::new().project_keys(vec!["TEST".to_string()]);
+        let filtered_issues = store.load_issues(&filter).await.unwrap();
+
+        assert_eq!(filtered_issues.len(), 2);
+        assert!(
+            filtered_issues
+                .iter()
+                .all(|i| i.fields.project.as_ref().unwrap().key == "TEST")
+        );
+
+        // ステータスでフィルタ
+        let filter = IssueFilter::new().statuses(vec!["Done".to_string()]);
+        let filtered_issues = store.load_issues(&filter).await.unwrap();
+
+        assert_eq!(filtered_issues.len(), 1);
+        assert_eq!(filtered_issues[0].fields.status.name, "Done");
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_count_issues() {
+        // DuckDBStore::count_issues()が正しく動作することをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "In Progress"),
+            create_test_issue("DEMO-1", "DEMO", "Done"),
+        ];
+
+        store.save_issues(&issues).await.unwrap();
+
+        // 全件カウント
+        let total_count = store.count_issues(&IssueFilter::new()).await.unwrap();
+        assert_eq!(total_count, 3);
+
+        // フィルタ適用カウント
+        let filter = IssueFilter::new().project_keys(vec!["TEST".to_string()]);
+        let filtered_count = store.count_issues(&filter).await.unwrap();
+        assert_eq!(filtered_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_delete_issues() {
+        // DuckDBStore::delete_issues()が正しく動作することをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "In Progress"),
+            create_test_issue("DEMO-1", "DEMO", "Done"),
+        ];
+
+        store.save_issues(&issues).await.unwrap();
+
+        // 削除
+        let deleted_count = store
+            .delete_issues(&["TEST-1".to_string(), "DEMO-1".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(deleted_count, 2);
+
+        // 残りを確認
+        let remaining_issues = store.load_all_issues().await.unwrap();
+        assert_eq!(remaining_issues.len(), 1);
+        assert_eq!(remaining_issues[0].key, "TEST-2");
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_get_stats() {
+        // DuckDBStore::get_stats()が正しく動作することをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "In Progress"),
+            create_test_issue("DEMO-1", "DEMO", "Done"),
+        ];
+
+        store.save_issues(&issues).await.unwrap();
+
+        let stats = store.get_stats().await.unwrap();
+
+        assert_eq!(stats.total_issues, 3);
+        assert_eq!(stats.issues_by_project.get("TEST"), Some(&2));
+        assert_eq!(stats.issues_by_project.get("DEMO"), Some(&1));
+        assert_eq!(stats.issues_by_status.get("Open"), Some(&1));
+        assert_eq!(stats.issues_by_status.get("In Progress"), Some(&1));
+        assert_eq!(stats.issues_by_status.get("Done"), Some(&1));
+        assert_eq!(stats.index_count, 5);
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_group_counts() {
+        // ステータス別の集計がStorageStatsと一致することをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "Open"),
+            create_test_issue("TEST-3", "TEST", "Done"),
+            create_test_issue("DEMO-1", "DEMO", "Open"),
+        ];
+        store.save_issues(&issues).await.unwrap();
+
+        let grouped = store
+            .group_counts(&IssueFilter::new(), GroupBy::Status)
+            .await
+            .unwrap();
+        assert_eq!(
+            grouped,
+            vec![("Open".to_string(), 3), ("Done".to_string(), 1)]
+        );
+
+        let stats = store.get_stats().await.unwrap();
+        let grouped_map: HashMap<String, usize> = grouped.into_iter().collect();
+        assert_eq!(grouped_map, stats.issues_by_status);
+
+        // フィルター条件が集計に反映される
+        let filter = IssueFilter::new().project_keys(vec!["TEST".to_string()]);
+        let grouped = store.group_counts(&filter, GroupBy::Project).await.unwrap();
+        assert_eq!(grouped, vec![("TEST".to_string(), 3)]);
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_parent_round_trip() {
+        // サブタスクの親情報が保存・読み込み後も保持され、親キーで絞り込めることをテスト
+        use crate::models::{ParentFields, ParentRef};
+
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let parent = create_test_issue("TEST-1", "TEST", "In Progress");
+        let mut subtask = create_test_issue("TEST-2", "TEST", "Open");
+        subtask.fields.parent = Some(ParentRef {
+            id: "TEST-1".to_string(),
+            key: "TEST-1".to_string(),
+            self_url: None,
+            fields: ParentFields {
+                summary: Some(parent.fields.summary.clone()),
+                status: Some(parent.fields.status.clone()),
+                issue_type: Some(parent.fields.issue_type.clone()),
+            },
+        });
+
+        store.save_issues(&[parent, subtask]).await.unwrap();
+
+        let filter = IssueFilter::new().parent_keys(vec!["TEST-1".to_string()]);
+        let children = store.load_issues(&filter).await.unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].key, "TEST-2");
+
+        let loaded_parent = children[0].fields.parent.as_ref().unwrap();
+        assert_eq!(loaded_parent.key, "TEST-1");
+        assert_eq!(loaded_parent.summary(), Some("Test issue TEST-1"));
+        assert_eq!(loaded_parent.status_name(), Some("In Progress"));
+
+        // 件数・集計・射影も同じ条件で絞り込まれる
+        assert_eq!(store.count_issues(&filter).await.unwrap(), 1);
+        assert_eq!(
+            store.group_counts(&filter, GroupBy::Status).await.unwrap(),
+            vec![("Open".to_string(), 1)]
+        );
+        let projections = store
+            .load_projection(&filter, &[IssueField::Key])
+            .await
+            .unwrap();
+        assert_eq!(projections.len(), 1);
+        assert_eq!(projections[0].key.as_deref(), Some("TEST-2"));
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_parent_key_backfill() {
+        // 親キーの列が無い既存のデータベースでも、初期化時に圧縮済みのJSONから列を埋めることをテスト
+        use crate::models::ParentRef;
+
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("legacy.duckdb");
+        let mut subtask = create_test_issue("TEST-2", "TEST", "Open");
+        subtask.fields.parent = Some(ParentRef {
+            id: "TEST-1".to_string(),
+            key: "TEST-1".to_string(),
+            self_url: None,
+            fields: Default::default(),
+        });
+
+        {
+            let mut store = DuckDBStore::new(&db_path)
+                .unwrap()
+                .with_raw_compression(true);
+            store.initialize().await.unwrap();
+            store
+                .save_issues(&[create_test_issue("TEST-1", "TEST", "Open"), subtask])
+                .await
+                .unwrap();
+
+            // 列を追加する前のスキーマを再現する
+            // インデックスがあると列を削除できないため、一度すべて削除してから当時のものを作り直す
+            let conn = Arc::clone(&store.connection);
+            task::spawn_blocking(move || {
+                let conn = conn.lock().unwrap();
+                let legacy_indexes = [
+                    ("idx_issues_project_key", "project_key"),
+                    ("idx_issues_status_name", "status_name"),
+                    ("idx_issues_created", "created"),
+                    ("idx_issues_updated", "updated"),
+                    ("idx_issues_resolution_name", "resolution_name"),
+                ];
+                conn.execute("DROP INDEX idx_issues_parent_key", params![])
+                    .unwrap();
+                for (name, _) in legacy_indexes {
+                    conn.execute(&format!("DROP INDEX {}", name), params![])
+                        .unwrap();
+                }
+                conn.execute("ALTER TABLE issues DROP COLUMN parent_key", params![])
+                    .unwrap();
+                for (name, column) in legacy_indexes {
+                    conn.execute(
+                        &format!("CREATE INDEX {} ON issues({})", name, column),
+                        params![],
+                    )
+                    .unwrap();
+                }
+            })
+            .await
+            .unwrap();
+            store.close().await.unwrap();
+        }
+
+        let store = DuckDBStore::new(&db_path).unwrap();
+        store.initialize().await.unwrap();
+
+        let filter = IssueFilter::new().parent_keys(vec!["TEST-1".to_string()]);
+        let children = store.load_issues(&filter).await.unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].key, "TEST-2");
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_filter_config() {
+        // DuckDBStoreでFilterConfigの保存と読み込みが正しく動作することをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let filter = IssueFilter::new()
+            .project_keys(vec!["TEST".to_string()])
+            .statuses(vec!["Open".to_string()]);
+
+        let config =
+            FilterConfig::new("test_filter".to_string(), "Test Filter".to_string(), filter)
+                .description("Test description".to_string());
+
+        // 保存
+        store.save_filter_config(&config).await.unwrap();
+
+        // 読み込み
+        let loaded_config = store.load_filter_config().await.unwrap();
+        assert!(loaded_config.is_some());
+
+        let loaded_config = loaded_config.unwrap();
+        assert_eq!(loaded_config.id, "test_filter");
+        assert_eq!(loaded_config.name, "Test Filter");
+        assert_eq!(
+            loaded_config.description,
+            Some("Test description".to_string())
+        );
+        assert_eq!(loaded_config.filter.project_keys, vec!["TEST"]);
+        assert_eq!(loaded_config.filter.statuses, vec!["Open"]);
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_filter_config_round_trip_timestamps() {
+        // 保存したフィルター設定の日時・使用回数を含む全フィールドが読み込み後に一致することをテスト
+        use chrono::TimeZone;
+
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let filter = IssueFilter::new()
+            .project_keys(vec!["TEST".to_string()])
+            .sort_order(SortOrder::UpdatedDesc);
+        let mut config = FilterConfig::new("saved".to_string(), "Saved Filter".to_string(), filter)
+            .description("Weekly review".to_string());
+        // TIMESTAMP列はマイクロ秒精度のため、マイクロ秒までの日時で検証する
+        config.created_at = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap()
+            + chrono::Duration::microseconds(678_901);
+        config.updated_at = Utc.with_ymd_and_hms(2024, 2, 29, 23, 59, 59).unwrap();
+        config.usage_count = 7;
+        config.last_used_at = Some(
+            Utc.with_ymd_and_hms(2024, 2, 28, 12, 0, 0).unwrap()
+                + chrono::Duration::microseconds(1),
+        );
+
+        store.save_filter_config(&config).await.unwrap();
+        let loaded = store.load_filter_config().await.unwrap().unwrap();
+
+        assert_eq!(loaded.created_at, config.created_at);
+        assert_eq!(loaded.updated_at, config.updated_at);
+        assert_eq!(loaded.usage_count, 7);
+        assert_eq!(loaded.last_used_at, config.last_used_at);
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&config).unwrap()
+        );
+
+        // 未使用の設定はlast_used_atがNoneのまま復元される
+        let mut unused = config.clone();
+        unused.id = "unused".to_string();
+        unused.updated_at = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        unused.usage_count = 0;
+        unused.last_used_at = None;
+        store.save_filter_config(&unused).await.unwrap();
+        let loaded = store.load_filter_config().await.unwrap().unwrap();
+        assert_eq!(loaded.id, "unused");
+        assert_eq!(loaded.last_used_at, None);
+        assert_eq!(loaded.created_at, unused.created_at);
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_touch_filter_config() {
+        // フィルター設定の使用記録で使用回数と最終使用日時が更新されることをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let filter = IssueFilter::new().project_keys(vec!["TEST".to_string()]);
+        let config = FilterConfig::new("my_filter".to_string(), "My Filter".to_string(), filter);
+        store.save_filter_config(&config).await.unwrap();
+
+        store.touch_filter_config("my_filter").await.unwrap();
+        let first = store.load_filter_config().await.unwrap().unwrap();
+        assert_eq!(first.usage_count, 1);
+        let first_used_at = first.last_used_at.unwrap();
+
+        store.touch_filter_config("my_filter").await.unwrap();
+        let second = store.load_filter_config().await.unwrap().unwrap();
+        assert_eq!(second.usage_count, 2);
+        assert!(second.last_used_at.unwrap() >= first_used_at);
+
+        // 存在しないIDはNotFound
+        assert!(matches!(
+            store.touch_filter_config("missing").await,
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_load_issues_with_saved_filter() {
+        // 保存済みフィルターでIssueを読み込むと使用回数が記録されることをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("DEMO-1", "DEMO", "Open"),
+        ];
+        store.save_issues(&issues).await.unwrap();
+
+        let filter = IssueFilter::new().project_keys(vec!["TEST".to_string()]);
+        let config = FilterConfig::new("test_only".to_string(), "TEST only".to_string(), filter);
+        store.save_filter_config(&config).await.unwrap();
+
+        let loaded = store
+            .load_issues_with_saved_filter("test_only")
+            .await
+            .unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].key, "TEST-1");
+
+        let config = store.load_filter_config().await.unwrap().unwrap();
+        assert_eq!(config.usage_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_flush_and_close() {
+        // flush()とclose()の後に再オープンしてもデータが残っていることをテスト
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("flush.duckdb");
+
+        let mut store = DuckDBStore::new(&db_path).unwrap();
+        store.initialize().await.unwrap();
+        store
+            .save_issues(&[
+                create_test_issue("TEST-1", "TEST", "Open"),
+                create_test_issue("TEST-2", "TEST", "Done"),
+            ])
+            .await
+            .unwrap();
+
+        store.flush().await.unwrap();
+        store.close().await.unwrap();
+
+        let reopened = DuckDBStore::new(&db_path).unwrap();
+        reopened.initialize().await.unwrap();
+        let stats = reopened.get_stats().await.unwrap();
+        assert_eq!(stats.total_issues, 2);
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_raw_compression() {
+        // 圧縮して保存しても同じIssueが読み込め、データベースファイルが小さくなることをテスト
+        let temp_dir = tempfile::TempDir::new().unwrap();
+
+        let issues: Vec<Issue> = (1..=1000)
+            .map(|i| {
+                let key = format!("ZIP-{}", i);
+                let mut issue = create_test_issue(&key, "ZIP", "Open");
+                issue.id = i.to_string();
+                let body = format!("{} の再現手順: 画面を開いて保存を押す。", key).repeat(40);
+                issue.fields.description = Some(crate::text_to_adf(&body));
+                issue.fields.custom_fields.insert(
+                    "customfield_10020".to_string(),
+                    serde_json::json!({"value": if i % 2 == 0 { "Backend" } else { "Frontend" }}),
+                );
+                issue
+            })
+            .collect();
+
+        let mut sizes = Vec::new();
+        let mut loaded_sets = Vec::new();
+        for compressed in [false, true] {
+            let db_path = temp_dir
+                .path()
+                .join(format!("compressed_{}.duckdb", compressed));
+            let mut store = DuckDBStore::new(&db_path)
+                .unwrap()
+                .with_raw_compression(compressed);
+            store.initialize().await.unwrap();
+            store.save_issues(&issues).await.unwrap();
+
+            assert_eq!(
+                store.recorded_raw_compression().await.unwrap(),
+                Some(compressed)
+            );
+
+            let loaded = store
+                .load_issues(&IssueFilter::new().sort_order(SortOrder::KeyAsc))
+                .await
+                .unwrap();
+            let loaded: Vec<serde_json::Value> = loaded
+                .iter()
+                .map(|issue| serde_json::to_value(issue).unwrap())
+                .collect();
+            loaded_sets.push(loaded);
+
+            // 圧縮時もカスタムフィールド条件で検索できる
+            let filter = IssueFilter::new().custom_field_filter(CustomFieldFilter::new(
+                "customfield_10020".to_string(),
+                CustomFieldOperator::Eq,
+                serde_json::json!("Backend"),
+            ));
+            assert_eq!(store.count_issues(&filter).await.unwrap(), 500);
+
+            store.close().await.unwrap();
+            sizes.push(std::fs::metadata(&db_path).unwrap().len());
+        }
+
+        assert_eq!(loaded_sets[0].len(), issues.len());
+        assert_eq!(loaded_sets[0], loaded_sets[1]);
+        assert!(
+            sizes[1] < sizes[0],
+            "compressed {} bytes should be smaller than uncompressed {} bytes",
+            sizes[1],
+            sizes[0]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_maintenance_report() {
+        // データ投入後のメンテナンスレポートが妥当な値を返すことをテスト
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut store = DuckDBStore::new(temp_dir.path().join("maintenance.duckdb")).unwrap();
+        store.initialize().await.unwrap();
+
+        let issues: Vec<Issue> = (1..=20)
+            .map(|i| create_test_issue(&format!("TEST-{}", i), "TEST", "Open"))
+            .collect();
+        store.save_issues(&issues).await.unwrap();
+
+        let filter = IssueFilter::new().project_keys(vec!["TEST".to_string()]);
+        let config = FilterConfig::new("my_filter".to_string(), "My Filter".to_string(), filter);
+        store.save_filter_config(&config).await.unwrap();
+
+        let report = store.maintenance_report().await.unwrap();
+        assert_eq!(report.issue_count, 20);
+        assert_eq!(report.history_count, 0);
+        assert_eq!(report.filter_config_count, 1);
+        assert!(report.database_size_bytes > 0);
+        assert!(report.free_blocks <= report.total_blocks);
+        assert!((0.0..=1.0).contains(&report.fragmentation_ratio));
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_optimize_if_needed_below_threshold() {
+        // 断片化率が閾値以下の場合は最適化を実行しないことをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+        store
+            .save_issues(&[create_test_issue("TEST-1", "TEST", "Open")])
+            .await
+            .unwrap();
+
+        let report = store.maintenance_report().await.unwrap();
+        assert_eq!(report.database_size_bytes, 0);
+
+        // 断片化率は1.0を超えないため、閾値1.0では常に何もしない
+        assert!(!store.optimize_if_needed(1.0).await.unwrap());
+
+        // 最適化しなくてもデータはそのまま
+        let report = store.maintenance_report().await.unwrap();
+        assert_eq!(report.issue_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_optimize_if_needed_runs_when_fragmented() {
+        // 大量のIssueを削除・再保存して断片化させると、閾値を超えた場合に最適化が実行されることをテスト
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut store = DuckDBStore::new(temp_dir.path().join("fragmented.duckdb")).unwrap();
+        store.initialize().await.unwrap();
+
+        // 圧縮されにくい説明文で複数ブロックにまたがるデータを作る
+        let mut seed: u64 = 42;
+        let issues: Vec<Issue> = (1..=2000)
+            .map(|i| {
+                let mut issue = create_test_issue(&format!("TEST-{}", i), "TEST", "Open");
+                let description: String = (0..512)
+                    .map(|_| {
+                        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+                        format!("{:02x}", (seed >> 56) as u8)
+                    })
+                    .collect();
+                issue.fields.description = Some(serde_json::Value::String(description));
+                issue
+            })
+            .collect();
+        store.save_issues(&issues).await.unwrap();
+        store.flush().await.unwrap();
+
+        // 大半を削除し、残りを再保存して未使用ブロックを作る
+        let deleted: Vec<String> = issues[10..].iter().map(|i| i.key.clone()).collect();
+        store.delete_issues(&deleted).await.unwrap();
+        store.save_issues(&issues[..10]).await.unwrap();
+        store.flush().await.unwrap();
+
+        let report = store.maintenance_report().await.unwrap();
+        assert!(report.free_blocks > 0, "{:?}", report);
+        assert!(report.fragmentation_ratio > 0.0, "{:?}", report);
+
+        // 断片化率ちょうどの閾値では実行せず、それより低い閾値では実行する
+        assert!(
+            !store
+                .optimize_if_needed(report.fragmentation_ratio)
+                .await
+                .unwrap()
+        );
+        assert!(store.optimize_if_needed(0.0).await.unwrap());
+
+        // 最適化後も残したIssueはそのまま読み込める
+        let report = store.maintenance_report().await.unwrap();
+        assert_eq!(report.issue_count, 10);
+        let loaded = store.load_issues(&IssueFilter::new()).await.unwrap();
+        assert_eq!(loaded.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_save_and_load_history() {
+        // DuckDBStoreで履歴データの保存と読み込みが正しく動作することをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let author = HistoryAuthor {
+            account_id: "user123".to_string(),
+            display_name: "Test User".to_string(),
+            email_address: Some("test@example.com".to_string()),
+        };
+
+        let histories = vec![
+            IssueHistory::new(
+                "10000".to_string(),
+                "TEST-123".to_string(),
+                "change_1".to_string(),
+                Utc::now(),
+                "status".to_string(),
+            )
+            .with_author(author.clone())
+            .with_field_change(
+                Some("Open".to_string()),
+                Some("In Progress".to_string()),
+                Some("Open".to_string()),
+                Some("In Progress".to_string()),
+            ),
+            IssueHistory::new(
+                "10000".to_string(),
+                "TEST-123".to_string(),
+                "change_2".to_string(),
+                Utc::now(),
+                "assignee".to_string(),
+            )
+            .with_author(author)
+            .with_field_change(
+                None,
+                Some("user456".to_string()),
+                None,
+                Some("Jane Doe".to_string()),
+            ),
+        ];
+
+        // 保存
+        let saved_count = store.save_issue_history(&histories).await.unwrap();
+        assert_eq!(saved_count, 2);
+
+        // 全件読み込み
+        let filter = HistoryFilter::new();
+        let loaded_histories = store.load_issue_history(&filter).await.unwrap();
+        assert_eq!(loaded_histories.len(), 2);
+
+        // 特定課題の履歴のみ読み込み
+        let filter = HistoryFilter::new().issue_keys(vec!["TEST-123".to_string()]);
+        let filtered_histories = store.load_issue_history(&filter).await.unwrap();
+        assert_eq!(filtered_histories.len(), 2);
+
+        // ステータス変更のみ読み込み
+        let filter = HistoryFilter::new().field_names(vec!["status".to_string()]);
+        let status_histories = store.load_issue_history(&filter).await.unwrap();
+        assert_eq!(status_histories.len(), 1);
+        assert_eq!(status_histories[0].field_name, "status");
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_stream_issue_history() {
+        // ストリームで取得した履歴がload_issue_historyと同じ内容・順序になることをテスト
+        use chrono::TimeZone;
+
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        // 複数ページにまたがる件数を用意し、同じ日時のレコードも含める
+        let base_time = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap()
+            + chrono::Duration::milliseconds(250);
+        let created_at = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        let histories: Vec<IssueHistory> = (0..2500)
+            .map(|i| {
+                IssueHistory::new(
+                    format!("{}", 10000 + i % 50),
+                    format!("TEST-{}", i % 50),
+                    format!("change_{}", i),
+                    base_time + chrono::Duration::minutes((i % 10) as i64),
+                    "status".to_string(),
+                )
+                .with_created_at(created_at)
+            })
+            .collect();
+        store.save_issue_history(&histories).await.unwrap();
+
+        let filter = HistoryFilter::new();
+        let loaded = store.load_issue_history(&filter).await.unwrap();
+        let streamed: Vec<IssueHistory> = store
+            .stream_issue_history(filter)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(streamed.len(), 2500);
+        let loaded_ids: Vec<&str> = loaded.iter().map(|h| h.change_id.as_str()).collect();
+        let streamed_ids: Vec<&str> = streamed.iter().map(|h| h.change_id.as_str()).collect();
+        assert_eq!(streamed_ids, loaded_ids);
+
+        // 日時は保存した値のまま復元される
+        let saved_timestamps: HashMap<&str, chrono::DateTime<Utc>> = histories
+            .iter()
+            .map(|h| (h.change_id.as_str(), h.change_timestamp))
+            .collect();
+        for history in &streamed {
+            assert_eq!(
+                history.change_timestamp,
+                saved_timestamps[history.change_id.as_str()]
+            );
+            assert_eq!(history.created_at, created_at);
+        }
+
+        // 件数制限がページをまたいでも守られる
+        let limited: Vec<IssueHistory> = store
+            .stream_issue_history(HistoryFilter::new().limit(1500))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(limited.len(), 1500);
+        assert_eq!(limited[1499].change_id, loaded[1499].change_id);
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_history_stats() {
+        // DuckDBStoreで履歴統計が正しく動作することをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let histories = vec![
+            IssueHistory::new(
+                "10000".to_string(),
+                "TEST-123".to_string(),
+                "change_1".to_string(),
+                Utc::now(),
+                "status".to_string(),
+            ),
+            IssueHistory::new(
+                "10001".to_string(),
+                "TEST-124".to_string(),
+                "change_2".to_string(),
+                Utc::now(),
+                "status".to_string(),
+            ),
+            IssueHistory::new(
+                "10000".to_string(),
+                "TEST-123".to_string(),
+                "change_3".to_string(),
+                Utc::now(),
+                "assignee".to_string(),
+            ),
+        ];
+
+        store.save_issue_history(&histories).await.unwrap();
+
+        let stats = store.get_history_stats().await.unwrap();
+        assert_eq!(stats.total_changes, 3);
+        assert_eq!(stats.unique_issues, 2); // TEST-123, TEST-124
+        assert_eq!(stats.field_change_counts.get("status"), Some(&2));
+        assert_eq!(stats.field_change_counts.get("assignee"), Some(&1));
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_history_stats_oldest_and_newest_change() {
+        // 履歴の最古・最新の変更日時がUTCでマイクロ秒まで正しく返り、履歴が無い場合はNoneになることをテスト
+        use chrono::TimeZone;
+
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let empty = store.get_history_stats().await.unwrap();
+        assert_eq!(empty.oldest_change, None);
+        assert_eq!(empty.newest_change, None);
+
+        let oldest = Utc.with_ymd_and_hms(2023, 12, 31, 23, 59, 59).unwrap()
+            + chrono::Duration::microseconds(123_456);
+        let middle = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let newest = Utc.with_ymd_and_hms(2024, 6, 30, 8, 15, 30).unwrap()
+            + chrono::Duration::microseconds(1);
+
+        // 保存順と日時の順序は無関係
+        let histories: Vec<IssueHistory> = [
+            (middle, "change_1"),
+            (newest, "change_2"),
+            (oldest, "change_3"),
+        ]
+        .into_iter()
+        .map(|(timestamp, change_id)| {
+            IssueHistory::new(
+                "10000".to_string(),
+                "TEST-123".to_string(),
+                change_id.to_string(),
+                timestamp,
+                "status".to_string(),
+            )
+        })
+        .collect();
+        store.save_issue_history(&histories).await.unwrap();
+
+        let stats = store.get_history_stats().await.unwrap();
+        assert_eq!(stats.oldest_change, Some(oldest));
+        assert_eq!(stats.newest_change, Some(newest));
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_delete_history() {
+        // DuckDBStoreで履歴削除が正しく動作することをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+
+        let histories = vec![
+            IssueHistory::new(
+                "10000".to_string(),
+                "TEST-123".to_string(),
+                "change_1".to_string(),
+                Utc::now(),
+                "status".to_string(),
+            ),
+            IssueHistory::new(
+                "10001".to_string(),
+                "TEST-124".to_string(),
+                "change_2".to_string(),
+                Utc::now(),
+                "status".to_string(),
+            ),
+        ];
+
+        store.save_issue_history(&histories).await.unwrap();
+
+        // TEST-123の履歴を削除
+        let deleted_count = store
+            .delete_issue_history(&["TEST-123".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(deleted_count, 1);
+
+        // 残りの履歴を確認
+        let filter = HistoryFilter::new();
+        let remaining_histories = store.load_issue_history(&filter).await.unwrap();
+        assert_eq!(remaining_histories.len(), 1);
+        assert_eq!(remaining_histories[0].issue_key, "TEST-124");
     }
 
-    async fn delete_issue_history(&mut self, issue_keys: &[String]) -> Result<usize, Error> {
-        let conn = Arc::clone(&self.connection);
-        let keys = issue_keys.to_vec();
+    #[tokio::test]
+    async fn test_duckdb_store_save_issues_with_history() {
+        // 埋め込みchangelogを持つIssueを保存すると履歴も一緒に保存されることをテスト
+        use crate::models::{Changelog, History, HistoryItem};
 
-        task::spawn_blocking(move || {
-            let conn = conn.lock().unwrap();
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
 
-            let placeholders: Vec<String> = keys.iter().map(|_| "?".to_string()).collect();
-            let query = format!(
-                "DELETE FROM issue_history WHERE issue_key IN ({})",
-                placeholders.join(", ")
-            );
+        let mut issue = create_test_issue("TEST-1", "TEST", "In Progress");
+        let author = issue.fields.reporter.clone();
+        issue.changelog = Some(Changelog {
+            start_at: 0,
+            max_results: 100,
+            total: 1,
+            histories: vec![History {
+                id: "10001".to_string(),
+                author,
+                created: Utc::now(),
+                items: vec![
+                    HistoryItem {
+                        field: "status".to_string(),
+                        field_type: "jira".to_string(),
+                        from: Some("1".to_string()),
+                        from_string: Some("Open".to_string()),
+                        to: Some("3".to_string()),
+                        to_string: Some("In Progress".to_string()),
+                    },
+                    HistoryItem {
+                        field: "priority".to_string(),
+                        field_type: "jira".to_string(),
+                        from: Some("3".to_string()),
+                        from_string: Some("Medium".to_string()),
+                        to: Some("2".to_string()),
+                        to_string: Some("High".to_string()),
+                    },
+                ],
+            }],
+        });
+        let plain_issue = create_test_issue("TEST-2", "TEST", "Open");
 
-            let params_refs: Vec<&dyn duckdb::ToSql> =
-                keys.iter().map(|k| k as &dyn duckdb::ToSql).collect();
+        let (issue_count, history_count) = store
+            .save_issues_with_history(&[issue.clone(), plain_issue])
+            .await
+            .unwrap();
+        assert_eq!(issue_count, 2);
+        assert_eq!(history_count, 2);
+
+        // Issueと履歴の両方が読み込めること
+        assert_eq!(store.count_issues(&IssueFilter::new()).await.unwrap(), 2);
+        let filter = HistoryFilter::new().issue_keys(vec!["TEST-1".to_string()]);
+        let histories = store.load_issue_history(&filter).await.unwrap();
+        assert_eq!(histories.len(), 2);
+
+        let status_change = histories.iter().find(|h| h.field_name == "status").unwrap();
+        assert_eq!(status_change.change_id, "10001");
+        assert_eq!(status_change.from_display_value, Some("Open".to_string()));
+        assert_eq!(
+            status_change.to_display_value,
+            Some("In Progress".to_string())
+        );
+        assert_eq!(
+            status_change.author.as_ref().unwrap().account_id,
+            "test_user"
+        );
 
-            let deleted_count = conn.execute(&query, params_refs.as_slice())?;
-            Ok::<usize, duckdb::Error>(deleted_count)
-        })
-        .await
-        .map_err(|e| Error::DatabaseError(format!("Task join error: {}", e)))?
-        .map_err(|e| Error::DatabaseError(format!("Delete history operation failed: {}", e)))
+        // 再保存しても履歴が重複しないこと
+        let (_, history_count) = store.save_issues_with_history(&[issue]).await.unwrap();
+        assert_eq!(history_count, 2);
+        let histories = store
+            .load_issue_history(&HistoryFilter::new())
+            .await
+            .unwrap();
+        assert_eq!(histories.len(), 2);
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::models::{IssueFields, IssueType, Priority, Project, Status, StatusCategory, User};
-    use std::collections::HashMap;
-    use tempfile::TempDir;
 
-    fn create_test_issue(key: &str, project_key: &str, status: &str) -> Issue {
-        let status_category = StatusCategory {
-            id: 1,
-            key: "new".to_string(),
-            name: "新規".to_string(),
-            color_name: "blue-gray".to_string(),
-            self_url: Some("http://example.com".to_string()),
-        };
+    #[tokio::test]
+    async fn test_duckdb_store_sort_orders() {
+        // 担当者・報告者・ステータス・種別のソートが正しく動作することをテスト（未割り当ては末尾）
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
 
-        let issue_status = Status {
-            id: "1".to_string(),
-            name: status.to_string(),
-            description: None,
-            icon_url: None,
-            status_category,
-            self_url: "http://example.com".to_string(),
-        };
+        store.save_issues(&sample_sort_issues()).await.unwrap();
+
+        let cases = vec![
+            (SortOrder::AssigneeAsc, vec!["SORT-3", "SORT-1", "SORT-2"]),
+            (SortOrder::AssigneeDesc, vec!["SORT-1", "SORT-3", "SORT-2"]),
+            (SortOrder::ReporterAsc, vec!["SORT-2", "SORT-3", "SORT-1"]),
+            (SortOrder::ReporterDesc, vec!["SORT-1", "SORT-3", "SORT-2"]),
+            (SortOrder::StatusAsc, vec!["SORT-2", "SORT-3", "SORT-1"]),
+            (SortOrder::StatusDesc, vec!["SORT-1", "SORT-3", "SORT-2"]),
+            (SortOrder::TypeAsc, vec!["SORT-2", "SORT-3", "SORT-1"]),
+            (SortOrder::TypeDesc, vec!["SORT-1", "SORT-3", "SORT-2"]),
+        ];
 
-        let issue_type = IssueType {
-            id: "1".to_string(),
-            name: "Task".to_string(),
-            description: None,
-            icon_url: None,
-            subtask: Some(false),
-            self_url: "http://example.com".to_string(),
-        };
+        for (sort_order, expected) in cases {
+            let filter = IssueFilter::new().sort_order(sort_order.clone());
+            let keys: Vec<String> = store
+                .load_issues(&filter)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|i| i.key)
+                .collect();
+            assert_eq!(keys, expected, "{:?}", sort_order);
+        }
+    }
 
-        let project = Project {
-            id: "1".to_string(),
-            key: project_key.to_string(),
-            name: format!("Project {}", project_key),
-            project_type_key: Some("software".to_string()),
-            description: None,
-            lead: None,
-            url: None,
-            simplified: None,
-            self_url: "http://example.com".to_string(),
-            avatar_urls: None,
-        };
+    #[tokio::test]
+    async fn test_duckdb_store_unassigned_filter() {
+        // 未割り当てフィルターで担当者の無いIssueのみ取得できることをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
 
-        let reporter = User {
-            account_id: "test_user".to_string(),
-            display_name: "Test User".to_string(),
-            email_address: Some("test@example.com".to_string()),
-            self_url: "http://example.com".to_string(),
-            avatar_urls: None,
-            active: Some(true),
-            time_zone: None,
-            account_type: None,
-        };
+        store.save_issues(&sample_sort_issues()).await.unwrap();
 
-        let fields = IssueFields {
-            summary: format!("Test issue {}", key),
-            description: Some(serde_json::Value::String("Test description".to_string())),
-            status: issue_status,
-            priority: Some(Priority {
-                id: "1".to_string(),
-                name: "Medium".to_string(),
-                description: None,
-                icon_url: None,
-                status_color: None,
-                self_url: "http://example.com".to_string(),
-            }),
-            issue_type,
-            assignee: None,
-            reporter,
-            created: Utc::now(),
-            updated: Utc::now(),
-            resolution_date: None,
-            project: Some(project),
-            custom_fields: HashMap::new(),
-        };
+        let cases = vec![
+            (IssueFilter::new().assignee_unassigned(true), vec!["SORT-2"]),
+            (
+                IssueFilter::new().assignee_unassigned(false),
+                vec!["SORT-1", "SORT-3"],
+            ),
+            // 担当者指定と組み合わせた場合はいずれかに一致すればよい
+            (
+                IssueFilter::new()
+                    .assignees(vec!["Alice".to_string()])
+                    .assignee_unassigned(true),
+                vec!["SORT-2", "SORT-3"],
+            ),
+            (
+                IssueFilter::new().assignees(vec!["Alice".to_string()]),
+                vec!["SORT-3"],
+            ),
+        ];
 
-        Issue {
-            id: key.to_string(),
-            key: key.to_string(),
-            fields,
-            self_url: "http://example.com".to_string(),
-            changelog: None,
+        for (filter, expected) in cases {
+            let keys: Vec<String> = store
+                .load_issues(&filter.clone().sort_order(SortOrder::KeyAsc))
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|i| i.key)
+                .collect();
+            assert_eq!(keys, expected, "{:?}", filter);
         }
     }
 
     #[tokio::test]
-    async fn test_duckdb_store_new() {
-        // DuckDBStore::new()で正しく作成されることをテスト
-        let temp_dir = TempDir::new().unwrap();
-        let db_path = temp_dir.path().join("test.db");
-        let store = DuckDBStore::new(&db_path).unwrap();
+    async fn test_duckdb_store_stats_by_resolution() {
+        // 解決状況別の件数が正しく集計されることをテスト（未解決は含まない）
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
 
-        assert_eq!(store.db_path, db_path);
+        store
+            .save_issues(&sample_resolution_issues())
+            .await
+            .unwrap();
+
+        let stats = store.get_stats().await.unwrap();
+        assert_eq!(stats.issues_by_resolution.get("Done"), Some(&2));
+        assert_eq!(stats.issues_by_resolution.get("Won't Fix"), Some(&1));
+        assert_eq!(stats.issues_by_resolution.len(), 2);
     }
 
     #[tokio::test]
-    async fn test_duckdb_store_new_in_memory() {
-        // DuckDBStore::new_in_memory()で正しく作成されることをテスト
-        let store = DuckDBStore::new_in_memory().unwrap();
+    async fn test_duckdb_store_stats_by_assignee_and_priority() {
+        // 担当者別・優先度別の件数が正しく集計されることをテスト（未割り当ては専用のキー）
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+        let priority = |name: &str| Priority {
+            id: name.to_string(),
+            name: name.to_string(),
+            self_url: format!("http://example.com/priority/{}", name),
+            description: None,
+            icon_url: None,
+            status_color: None,
+        };
 
-        assert_eq!(store.db_path, PathBuf::from(":memory:"));
+        // SORT-1: Bob, SORT-2: 未割り当て, SORT-3: Alice
+        let mut issues = sample_sort_issues();
+        issues[0].fields.priority = Some(priority("High"));
+        issues[1].fields.priority = Some(priority("High"));
+        issues[2].fields.priority = None;
+        let mut extra = issues[2].clone();
+        extra.id = "extra".to_string();
+        extra.key = "SORT-4".to_string();
+        extra.fields.priority = Some(priority("Low"));
+        issues.push(extra);
+        store.save_issues(&issues).await.unwrap();
+
+        let stats = store.get_stats().await.unwrap();
+        let expected_assignees: HashMap<String, usize> = [
+            ("Alice".to_string(), 2),
+            ("Bob".to_string(), 1),
+            (UNASSIGNED_BUCKET.to_string(), 1),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(stats.issues_by_assignee, expected_assignees);
+
+        let expected_priorities: HashMap<String, usize> =
+            [("High".to_string(), 2), ("Low".to_string(), 1)]
+                .into_iter()
+                .collect();
+        assert_eq!(stats.issues_by_priority, expected_priorities);
     }
 
     #[tokio::test]
-    async fn test_duckdb_store_initialize() {
-        // DuckDBStore::initialize()でスキーマが作成されることをテスト
-        let store = DuckDBStore::new_in_memory().unwrap();
-
+    async fn test_duckdb_store_custom_field_filters() {
+        // 選択リストの等価比較とストーリーポイントの数値比較で絞り込めることをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
         store.initialize().await.unwrap();
 
-        // テーブルが作成されているかチェック（DuckDB specific）
-        let conn = Arc::clone(&store.connection);
-        let result = task::spawn_blocking(move || {
-            let conn = conn.lock().unwrap();
-            let count: i64 = conn.prepare("SELECT COUNT(*) FROM information_schema.tables WHERE table_name IN ('issues', 'filter_configs')")
-                .unwrap()
-                .query_row(params![], |row| row.get(0))
-                .unwrap();
-            count
-        }).await.unwrap();
+        store
+            .save_issues(&sample_custom_field_issues())
+            .await
+            .unwrap();
 
-        assert_eq!(result, 2); // issuesとfilter_configsテーブル
+        let keys = |issues: Vec<Issue>| {
+            let mut keys: Vec<String> = issues.into_iter().map(|i| i.key).collect();
+            keys.sort();
+            keys
+        };
+
+        // 選択リストの値で一致
+        let filter = IssueFilter::new().custom_field_filter(CustomFieldFilter::new(
+            "customfield_10020",
+            CustomFieldOperator::Eq,
+            serde_json::json!("High"),
+        ));
+        let issues = store.load_issues(&filter).await.unwrap();
+        assert_eq!(keys(issues), vec!["CF-1", "CF-3"]);
+
+        // ストーリーポイントが4より大きい
+        let filter = IssueFilter::new().custom_field_filter(CustomFieldFilter::new(
+            "customfield_10016",
+            CustomFieldOperator::Gt,
+            serde_json::json!(4),
+        ));
+        let issues = store.load_issues(&filter).await.unwrap();
+        assert_eq!(keys(issues), vec!["CF-2", "CF-3"]);
+
+        // 複数条件はANDで結合される
+        let filter = IssueFilter::new()
+            .custom_field_filter(CustomFieldFilter::new(
+                "customfield_10020",
+                CustomFieldOperator::Eq,
+                serde_json::json!("High"),
+            ))
+            .custom_field_filter(CustomFieldFilter::new(
+                "customfield_10016",
+                CustomFieldOperator::Lt,
+                serde_json::json!(5),
+            ));
+        let issues = store.load_issues(&filter).await.unwrap();
+        assert_eq!(keys(issues), vec!["CF-1"]);
+    }
+
+    #[test]
+    fn test_build_limit_clause_clamps_large_values() {
+        // 上限を超えるLIMITは省略され、OFFSETは上限に丸められることをテスト
+        assert_eq!(build_limit_clause(None, Some(usize::MAX)), "");
+        assert_eq!(
+            build_limit_clause(Some(usize::MAX), Some(10)),
+            format!("LIMIT 10 OFFSET {}", MAX_SQL_LIMIT)
+        );
+        assert_eq!(build_limit_clause(Some(5), None), "OFFSET 5");
     }
 
     #[tokio::test]
-    async fn test_duckdb_store_save_and_load_issues() {
-        // DuckDBStoreでIssueの保存と読み込みが正しく動作することをテスト
+    async fn test_duckdb_store_extreme_limit_and_offset() {
+        // 極端なlimitとデータ件数を超えるoffsetがエラーにならないことをテスト
         let mut store = DuckDBStore::new_in_memory().unwrap();
         store.initialize().await.unwrap();
 
         let issues = vec![
             create_test_issue("TEST-1", "TEST", "Open"),
-            create_test_issue("TEST-2", "TEST", "In Progress"),
-            create_test_issue("DEMO-1", "DEMO", "Done"),
+            create_test_issue("TEST-2", "TEST", "Open"),
+            create_test_issue("TEST-3", "TEST", "Done"),
         ];
+        store.save_issues(&issues).await.unwrap();
 
-        // 保存
-        let saved_count = store.save_issues(&issues).await.unwrap();
-        assert_eq!(saved_count, 3);
+        // usize::MAXのlimitは全件を返す
+        let loaded = store
+            .load_issues(&IssueFilter::new().limit(usize::MAX))
+            .await
+            .unwrap();
+        assert_eq!(loaded.len(), 3);
 
-        // 全件読み込み
-        let loaded_issues = store.load_all_issues().await.unwrap();
-        assert_eq!(loaded_issues.len(), 3);
+        // 件数を超えるoffsetは空の結果
+        let loaded = store
+            .load_issues(&IssueFilter::new().offset(10).limit(5))
+            .await
+            .unwrap();
+        assert!(loaded.is_empty());
 
-        // キーが正しく保存されているか確認
-        let mut loaded_keys: Vec<String> = loaded_issues.iter().map(|i| i.key.clone()).collect();
-        loaded_keys.sort();
-        assert_eq!(loaded_keys, vec!["DEMO-1", "TEST-1", "TEST-2"]);
+        let loaded = store
+            .load_issues(&IssueFilter::new().offset(usize::MAX).limit(usize::MAX))
+            .await
+            .unwrap();
+        assert!(loaded.is_empty());
+
+        // 履歴の取得でも同様
+        let history = store
+            .load_issue_history(&HistoryFilter::new().limit(usize::MAX))
+            .await
+            .unwrap();
+        assert!(history.is_empty());
     }
 
     #[tokio::test]
-    async fn test_duckdb_store_filter_issues() {
-        // DuckDBStoreでIssueのフィルタリングが正しく動作することをテスト
+    async fn test_duckdb_store_load_projection() {
+        // キーとステータスのみを要求した場合に、その項目だけが設定されることをテスト
         let mut store = DuckDBStore::new_in_memory().unwrap();
         store.initialize().await.unwrap();
 
         let issues = vec![
-            create_test_issue("TEST-1", "TEST", "Open"),
-            create_test_issue("TEST-2", "TEST", "In Progress"),
-            create_test_issue("DEMO-1", "DEMO", "Done"),
+            create_test_issue("PROJ-1", "PROJ", "Open"),
+            create_test_issue("PROJ-2", "PROJ", "Done"),
+            create_test_issue("OTHER-1", "OTHER", "Open"),
         ];
-
         store.save_issues(&issues).await.unwrap();
 
-        // プロジェクトでフィルタ
-        let filter = IssueFilter::new().project_keys(vec!["TEST".to_string()]);
-        let filtered_issues = store.load_issues(&filter).await.unwrap();
+        let filter = IssueFilter::new()
+            .project_keys(vec!["PROJ".to_string()])
+            .sort_order(SortOrder::KeyAsc);
+        let projections = store
+            .load_projection(&filter, &[IssueField::Key, IssueField::Status])
+            .await
+            .unwrap();
 
-        assert_eq!(filtered_issues.len(), 2);
-        assert!(
-            filtered_issues
-                .iter()
-                .all(|i| i.fields.project.as_ref().unwrap().key == "TEST")
-        );
+        assert_eq!(projections.len(), 2);
+        assert_eq!(projections[0].key.as_deref(), Some("PROJ-1"));
+        assert_eq!(projections[0].status.as_deref(), Some("Open"));
+        assert_eq!(projections[1].key.as_deref(), Some("PROJ-2"));
+        assert_eq!(projections[1].status.as_deref(), Some("Done"));
 
-        // ステータスでフィルタ
-        let filter = IssueFilter::new().statuses(vec!["Done".to_string()]);
-        let filtered_issues = store.load_issues(&filter).await.unwrap();
+        // 要求していない項目は設定されない
+        assert!(projections[0].summary.is_none());
+        assert!(projections[0].created.is_none());
 
-        assert_eq!(filtered_issues.len(), 1);
-        assert_eq!(filtered_issues[0].fields.status.name, "Done");
+        // 日時の項目も取得できる
+        let projections = store
+            .load_projection(&filter.limit(1), &[IssueField::Created])
+            .await
+            .unwrap();
+        assert_eq!(projections.len(), 1);
+        assert!(projections[0].created.is_some());
+        assert!(projections[0].key.is_none());
     }
 
     #[tokio::test]
-    async fn test_duckdb_store_count_issues() {
-        // DuckDBStore::count_issues()が正しく動作することをテスト
+    async fn test_duckdb_store_time_tracking_columns() {
+        // 時間管理の秒数が列に保存され、GROUP BYで集計できることをテスト
         let mut store = DuckDBStore::new_in_memory().unwrap();
         store.initialize().await.unwrap();
 
-        let issues = vec![
-            create_test_issue("TEST-1", "TEST", "Open"),
-            create_test_issue("TEST-2", "TEST", "In Progress"),
-            create_test_issue("DEMO-1", "DEMO", "Done"),
-        ];
+        let time_tracking = |spent: i64| crate::TimeTracking {
+            original_estimate_seconds: Some(28800),
+            time_spent_seconds: Some(spent),
+            ..Default::default()
+        };
+        let mut issue1 = create_test_issue("TIME-1", "TIME", "Open");
+        issue1.fields.time_tracking = Some(time_tracking(3600));
+        let mut issue2 = create_test_issue("TIME-2", "TIME", "Open");
+        issue2.fields.time_tracking = Some(time_tracking(7200));
+        let mut issue3 = create_test_issue("TIME-3", "TIME", "Done");
+        issue3.fields.time_tracking = Some(time_tracking(1800));
+        // 時間管理情報の無いIssueは集計に影響しない
+        let issue4 = create_test_issue("TIME-4", "TIME", "Done");
+        store
+            .save_issues(&[issue1, issue2, issue3, issue4])
+            .await
+            .unwrap();
+
+        let conn = Arc::clone(&store.connection);
+        let sums = task::spawn_blocking(move || {
+            let conn = conn.lock().unwrap();
+            let mut stmt = conn
+                .prepare(
+                    "SELECT status_name, SUM(time_spent_seconds), SUM(original_estimate_seconds)
+                     FROM issues GROUP BY status_name ORDER BY status_name",
+                )
+                .unwrap();
+            stmt.query_map(params![], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, i64>(2)?,
+                ))
+            })
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            sums,
+            vec![
+                ("Done".to_string(), 1800, 28800),
+                ("Open".to_string(), 10800, 57600),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_duckdb_store_vote_and_watch_columns() {
+        // 投票数・ウォッチャー数が列に保存されて集計でき、読み込んだIssueにも残ることをテスト
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
 
-        store.save_issues(&issues).await.unwrap();
+        let with_counts = |key: &str, votes: u64, watches: u64| {
+            let mut issue = create_test_issue(key, "SOCIAL", "Open");
+            issue.fields.votes = Some(crate::Votes {
+                votes,
+                ..Default::default()
+            });
+            issue.fields.watches = Some(crate::Watches {
+                watch_count: watches,
+                is_watching: true,
+                ..Default::default()
+            });
+            issue
+        };
+        let mut issue1 = with_counts("SOCIAL-1", 3, 2);
+        issue1.id = "9201".to_string();
+        let mut issue2 = with_counts("SOCIAL-2", 4, 5);
+        issue2.id = "9202".to_string();
+        // 件数の無いIssueは集計に影響しない
+        let mut issue3 = create_test_issue("SOCIAL-3", "SOCIAL", "Open");
+        issue3.id = "9203".to_string();
+        store.save_issues(&[issue1, issue2, issue3]).await.unwrap();
 
-        // 全件カウント
-        let total_count = store.count_issues(&IssueFilter::new()).await.unwrap();
-        assert_eq!(total_count, 3);
+        let conn = Arc::clone(&store.connection);
+        let sums = task::spawn_blocking(move || {
+            let conn = conn.lock().unwrap();
+            conn.query_row(
+                "SELECT SUM(vote_count), SUM(watch_count), COUNT(vote_count) FROM issues",
+                params![],
+                |row| {
+                    Ok((
+                        row.get::<_, i64>(0)?,
+                        row.get::<_, i64>(1)?,
+                        row.get::<_, i64>(2)?,
+                    ))
+                },
+            )
+            .unwrap()
+        })
+        .await
+        .unwrap();
+        assert_eq!(sums, (7, 7, 2));
 
-        // フィルタ適用カウント
-        let filter = IssueFilter::new().project_keys(vec!["TEST".to_string()]);
-        let filtered_count = store.count_issues(&filter).await.unwrap();
-        assert_eq!(filtered_count, 2);
+        let loaded = store
+            .load_issues(&IssueFilter::new().sort_order(SortOrder::KeyAsc))
+            .await
+            .unwrap();
+        let watches = loaded[1].fields.watches.as_ref().unwrap();
+        assert_eq!(loaded[1].fields.votes.as_ref().unwrap().votes, 4);
+        assert_eq!(watches.watch_count, 5);
+        assert!(watches.is_watching);
+        assert!(loaded[2].fields.votes.is_none());
     }
 
     #[tokio::test]
-    async fn test_duckdb_store_delete_issues() {
-        // DuckDBStore::delete_issues()が正しく動作することをテスト
+    async fn test_duckdb_store_group_counts_by_category() {
+        // 独自のステータス名でもステータスカテゴリで集計されることをテスト
         let mut store = DuckDBStore::new_in_memory().unwrap();
         store.initialize().await.unwrap();
 
+        let with_category = |key: &str, status: &str, category: &str| {
+            let mut issue = create_test_issue(key, "CAT", status);
+            issue.fields.status.status_category.key = category.to_string();
+            issue
+        };
         let issues = vec![
-            create_test_issue("TEST-1", "TEST", "Open"),
-            create_test_issue("TEST-2", "TEST", "In Progress"),
-            create_test_issue("DEMO-1", "DEMO", "Done"),
+            with_category("CAT-1", "Backlog", "new"),
+            with_category("CAT-2", "Code Review", "indeterminate"),
+            with_category("CAT-3", "QA", "indeterminate"),
+            with_category("CAT-4", "Waiting for Deploy", "indeterminate"),
+            with_category("CAT-5", "Shipped", "done"),
+            with_category("CAT-6", "Rejected", "done"),
         ];
-
         store.save_issues(&issues).await.unwrap();
 
-        // 削除
-        let deleted_count = store
-            .delete_issues(&["TEST-1".to_string(), "DEMO-1".to_string()])
+        let grouped = store
+            .group_counts_by_category(&IssueFilter::new())
             .await
             .unwrap();
-        assert_eq!(deleted_count, 2);
-
-        // 残りを確認
-        let remaining_issues = store.load_all_issues().await.unwrap();
-        assert_eq!(remaining_issues.len(), 1);
-        assert_eq!(remaining_issues[0].key, "TEST-2");
+        assert_eq!(
+            grouped,
+            vec![
+                ("indeterminate".to_string(), 3),
+                ("done".to_string(), 2),
+                ("new".to_string(), 1),
+            ]
+        );
     }
 
     #[tokio::test]
-    async fn test_duckdb_store_get_stats() {
-        // DuckDBStore::get_stats()が正しく動作することをテスト
+    async fn test_duckdb_store_date_range_filters() {
+        // 作成日時・更新日時の範囲で絞り込まれ、JsonStoreのmatches()と結果が一致することをテスト
+        use crate::DateRange;
+        use chrono::TimeZone;
+
         let mut store = DuckDBStore::new_in_memory().unwrap();
         store.initialize().await.unwrap();
 
-        let issues = vec![
-            create_test_issue("TEST-1", "TEST", "Open"),
-            create_test_issue("TEST-2", "TEST", "In Progress"),
-            create_test_issue("DEMO-1", "DEMO", "Done"),
-        ];
-
+        let day = |d: u32| Utc.with_ymd_and_hms(2024, 1, d, 12, 0, 0).unwrap();
+        let issues: Vec<Issue> = [(1, 2), (5, 20), (10, 11)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (created, updated))| {
+                let mut issue = create_test_issue(&format!("DATE-{}", i + 1), "DATE", "Open");
+                issue.id = format!("{}", 9000 + i);
+                issue.fields.created = day(created);
+                issue.fields.updated = day(updated);
+                issue
+            })
+            .collect();
         store.save_issues(&issues).await.unwrap();
 
-        let stats = store.get_stats().await.unwrap();
+        let cases = vec![
+            (
+                IssueFilter::new().created_range(DateRange::new(day(4), day(11))),
+                vec!["DATE-2", "DATE-3"],
+            ),
+            (
+                IssueFilter::new().updated_range(DateRange::new(day(1), day(12))),
+                vec!["DATE-1", "DATE-3"],
+            ),
+            (
+                IssueFilter::new()
+                    .created_range(DateRange::new(day(1), day(9)))
+                    .updated_range(DateRange::new(day(15), day(25))),
+                vec!["DATE-2"],
+            ),
+        ];
 
-        assert_eq!(stats.total_issues, 3);
-        assert_eq!(stats.issues_by_project.get("TEST"), Some(&2));
-        assert_eq!(stats.issues_by_project.get("DEMO"), Some(&1));
-        assert_eq!(stats.issues_by_status.get("Open"), Some(&1));
-        assert_eq!(stats.issues_by_status.get("In Progress"), Some(&1));
-        assert_eq!(stats.issues_by_status.get("Done"), Some(&1));
-        assert_eq!(stats.index_count, 4);
+        for (filter, expected) in cases {
+            let filter = filter.sort_order(SortOrder::KeyAsc);
+            let keys: Vec<String> = store
+                .load_issues(&filter)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|i| i.key)
+                .collect();
+            assert_eq!(keys, expected);
+            assert_eq!(store.count_issues(&filter).await.unwrap(), expected.len());
+
+            let matched: Vec<&str> = issues
+                .iter()
+                .filter(|issue| filter.matches(issue))
+                .map(|issue| issue.key.as_str())
+                .collect();
+            assert_eq!(matched, expected);
+        }
     }
 
     #[tokio::test]
-    async fn test_duckdb_store_filter_config() {
-        // DuckDBStoreでFilterConfigの保存と読み込みが正しく動作することをテスト
-        let mut store = DuckDBStore::new_in_memory().unwrap();
-        store.initialize().await.unwrap();
-
-        let filter = IssueFilter::new()
-            .project_keys(vec!["TEST".to_string()])
-            .statuses(vec!["Open".to_string()]);
+    async fn test_duckdb_store_parallel_reads_use_read_pool() {
+        // 書き込み用の接続がロックされていても、読み込みがプールの接続で並行に完了することをテスト
+        use std::time::Duration;
 
-        let config =
-            FilterConfig::new("test_filter".to_string(), "Test Filter".to_string(), filter)
-                .description("Test description".to_string());
+        let mut store = DuckDBStore::new_in_memory()
+            .unwrap()
+            .with_read_pool_size(3)
+            .unwrap();
+        store.initialize().await.unwrap();
+        store
+            .save_issues(&[
+                create_test_issue("POOL-1", "POOL", "Open"),
+                create_test_issue("POOL-2", "POOL", "Done"),
+            ])
+            .await
+            .unwrap();
+        store
+            .save_issue_history(&[IssueHistory::new(
+                "POOL-1".to_string(),
+                "POOL-1".to_string(),
+                "change_1".to_string(),
+                Utc::now(),
+                "status".to_string(),
+            )])
+            .await
+            .unwrap();
 
-        // 保存
-        store.save_filter_config(&config).await.unwrap();
+        // 別スレッドで書き込み用の接続をロックしたままにする
+        let writer_lock = Arc::clone(&store.connection);
+        let (locked_tx, locked_rx) = std::sync::mpsc::channel();
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+        let holder = std::thread::spawn(move || {
+            let _writer = writer_lock.lock().unwrap();
+            locked_tx.send(()).unwrap();
+            let _ = release_rx.recv();
+        });
+        locked_rx.recv().unwrap();
+
+        let filter = IssueFilter::new().project_keys(vec!["POOL".to_string()]);
+        let reads = futures::future::join_all((0..8).map(|_| store.load_issues(&filter)));
+        let results = tokio::time::timeout(Duration::from_secs(10), reads)
+            .await
+            .expect("reads must not wait for the writer connection");
 
-        // 読み込み
-        let loaded_config = store.load_filter_config().await.unwrap();
-        assert!(loaded_config.is_some());
+        for result in results {
+            assert_eq!(result.unwrap().len(), 2);
+        }
 
-        let loaded_config = loaded_config.unwrap();
-        assert_eq!(loaded_config.id, "test_filter");
-        assert_eq!(loaded_config.name, "Test Filter");
-        assert_eq!(
-            loaded_config.description,
-            Some("Test description".to_string())
-        );
-        assert_eq!(loaded_config.filter.project_keys, vec!["TEST"]);
-        assert_eq!(loaded_config.filter.statuses, vec!["Open"]);
+        // 統計・履歴・フィルター設定・メンテナンス状況の読み込みも書き込みを待たない
+        let history_filter = HistoryFilter::new();
+        let (stats, history, history_stats, filter_config, report) =
+            tokio::time::timeout(Duration::from_secs(10), async {
+                tokio::join!(
+                    store.get_stats(),
+                    store.load_issue_history(&history_filter),
+                    store.get_history_stats(),
+                    store.load_filter_config(),
+                    store.maintenance_report(),
+                )
+            })
+            .await
+            .expect("stats and history reads must not wait for the writer connection");
+        assert_eq!(stats.unwrap().total_issues, 2);
+        assert_eq!(history.unwrap().len(), 1);
+        assert_eq!(history_stats.unwrap().total_changes, 1);
+        assert!(filter_config.unwrap().is_none());
+        assert_eq!(report.unwrap().history_count, 1);
+
+        release_tx.send(()).unwrap();
+        holder.join().unwrap();
+        assert_eq!(store.count_issues(&filter).await.unwrap(), 2);
+        assert_eq!(store.read_pool.lock().unwrap().len(), 3);
     }
 
     #[tokio::test]
-    async fn test_duckdb_store_save_and_load_history() {
-        // DuckDBStoreで履歴データの保存と読み込みが正しく動作することをテスト
+    async fn test_duckdb_store_transaction_rolls_back_on_error() {
+        // トランザクション内でエラーが発生するとIssueと履歴の両方がロールバックされることをテスト
         let mut store = DuckDBStore::new_in_memory().unwrap();
         store.initialize().await.unwrap();
 
-        let author = HistoryAuthor {
-            account_id: "user123".to_string(),
-            display_name: "Test User".to_string(),
-            email_address: Some("test@example.com".to_string()),
-        };
-
+        let issues = vec![
+            create_test_issue("TX-1", "TX", "Open"),
+            create_test_issue("TX-2", "TX", "Open"),
+        ];
         let histories = vec![
             IssueHistory::new(
                 "10000".to_string(),
-                "TEST-123".to_string(),
+                "TX-1".to_string(),
                 "change_1".to_string(),
                 Utc::now(),
                 "status".to_string(),
             )
-            .with_author(author.clone())
             .with_field_change(
                 Some("Open".to_string()),
-                Some("In Progress".to_string()),
+                Some("Done".to_string()),
                 Some("Open".to_string()),
-                Some("In Progress".to_string()),
-            ),
-            IssueHistory::new(
-                "10000".to_string(),
-                "TEST-123".to_string(),
-                "change_2".to_string(),
-                Utc::now(),
-                "assignee".to_string(),
-            )
-            .with_author(author)
-            .with_field_change(
-                None,
-                Some("user456".to_string()),
-                None,
-                Some("Jane Doe".to_string()),
+                Some("Done".to_string()),
             ),
         ];
 
-        // 保存
-        let saved_count = store.save_issue_history(&histories).await.unwrap();
-        assert_eq!(saved_count, 2);
+        let result: Result<(), Error> = store
+            .transaction(|store| {
+                let issues = issues.clone();
+                let histories = histories.clone();
+                Box::pin(async move {
+                    store.save_issues(&issues).await?;
+                    store.save_issue_history(&histories).await?;
+
+                    // トランザクション内では未コミットの書き込みが見える
+                    let count = store.count_issues(&IssueFilter::new()).await?;
+                    assert_eq!(count, 2);
+
+                    Err(Error::InvalidInput("途中で失敗".to_string()))
+                })
+            })
+            .await;
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+
+        // どちらも書き込まれていない
+        assert_eq!(store.count_issues(&IssueFilter::new()).await.unwrap(), 0);
+        let loaded = store
+            .load_issue_history(&HistoryFilter::new())
+            .await
+            .unwrap();
+        assert!(loaded.is_empty());
+
+        // 成功した場合はコミットされる
+        let saved = store
+            .transaction(|store| {
+                Box::pin(async move {
+                    let saved = store.save_issues(&issues).await?;
+                    store.save_issue_history(&histories).await?;
+                    Ok(saved)
+                })
+            })
+            .await
+            .unwrap();
+        assert_eq!(saved, 2);
+        assert_eq!(store.count_issues(&IssueFilter::new()).await.unwrap(), 2);
+        let loaded = store
+            .load_issue_history(&HistoryFilter::new())
+            .await
+            .unwrap();
+        assert_eq!(loaded.len(), 1);
+    }
 
-        // 全件読み込み
-        let filter = HistoryFilter::new();
-        let loaded_histories = store.load_issue_history(&filter).await.unwrap();
-        assert_eq!(loaded_histories.len(), 2);
+    #[tokio::test]
+    async fn test_group_counts_with_filters_match_in_both_stores() {
+        // 優先度・種別・報告者・ラベル・サマリーの条件で、両ストアの集計と件数が一致することをテスト
+        use crate::JsonStore;
+
+        let with =
+            |key: &str, status: &str, priority: &str, issue_type: &str, labels: Vec<&str>| {
+                let mut issue = create_test_issue(key, "GRP", status);
+                issue.fields.priority.as_mut().unwrap().name = priority.to_string();
+                issue.fields.issue_type.name = issue_type.to_string();
+                issue
+                    .fields
+                    .custom_fields
+                    .insert("labels".to_string(), serde_json::json!(labels));
+                issue
+            };
+        let mut issues = vec![
+            with("GRP-1", "Open", "High", "Bug", vec!["backend"]),
+            with("GRP-2", "Open", "High", "Task", vec!["frontend"]),
+            with("GRP-3", "Done", "High", "Bug", vec!["backend", "urgent"]),
+            with("GRP-4", "Done", "Low", "Bug", vec![]),
+        ];
+        issues[3].fields.reporter.display_name = "Other User".to_string();
+
+        let filters = vec![
+            IssueFilter::new().priorities(vec!["High".to_string()]),
+            IssueFilter::new().issue_types(vec!["Bug".to_string()]),
+            IssueFilter::new().reporters(vec!["Test User".to_string()]),
+            IssueFilter::new().labels(vec!["backend".to_string(), "missing".to_string()]),
+            IssueFilter::new().summary_contains("issue grp-2".to_string()),
+        ];
+        let expected = vec![
+            vec![("Open".to_string(), 2), ("Done".to_string(), 1)],
+            vec![("Done".to_string(), 2), ("Open".to_string(), 1)],
+            vec![("Open".to_string(), 2), ("Done".to_string(), 1)],
+            vec![("Done".to_string(), 1), ("Open".to_string(), 1)],
+            vec![("Open".to_string(), 1)],
+        ];
 
-        // 特定課題の履歴のみ読み込み
-        let filter = HistoryFilter::new().issue_keys(vec!["TEST-123".to_string()]);
-        let filtered_histories = store.load_issue_history(&filter).await.unwrap();
-        assert_eq!(filtered_histories.len(), 2);
+        let mut duckdb_store = DuckDBStore::new_in_memory().unwrap();
+        duckdb_store.initialize().await.unwrap();
+        duckdb_store.save_issues(&issues).await.unwrap();
 
-        // ステータス変更のみ読み込み
-        let filter = HistoryFilter::new().field_names(vec!["status".to_string()]);
-        let status_histories = store.load_issue_history(&filter).await.unwrap();
-        assert_eq!(status_histories.len(), 1);
-        assert_eq!(status_histories[0].field_name, "status");
+        let temp_dir = TempDir::new().unwrap();
+        let mut json_store = JsonStore::new(temp_dir.path());
+        json_store.save_issues(&issues).await.unwrap();
+
+        for (filter, expected) in filters.iter().zip(expected) {
+            let total: usize = expected.iter().map(|(_, count)| count).sum();
+            assert_eq!(
+                duckdb_store
+                    .group_counts(filter, GroupBy::Status)
+                    .await
+                    .unwrap(),
+                expected
+            );
+            assert_eq!(
+                json_store
+                    .group_counts(filter, GroupBy::Status)
+                    .await
+                    .unwrap(),
+                expected
+            );
+            assert_eq!(duckdb_store.count_issues(filter).await.unwrap(), total);
+            assert_eq!(json_store.count_issues(filter).await.unwrap(), total);
+        }
     }
 
     #[tokio::test]
-    async fn test_duckdb_store_history_stats() {
-        // DuckDBStoreで履歴統計が正しく動作することをテスト
+    async fn test_duckdb_store_text_search_adf_description() {
+        // 本文検索はADFの説明をプレーンテキストとして照合し、構造上のキーには一致しないことをテスト
         let mut store = DuckDBStore::new_in_memory().unwrap();
         store.initialize().await.unwrap();
 
-        let histories = vec![
-            IssueHistory::new(
-                "10000".to_string(),
-                "TEST-123".to_string(),
-                "change_1".to_string(),
-                Utc::now(),
-                "status".to_string(),
-            ),
-            IssueHistory::new(
-                "10001".to_string(),
-                "TEST-124".to_string(),
-                "change_2".to_string(),
-                Utc::now(),
-                "status".to_string(),
-            ),
-            IssueHistory::new(
-                "10000".to_string(),
-                "TEST-123".to_string(),
-                "change_3".to_string(),
-                Utc::now(),
-                "assignee".to_string(),
-            ),
-        ];
+        let mut with_description = create_test_issue("TEXT-1", "TEXT", "Open");
+        with_description.fields.description = Some(serde_json::json!({
+            "type": "doc",
+            "version": 1,
+            "content": [{
+                "type": "paragraph",
+                "content": [{"type": "text", "text": "Deadlock when saving drafts"}]
+            }]
+        }));
+        let mut plain = create_test_issue("TEXT-2", "TEXT", "Open");
+        plain.fields.summary = "Improve deadlock detection".to_string();
+        store.save_issues(&[with_description, plain]).await.unwrap();
+
+        let search = |text: &str| {
+            IssueFilter::new()
+                .text_search(text.to_string())
+                .sort_order(SortOrder::KeyAsc)
+        };
+        let keys =
+            |issues: Vec<Issue>| -> Vec<String> { issues.into_iter().map(|i| i.key).collect() };
 
-        store.save_issue_history(&histories).await.unwrap();
+        // 説明の本文のみにある語で一致する
+        let found = store.load_issues(&search("drafts")).await.unwrap();
+        assert_eq!(keys(found), vec!["TEXT-1"]);
 
-        let stats = store.get_history_stats().await.unwrap();
-        assert_eq!(stats.total_changes, 3);
-        assert_eq!(stats.unique_issues, 2); // TEST-123, TEST-124
-        assert_eq!(stats.field_change_counts.get("status"), Some(&2));
-        assert_eq!(stats.field_change_counts.get("assignee"), Some(&1));
+        // サマリーと説明のどちらかにあれば一致する（大文字小文字は区別しない）
+        let found = store.load_issues(&search("DEADLOCK")).await.unwrap();
+        assert_eq!(keys(found), vec!["TEXT-1", "TEXT-2"]);
+
+        // ADFのノード種別や属性名には一致しない
+        for structural in ["paragraph", "content", "version"] {
+            let found = store.load_issues(&search(structural)).await.unwrap();
+            assert!(found.is_empty(), "{} should not match", structural);
+        }
     }
 
     #[tokio::test]
-    async fn test_duckdb_store_delete_history() {
-        // DuckDBStoreで履歴削除が正しく動作することをテスト
-        let mut store = DuckDBStore::new_in_memory().unwrap();
-        store.initialize().await.unwrap();
+    async fn test_description_contains_uses_plain_text_in_both_stores() {
+        // 説明検索が両ストアでADFのプレーンテキストに対して照合されることをテスト
+        use crate::JsonStore;
+
+        let mut adf = create_test_issue("DESC-1", "DESC", "Open");
+        adf.fields.description = Some(serde_json::json!({
+            "type": "doc",
+            "version": 1,
+            "content": [{
+                "type": "paragraph",
+                "content": [{"type": "text", "text": "Crash on Startup"}]
+            }]
+        }));
+        // 文字列の説明と説明の無いIssue
+        let text = create_test_issue("DESC-2", "DESC", "Open");
+        let mut empty = create_test_issue("DESC-3", "DESC", "Open");
+        empty.fields.description = None;
+        let issues = vec![adf, text, empty];
+
+        let mut duckdb_store = DuckDBStore::new_in_memory()
+            .unwrap()
+            .with_raw_compression(true);
+        duckdb_store.initialize().await.unwrap();
+        duckdb_store.save_issues(&issues).await.unwrap();
 
-        let histories = vec![
-            IssueHistory::new(
-                "10000".to_string(),
-                "TEST-123".to_string(),
-                "change_1".to_string(),
-                Utc::now(),
-                "status".to_string(),
-            ),
-            IssueHistory::new(
-                "10001".to_string(),
-                "TEST-124".to_string(),
-                "change_2".to_string(),
-                Utc::now(),
-                "status".to_string(),
-            ),
+        let temp_dir = TempDir::new().unwrap();
+        let mut json_store = JsonStore::new(temp_dir.path());
+        json_store.save_issues(&issues).await.unwrap();
+
+        let cases = [
+            ("crash on startup", vec!["DESC-1"]),
+            ("TEST DESCRIPTION", vec!["DESC-2"]),
+            ("paragraph", vec![]),
+            ("\"type\"", vec![]),
         ];
+        for (text, expected) in cases {
+            let filter = IssueFilter::new()
+                .description_contains(text.to_string())
+                .sort_order(SortOrder::KeyAsc);
+            for loaded in [
+                duckdb_store.load_issues(&filter).await.unwrap(),
+                json_store.load_issues(&filter).await.unwrap(),
+            ] {
+                let keys: Vec<String> = loaded.into_iter().map(|i| i.key).collect();
+                assert_eq!(keys, expected, "{}", text);
+            }
+        }
+    }
 
-        store.save_issue_history(&histories).await.unwrap();
+    #[tokio::test]
+    async fn test_date_range_end_is_exclusive_in_both_stores() {
+        // 終了日時ちょうどに更新されたIssueがDuckDBStoreとJsonStoreの両方で除外されることをテスト
+        use crate::{DateRange, JsonStore};
+        use chrono::TimeZone;
+
+        let end = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap()
+            + chrono::Duration::milliseconds(250);
+        let range = DateRange::new(end - chrono::Duration::days(1), end);
+
+        let issues: Vec<Issue> = [
+            ("EDGE-1", end - chrono::Duration::milliseconds(1)),
+            ("EDGE-2", end),
+            ("EDGE-3", range.start),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, (key, updated))| {
+            let mut issue = create_test_issue(key, "EDGE", "Open");
+            issue.id = format!("{}", 9100 + i);
+            issue.fields.updated = updated;
+            issue
+        })
+        .collect();
 
-        // TEST-123の履歴を削除
-        let deleted_count = store
-            .delete_issue_history(&["TEST-123".to_string()])
+        let filter = IssueFilter::new()
+            .updated_range(range)
+            .sort_order(SortOrder::KeyAsc);
+        let expected = vec!["EDGE-1", "EDGE-3"];
+
+        let mut duckdb_store = DuckDBStore::new_in_memory().unwrap();
+        duckdb_store.initialize().await.unwrap();
+        duckdb_store.save_issues(&issues).await.unwrap();
+        let duckdb_keys: Vec<String> = duckdb_store
+            .load_issues(&filter)
             .await
-            .unwrap();
-        assert_eq!(deleted_count, 1);
+            .unwrap()
+            .into_iter()
+            .map(|i| i.key)
+            .collect();
+        assert_eq!(duckdb_keys, expected);
+        assert_eq!(duckdb_store.count_issues(&filter).await.unwrap(), 2);
 
-        // 残りの履歴を確認
-        let filter = HistoryFilter::new();
-        let remaining_histories = store.load_issue_history(&filter).await.unwrap();
-        assert_eq!(remaining_histories.len(), 1);
-        assert_eq!(remaining_histories[0].issue_key, "TEST-124");
+        let temp_dir = TempDir::new().unwrap();
+        let mut json_store = JsonStore::new(temp_dir.path());
+        json_store.save_issues(&issues).await.unwrap();
+        let json_keys: Vec<String> = json_store
+            .load_issues(&filter)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|i| i.key)
+            .collect();
+        assert_eq!(json_keys, expected);
+    }
+
+    #[tokio::test]
+    async fn test_sort_ties_break_by_key_in_both_stores() {
+        // 並び替えの値が同じIssueが両ストアでキーの昇順に並び、ページングでも同じ順序になることをテスト
+        use crate::JsonStore;
+        use chrono::TimeZone;
+
+        let created = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let issues: Vec<Issue> = ["TIE-3", "TIE-1", "TIE-4", "TIE-2"]
+            .into_iter()
+            .map(|key| {
+                let mut issue = create_test_issue(key, "TIE", "Open");
+                issue.fields.created = created;
+                issue.fields.updated = created;
+                issue
+            })
+            .collect();
+
+        let mut duckdb_store = DuckDBStore::new_in_memory().unwrap();
+        duckdb_store.initialize().await.unwrap();
+        duckdb_store.save_issues(&issues).await.unwrap();
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut json_store = JsonStore::new(temp_dir.path());
+        json_store.save_issues(&issues).await.unwrap();
+
+        let keys =
+            |issues: Vec<Issue>| -> Vec<String> { issues.into_iter().map(|i| i.key).collect() };
+        for sort_order in [
+            SortOrder::CreatedAsc,
+            SortOrder::CreatedDesc,
+            SortOrder::UpdatedAsc,
+            SortOrder::UpdatedDesc,
+            SortOrder::PriorityAsc,
+            SortOrder::PriorityDesc,
+            SortOrder::AssigneeAsc,
+            SortOrder::AssigneeDesc,
+            SortOrder::ReporterAsc,
+            SortOrder::ReporterDesc,
+            SortOrder::StatusAsc,
+            SortOrder::StatusDesc,
+            SortOrder::TypeAsc,
+            SortOrder::TypeDesc,
+        ] {
+            let all = IssueFilter::new().sort_order(sort_order.clone());
+            assert_eq!(
+                keys(duckdb_store.load_issues(&all).await.unwrap()),
+                vec!["TIE-1", "TIE-2", "TIE-3", "TIE-4"],
+                "{:?}",
+                sort_order
+            );
+            assert_eq!(
+                keys(json_store.load_issues(&all).await.unwrap()),
+                vec!["TIE-1", "TIE-2", "TIE-3", "TIE-4"],
+                "{:?}",
+                sort_order
+            );
+
+            let page = all.limit(2).offset(2);
+            assert_eq!(
+                keys(duckdb_store.load_issues(&page).await.unwrap()),
+                vec!["TIE-3", "TIE-4"],
+                "{:?}",
+                sort_order
+            );
+            assert_eq!(
+                keys(json_store.load_issues(&page).await.unwrap()),
+                vec!["TIE-3", "TIE-4"],
+                "{:?}",
+                sort_order
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_group_counts_by_assignee_match_storage_stats_in_both_stores() {
+        // 担当者別の集計が両ストアで担当者別統計と同じキー（未割り当てを含む）になることをテスト
+        use crate::JsonStore;
+
+        // SORT-1: Bob, SORT-2: 未割り当て, SORT-3: Alice
+        let issues = sample_sort_issues();
+        let expected = vec![
+            (UNASSIGNED_BUCKET.to_string(), 1),
+            ("Alice".to_string(), 1),
+            ("Bob".to_string(), 1),
+        ];
+
+        let mut duckdb_store = DuckDBStore::new_in_memory().unwrap();
+        duckdb_store.initialize().await.unwrap();
+        duckdb_store.save_issues(&issues).await.unwrap();
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut json_store = JsonStore::new(temp_dir.path());
+        json_store.save_issues(&issues).await.unwrap();
+
+        let stores: [&dyn PersistenceStore; 2] = [&duckdb_store, &json_store];
+        for store in stores {
+            let grouped = store
+                .group_counts(&IssueFilter::new(), GroupBy::Assignee)
+                .await
+                .unwrap();
+            assert_eq!(grouped, expected);
+
+            let stats = store.get_stats().await.unwrap();
+            let grouped: HashMap<String, usize> = grouped.into_iter().collect();
+            assert_eq!(grouped, stats.issues_by_assignee);
+        }
     }
 }
@@ -2,8 +2,17 @@ use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum Error {
+    /// タイムアウトと接続エラー以外のHTTPリクエストの失敗（レスポンスの解析失敗など）
     #[error("HTTP request failed: {0}")]
-    RequestFailed(#[from] reqwest::Error),
+    RequestFailed(#[source] reqwest::Error),
+
+    /// リクエストがタイムアウトした
+    #[error("Request timed out: {0}")]
+    Timeout(#[source] reqwest::Error),
+
+    /// サーバーに接続できない、または送信中に接続が切れた
+    #[error("Network error: {0}")]
+    Network(#[source] reqwest::Error),
 
     #[error("JSON parsing failed: {0}")]
     JsonParsing(#[from] serde_json::Error),
@@ -23,8 +32,13 @@ pub enum Error {
     #[error("Not found: {0}")]
     NotFound(String),
 
-    #[error("Rate limit exceeded")]
-    RateLimitExceeded,
+    /// レート制限（429）が再試行の上限を超えて続いた
+    ///
+    /// `retry_after`は最後のレスポンスの`Retry-After`ヘッダーが示す待機時間です。
+    #[error("Rate limited (retry after: {retry_after:?})")]
+    RateLimited {
+        retry_after: Option<std::time::Duration>,
+    },
 
     #[error("Invalid input: {0}")]
     InvalidInput(String),
@@ -35,6 +49,9 @@ pub enum Error {
     #[error("Serialization error: {0}")]
     SerializationError(String),
 
+    #[error("Deserialization error: {0}")]
+    DeserializationError(String),
+
     #[error("Database error: {0}")]
     DatabaseError(String),
 
@@ -48,4 +65,81 @@ pub enum Error {
     Unexpected(String),
 }
 
+impl From<reqwest::Error> for Error {
+    /// reqwestのエラーを原因ごとに分類する
+    ///
+    /// タイムアウトは`Timeout`、接続失敗や送信中の通信エラーは`Network`、
+    /// それ以外は`RequestFailed`になります。
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            Error::Timeout(error)
+        } else if error.is_connect() || error.is_request() {
+            Error::Network(error)
+        } else {
+            Error::RequestFailed(error)
+        }
+    }
+}
+
+impl Error {
+    /// APIエラーのレスポンスボディをJIRAのエラーレスポンスとして解析する
+    ///
+    /// `ApiError`以外のエラーや、ボディがJIRAのエラー形式でない場合は`None`を返します。
+    pub fn jira_error_response(&self) -> Option<crate::models::JiraErrorResponse> {
+        match self {
+            Error::ApiError { message, .. } => serde_json::from_str(message).ok(),
+            _ => None,
+        }
+    }
+
+    /// 一時的なエラーで、再試行すれば成功し得るかどうか
+    ///
+    /// タイムアウト、通信エラー、レート制限（`RateLimited`と429）、5xxのAPIエラーが対象です。
+    /// 認証失敗や入力不正などの4xx、レスポンスの解析失敗は再送しても結果が変わらないため対象外です。
+    /// 組み込みの再試行処理（[`crate::RetryPolicy`]）と外部のスケジューラーで同じ判定を共有できます。
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::ApiError { status, .. } => *status >= 500 || *status == 429,
+            Error::RateLimited { .. } | Error::Timeout(_) | Error::Network(_) => true,
+            _ => false,
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_is_retryable() {
+        // 一時的なエラーのみが再試行対象に分類されることをテスト
+        let api_error = |status: u16| Error::ApiError {
+            status,
+            message: String::new(),
+        };
+
+        // 再試行の対象
+        for status in [500, 502, 503, 504, 429] {
+            assert!(api_error(status).is_retryable(), "status {}", status);
+        }
+        assert!(Error::RateLimited { retry_after: None }.is_retryable());
+
+        // 再試行の対象外
+        for status in [400, 401, 403, 404, 409, 422] {
+            assert!(!api_error(status).is_retryable(), "status {}", status);
+        }
+        let not_retryable = [
+            Error::AuthenticationFailed("invalid token".to_string()),
+            Error::InvalidInput("bad key".to_string()),
+            Error::NotFound("TEST-1".to_string()),
+            Error::InvalidConfiguration("missing url".to_string()),
+            Error::JsonParsing(serde_json::from_str::<u32>("x").unwrap_err()),
+            Error::DatabaseError("locked".to_string()),
+        ];
+        for error in &not_retryable {
+            assert!(!error.is_retryable(), "{:?}", error);
+        }
+    }
+}
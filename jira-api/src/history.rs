@@ -1,7 +1,7 @@
 use crate::{DateRange, Error};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// 課題の変更履歴レコード
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -107,6 +107,12 @@ pub struct HistoryStats {
     pub oldest_change: Option<DateTime<Utc>>,
     /// 最新の変更日時
     pub newest_change: Option<DateTime<Utc>>,
+    /// 集計済みの課題キー（`unique_issues`の算出用）
+    #[serde(skip)]
+    issue_keys: HashSet<String>,
+    /// 集計済みの変更者アカウントID（`unique_authors`の算出用）
+    #[serde(skip)]
+    author_ids: HashSet<String>,
 }
 
 impl IssueHistory {
@@ -296,6 +302,8 @@ impl HistoryStats {
             field_change_counts: HashMap::new(),
             oldest_change: None,
             newest_change: None,
+            issue_keys: HashSet::new(),
+            author_ids: HashSet::new(),
         }
     }
 
@@ -304,16 +312,15 @@ impl HistoryStats {
         self.total_changes = histories.len();
 
         // ユニークな課題数
-        let unique_issues: std::collections::HashSet<&String> =
-            histories.iter().map(|h| &h.issue_key).collect();
-        self.unique_issues = unique_issues.len();
+        self.issue_keys = histories.iter().map(|h| h.issue_key.clone()).collect();
+        self.unique_issues = self.issue_keys.len();
 
         // ユニークな変更者数
-        let unique_authors: std::collections::HashSet<&String> = histories
+        self.author_ids = histories
             .iter()
-            .filter_map(|h| h.author.as_ref().map(|a| &a.account_id))
+            .filter_map(|h| h.author.as_ref().map(|a| a.account_id.clone()))
             .collect();
-        self.unique_authors = unique_authors.len();
+        self.unique_authors = self.author_ids.len();
 
         // フィールド別変更数
         self.field_change_counts.clear();
@@ -330,6 +337,131 @@ impl HistoryStats {
             self.newest_change = histories.iter().map(|h| h.change_timestamp).max();
         }
     }
+
+    /// 履歴レコードを1件ずつ集計に追加
+    ///
+    /// ストリーミング同期などで全レコードを保持せずに統計を積み上げるために使用します。
+    pub fn add_one(&mut self, history: &IssueHistory) {
+        self.total_changes += 1;
+
+        if self.issue_keys.insert(history.issue_key.clone()) {
+            self.unique_issues += 1;
+        }
+
+        if let Some(ref author) = history.author
+            && self.author_ids.insert(author.account_id.clone())
+        {
+            self.unique_authors += 1;
+        }
+
+        *self
+            .field_change_counts
+            .entry(history.field_name.clone())
+            .or_insert(0) += 1;
+
+        self.include_timestamp(history.change_timestamp);
+    }
+
+    /// 別の統計情報を合算
+    ///
+    /// プロジェクトごとに並行して集計した統計を1つにまとめるために使用します。
+    /// 課題数・変更者数は重複を除いて合算しますが、DBの集計結果など
+    /// 個別のキーを持たない統計が含まれる場合は件数を単純に加算します。
+    pub fn merge(&mut self, other: &HistoryStats) {
+        self.total_changes += other.total_changes;
+
+        merge_unique(
+            &mut self.issue_keys,
+            &mut self.unique_issues,
+            &other.issue_keys,
+            other.unique_issues,
+        );
+        merge_unique(
+            &mut self.author_ids,
+            &mut self.unique_authors,
+            &other.author_ids,
+            other.unique_authors,
+        );
+
+        for (field, count) in &other.field_change_counts {
+            *self.field_change_counts.entry(field.clone()).or_insert(0) += count;
+        }
+
+        if let Some(oldest) = other.oldest_change {
+            self.include_timestamp(oldest);
+        }
+        if let Some(newest) = other.newest_change {
+            self.include_timestamp(newest);
+        }
+    }
+
+    /// 最古・最新の変更日時に日時を反映
+    fn include_timestamp(&mut self, timestamp: DateTime<Utc>) {
+        if self.oldest_change.is_none_or(|oldest| timestamp < oldest) {
+            self.oldest_change = Some(timestamp);
+        }
+        if self.newest_change.is_none_or(|newest| timestamp > newest) {
+            self.newest_change = Some(timestamp);
+        }
+    }
+}
+
+impl Default for HistoryStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 遷移元の値が無い（初期状態からの）ステータス変更を表す疑似ステータス
+pub const INITIAL_STATUS: &str = "(initial)";
+
+/// 変更履歴からステータス遷移のグラフを作成
+///
+/// ステータス変更の「遷移元→遷移先」ごとに発生回数を数えます。ステータス以外のフィールドの変更は無視します。
+/// ステータス名は表示値を優先し、無い場合は内部値を使用します。遷移元が無い変更は
+/// [`INITIAL_STATUS`]からの遷移として数えます。Sankey図などのフロー可視化に利用できます。
+pub fn status_transition_graph(histories: &[IssueHistory]) -> HashMap<(String, String), usize> {
+    let mut graph = HashMap::new();
+
+    for history in histories {
+        if history.change_type() != ChangeType::StatusChange {
+            continue;
+        }
+
+        let from = history
+            .from_display_value
+            .as_deref()
+            .or(history.from_value.as_deref())
+            .unwrap_or(INITIAL_STATUS);
+        let Some(to) = history
+            .to_display_value
+            .as_deref()
+            .or(history.to_value.as_deref())
+        else {
+            continue;
+        };
+
+        *graph.entry((from.to_string(), to.to_string())).or_insert(0) += 1;
+    }
+
+    graph
+}
+
+/// ユニーク件数を合算する
+///
+/// 双方がキーの集合を保持している場合は和集合の件数、そうでない場合は件数の単純加算とする。
+fn merge_unique(
+    own: &mut HashSet<String>,
+    own_count: &mut usize,
+    other: &HashSet<String>,
+    other_count: usize,
+) {
+    if own.len() == *own_count && other.len() == other_count {
+        own.extend(other.iter().cloned());
+        *own_count = own.len();
+    } else {
+        *own_count += other_count;
+    }
 }
 
 #[cfg(test)]
@@ -445,6 +577,64 @@ mod tests {
         assert_eq!(stats.field_change_counts.get("assignee"), Some(&1));
     }
 
+    #[test]
+    fn test_history_stats_incremental_matches_update() {
+        // add_one()で1件ずつ集計した結果と、merge()で合算した結果がupdate()と一致することをテスト
+        use chrono::{Duration, TimeZone};
+
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let author = |id: &str| HistoryAuthor {
+            account_id: id.to_string(),
+            display_name: id.to_string(),
+            email_address: None,
+        };
+
+        let histories: Vec<IssueHistory> = (0..10)
+            .map(|i| {
+                let history = IssueHistory::new(
+                    format!("{}", i % 4),
+                    format!("TEST-{}", i % 4),
+                    format!("c{}", i),
+                    base + Duration::hours((i * 7 % 10) as i64),
+                    if i % 3 == 0 { "status" } else { "assignee" }.to_string(),
+                );
+                if i % 5 == 0 {
+                    history
+                } else {
+                    history.with_author(author(&format!("user{}", i % 3)))
+                }
+            })
+            .collect();
+
+        let mut expected = HistoryStats::new();
+        expected.update(&histories);
+
+        let mut incremental = HistoryStats::new();
+        for history in &histories {
+            incremental.add_one(history);
+        }
+
+        // 前半と後半を別々に集計して合算（課題・変更者は両方に跨る）
+        let mut merged = HistoryStats::new();
+        let mut second_half = HistoryStats::new();
+        for history in &histories[..5] {
+            merged.add_one(history);
+        }
+        second_half.update(&histories[5..]);
+        merged.merge(&second_half);
+
+        for stats in [&incremental, &merged] {
+            assert_eq!(stats.total_changes, expected.total_changes);
+            assert_eq!(stats.unique_issues, expected.unique_issues);
+            assert_eq!(stats.unique_authors, expected.unique_authors);
+            assert_eq!(stats.field_change_counts, expected.field_change_counts);
+            assert_eq!(stats.oldest_change, expected.oldest_change);
+            assert_eq!(stats.newest_change, expected.newest_change);
+        }
+        assert_eq!(expected.unique_issues, 4);
+        assert_eq!(expected.unique_authors, 3);
+    }
+
     #[test]
     fn test_change_summary() {
         let author = HistoryAuthor {
@@ -483,4 +673,56 @@ mod tests {
         let invalid_filter = HistoryFilter::new().limit(0);
         assert!(invalid_filter.validate().is_err());
     }
+
+    #[test]
+    fn test_status_transition_graph() {
+        // 繰り返し発生した遷移が数えられ、ステータス以外の変更が無視されることをテスト
+        let status_change = |key: &str, change_id: &str, from: Option<&str>, to: &str| {
+            IssueHistory::new(
+                "1".to_string(),
+                key.to_string(),
+                change_id.to_string(),
+                Utc::now(),
+                "status".to_string(),
+            )
+            .with_field_change(
+                None,
+                None,
+                from.map(|s| s.to_string()),
+                Some(to.to_string()),
+            )
+        };
+
+        let histories = vec![
+            status_change("TEST-1", "c1", None, "To Do"),
+            status_change("TEST-1", "c2", Some("To Do"), "In Progress"),
+            status_change("TEST-1", "c3", Some("In Progress"), "To Do"),
+            status_change("TEST-1", "c4", Some("To Do"), "In Progress"),
+            status_change("TEST-1", "c5", Some("In Progress"), "Done"),
+            status_change("TEST-2", "c6", Some("To Do"), "In Progress"),
+            IssueHistory::new(
+                "1".to_string(),
+                "TEST-1".to_string(),
+                "c7".to_string(),
+                Utc::now(),
+                "assignee".to_string(),
+            )
+            .with_field_change(
+                None,
+                None,
+                Some("Alice".to_string()),
+                Some("Bob".to_string()),
+            ),
+        ];
+
+        let graph = status_transition_graph(&histories);
+        let edge = |from: &str, to: &str| graph.get(&(from.to_string(), to.to_string())).copied();
+
+        assert_eq!(graph.len(), 4);
+        assert_eq!(edge(INITIAL_STATUS, "To Do"), Some(1));
+        assert_eq!(edge("To Do", "In Progress"), Some(3));
+        assert_eq!(edge("In Progress", "To Do"), Some(1));
+        assert_eq!(edge("In Progress", "Done"), Some(1));
+        assert_eq!(edge("Alice", "Bob"), None);
+    }
 }
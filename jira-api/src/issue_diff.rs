@@ -0,0 +1,230 @@
+//! 2つのIssueスナップショットの差分
+//!
+//! 変更履歴（changelog）を取得せずに「何が変わったか」を表示するため、
+//! 同期前後のIssueを比較して変更されたフィールドを列挙します。通知文の生成などに使用します。
+
+use crate::Issue;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+/// フィールド1件分の差分
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldDiff {
+    /// フィールド名（`summary`、`status`など。カスタムフィールドはフィールドID）
+    pub field: String,
+    /// 変更前の値（未設定の場合はNone）
+    pub old_value: Option<String>,
+    /// 変更後の値（未設定の場合はNone）
+    pub new_value: Option<String>,
+}
+
+impl FieldDiff {
+    fn new(field: &str, old_value: Option<String>, new_value: Option<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            old_value,
+            new_value,
+        }
+    }
+}
+
+/// 2つのIssueスナップショットを比較して変更されたフィールドを返す
+///
+/// サマリー・ステータス・担当者・優先度・ラベル・カスタムフィールドを比較します。
+/// 担当者は表示名、ラベルは順序を無視した集合として比較し、カンマ区切りの文字列で表します。
+/// 結果は上記の順で、カスタムフィールドはフィールドIDの昇順に並びます。
+pub fn diff_issues(old: &Issue, new: &Issue) -> Vec<FieldDiff> {
+    let mut diffs = Vec::new();
+
+    let mut push_if_changed =
+        |field: &str, old_value: Option<String>, new_value: Option<String>| {
+            if old_value != new_value {
+                diffs.push(FieldDiff::new(field, old_value, new_value));
+            }
+        };
+
+    push_if_changed(
+        "summary",
+        Some(old.fields.summary.clone()),
+        Some(new.fields.summary.clone()),
+    );
+    push_if_changed(
+        "status",
+        Some(old.fields.status.name.clone()),
+        Some(new.fields.status.name.clone()),
+    );
+    push_if_changed(
+        "assignee",
+        old.fields.assignee.as_ref().map(|a| a.display_name.clone()),
+        new.fields.assignee.as_ref().map(|a| a.display_name.clone()),
+    );
+    push_if_changed(
+        "priority",
+        old.fields.priority.as_ref().map(|p| p.name.clone()),
+        new.fields.priority.as_ref().map(|p| p.name.clone()),
+    );
+
+    let old_labels = labels_of(old);
+    let new_labels = labels_of(new);
+    if old_labels != new_labels {
+        push_if_changed("labels", join_labels(&old_labels), join_labels(&new_labels));
+    }
+
+    let custom_field_ids: BTreeSet<&String> = old
+        .fields
+        .custom_fields
+        .keys()
+        .chain(new.fields.custom_fields.keys())
+        .filter(|id| id.as_str() != "labels")
+        .collect();
+    for id in custom_field_ids {
+        let old_value = old.fields.custom_fields.get(id);
+        let new_value = new.fields.custom_fields.get(id);
+        if old_value.unwrap_or(&Value::Null) != new_value.unwrap_or(&Value::Null) {
+            push_if_changed(
+                id,
+                old_value.and_then(value_to_text),
+                new_value.and_then(value_to_text),
+            );
+        }
+    }
+
+    diffs
+}
+
+/// Issueのラベル一覧（重複を除いて昇順）
+fn labels_of(issue: &Issue) -> BTreeSet<String> {
+    issue
+        .labels()
+        .into_iter()
+        .map(|label| label.to_string())
+        .collect()
+}
+
+fn join_labels(labels: &BTreeSet<String>) -> Option<String> {
+    if labels.is_empty() {
+        None
+    } else {
+        Some(labels.iter().cloned().collect::<Vec<_>>().join(", "))
+    }
+}
+
+/// カスタムフィールドの値を表示用の文字列に変換
+///
+/// 選択リストやユーザーなどのオブジェクトは`value`、`name`、`displayName`の順に表示値を探し、
+/// 見つからない場合はJSON文字列で表します。
+fn value_to_text(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        Value::Object(map) => ["value", "name", "displayName"]
+            .iter()
+            .find_map(|key| map.get(*key).and_then(|v| v.as_str()))
+            .map(|s| s.to_string())
+            .or_else(|| Some(value.to_string())),
+        _ => Some(value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::User;
+    use crate::testing::sample_issue_json;
+    use serde_json::json;
+
+    fn sample_issue() -> Issue {
+        let mut value = sample_issue_json("10001", "TEST-1");
+        value["fields"]["labels"] = json!(["backend"]);
+        value["fields"]["customfield_10016"] = json!({"value": "Team A"});
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn user(name: &str) -> User {
+        User {
+            account_id: name.to_lowercase(),
+            display_name: name.to_string(),
+            email_address: None,
+            self_url: "http://example.com".to_string(),
+            avatar_urls: None,
+            active: Some(true),
+            time_zone: None,
+            account_type: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_issues_unchanged() {
+        // 同じスナップショット同士では差分が無いことをテスト
+        let issue = sample_issue();
+        assert!(diff_issues(&issue, &issue.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_diff_issues_status_assignee_and_labels() {
+        // ステータス変更・担当者変更・ラベル追加・カスタムフィールド変更が差分になることをテスト
+        let old = sample_issue();
+
+        let mut status_changed = old.clone();
+        status_changed.fields.status.name = "In Progress".to_string();
+        assert_eq!(
+            diff_issues(&old, &status_changed),
+            vec![FieldDiff::new(
+                "status",
+                Some("To Do".to_string()),
+                Some("In Progress".to_string())
+            )]
+        );
+
+        let mut reassigned = old.clone();
+        reassigned.fields.assignee = Some(user("Alice"));
+        let mut reassigned_again = reassigned.clone();
+        reassigned_again.fields.assignee = Some(user("Bob"));
+        assert_eq!(
+            diff_issues(&old, &reassigned),
+            vec![FieldDiff::new("assignee", None, Some("Alice".to_string()))]
+        );
+        assert_eq!(
+            diff_issues(&reassigned, &reassigned_again),
+            vec![FieldDiff::new(
+                "assignee",
+                Some("Alice".to_string()),
+                Some("Bob".to_string())
+            )]
+        );
+
+        let mut labeled = old.clone();
+        labeled
+            .fields
+            .custom_fields
+            .insert("labels".to_string(), json!(["urgent", "backend"]));
+        labeled
+            .fields
+            .custom_fields
+            .insert("customfield_10016".to_string(), json!({"value": "Team B"}));
+        assert_eq!(
+            diff_issues(&old, &labeled),
+            vec![
+                FieldDiff::new(
+                    "labels",
+                    Some("backend".to_string()),
+                    Some("backend, urgent".to_string())
+                ),
+                FieldDiff::new(
+                    "customfield_10016",
+                    Some("Team A".to_string()),
+                    Some("Team B".to_string())
+                ),
+            ]
+        );
+
+        // ラベルの並び順だけが変わった場合は差分にしない
+        let mut reordered = labeled.clone();
+        reordered
+            .fields
+            .custom_fields
+            .insert("labels".to_string(), json!(["backend", "urgent"]));
+        assert!(diff_issues(&labeled, &reordered).is_empty());
+    }
+}
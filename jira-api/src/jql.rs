@@ -0,0 +1,526 @@
+//! JQLクエリを安全に組み立てるためのヘルパー
+//!
+//! `format!("project = {}", key)`のように値を直接埋め込むと、空白や引用符を含む
+//! 値でクエリが壊れるほか、ユーザー入力によるJQLインジェクションの原因になります。
+//! ここで提供する関数は値を必ずダブルクォートで囲み、内部の特殊文字をエスケープします。
+//! 複数の条件を組み合わせる場合は[`JqlBuilder`]を使用してください。
+
+use crate::Error;
+use crate::time_filter::format_jira_datetime;
+use chrono::{DateTime, Utc};
+
+/// 値をJQLの文字列リテラルとしてクォートする
+///
+/// 値全体をダブルクォートで囲み、内部のダブルクォートとバックスラッシュを
+/// バックスラッシュでエスケープします。
+pub fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(ch);
+            }
+            _ => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// `field IN ("a", "b", ...)`形式の条件を構築する
+///
+/// 各値は[`quote`]でクォートされます。空のリストは不正なJQLになるため、
+/// 呼び出し側で事前に除外してください。
+pub fn in_list<S: AsRef<str>>(field: &str, values: &[S]) -> String {
+    let quoted: Vec<String> = values.iter().map(|v| quote(v.as_ref())).collect();
+    format!("{} IN ({})", field, quoted.join(", "))
+}
+
+/// 全件取得時に並び順を安定させるために付与するORDER BY句
+pub const STABLE_ORDER_BY: &str = "ORDER BY key ASC";
+
+/// JQLにORDER BY句が含まれているか判定する
+///
+/// クォートされた文字列リテラル内の`ORDER BY`は無視します。大文字・小文字は区別しません。
+pub fn has_order_by(jql: &str) -> bool {
+    find_order_by(jql).is_some()
+}
+
+/// ORDER BY句を指定した並び順で置き換える
+///
+/// JQLに既存のORDER BY句があれば取り除いてから付与するため、並び順が重複しません。
+/// 並び順が空の場合は既存のORDER BY句を取り除いたJQLを返します。
+/// フィールド名が不正な場合は`InvalidInput`エラーを返します。
+pub fn with_order_by(jql: &str, order_by: &[(String, OrderDirection)]) -> Result<String, Error> {
+    if let Some((field, _)) = order_by.iter().find(|(f, _)| !is_valid_field_name(f)) {
+        return Err(Error::InvalidInput(format!(
+            "Invalid ORDER BY field: {:?}",
+            field
+        )));
+    }
+
+    let condition = match find_order_by(jql) {
+        Some(index) => jql[..index].trim(),
+        None => jql.trim(),
+    };
+    if order_by.is_empty() {
+        return Ok(condition.to_string());
+    }
+
+    let order: Vec<String> = order_by
+        .iter()
+        .map(|(field, direction)| format!("{} {}", field, direction.as_jql()))
+        .collect();
+    let order_clause = format!("ORDER BY {}", order.join(", "));
+
+    if condition.is_empty() {
+        Ok(order_clause)
+    } else {
+        Ok(format!("{} {}", condition, order_clause))
+    }
+}
+
+/// クォートの外にある`ORDER BY`の開始位置（バイト単位）を探す
+fn find_order_by(jql: &str) -> Option<usize> {
+    let bytes = jql.as_bytes();
+    let mut quote: Option<u8> = None;
+    let mut escaped = false;
+
+    for (i, &b) in bytes.iter().enumerate() {
+        match quote {
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if b == b'\\' {
+                    escaped = true;
+                } else if b == q {
+                    quote = None;
+                }
+            }
+            None if b == b'"' || b == b'\'' => quote = Some(b),
+            None if is_order_by_at(bytes, i) => return Some(i),
+            None => {}
+        }
+    }
+
+    None
+}
+
+/// 指定位置から単語としての`ORDER`、空白、`BY`が続くか判定する
+fn is_order_by_at(bytes: &[u8], index: usize) -> bool {
+    let is_word = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    if index > 0 && is_word(bytes[index - 1]) {
+        return false;
+    }
+
+    let rest = &bytes[index..];
+    if rest.len() < 5 || !rest[..5].eq_ignore_ascii_case(b"order") {
+        return false;
+    }
+
+    let spaces = rest[5..]
+        .iter()
+        .take_while(|b| b.is_ascii_whitespace())
+        .count();
+    let by_start = 5 + spaces;
+    spaces > 0
+        && rest.len() >= by_start + 2
+        && rest[by_start..by_start + 2].eq_ignore_ascii_case(b"by")
+        && rest.get(by_start + 2).is_none_or(|b| !is_word(*b))
+}
+
+/// ORDER BY句が無いJQLに[`STABLE_ORDER_BY`]を付与する
+///
+/// ページングでは並び順が一定でないと、ページ間でIssueが重複したり欠落したりします。
+/// 既にORDER BY句があるJQLはそのまま返します。
+pub fn with_stable_order(jql: &str) -> String {
+    if has_order_by(jql) {
+        return jql.to_string();
+    }
+
+    let condition = jql.trim();
+    if condition.is_empty() {
+        STABLE_ORDER_BY.to_string()
+    } else {
+        format!("{} {}", condition, STABLE_ORDER_BY)
+    }
+}
+
+/// ORDER BY句の並び順
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderDirection {
+    /// 昇順
+    Asc,
+    /// 降順
+    Desc,
+}
+
+impl OrderDirection {
+    fn as_jql(&self) -> &'static str {
+        match self {
+            OrderDirection::Asc => "ASC",
+            OrderDirection::Desc => "DESC",
+        }
+    }
+}
+
+/// 型付きでJQLクエリを組み立てるビルダー
+///
+/// 各条件は`AND`で結合され、値は[`quote`]でエスケープされます。
+/// 不正な入力（空の値リストや不正なフィールド名）は[`JqlBuilder::build`]でエラーになります。
+///
+/// # Examples
+///
+/// ```
+/// use jira_api::jql::{JqlBuilder, OrderDirection};
+///
+/// let jql = JqlBuilder::new()
+///     .project("TEST")
+///     .status_in(&["Open", "In Progress"])
+///     .order_by("updated", OrderDirection::Desc)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(
+///     jql,
+///     "project = \"TEST\" AND status IN (\"Open\", \"In Progress\") ORDER BY updated DESC"
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct JqlBuilder {
+    clauses: Vec<String>,
+    order_by: Vec<(String, OrderDirection)>,
+    errors: Vec<String>,
+}
+
+impl JqlBuilder {
+    /// 空のビルダーを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// プロジェクトで絞り込む
+    pub fn project(mut self, key: &str) -> Self {
+        self.clauses.push(format!("project = {}", quote(key)));
+        self
+    }
+
+    /// ステータスのいずれかに一致するものに絞り込む
+    pub fn status_in(mut self, statuses: &[&str]) -> Self {
+        if statuses.is_empty() {
+            self.errors
+                .push("status_in requires at least one status".to_string());
+        } else {
+            self.clauses.push(in_list("status", statuses));
+        }
+        self
+    }
+
+    /// 担当者のアカウントIDで絞り込む
+    pub fn assignee(mut self, account_id: &str) -> Self {
+        self.clauses
+            .push(format!("assignee = {}", quote(account_id)));
+        self
+    }
+
+    /// 指定日時より後に更新されたものに絞り込む
+    pub fn updated_after(mut self, since: DateTime<Utc>) -> Self {
+        self.clauses.push(format!(
+            "updated > {}",
+            quote(&format_jira_datetime(&since))
+        ));
+        self
+    }
+
+    /// 指定日時以降に更新されたものに絞り込む
+    ///
+    /// JQLの日時は分単位のため、秒以下は切り捨てて比較します。
+    pub fn updated_since(mut self, since: DateTime<Utc>) -> Self {
+        self.clauses.push(format!(
+            "updated >= {}",
+            quote(&format_jira_datetime(&since))
+        ));
+        self
+    }
+
+    /// 組み立て済みのJQL条件を追加する
+    ///
+    /// [`crate::TimeBasedFilter::to_jql_time_condition`]など既存の条件と組み合わせるためのもので、
+    /// 条件は括弧で囲まれます。値のエスケープは呼び出し側の責任です。
+    pub fn condition(mut self, condition: &str) -> Self {
+        if condition.trim().is_empty() {
+            self.errors.push("condition must not be empty".to_string());
+        } else {
+            self.clauses.push(format!("({})", condition));
+        }
+        self
+    }
+
+    /// 並び順を追加する（複数指定した場合は指定順に優先）
+    pub fn order_by(mut self, field: &str, direction: OrderDirection) -> Self {
+        if is_valid_field_name(field) {
+            self.order_by.push((field.to_string(), direction));
+        } else {
+            self.errors
+                .push(format!("Invalid ORDER BY field: {:?}", field));
+        }
+        self
+    }
+
+    /// JQL文字列を生成する
+    ///
+    /// 組み立て中に不正な入力があった場合は`InvalidInput`エラーを返します。
+    pub fn build(&self) -> Result<String, Error> {
+        if !self.errors.is_empty() {
+            return Err(Error::InvalidInput(self.errors.join("; ")));
+        }
+
+        let mut jql = self.clauses.join(" AND ");
+
+        if !self.order_by.is_empty() {
+            let order: Vec<String> = self
+                .order_by
+                .iter()
+                .map(|(field, direction)| format!("{} {}", field, direction.as_jql()))
+                .collect();
+            if !jql.is_empty() {
+                jql.push(' ');
+            }
+            jql.push_str("ORDER BY ");
+            jql.push_str(&order.join(", "));
+        }
+
+        Ok(jql)
+    }
+}
+
+/// JQL文字列に変換できる型
+///
+/// 検索メソッドは文字列と[`JqlBuilder`]のどちらでも受け付けます。
+/// 文字列はそのまま使用し、ビルダーは[`JqlBuilder::build`]の結果を使用します。
+/// ビルダーの組み立てに失敗している場合はリクエストを送信せずに`InvalidInput`エラーを返します。
+pub trait IntoJql {
+    /// JQL文字列に変換する
+    fn into_jql(self) -> Result<String, Error>;
+}
+
+impl IntoJql for &str {
+    fn into_jql(self) -> Result<String, Error> {
+        Ok(self.to_string())
+    }
+}
+
+impl IntoJql for String {
+    fn into_jql(self) -> Result<String, Error> {
+        Ok(self)
+    }
+}
+
+impl IntoJql for &String {
+    fn into_jql(self) -> Result<String, Error> {
+        Ok(self.clone())
+    }
+}
+
+impl IntoJql for JqlBuilder {
+    fn into_jql(self) -> Result<String, Error> {
+        self.build()
+    }
+}
+
+impl IntoJql for &JqlBuilder {
+    fn into_jql(self) -> Result<String, Error> {
+        self.build()
+    }
+}
+
+/// ORDER BYに指定できるフィールド名か判定する
+///
+/// 通常のフィールド名（`updated`, `customfield_10010`）と`cf[10010]`形式を許可します。
+fn is_valid_field_name(field: &str) -> bool {
+    !field.is_empty()
+        && field
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '[' | ']'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_plain_value() {
+        // 特殊文字を含まない値がダブルクォートで囲まれることをテスト
+        assert_eq!(quote("PROJ"), "\"PROJ\"");
+    }
+
+    #[test]
+    fn test_quote_value_with_spaces() {
+        // 空白を含む値がそのままクォートされることをテスト
+        assert_eq!(quote("My Project"), "\"My Project\"");
+    }
+
+    #[test]
+    fn test_quote_value_with_quotes() {
+        // ダブルクォートがエスケープされ、クエリを抜け出せないことをテスト
+        assert_eq!(
+            quote("PROJ\" OR project = \"OTHER"),
+            "\"PROJ\\\" OR project = \\\"OTHER\""
+        );
+    }
+
+    #[test]
+    fn test_quote_value_with_backslashes() {
+        // バックスラッシュがエスケープされることをテスト
+        assert_eq!(quote("a\\b"), "\"a\\\\b\"");
+        // 末尾のバックスラッシュで閉じクォートがエスケープされないこと
+        assert_eq!(quote("end\\"), "\"end\\\\\"");
+    }
+
+    #[test]
+    fn test_in_list() {
+        // IN句の各値がクォートされることをテスト
+        let jql = in_list("status", &["Open", "In Progress", "Say \"hi\""]);
+        assert_eq!(
+            jql,
+            "status IN (\"Open\", \"In Progress\", \"Say \\\"hi\\\"\")"
+        );
+    }
+
+    #[test]
+    fn test_jql_builder_multiple_clauses() {
+        // 複数条件がANDで結合され、値がクォートされ、ORDER BYが付与されることをテスト
+        use chrono::TimeZone;
+
+        let since = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 0).unwrap();
+        let jql = JqlBuilder::new()
+            .project("My \"Project\"")
+            .status_in(&["Open", "In Progress"])
+            .assignee("557058:f58131cb")
+            .updated_after(since)
+            .order_by("priority", OrderDirection::Desc)
+            .order_by("key", OrderDirection::Asc)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            jql,
+            "project = \"My \\\"Project\\\"\" AND status IN (\"Open\", \"In Progress\") \
+             AND assignee = \"557058:f58131cb\" AND updated > \"2024-01-02 03:04\" \
+             ORDER BY priority DESC, key ASC"
+        );
+    }
+
+    #[test]
+    fn test_jql_builder_condition_and_order_only() {
+        // 既存条件は括弧で囲まれ、条件が無い場合はORDER BYのみになることをテスト
+        let jql = JqlBuilder::new()
+            .project("TEST")
+            .condition("updated >= '2024-01-01 00:00' OR created >= '2024-01-01 00:00'")
+            .build()
+            .unwrap();
+        assert_eq!(
+            jql,
+            "project = \"TEST\" AND (updated >= '2024-01-01 00:00' OR created >= '2024-01-01 00:00')"
+        );
+
+        let jql = JqlBuilder::new()
+            .order_by("cf[10010]", OrderDirection::Asc)
+            .build()
+            .unwrap();
+        assert_eq!(jql, "ORDER BY cf[10010] ASC");
+    }
+
+    #[test]
+    fn test_into_jql() {
+        // 文字列はそのまま、ビルダーはbuild()の結果に変換されることをテスト
+        let builder = JqlBuilder::new().project("TEST");
+        assert_eq!(
+            "project = \"TEST\"".into_jql().unwrap(),
+            builder.clone().into_jql().unwrap()
+        );
+        assert_eq!((&builder).into_jql().unwrap(), builder.build().unwrap());
+        assert_eq!(
+            String::from("order by key").into_jql().unwrap(),
+            "order by key"
+        );
+
+        assert!(matches!(
+            JqlBuilder::new().status_in(&[]).into_jql(),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_jql_builder_invalid_input() {
+        // 空のステータスリストや不正なフィールド名はbuild()でエラーになることをテスト
+        assert!(matches!(
+            JqlBuilder::new().project("TEST").status_in(&[]).build(),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            JqlBuilder::new()
+                .order_by("updated; DROP", OrderDirection::Asc)
+                .build(),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            JqlBuilder::new().condition("  ").build(),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_with_stable_order() {
+        // ORDER BYが無いJQLにのみ安定した並び順が付与されることをテスト
+        assert_eq!(
+            with_stable_order("project = TEST"),
+            "project = TEST ORDER BY key ASC"
+        );
+        assert_eq!(with_stable_order("  "), "ORDER BY key ASC");
+        assert_eq!(
+            with_stable_order("project = TEST order by updated DESC"),
+            "project = TEST order by updated DESC"
+        );
+        assert_eq!(
+            with_stable_order("(project = TEST)ORDER BY created"),
+            "(project = TEST)ORDER BY created"
+        );
+
+        // 文字列リテラル内のORDER BYは並び順として扱わない
+        assert!(!has_order_by(
+            r#"summary ~ "order by" AND text ~ 'ORDER BY'"#
+        ));
+        assert!(!has_order_by(r#"summary ~ "say \"order by\"""#));
+    }
+
+    #[test]
+    fn test_with_order_by_replaces_existing() {
+        // 並び順が付与され、既存のORDER BYは重複せずに置き換えられることをテスト
+        let order = vec![("created".to_string(), OrderDirection::Desc)];
+        assert_eq!(
+            with_order_by("project = TEST", &order).unwrap(),
+            "project = TEST ORDER BY created DESC"
+        );
+        assert_eq!(
+            with_order_by("project = TEST ORDER BY key ASC", &order).unwrap(),
+            "project = TEST ORDER BY created DESC"
+        );
+        assert_eq!(
+            with_order_by(r#"summary ~ "order by" order  by updated"#, &order).unwrap(),
+            r#"summary ~ "order by" ORDER BY created DESC"#
+        );
+        assert_eq!(with_order_by("", &order).unwrap(), "ORDER BY created DESC");
+
+        // "border"や"orderly"のような単語はORDER BYとして扱わない
+        assert!(!has_order_by("labels = border AND summary ~ orderly"));
+
+        let invalid = vec![("created; DROP".to_string(), OrderDirection::Asc)];
+        assert!(matches!(
+            with_order_by("project = TEST", &invalid),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+}
@@ -1,13 +1,51 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use futures::{Stream, TryStreamExt, stream};
 use serde::{Deserialize, Serialize};
-use std::io::{Read, Write};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::io::{BufWriter, Read};
 use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::atomic::AtomicU64;
 use tokio::fs::{File, create_dir_all};
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::io::AsyncReadExt;
+use tokio::task;
 
-use crate::{Error, FilterConfig, Issue, IssueFilter, PersistenceStore, SortOrder, StorageStats};
+use crate::{
+    Error, FilterConfig, GroupBy, Issue, IssueField, IssueFilter, IssueProjection,
+    PersistenceStore, SortOrder, StorageStats, UNASSIGNED_BUCKET,
+};
+
+/// gzipファイルの先頭2バイト（マジックナンバー）
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// トランザクション中に書き込む一時ファイルの接尾辞
+const TRANSACTION_SUFFIX: &str = ".txn";
+
+/// アトミックな置き換えのために書き込む一時ファイルの接尾辞（後ろにプロセスIDと連番が付く）
+const TEMP_FILE_SUFFIX: &str = ".tmp";
+
+/// 一時ファイル名をプロセス内で一意にするための連番
+static TEMP_FILE_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// シリアライズ結果を書き込む際のバッファサイズ
+const WRITE_BUFFER_SIZE: usize = 64 * 1024;
+
+/// データファイルの（サブディレクトリ, ファイル名の拡張子を除いた部分）の一覧
+const DATA_FILES: [(&str, &str); 4] = [
+    ("issues", "issues"),
+    ("filters", "filter_config"),
+    ("history", "history"),
+    ("metadata", "metadata"),
+];
+
+/// トランザクション開始時点のメモリ上の状態（ロールバック用）
+struct JsonTransaction {
+    metadata_cache: Option<StorageStats>,
+    last_sync_time: Option<DateTime<Utc>>,
+}
 
 /// JSON形式のファイルストア（gzip圧縮対応）
 pub struct JsonStore {
@@ -19,6 +57,8 @@ pub struct JsonStore {
     metadata_cache: Option<StorageStats>,
     /// 最後の更新時刻
     last_sync_time: Option<DateTime<Utc>>,
+    /// 進行中のトランザクション
+    transaction: Option<JsonTransaction>,
 }
 
 impl JsonStore {
@@ -29,6 +69,7 @@ impl JsonStore {
             use_compression: true,
             metadata_cache: None,
             last_sync_time: None,
+            transaction: None,
         }
     }
 
@@ -61,34 +102,129 @@ impl JsonStore {
         Ok(())
     }
 
-    /// Issuesファイルのパスを取得
-    fn get_issues_file_path(&self) -> PathBuf {
+    /// Issueを既存のデータに追記（キー単位でupsert）
+    ///
+    /// 既存のIssueファイルを読み込み、同じキーのIssueは更新日時が新しい方で置き換え、
+    /// 未登録のキーは末尾に追加して書き戻します。書き込みは一時ファイルを経由した
+    /// リネームで行うため、途中で中断しても既存のファイルは壊れません。
+    ///
+    /// 戻り値は新規に追加されたIssueの件数です（置き換えた件数は含みません）。
+    pub async fn append_issues(&mut self, issues: &[Issue]) -> Result<usize, Error> {
+        // 同一キーは更新日時が同じか新しい場合のみ置き換える
+        self.merge_issues(issues, |new, old| new.fields.updated >= old.fields.updated)
+            .await
+    }
+
+    /// 既存のIssueファイルにIssueをキー単位でマージして書き戻す
+    ///
+    /// `replace`が`true`を返した同一キーのIssueを置き換え、未登録のキーは末尾に追加します。
+    /// 戻り値は新規に追加されたIssueの件数です。
+    async fn merge_issues(
+        &mut self,
+        issues: &[Issue],
+        replace: impl Fn(&Issue, &Issue) -> bool,
+    ) -> Result<usize, Error> {
+        self.initialize().await?;
+
+        let issues_path = self.get_issues_file_path();
+        let mut all_issues: Vec<Issue> = match self.readable_file_path(&issues_path).await? {
+            Some(path) => self.read_json_file(&path).await?,
+            None => Vec::new(),
+        };
+
+        let mut positions: HashMap<String, usize> = all_issues
+            .iter()
+            .enumerate()
+            .map(|(index, issue)| (issue.key.clone(), index))
+            .collect();
+        let mut added_count = 0;
+
+        for issue in issues {
+            match positions.get(&issue.key) {
+                Some(&index) => {
+                    if replace(issue, &all_issues[index]) {
+                        all_issues[index] = issue.clone();
+                    }
+                }
+                None => {
+                    positions.insert(issue.key.clone(), all_issues.len());
+                    all_issues.push(issue.clone());
+                    added_count += 1;
+                }
+            }
+        }
+
+        // メタデータキャッシュを更新
+        let stats = self.calculate_stats(&all_issues);
+        self.write_json_file(&issues_path, all_issues).await?;
+        self.metadata_cache = Some(stats);
+        self.last_sync_time = Some(Utc::now());
+
+        let metadata_path = self.get_metadata_file_path();
+        if let Some(ref stats) = self.metadata_cache {
+            self.write_json_file(&metadata_path, stats.clone()).await?;
+        }
+
+        Ok(added_count)
+    }
+
+    /// コミット済みのデータファイルのパスを取得（拡張子は圧縮設定に従う）
+    fn committed_file_path(&self, dir: &str, name: &str) -> PathBuf {
         let filename = if self.use_compression {
-            "issues.json.gz"
+            format!("{}.json.gz", name)
         } else {
-            "issues.json"
+            format!("{}.json", name)
         };
-        self.data_dir.join("issues").join(filename)
+        self.data_dir.join(dir).join(filename)
+    }
+
+    /// 読み込み元のデータファイルのパスを取得（ファイルが無い場合はNone）
+    ///
+    /// 圧縮設定に対応する拡張子のファイルが無い場合は、もう一方の拡張子のファイルを返します。
+    /// 異なる圧縮設定で保存したディレクトリを開き直しても既存のデータを読み込めます。
+    /// 内容の圧縮の有無は`read_json_file`がファイル先頭のバイトで判定します。
+    async fn readable_file_path(&self, path: &Path) -> Result<Option<PathBuf>, Error> {
+        if tokio::fs::try_exists(path).await? {
+            return Ok(Some(path.to_path_buf()));
+        }
+
+        // トランザクション中のファイルは開始時に読み込み元から複製済み
+        if self.transaction.is_some() {
+            return Ok(None);
+        }
+
+        let other = other_extension_path(path);
+        if tokio::fs::try_exists(&other).await? {
+            return Ok(Some(other));
+        }
+        Ok(None)
+    }
+
+    /// 読み書きに使用するデータファイルのパスを取得
+    ///
+    /// トランザクション中は一時ファイルのパスを返し、コミット時に本来のファイルと置き換えます。
+    fn data_file_path(&self, dir: &str, name: &str) -> PathBuf {
+        let path = self.committed_file_path(dir, name);
+        if self.transaction.is_some() {
+            staged_file_path(&path)
+        } else {
+            path
+        }
+    }
+
+    /// Issuesファイルのパスを取得
+    fn get_issues_file_path(&self) -> PathBuf {
+        self.data_file_path("issues", "issues")
     }
 
     /// フィルター設定ファイルのパスを取得
     fn get_filter_config_file_path(&self) -> PathBuf {
-        let filename = if self.use_compression {
-            "filter_config.json.gz"
-        } else {
-            "filter_config.json"
-        };
-        self.data_dir.join("filters").join(filename)
+        self.data_file_path("filters", "filter_config")
     }
 
     /// 履歴ファイルのパスを取得
     fn get_history_file_path(&self) -> PathBuf {
-        let filename = if self.use_compression {
-            "history.json.gz"
-        } else {
-            "history.json"
-        };
-        self.data_dir.join("history").join(filename)
+        self.data_file_path("history", "history")
     }
 
     /// 履歴データにフィルターを適用
@@ -165,43 +301,53 @@ impl JsonStore {
 
     /// メタデータファイルのパスを取得
     fn get_metadata_file_path(&self) -> PathBuf {
-        let filename = if self.use_compression {
-            "metadata.json.gz"
-        } else {
-            "metadata.json"
-        };
-        self.data_dir.join("metadata").join(filename)
+        self.data_file_path("metadata", "metadata")
     }
 
     /// データをJSONファイルに書き込み（圧縮対応）
-    async fn write_json_file<T>(&self, path: &Path, data: &T) -> Result<(), Error>
+    ///
+    /// 同じディレクトリの一時ファイル（例: `issues.json.gz.tmp.<pid>.<連番>`）に書き込んで
+    /// `sync_all`した後、本来のファイルへリネームして置き換えます。同一ファイルシステム内の
+    /// リネームはアトミックなため、書き込み途中で中断しても既存のファイルは壊れません。
+    ///
+    /// シリアライズ・gzip圧縮・ファイルへの書き込みは全て`spawn_blocking`のスレッドで行うため、
+    /// 大量のIssueを保存する場合もランタイムのワーカースレッドを塞ぎません。
+    /// シリアライズ結果は一括でバッファに構築せず、ファイルへ直接書き込みます。
+    ///
+    /// 置き換えに成功した後は、もう一方の拡張子で保存されていた古いファイルを削除します。
+    async fn write_json_file<T>(&self, path: &Path, data: T) -> Result<(), Error>
     where
-        T: Serialize + ?Sized,
+        T: Serialize + Send + 'static,
     {
-        let json_data = serde_json::to_vec_pretty(data)
-            .map_err(|e| Error::SerializationError(format!("JSON serialization failed: {}", e)))?;
-
-        let final_data = if self.use_compression {
-            // gzip圧縮
-            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
-            encoder
-                .write_all(&json_data)
-                .map_err(|e| Error::IoError(e))?;
-            encoder.finish().map_err(|e| Error::IoError(e))?
-        } else {
-            json_data
-        };
+        let temp_path = temp_file_path(path);
+        let writer_path = temp_path.clone();
+        let use_compression = self.use_compression;
+
+        let written =
+            task::spawn_blocking(move || write_json_blocking(&writer_path, &data, use_compression))
+                .await
+                .map_err(|e| Error::Unexpected(format!("JSON writer task failed: {}", e)))
+                .and_then(|result| result);
+
+        if let Err(e) = written {
+            // 書きかけの一時ファイルは残さない
+            let _ = tokio::fs::remove_file(&temp_path).await;
+            return Err(e);
+        }
 
-        let mut file = File::create(path).await.map_err(|e| Error::IoError(e))?;
-        file.write_all(&final_data)
-            .await
-            .map_err(|e| Error::IoError(e))?;
-        file.sync_all().await.map_err(|e| Error::IoError(e))?;
+        tokio::fs::rename(&temp_path, path).await?;
 
+        // トランザクション中の書き込みはコミット時に古いファイルを削除する
+        if self.transaction.is_none() {
+            remove_stale_file(path).await?;
+        }
         Ok(())
     }
 
     /// JSONファイルからデータを読み込み（圧縮対応）
+    ///
+    /// 圧縮の有無は`use_compression`ではなくファイル先頭のgzipマジックナンバーで判定するため、
+    /// 異なる圧縮設定で書き込まれたファイルも読み込めます。
     async fn read_json_file<T>(&self, path: &Path) -> Result<T, Error>
     where
         T: for<'de> Deserialize<'de>,
@@ -213,7 +359,7 @@ impl JsonStore {
             .await
             .map_err(|e| Error::IoError(e))?;
 
-        let json_data = if self.use_compression {
+        let json_data = if raw_data.starts_with(&GZIP_MAGIC) {
             // gzip解凍
             let mut decoder = GzDecoder::new(&raw_data[..]);
             let mut decompressed = Vec::new();
@@ -238,52 +384,36 @@ impl JsonStore {
             .collect();
 
         // ソート
-        match filter.sort_order {
-            SortOrder::CreatedAsc => {
-                filtered.sort_by(|a, b| a.fields.created.cmp(&b.fields.created))
-            }
-            SortOrder::CreatedDesc => {
-                filtered.sort_by(|a, b| b.fields.created.cmp(&a.fields.created))
-            }
-            SortOrder::UpdatedAsc => {
-                filtered.sort_by(|a, b| a.fields.updated.cmp(&b.fields.updated))
-            }
-            SortOrder::UpdatedDesc => {
-                filtered.sort_by(|a, b| b.fields.updated.cmp(&a.fields.updated))
-            }
-            SortOrder::KeyAsc => filtered.sort_by(|a, b| a.key.cmp(&b.key)),
-            SortOrder::KeyDesc => filtered.sort_by(|a, b| b.key.cmp(&a.key)),
-            SortOrder::PriorityAsc => filtered.sort_by(|a, b| {
-                let priority_a = a
-                    .fields
-                    .priority
-                    .as_ref()
-                    .map(|p| &p.name)
-                    .map_or("None", |v| v);
-                let priority_b = b
-                    .fields
-                    .priority
-                    .as_ref()
-                    .map(|p| &p.name)
-                    .map_or("None", |v| v);
-                priority_a.cmp(priority_b)
-            }),
-            SortOrder::PriorityDesc => filtered.sort_by(|a, b| {
-                let priority_a = a
+        filtered.sort_by(|a, b| {
+            let ordering = match filter.sort_order {
+                SortOrder::CreatedAsc => a.fields.created.cmp(&b.fields.created),
+                SortOrder::CreatedDesc => b.fields.created.cmp(&a.fields.created),
+                SortOrder::UpdatedAsc => a.fields.updated.cmp(&b.fields.updated),
+                SortOrder::UpdatedDesc => b.fields.updated.cmp(&a.fields.updated),
+                SortOrder::KeyAsc => a.key.cmp(&b.key),
+                SortOrder::KeyDesc => b.key.cmp(&a.key),
+                SortOrder::PriorityAsc => priority_name(a).cmp(priority_name(b)),
+                SortOrder::PriorityDesc => priority_name(b).cmp(priority_name(a)),
+                SortOrder::AssigneeAsc => cmp_nulls_last(assignee_name(a), assignee_name(b), false),
+                SortOrder::AssigneeDesc => cmp_nulls_last(assignee_name(a), assignee_name(b), true),
+                SortOrder::ReporterAsc => a
                     .fields
-                    .priority
-                    .as_ref()
-                    .map(|p| &p.name)
-                    .map_or("None", |v| v);
-                let priority_b = b
+                    .reporter
+                    .display_name
+                    .cmp(&b.fields.reporter.display_name),
+                SortOrder::ReporterDesc => b
                     .fields
-                    .priority
-                    .as_ref()
-                    .map(|p| &p.name)
-                    .map_or("None", |v| v);
-                priority_b.cmp(priority_a)
-            }),
-        }
+                    .reporter
+                    .display_name
+                    .cmp(&a.fields.reporter.display_name),
+                SortOrder::StatusAsc => a.fields.status.name.cmp(&b.fields.status.name),
+                SortOrder::StatusDesc => b.fields.status.name.cmp(&a.fields.status.name),
+                SortOrder::TypeAsc => a.fields.issue_type.name.cmp(&b.fields.issue_type.name),
+                SortOrder::TypeDesc => b.fields.issue_type.name.cmp(&a.fields.issue_type.name),
+            };
+            // DuckDBStoreと同じく同じ値の行はキーの昇順に並べ、ページングの結果を固定する
+            ordering.then_with(|| a.key.cmp(&b.key))
+        });
 
         // オフセットと制限の適用
         let start = filter.offset.unwrap_or(0);
@@ -325,6 +455,34 @@ impl JsonStore {
                 .issues_by_type
                 .entry(issue.fields.issue_type.name.clone())
                 .or_insert(0) += 1;
+
+            // 解決状況別統計
+            if let Some(ref resolution) = issue.fields.resolution {
+                *stats
+                    .issues_by_resolution
+                    .entry(resolution.name.clone())
+                    .or_insert(0) += 1;
+            }
+
+            // 担当者別統計
+            let assignee = issue
+                .fields
+                .assignee
+                .as_ref()
+                .map(|a| a.display_name.as_str())
+                .unwrap_or(UNASSIGNED_BUCKET);
+            *stats
+                .issues_by_assignee
+                .entry(assignee.to_string())
+                .or_insert(0) += 1;
+
+            // 優先度別統計
+            if let Some(ref priority) = issue.fields.priority {
+                *stats
+                    .issues_by_priority
+                    .entry(priority.name.clone())
+                    .or_insert(0) += 1;
+            }
         }
 
         stats.last_updated = Utc::now();
@@ -336,27 +494,131 @@ impl JsonStore {
     /// メタデータキャッシュを更新
     async fn update_metadata_cache(&mut self) -> Result<(), Error> {
         let issues_path = self.get_issues_file_path();
-        if issues_path.exists() {
-            let issues: Vec<Issue> = self.read_json_file(&issues_path).await.unwrap_or_default();
+        if let Some(path) = self.readable_file_path(&issues_path).await? {
+            let issues: Vec<Issue> = self.read_json_file(&path).await.unwrap_or_default();
             self.metadata_cache = Some(self.calculate_stats(&issues));
 
             // メタデータをファイルにも保存
             let metadata_path = self.get_metadata_file_path();
             if let Some(ref stats) = self.metadata_cache {
-                self.write_json_file(&metadata_path, stats).await?;
+                self.write_json_file(&metadata_path, stats.clone()).await?;
             }
         }
         Ok(())
     }
 }
 
+/// データをJSONとしてファイルへ書き込み、ディスクへ同期する（ブロッキング）
+fn write_json_blocking<T>(path: &Path, data: &T, use_compression: bool) -> Result<(), Error>
+where
+    T: Serialize + ?Sized,
+{
+    let serialization_error = |e: serde_json::Error| {
+        Error::SerializationError(format!("JSON serialization failed: {}", e))
+    };
+    let file = std::fs::File::create(path)?;
+
+    // シリアライズ時の細かな書き込みはバッファにまとめてから圧縮・書き込みに回す
+    let file = if use_compression {
+        // gzip圧縮しながら書き込み
+        let encoder = GzEncoder::new(file, Compression::default());
+        let mut writer = BufWriter::with_capacity(WRITE_BUFFER_SIZE, encoder);
+        serde_json::to_writer_pretty(&mut writer, data).map_err(serialization_error)?;
+        writer
+            .into_inner()
+            .map_err(|e| Error::IoError(e.into_error()))?
+            .finish()?
+    } else {
+        let mut writer = BufWriter::with_capacity(WRITE_BUFFER_SIZE, file);
+        serde_json::to_writer_pretty(&mut writer, data).map_err(serialization_error)?;
+        writer
+            .into_inner()
+            .map_err(|e| Error::IoError(e.into_error()))?
+    };
+    file.sync_all()?;
+
+    Ok(())
+}
+
+/// もう一方の拡張子のパスを取得（`issues.json.gz` ⇔ `issues.json`）
+fn other_extension_path(path: &Path) -> PathBuf {
+    match path.extension() {
+        Some(extension) if extension == "gz" => path.with_extension(""),
+        _ => {
+            let mut other = path.as_os_str().to_owned();
+            other.push(".gz");
+            PathBuf::from(other)
+        }
+    }
+}
+
+/// 書き込み後に不要となった、もう一方の拡張子のファイルを削除
+async fn remove_stale_file(path: &Path) -> Result<(), Error> {
+    match tokio::fs::remove_file(other_extension_path(path)).await {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(Error::IoError(e)),
+        _ => Ok(()),
+    }
+}
+
+/// トランザクション中に書き込む一時ファイルのパスを取得
+fn staged_file_path(path: &Path) -> PathBuf {
+    let mut staged = path.as_os_str().to_owned();
+    staged.push(TRANSACTION_SUFFIX);
+    PathBuf::from(staged)
+}
+
+/// アトミックな置き換え用の一時ファイルのパスを取得（例: `issues.json.gz.tmp.<pid>.<連番>`）
+///
+/// 同じプロセス内で同じファイルへの書き込みが重なっても一時ファイルを共有しないよう、
+/// 呼び出しごとに異なる連番を付けます。
+fn temp_file_path(path: &Path) -> PathBuf {
+    let sequence = TEMP_FILE_SEQUENCE.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(format!(
+        "{}.{}.{}",
+        TEMP_FILE_SUFFIX,
+        std::process::id(),
+        sequence
+    ));
+    PathBuf::from(temp)
+}
+
+/// 担当者の表示名を取得（未割り当てはNone）
+fn assignee_name(issue: &Issue) -> Option<&str> {
+    issue
+        .fields
+        .assignee
+        .as_ref()
+        .map(|a| a.display_name.as_str())
+}
+
+/// 並び替えに使う優先度名（優先度が無い場合は`None`）
+fn priority_name(issue: &Issue) -> &str {
+    issue
+        .fields
+        .priority
+        .as_ref()
+        .map_or("None", |p| p.name.as_str())
+}
+
+/// 値のない項目を昇順・降順どちらでも末尾に並べる比較（SQLの`NULLS LAST`相当）
+fn cmp_nulls_last(a: Option<&str>, b: Option<&str>, descending: bool) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) if descending => b.cmp(a),
+        (Some(a), Some(b)) => a.cmp(b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
 #[async_trait]
 impl PersistenceStore for JsonStore {
     async fn save_issues(&mut self, issues: &[Issue]) -> Result<usize, Error> {
         self.initialize().await?;
 
         let issues_path = self.get_issues_file_path();
-        self.write_json_file(&issues_path, issues).await?;
+        self.write_json_file(&issues_path, issues.to_vec()).await?;
 
         // メタデータキャッシュを更新
         self.metadata_cache = Some(self.calculate_stats(issues));
@@ -365,20 +627,31 @@ impl PersistenceStore for JsonStore {
         // メタデータファイルも更新
         let metadata_path = self.get_metadata_file_path();
         if let Some(ref stats) = self.metadata_cache {
-            self.write_json_file(&metadata_path, stats).await?;
+            self.write_json_file(&metadata_path, stats.clone()).await?;
         }
 
         Ok(issues.len())
     }
 
+    /// 読み込みのたびにファイル全体を読み込んで並び替えるため、エクスポートはページングしません。
+    fn export_page_size(&self) -> Option<usize> {
+        None
+    }
+
+    /// 既存のIssueファイルを読み込み、同じキーのIssueを更新日時に関わらず置き換えて書き戻します。
+    async fn upsert_issues(&mut self, issues: &[Issue]) -> Result<usize, Error> {
+        self.merge_issues(issues, |_, _| true).await?;
+        Ok(issues.len())
+    }
+
     async fn load_issues(&self, filter: &IssueFilter) -> Result<Vec<Issue>, Error> {
         let issues_path = self.get_issues_file_path();
 
-        if !issues_path.exists() {
+        let Some(path) = self.readable_file_path(&issues_path).await? else {
             return Ok(Vec::new());
-        }
+        };
 
-        let all_issues: Vec<Issue> = self.read_json_file(&issues_path).await?;
+        let all_issues: Vec<Issue> = self.read_json_file(&path).await?;
         Ok(self.filter_issues(&all_issues, filter))
     }
 
@@ -392,14 +665,49 @@ impl PersistenceStore for JsonStore {
         Ok(issues.len())
     }
 
+    async fn group_counts(
+        &self,
+        filter: &IssueFilter,
+        by: GroupBy,
+    ) -> Result<Vec<(String, usize)>, Error> {
+        // 件数制限とオフセットは集計対象から外す
+        let mut group_filter = filter.clone();
+        group_filter.limit = None;
+        group_filter.offset = None;
+
+        let issues = self.load_issues(&group_filter).await?;
+
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for issue in &issues {
+            *counts.entry(by.key_of(issue)).or_insert(0) += 1;
+        }
+
+        let mut grouped: Vec<(String, usize)> = counts.into_iter().collect();
+        grouped.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Ok(grouped)
+    }
+
+    async fn load_projection(
+        &self,
+        filter: &IssueFilter,
+        fields: &[IssueField],
+    ) -> Result<Vec<IssueProjection>, Error> {
+        // JSONファイルは全体を読み込む必要があるため、読み込んだIssueから項目を取り出す
+        let issues = self.load_issues(filter).await?;
+        Ok(issues
+            .iter()
+            .map(|issue| IssueProjection::from_issue(issue, fields))
+            .collect())
+    }
+
     async fn delete_issues(&mut self, issue_keys: &[String]) -> Result<usize, Error> {
         let issues_path = self.get_issues_file_path();
 
-        if !issues_path.exists() {
+        let Some(path) = self.readable_file_path(&issues_path).await? else {
             return Ok(0);
-        }
+        };
 
-        let mut all_issues: Vec<Issue> = self.read_json_file(&issues_path).await?;
+        let mut all_issues: Vec<Issue> = self.read_json_file(&path).await?;
         let original_count = all_issues.len();
 
         // 指定されたキーのIssueを削除
@@ -407,23 +715,104 @@ impl PersistenceStore for JsonStore {
         let deleted_count = original_count - all_issues.len();
 
         if deleted_count > 0 {
-            // 更新されたデータを保存
-            self.write_json_file(&issues_path, &all_issues).await?;
-
-            // メタデータキャッシュを更新
-            self.metadata_cache = Some(self.calculate_stats(&all_issues));
+            // 更新されたデータを保存し、メタデータキャッシュを更新
+            let stats = self.calculate_stats(&all_issues);
+            self.write_json_file(&issues_path, all_issues).await?;
+            self.metadata_cache = Some(stats);
             self.last_sync_time = Some(Utc::now());
         }
 
         Ok(deleted_count)
     }
 
+    /// 既存のデータファイルを一時ファイルに複製し、以降の書き込みを一時ファイルに向けます。
+    async fn begin_transaction(&mut self) -> Result<(), Error> {
+        if self.transaction.is_some() {
+            return Err(Error::InvalidInput(
+                "Transaction already in progress".to_string(),
+            ));
+        }
+
+        self.initialize().await?;
+
+        for (dir, name) in DATA_FILES {
+            let path = self.committed_file_path(dir, name);
+            let staged = staged_file_path(&path);
+            if let Some(source) = self.readable_file_path(&path).await? {
+                tokio::fs::copy(&source, &staged).await?;
+            } else if tokio::fs::try_exists(&staged).await? {
+                // 前回の中断で残った一時ファイルは破棄する
+                tokio::fs::remove_file(&staged).await?;
+            }
+        }
+
+        self.transaction = Some(JsonTransaction {
+            metadata_cache: self.metadata_cache.clone(),
+            last_sync_time: self.last_sync_time,
+        });
+        Ok(())
+    }
+
+    /// 一時ファイルを本来のファイルへリネームして置き換えます。
+    async fn commit_transaction(&mut self) -> Result<(), Error> {
+        if self.transaction.take().is_none() {
+            return Err(Error::InvalidInput(
+                "No transaction in progress".to_string(),
+            ));
+        }
+
+        for (dir, name) in DATA_FILES {
+            let path = self.committed_file_path(dir, name);
+            let staged = staged_file_path(&path);
+            if tokio::fs::try_exists(&staged).await? {
+                tokio::fs::rename(&staged, &path).await?;
+                remove_stale_file(&path).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 一時ファイルを削除し、メタデータキャッシュを開始時点の状態に戻します。
+    async fn rollback_transaction(&mut self) -> Result<(), Error> {
+        let Some(transaction) = self.transaction.take() else {
+            return Err(Error::InvalidInput(
+                "No transaction in progress".to_string(),
+            ));
+        };
+
+        self.metadata_cache = transaction.metadata_cache;
+        self.last_sync_time = transaction.last_sync_time;
+
+        for (dir, name) in DATA_FILES {
+            let staged = staged_file_path(&self.committed_file_path(dir, name));
+            if tokio::fs::try_exists(&staged).await? {
+                tokio::fs::remove_file(&staged).await?;
+            }
+        }
+        Ok(())
+    }
+
     async fn optimize(&mut self) -> Result<(), Error> {
         // JSONストアの場合、最適化は主にメタデータの再計算
         self.update_metadata_cache().await?;
         Ok(())
     }
 
+    async fn flush(&mut self) -> Result<(), Error> {
+        // キャッシュが無い場合はIssueファイルから再計算して書き出す
+        if self.metadata_cache.is_none() {
+            return self.update_metadata_cache().await;
+        }
+
+        // write_json_fileはsync_allまで行うため、書き込み後はディスクに反映済み
+        let metadata_path = self.get_metadata_file_path();
+        if let Some(ref stats) = self.metadata_cache {
+            self.write_json_file(&metadata_path, stats.clone()).await?;
+        }
+
+        Ok(())
+    }
+
     async fn get_stats(&self) -> Result<StorageStats, Error> {
         if let Some(ref cache) = self.metadata_cache {
             return Ok(cache.clone());
@@ -438,7 +827,7 @@ impl PersistenceStore for JsonStore {
         self.initialize().await?;
 
         let config_path = self.get_filter_config_file_path();
-        self.write_json_file(&config_path, config).await?;
+        self.write_json_file(&config_path, config.clone()).await?;
 
         Ok(())
     }
@@ -446,11 +835,11 @@ impl PersistenceStore for JsonStore {
     async fn load_filter_config(&self) -> Result<Option<FilterConfig>, Error> {
         let config_path = self.get_filter_config_file_path();
 
-        if !config_path.exists() {
+        let Some(path) = self.readable_file_path(&config_path).await? else {
             return Ok(None);
-        }
+        };
 
-        let config: FilterConfig = self.read_json_file(&config_path).await?;
+        let config: FilterConfig = self.read_json_file(&path).await?;
         Ok(Some(config))
     }
 
@@ -461,7 +850,8 @@ impl PersistenceStore for JsonStore {
         self.initialize().await?;
 
         let history_path = self.get_history_file_path();
-        self.write_json_file(&history_path, history).await?;
+        self.write_json_file(&history_path, history.to_vec())
+            .await?;
         Ok(history.len())
     }
 
@@ -471,23 +861,36 @@ impl PersistenceStore for JsonStore {
     ) -> Result<Vec<crate::IssueHistory>, Error> {
         let history_path = self.get_history_file_path();
 
-        if !history_path.exists() {
+        let Some(path) = self.readable_file_path(&history_path).await? else {
             return Ok(Vec::new());
-        }
+        };
 
-        let all_history: Vec<crate::IssueHistory> = self.read_json_file(&history_path).await?;
+        let all_history: Vec<crate::IssueHistory> = self.read_json_file(&path).await?;
         let filtered = self.apply_history_filter(&all_history, filter);
         Ok(filtered)
     }
 
+    fn stream_issue_history(
+        &self,
+        filter: crate::HistoryFilter,
+    ) -> Pin<Box<dyn Stream<Item = Result<crate::IssueHistory, Error>> + Send + '_>> {
+        // 履歴ファイルは1つのJSON配列のため、最初に要求された時点で読み込み、
+        // フィルター適用後のレコードを1件ずつ返す
+        Box::pin(
+            stream::once(async move { self.load_issue_history(&filter).await })
+                .map_ok(|histories| stream::iter(histories.into_iter().map(Ok)))
+                .try_flatten(),
+        )
+    }
+
     async fn get_history_stats(&self) -> Result<crate::HistoryStats, Error> {
         let history_path = self.get_history_file_path();
 
-        if !history_path.exists() {
+        let Some(path) = self.readable_file_path(&history_path).await? else {
             return Ok(crate::HistoryStats::new());
-        }
+        };
 
-        let all_history: Vec<crate::IssueHistory> = self.read_json_file(&history_path).await?;
+        let all_history: Vec<crate::IssueHistory> = self.read_json_file(&path).await?;
         let mut stats = crate::HistoryStats::new();
         stats.update(&all_history);
         Ok(stats)
@@ -496,11 +899,11 @@ impl PersistenceStore for JsonStore {
     async fn delete_issue_history(&mut self, issue_keys: &[String]) -> Result<usize, Error> {
         let history_path = self.get_history_file_path();
 
-        if !history_path.exists() {
+        let Some(path) = self.readable_file_path(&history_path).await? else {
             return Ok(0);
-        }
+        };
 
-        let all_history: Vec<crate::IssueHistory> = self.read_json_file(&history_path).await?;
+        let all_history: Vec<crate::IssueHistory> = self.read_json_file(&path).await?;
         let original_len = all_history.len();
 
         let filtered_history: Vec<crate::IssueHistory> = all_history
@@ -509,7 +912,7 @@ impl PersistenceStore for JsonStore {
             .collect();
 
         let deleted_count = original_len - filtered_history.len();
-        self.write_json_file(&history_path, &filtered_history)
+        self.write_json_file(&history_path, filtered_history)
             .await?;
         Ok(deleted_count)
     }
@@ -518,9 +921,11 @@ impl PersistenceStore for JsonStore {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::HistoryFilter;
     use crate::models::{IssueFields, IssueType, Priority, Project, Status, StatusCategory, User};
-    use std::collections::HashMap;
+    use crate::testing::{
+        sample_custom_field_issues, sample_resolution_issues, sample_sort_issues,
+    };
+    use crate::{CustomFieldFilter, CustomFieldOperator, HistoryFilter};
     use tempfile::TempDir;
 
     fn create_test_issue(key: &str, project_key: &str, status: &str) -> Issue {
@@ -591,8 +996,13 @@ mod tests {
             reporter,
             created: Utc::now(),
             updated: Utc::now(),
+            resolution: None,
+            time_tracking: None,
+            votes: None,
+            watches: None,
             resolution_date: None,
             project: Some(project),
+            parent: None,
             custom_fields: HashMap::new(),
         };
 
@@ -602,6 +1012,7 @@ mod tests {
             fields,
             self_url: "http://example.com".to_string(),
             changelog: None,
+            rendered_fields: None,
         }
     }
 
@@ -664,6 +1075,122 @@ mod tests {
         assert_eq!(issue_keys, vec!["DEMO-1", "TEST-1", "TEST-2"]);
     }
 
+    #[tokio::test]
+    async fn test_json_store_save_and_load_many_issues() {
+        // 大量のIssueを逐次書き込みで保存しても全件を正しく読み込めることをテスト
+        for use_compression in [true, false] {
+            let temp_dir = TempDir::new().unwrap();
+            let mut store = JsonStore::new(temp_dir.path()).with_compression(use_compression);
+
+            let issues: Vec<Issue> = (0..10_000)
+                .map(|i| create_test_issue(&format!("BULK-{}", i), "BULK", "Open"))
+                .collect();
+
+            let saved_count = store.save_issues(&issues).await.unwrap();
+            assert_eq!(saved_count, 10_000);
+
+            let loaded = store.load_all_issues().await.unwrap();
+            assert_eq!(loaded.len(), 10_000);
+            let mut keys: Vec<String> = loaded.into_iter().map(|i| i.key).collect();
+            keys.sort();
+            let mut expected: Vec<String> = issues.into_iter().map(|i| i.key).collect();
+            expected.sort();
+            assert_eq!(keys, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_json_store_export_ndjson() {
+        // NDJSON形式で書き出したIssueが元のIssueに復元できることをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+
+        let issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "In Progress"),
+            create_test_issue("DEMO-1", "DEMO", "Done"),
+        ];
+        store.save_issues(&issues).await.unwrap();
+
+        let filter = IssueFilter::new().project_keys(vec!["TEST".to_string()]);
+        let mut output: Vec<u8> = Vec::new();
+        let exported = store.export_ndjson(&filter, &mut output).await.unwrap();
+        assert_eq!(exported, 2);
+
+        // 1行に1つのIssueが書き出されている
+        let text = String::from_utf8(output).unwrap();
+        let parsed: Vec<Issue> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(parsed.len(), exported);
+
+        // 通常の読み込みと同じIssueが同じ順序で出力される
+        let expected = store.load_issues(&filter).await.unwrap();
+        let parsed_keys: Vec<&str> = parsed.iter().map(|i| i.key.as_str()).collect();
+        let expected_keys: Vec<&str> = expected.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(parsed_keys, expected_keys);
+        assert_eq!(parsed[0].fields.summary, expected[0].fields.summary);
+    }
+
+    #[tokio::test]
+    async fn test_json_store_export_csv_round_trip() {
+        // CSVに書き出したIssueを読み直すと、クオートが必要な値も含めて元の値に戻ることをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+        store.initialize().await.unwrap();
+
+        let mut issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "In Progress"),
+            create_test_issue("DEMO-1", "DEMO", "Done"),
+        ];
+        issues[0].fields.summary = "Login fails, \"Safari\" only\nsecond line".to_string();
+        store.save_issues(&issues).await.unwrap();
+
+        let filter = IssueFilter::new()
+            .project_keys(vec!["TEST".to_string()])
+            .sort_order(SortOrder::KeyAsc);
+        let path = temp_dir.path().join("issues.csv");
+        let exported = store.export_csv(&filter, &path).await.unwrap();
+        assert_eq!(exported, 2);
+
+        // 再読込
+        let mut reader = csv::Reader::from_path(&path).unwrap();
+        let headers: Vec<String> = reader.headers().unwrap().iter().map(String::from).collect();
+        assert_eq!(headers, crate::CSV_EXPORT_COLUMNS);
+        let rows: Vec<csv::StringRecord> = reader.records().map(|r| r.unwrap()).collect();
+        assert_eq!(rows.len(), exported);
+
+        let expected = store.load_issues(&filter).await.unwrap();
+        for (row, issue) in rows.iter().zip(&expected) {
+            assert_eq!(&row[0], issue.key);
+            assert_eq!(&row[1], issue.fields.summary);
+            assert_eq!(&row[2], issue.fields.status.name);
+            assert_eq!(&row[3], "Medium");
+            assert_eq!(&row[4], "Task");
+            assert_eq!(&row[5], "TEST");
+            assert_eq!(&row[6], "");
+            assert_eq!(&row[7], "Test User");
+            let created: DateTime<Utc> = row[8].parse().unwrap();
+            assert_eq!(created, issue.fields.created);
+        }
+        assert_eq!(&rows[0][1], "Login fails, \"Safari\" only\nsecond line");
+
+        // 一致するIssueが無い場合もヘッダー行のみのファイルを作成する
+        let empty_filter = IssueFilter::new().project_keys(vec!["NONE".to_string()]);
+        let empty_path = temp_dir.path().join("empty.csv");
+        assert_eq!(
+            store.export_csv(&empty_filter, &empty_path).await.unwrap(),
+            0
+        );
+        let content = std::fs::read_to_string(&empty_path).unwrap();
+        assert_eq!(
+            content,
+            format!("{}\n", crate::CSV_EXPORT_COLUMNS.join(","))
+        );
+    }
+
     #[tokio::test]
     async fn test_json_store_filter_issues() {
         // JsonStoreでIssueのフィルタリングが正しく動作することをテスト
@@ -721,6 +1248,81 @@ mod tests {
         assert_eq!(filtered_count, 2);
     }
 
+    #[tokio::test]
+    async fn test_json_store_append_issues_round_trip() {
+        // ファイルが無い状態からappend_issues()で追記したIssueがそのまま読み込めることをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+
+        let issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "In Progress"),
+        ];
+        let added = store.append_issues(&issues).await.unwrap();
+        assert_eq!(added, 2);
+
+        // 読み込み結果は作成日時降順のため、キー順に揃えて比較する
+        let mut loaded = store.load_all_issues().await.unwrap();
+        loaded.sort_by(|a, b| a.key.cmp(&b.key));
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&issues).unwrap()
+        );
+        assert_eq!(store.get_stats().await.unwrap().total_issues, 2);
+
+        // 一時ファイルはリネーム済みで残っていない
+        assert!(temp_files_beside(&store.get_issues_file_path()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_json_store_append_issues_upserts_by_key() {
+        // 同一キーは新しい方で置き換え、新規キーのみ追加件数に数えることをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+
+        let base = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "Open"),
+        ];
+        store.save_issues(&base).await.unwrap();
+
+        // TEST-1は更新日時が新しいので置き換わる
+        let mut newer = create_test_issue("TEST-1", "TEST", "Done");
+        newer.fields.updated = base[0].fields.updated + chrono::Duration::hours(1);
+        // TEST-2は更新日時が古いので既存のまま
+        let mut older = create_test_issue("TEST-2", "TEST", "Done");
+        older.fields.updated = base[1].fields.updated - chrono::Duration::hours(1);
+        let added = store
+            .append_issues(&[
+                newer,
+                older,
+                create_test_issue("TEST-3", "TEST", "In Progress"),
+            ])
+            .await
+            .unwrap();
+        assert_eq!(added, 1);
+
+        let mut loaded = store.load_all_issues().await.unwrap();
+        loaded.sort_by(|a, b| a.key.cmp(&b.key));
+        let statuses: Vec<(&str, &str)> = loaded
+            .iter()
+            .map(|i| (i.key.as_str(), i.fields.status.name.as_str()))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("TEST-1", "Done"),
+                ("TEST-2", "Open"),
+                ("TEST-3", "In Progress")
+            ]
+        );
+
+        // 同じIssueを再度追記しても件数は増えない
+        let added = store.append_issues(&loaded).await.unwrap();
+        assert_eq!(added, 0);
+        assert_eq!(store.count_issues(&IssueFilter::new()).await.unwrap(), 3);
+    }
+
     #[tokio::test]
     async fn test_json_store_delete_issues() {
         // JsonStore::delete_issues()が正しく動作することをテスト
@@ -749,8 +1351,8 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_json_store_get_stats() {
-        // JsonStore::get_stats()が正しく動作することをテスト
+    async fn test_json_store_flush_writes_latest_metadata() {
+        // flush()後のメタデータファイルが削除後の最新の統計を反映することをテスト
         let temp_dir = TempDir::new().unwrap();
         let mut store = JsonStore::new(temp_dir.path());
 
@@ -759,7 +1361,227 @@ mod tests {
             create_test_issue("TEST-2", "TEST", "In Progress"),
             create_test_issue("DEMO-1", "DEMO", "Done"),
         ];
-
+        store.save_issues(&issues).await.unwrap();
+        store
+            .delete_issues(&["TEST-1".to_string(), "DEMO-1".to_string()])
+            .await
+            .unwrap();
+
+        store.flush().await.unwrap();
+
+        let metadata: StorageStats = store
+            .read_json_file(&store.get_metadata_file_path())
+            .await
+            .unwrap();
+        assert_eq!(metadata.total_issues, 1);
+        assert_eq!(metadata.issues_by_project.get("TEST"), Some(&1));
+        assert_eq!(metadata.issues_by_project.get("DEMO"), None);
+    }
+
+    #[tokio::test]
+    async fn test_json_store_read_detects_gzip() {
+        // 圧縮設定に関係なく、gzipマジックナンバーで解凍の要否を判定することをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let issues = vec![create_test_issue("TEST-1", "TEST", "Open")];
+
+        let mut compressed_store = JsonStore::new(temp_dir.path()).with_compression(true);
+        let plain_store = JsonStore::new(temp_dir.path()).with_compression(false);
+        compressed_store.initialize().await.unwrap();
+
+        // 圧縮して書き込んだファイルを非圧縮設定のストアで読み込む
+        let gz_path = temp_dir.path().join("issues").join("gz.json");
+        compressed_store
+            .write_json_file(&gz_path, issues.clone())
+            .await
+            .unwrap();
+        let loaded: Vec<Issue> = plain_store.read_json_file(&gz_path).await.unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].key, "TEST-1");
+
+        // 非圧縮で書き込んだファイルを圧縮設定のストアで読み込む
+        let plain_path = temp_dir.path().join("issues").join("plain.json.gz");
+        plain_store
+            .write_json_file(&plain_path, issues)
+            .await
+            .unwrap();
+        let loaded: Vec<Issue> = compressed_store.read_json_file(&plain_path).await.unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].key, "TEST-1");
+    }
+
+    #[tokio::test]
+    async fn test_json_store_reopen_with_other_compression() {
+        // 圧縮設定を切り替えてディレクトリを開き直しても、保存済みのデータが読み書きできることをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "Done"),
+        ];
+
+        let mut compressed_store = JsonStore::new(temp_dir.path()).with_compression(true);
+        compressed_store.save_issues(&issues).await.unwrap();
+
+        // 圧縮なしで開き直して読み込む
+        let mut plain_store = JsonStore::new(temp_dir.path()).with_compression(false);
+        let loaded = plain_store.load_all_issues().await.unwrap();
+        assert_eq!(loaded.len(), 2);
+
+        // 追加の保存は圧縮設定に対応する拡張子のファイルに書き込まれ、古いファイルは削除される
+        plain_store
+            .append_issues(&[create_test_issue("TEST-3", "TEST", "Open")])
+            .await
+            .unwrap();
+        let issues_dir = temp_dir.path().join("issues");
+        let raw = std::fs::read(issues_dir.join("issues.json")).unwrap();
+        assert!(!raw.starts_with(&GZIP_MAGIC));
+        assert!(!issues_dir.join("issues.json.gz").exists());
+
+        // 圧縮ありで開き直しても、圧縮なしで書き込んだ内容が読める
+        let reopened = JsonStore::new(temp_dir.path()).with_compression(true);
+        assert_eq!(reopened.load_all_issues().await.unwrap().len(), 3);
+    }
+
+    /// `path`と同じディレクトリにある、`path`の一時ファイルの一覧
+    fn temp_files_beside(path: &Path) -> Vec<PathBuf> {
+        let mut prefix = path.file_name().unwrap().to_os_string();
+        prefix.push(TEMP_FILE_SUFFIX);
+        let prefix = prefix.to_string_lossy().into_owned();
+
+        std::fs::read_dir(path.parent().unwrap())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|p| {
+                p.file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .starts_with(&prefix)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_temp_file_path_is_unique() {
+        // 同じプロセスから同じファイルへの書き込みでも一時ファイルが重ならないことをテスト
+        let path = Path::new("/data/issues/issues.json.gz");
+        let first = temp_file_path(path);
+        let second = temp_file_path(path);
+
+        assert_ne!(first, second);
+        let prefix = format!("/data/issues/issues.json.gz.tmp.{}.", std::process::id());
+        assert!(first.to_string_lossy().starts_with(&prefix));
+        assert!(second.to_string_lossy().starts_with(&prefix));
+    }
+
+    #[tokio::test]
+    async fn test_json_store_write_survives_interrupted_write() {
+        // 書き込み途中の一時ファイルが残っても既存データが読めることをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+
+        let issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "Open"),
+        ];
+        store.save_issues(&issues).await.unwrap();
+
+        // 書き込み中にクラッシュした状況を模して、途中までの一時ファイルを残す
+        let issues_path = store.get_issues_file_path();
+        let temp_path = temp_file_path(&issues_path);
+        std::fs::write(&temp_path, GZIP_MAGIC).unwrap();
+
+        let loaded = store.load_all_issues().await.unwrap();
+        assert_eq!(loaded.len(), 2);
+
+        // シリアライズに失敗した書き込みでも本来のファイルは置き換わらず、一時ファイルも残らない
+        let invalid: HashMap<(u8, u8), u8> = HashMap::from([((1, 2), 3)]);
+        assert!(store.write_json_file(&issues_path, invalid).await.is_err());
+        assert_eq!(temp_files_beside(&issues_path), vec![temp_path.clone()]);
+        let loaded = store.load_all_issues().await.unwrap();
+        assert_eq!(loaded.len(), 2);
+
+        // 次の保存は別の一時ファイルを使い、残っていた一時ファイルには触れずに置き換える
+        store
+            .save_issues(&[create_test_issue("TEST-3", "TEST", "Done")])
+            .await
+            .unwrap();
+        assert_eq!(temp_files_beside(&issues_path), vec![temp_path.clone()]);
+        let loaded = store.load_all_issues().await.unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].key, "TEST-3");
+    }
+
+    #[tokio::test]
+    async fn test_json_store_stats_by_resolution() {
+        // 解決状況別の件数が正しく集計されることをテスト（未解決は含まない）
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+
+        store
+            .save_issues(&sample_resolution_issues())
+            .await
+            .unwrap();
+
+        let stats = store.get_stats().await.unwrap();
+        assert_eq!(stats.issues_by_resolution.get("Done"), Some(&2));
+        assert_eq!(stats.issues_by_resolution.get("Won't Fix"), Some(&1));
+        assert_eq!(stats.issues_by_resolution.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_json_store_stats_by_assignee_and_priority() {
+        // 担当者別・優先度別の件数が正しく集計されることをテスト（未割り当ては専用のキー）
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+        let priority = |name: &str| Priority {
+            id: name.to_string(),
+            name: name.to_string(),
+            self_url: format!("http://example.com/priority/{}", name),
+            description: None,
+            icon_url: None,
+            status_color: None,
+        };
+
+        // SORT-1: Bob, SORT-2: 未割り当て, SORT-3: Alice
+        let mut issues = sample_sort_issues();
+        issues[0].fields.priority = Some(priority("High"));
+        issues[1].fields.priority = Some(priority("High"));
+        issues[2].fields.priority = None;
+        let mut extra = issues[2].clone();
+        extra.id = "extra".to_string();
+        extra.key = "SORT-4".to_string();
+        extra.fields.priority = Some(priority("Low"));
+        issues.push(extra);
+        store.save_issues(&issues).await.unwrap();
+
+        let stats = store.get_stats().await.unwrap();
+        let expected_assignees: HashMap<String, usize> = [
+            ("Alice".to_string(), 2),
+            ("Bob".to_string(), 1),
+            (UNASSIGNED_BUCKET.to_string(), 1),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(stats.issues_by_assignee, expected_assignees);
+
+        let expected_priorities: HashMap<String, usize> =
+            [("High".to_string(), 2), ("Low".to_string(), 1)]
+                .into_iter()
+                .collect();
+        assert_eq!(stats.issues_by_priority, expected_priorities);
+    }
+
+    #[tokio::test]
+    async fn test_json_store_get_stats() {
+        // JsonStore::get_stats()が正しく動作することをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+
+        let issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "In Progress"),
+            create_test_issue("DEMO-1", "DEMO", "Done"),
+        ];
+
         store.save_issues(&issues).await.unwrap();
 
         let stats = store.get_stats().await.unwrap();
@@ -772,6 +1594,73 @@ mod tests {
         assert_eq!(stats.issues_by_status.get("Done"), Some(&1));
     }
 
+    #[tokio::test]
+    async fn test_json_store_parent_round_trip() {
+        // サブタスクの親情報が保存・読み込み後も保持され、親キーで絞り込めることをテスト
+        use crate::models::{ParentFields, ParentRef};
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+
+        let parent = create_test_issue("TEST-1", "TEST", "In Progress");
+        let mut subtask = create_test_issue("TEST-2", "TEST", "Open");
+        subtask.fields.parent = Some(ParentRef {
+            id: "TEST-1".to_string(),
+            key: "TEST-1".to_string(),
+            self_url: None,
+            fields: ParentFields {
+                summary: Some(parent.fields.summary.clone()),
+                status: Some(parent.fields.status.clone()),
+                issue_type: Some(parent.fields.issue_type.clone()),
+            },
+        });
+
+        store.save_issues(&[parent, subtask]).await.unwrap();
+
+        let filter = IssueFilter::new().parent_keys(vec!["TEST-1".to_string()]);
+        let children = store.load_issues(&filter).await.unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].key, "TEST-2");
+
+        let loaded_parent = children[0].fields.parent.as_ref().unwrap();
+        assert_eq!(loaded_parent.key, "TEST-1");
+        assert_eq!(loaded_parent.summary(), Some("Test issue TEST-1"));
+        assert_eq!(loaded_parent.status_name(), Some("In Progress"));
+    }
+
+    #[tokio::test]
+    async fn test_json_store_group_counts() {
+        // ステータス別の集計がStorageStatsと一致することをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+
+        let issues = vec![
+            create_test_issue("TEST-1", "TEST", "Open"),
+            create_test_issue("TEST-2", "TEST", "Open"),
+            create_test_issue("TEST-3", "TEST", "Done"),
+            create_test_issue("DEMO-1", "DEMO", "Open"),
+        ];
+        store.save_issues(&issues).await.unwrap();
+
+        let grouped = store
+            .group_counts(&IssueFilter::new(), GroupBy::Status)
+            .await
+            .unwrap();
+        assert_eq!(
+            grouped,
+            vec![("Open".to_string(), 3), ("Done".to_string(), 1)]
+        );
+
+        let stats = store.get_stats().await.unwrap();
+        let grouped_map: HashMap<String, usize> = grouped.into_iter().collect();
+        assert_eq!(grouped_map, stats.issues_by_status);
+
+        // フィルター条件が集計に反映される
+        let filter = IssueFilter::new().project_keys(vec!["TEST".to_string()]);
+        let grouped = store.group_counts(&filter, GroupBy::Project).await.unwrap();
+        assert_eq!(grouped, vec![("TEST".to_string(), 3)]);
+    }
+
     #[tokio::test]
     async fn test_json_store_filter_config() {
         // JsonStoreでFilterConfigの保存と読み込みが正しく動作することをテスト
@@ -804,6 +1693,34 @@ mod tests {
         assert_eq!(loaded_config.filter.statuses, vec!["Open"]);
     }
 
+    #[tokio::test]
+    async fn test_json_store_touch_filter_config() {
+        // フィルター設定の使用記録で使用回数と最終使用日時が更新されることをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+        store.initialize().await.unwrap();
+
+        let filter = IssueFilter::new().project_keys(vec!["TEST".to_string()]);
+        let config = FilterConfig::new("my_filter".to_string(), "My Filter".to_string(), filter);
+        store.save_filter_config(&config).await.unwrap();
+
+        store.touch_filter_config("my_filter").await.unwrap();
+        let first = store.load_filter_config().await.unwrap().unwrap();
+        assert_eq!(first.usage_count, 1);
+        let first_used_at = first.last_used_at.unwrap();
+
+        store.touch_filter_config("my_filter").await.unwrap();
+        let second = store.load_filter_config().await.unwrap().unwrap();
+        assert_eq!(second.usage_count, 2);
+        assert!(second.last_used_at.unwrap() >= first_used_at);
+
+        // 存在しないIDはNotFound
+        assert!(matches!(
+            store.touch_filter_config("missing").await,
+            Err(Error::NotFound(_))
+        ));
+    }
+
     #[tokio::test]
     async fn test_json_store_save_and_load_history() {
         // JsonStoreで履歴データの保存と読み込みが正しく動作することをテスト
@@ -872,6 +1789,39 @@ mod tests {
         assert_eq!(status_histories[0].field_name, "status");
     }
 
+    #[tokio::test]
+    async fn test_json_store_stream_issue_history() {
+        // ストリームで取得した履歴がload_issue_historyと同じ内容・順序になることをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+        store.initialize().await.unwrap();
+
+        let base_time = Utc::now();
+        let histories: Vec<crate::IssueHistory> = (0..20)
+            .map(|i| {
+                crate::IssueHistory::new(
+                    format!("{}", 10000 + i % 5),
+                    format!("TEST-{}", i % 5),
+                    format!("change_{}", i),
+                    base_time + chrono::Duration::minutes(i as i64),
+                    "status".to_string(),
+                )
+            })
+            .collect();
+        store.save_issue_history(&histories).await.unwrap();
+
+        let filter = crate::HistoryFilter::new().issue_keys(vec!["TEST-1".to_string()]);
+        let loaded = store.load_issue_history(&filter).await.unwrap();
+        let streamed: Vec<crate::IssueHistory> = store
+            .stream_issue_history(filter)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(streamed.len(), 4);
+        assert_eq!(streamed, loaded);
+    }
+
     #[tokio::test]
     async fn test_json_store_history_stats() {
         // JsonStoreで履歴統計が正しく動作することをテスト
@@ -955,4 +1905,368 @@ mod tests {
         assert_eq!(remaining_histories.len(), 1);
         assert_eq!(remaining_histories[0].issue_key, "TEST-124");
     }
+
+    #[tokio::test]
+    async fn test_json_store_sort_orders() {
+        // 担当者・報告者・ステータス・種別のソートが正しく動作することをテスト（未割り当ては末尾）
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+
+        store.save_issues(&sample_sort_issues()).await.unwrap();
+
+        let cases = vec![
+            (SortOrder::AssigneeAsc, vec!["SORT-3", "SORT-1", "SORT-2"]),
+            (SortOrder::AssigneeDesc, vec!["SORT-1", "SORT-3", "SORT-2"]),
+            (SortOrder::ReporterAsc, vec!["SORT-2", "SORT-3", "SORT-1"]),
+            (SortOrder::ReporterDesc, vec!["SORT-1", "SORT-3", "SORT-2"]),
+            (SortOrder::StatusAsc, vec!["SORT-2", "SORT-3", "SORT-1"]),
+            (SortOrder::StatusDesc, vec!["SORT-1", "SORT-3", "SORT-2"]),
+            (SortOrder::TypeAsc, vec!["SORT-2", "SORT-3", "SORT-1"]),
+            (SortOrder::TypeDesc, vec!["SORT-1", "SORT-3", "SORT-2"]),
+        ];
+
+        for (sort_order, expected) in cases {
+            let filter = IssueFilter::new().sort_order(sort_order.clone());
+            let keys: Vec<String> = store
+                .load_issues(&filter)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|i| i.key)
+                .collect();
+            assert_eq!(keys, expected, "{:?}", sort_order);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_json_store_text_search_adf_description() {
+        // 本文検索はADFの説明をプレーンテキストとして照合し、構造上のキーには一致しないことをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+
+        let mut with_description = create_test_issue("TEXT-1", "TEXT", "Open");
+        with_description.fields.description = Some(serde_json::json!({
+            "type": "doc",
+            "version": 1,
+            "content": [{
+                "type": "paragraph",
+                "content": [{"type": "text", "text": "Deadlock when saving drafts"}]
+            }]
+        }));
+        let mut plain = create_test_issue("TEXT-2", "TEXT", "Open");
+        plain.fields.summary = "Improve deadlock detection".to_string();
+        store.save_issues(&[with_description, plain]).await.unwrap();
+
+        let search = |text: &str| {
+            IssueFilter::new()
+                .text_search(text.to_string())
+                .sort_order(SortOrder::KeyAsc)
+        };
+        let keys =
+            |issues: Vec<Issue>| -> Vec<String> { issues.into_iter().map(|i| i.key).collect() };
+
+        // 説明の本文のみにある語で一致する
+        let found = store.load_issues(&search("drafts")).await.unwrap();
+        assert_eq!(keys(found), vec!["TEXT-1"]);
+
+        // サマリーと説明のどちらかにあれば一致する（大文字小文字は区別しない）
+        let found = store.load_issues(&search("DEADLOCK")).await.unwrap();
+        assert_eq!(keys(found), vec!["TEXT-1", "TEXT-2"]);
+
+        // ADFのノード種別や属性名には一致しない
+        for structural in ["paragraph", "content", "version"] {
+            let found = store.load_issues(&search(structural)).await.unwrap();
+            assert!(found.is_empty(), "{} should not match", structural);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_json_store_unassigned_filter() {
+        // 未割り当てフィルターで担当者の無いIssueのみ取得できることをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+
+        store.save_issues(&sample_sort_issues()).await.unwrap();
+
+        let cases = vec![
+            (IssueFilter::new().assignee_unassigned(true), vec!["SORT-2"]),
+            (
+                IssueFilter::new().assignee_unassigned(false),
+                vec!["SORT-1", "SORT-3"],
+            ),
+            // 担当者指定と組み合わせた場合はいずれかに一致すればよい
+            (
+                IssueFilter::new()
+                    .assignees(vec!["Alice".to_string()])
+                    .assignee_unassigned(true),
+                vec!["SORT-2", "SORT-3"],
+            ),
+        ];
+
+        for (filter, expected) in cases {
+            let keys: Vec<String> = store
+                .load_issues(&filter.clone().sort_order(SortOrder::KeyAsc))
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|i| i.key)
+                .collect();
+            assert_eq!(keys, expected, "{:?}", filter);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_json_store_custom_field_filters() {
+        // 選択リストの等価比較とストーリーポイントの数値比較で絞り込めることをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+
+        store
+            .save_issues(&sample_custom_field_issues())
+            .await
+            .unwrap();
+
+        let keys = |issues: Vec<Issue>| {
+            let mut keys: Vec<String> = issues.into_iter().map(|i| i.key).collect();
+            keys.sort();
+            keys
+        };
+
+        // 選択リストの値で一致
+        let filter = IssueFilter::new().custom_field_filter(CustomFieldFilter::new(
+            "customfield_10020",
+            CustomFieldOperator::Eq,
+            serde_json::json!("High"),
+        ));
+        let issues = store.load_issues(&filter).await.unwrap();
+        assert_eq!(keys(issues), vec!["CF-1", "CF-3"]);
+
+        // ストーリーポイントが4より大きい
+        let filter = IssueFilter::new().custom_field_filter(CustomFieldFilter::new(
+            "customfield_10016",
+            CustomFieldOperator::Gt,
+            serde_json::json!(4),
+        ));
+        let issues = store.load_issues(&filter).await.unwrap();
+        assert_eq!(keys(issues), vec!["CF-2", "CF-3"]);
+
+        // 複数条件はANDで結合される
+        let filter = IssueFilter::new()
+            .custom_field_filter(CustomFieldFilter::new(
+                "customfield_10020",
+                CustomFieldOperator::Eq,
+                serde_json::json!("High"),
+            ))
+            .custom_field_filter(CustomFieldFilter::new(
+                "customfield_10016",
+                CustomFieldOperator::Lt,
+                serde_json::json!(5),
+            ));
+        let issues = store.load_issues(&filter).await.unwrap();
+        assert_eq!(keys(issues), vec!["CF-1"]);
+    }
+
+    #[tokio::test]
+    async fn test_json_store_load_projection() {
+        // キーとステータスのみを要求した場合に、その項目だけが設定されることをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+
+        let issues = vec![
+            create_test_issue("PROJ-1", "PROJ", "Open"),
+            create_test_issue("PROJ-2", "PROJ", "Done"),
+            create_test_issue("OTHER-1", "OTHER", "Open"),
+        ];
+        store.save_issues(&issues).await.unwrap();
+
+        let filter = IssueFilter::new()
+            .project_keys(vec!["PROJ".to_string()])
+            .sort_order(SortOrder::KeyAsc);
+        let projections = store
+            .load_projection(&filter, &[IssueField::Key, IssueField::Status])
+            .await
+            .unwrap();
+
+        assert_eq!(projections.len(), 2);
+        assert_eq!(projections[0].key.as_deref(), Some("PROJ-1"));
+        assert_eq!(projections[0].status.as_deref(), Some("Open"));
+        assert_eq!(projections[1].key.as_deref(), Some("PROJ-2"));
+        assert_eq!(projections[1].status.as_deref(), Some("Done"));
+
+        // 要求していない項目は設定されない
+        assert!(projections[0].summary.is_none());
+        assert!(projections[0].created.is_none());
+
+        // 日時の項目も取得できる
+        let projections = store
+            .load_projection(&filter.limit(1), &[IssueField::Created])
+            .await
+            .unwrap();
+        assert_eq!(projections.len(), 1);
+        assert!(projections[0].created.is_some());
+        assert!(projections[0].key.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_json_store_time_tracking_round_trip() {
+        // 時間管理情報が保存・読み込みで失われないことをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+
+        let time_tracking = crate::TimeTracking {
+            original_estimate: Some("1d".to_string()),
+            remaining_estimate: Some("4h".to_string()),
+            time_spent: Some("4h".to_string()),
+            original_estimate_seconds: Some(28800),
+            remaining_estimate_seconds: Some(14400),
+            time_spent_seconds: Some(14400),
+        };
+        let mut issue = create_test_issue("TIME-1", "TIME", "Open");
+        issue.fields.time_tracking = Some(time_tracking.clone());
+        store
+            .save_issues(&[issue, create_test_issue("TIME-2", "TIME", "Open")])
+            .await
+            .unwrap();
+
+        let loaded = store
+            .load_issues(&IssueFilter::new().sort_order(SortOrder::KeyAsc))
+            .await
+            .unwrap();
+        assert_eq!(loaded[0].fields.time_tracking, Some(time_tracking));
+        assert_eq!(loaded[1].fields.time_tracking, None);
+    }
+
+    #[tokio::test]
+    async fn test_json_store_group_counts_by_category() {
+        // 独自のステータス名でもステータスカテゴリで集計されることをテスト
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+
+        let with_category = |key: &str, status: &str, category: &str| {
+            let mut issue = create_test_issue(key, "CAT", status);
+            issue.fields.status.status_category.key = category.to_string();
+            issue
+        };
+        let issues = vec![
+            with_category("CAT-1", "Backlog", "new"),
+            with_category("CAT-2", "Code Review", "indeterminate"),
+            with_category("CAT-3", "QA", "indeterminate"),
+            with_category("CAT-4", "Waiting for Deploy", "indeterminate"),
+            with_category("CAT-5", "Shipped", "done"),
+            with_category("CAT-6", "Rejected", "done"),
+        ];
+        store.save_issues(&issues).await.unwrap();
+
+        let grouped = store
+            .group_counts_by_category(&IssueFilter::new())
+            .await
+            .unwrap();
+        assert_eq!(
+            grouped,
+            vec![
+                ("indeterminate".to_string(), 3),
+                ("done".to_string(), 2),
+                ("new".to_string(), 1),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_json_store_transaction_rolls_back_on_error() {
+        // トランザクション内でエラーが発生するとIssueと履歴の両方が元に戻ることをテスト
+        use crate::IssueHistory;
+        use chrono::Utc;
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path()).with_compression(false);
+        store
+            .save_issues(&[create_test_issue("OLD-1", "OLD", "Open")])
+            .await
+            .unwrap();
+
+        let issues = vec![
+            create_test_issue("TX-1", "TX", "Open"),
+            create_test_issue("TX-2", "TX", "Open"),
+        ];
+        let histories = vec![
+            IssueHistory::new(
+                "10000".to_string(),
+                "TX-1".to_string(),
+                "change_1".to_string(),
+                Utc::now(),
+                "status".to_string(),
+            )
+            .with_field_change(
+                Some("Open".to_string()),
+                Some("Done".to_string()),
+                Some("Open".to_string()),
+                Some("Done".to_string()),
+            ),
+        ];
+
+        let result: Result<(), Error> = store
+            .transaction(|store| {
+                let issues = issues.clone();
+                let histories = histories.clone();
+                Box::pin(async move {
+                    store.save_issues(&issues).await?;
+                    store.save_issue_history(&histories).await?;
+
+                    // トランザクション内では未コミットの書き込みが見える
+                    assert_eq!(store.load_all_issues().await?.len(), 2);
+
+                    Err(Error::InvalidInput("途中で失敗".to_string()))
+                })
+            })
+            .await;
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+
+        // 開始前の状態のまま、一時ファイルも残らない
+        let loaded = store.load_all_issues().await.unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].key, "OLD-1");
+        assert!(
+            store
+                .load_issue_history(&HistoryFilter::new())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(store.get_stats().await.unwrap().total_issues, 1);
+        assert!(
+            !temp_dir
+                .path()
+                .join("issues")
+                .join("issues.json.txn")
+                .exists()
+        );
+
+        // 成功した場合はコミットされる
+        store
+            .transaction(|store| {
+                Box::pin(async move {
+                    store.save_issues(&issues).await?;
+                    store.save_issue_history(&histories).await?;
+                    Ok(())
+                })
+            })
+            .await
+            .unwrap();
+        assert_eq!(store.load_all_issues().await.unwrap().len(), 2);
+        assert_eq!(
+            store
+                .load_issue_history(&HistoryFilter::new())
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(
+            !temp_dir
+                .path()
+                .join("issues")
+                .join("issues.json.txn")
+                .exists()
+        );
+    }
 }
@@ -12,10 +12,12 @@
 //! - **データ永続化**: JSON（圧縮対応）およびDuckDB形式での保存
 //! - **変更履歴**: Issue変更履歴の取得と管理
 //! - **設定管理**: 認証情報とフィルター条件の永続化
+//! - **Webhook**: Webhookペイロードから`Issue`と変更履歴への変換
 //!
 //! ## サポートAPIエンドポイント
 //!
 //! - `/rest/api/3/search` - Issue検索
+//! - `/rest/api/3/search/jql` - Issue検索（トークン方式のページング）
 //! - `/rest/api/3/project` - プロジェクト一覧
 //! - `/rest/api/3/priority` - 優先度一覧
 //! - `/rest/api/3/issuetype` - Issue種別一覧
@@ -120,53 +122,85 @@
 //! # });
 //! ```
 
+pub mod adf;
+pub mod cancel;
 pub mod changelog_parser;
 pub mod client;
+pub mod clock;
 pub mod config_store;
 pub mod duckdb_store;
 pub mod error;
 pub mod history;
+pub mod issue_diff;
+pub mod jql;
 pub mod json_store;
 pub mod memory;
+pub(crate) mod metadata_cache;
+pub mod metrics;
 pub mod models;
 pub mod persistence;
+pub mod retry;
 pub mod sync;
+#[cfg(test)]
+pub(crate) mod testing;
 pub mod time_filter;
+pub mod webhook;
 
-pub use client::{Auth, JiraClient, JiraConfig};
+pub use adf::{adf_to_plain_text, markdown_to_adf, text_to_adf};
+pub use cancel::CancellationToken;
+pub use client::{
+    ACTOR_HEADER, Auth, ClientMetrics, DEFAULT_TIMEOUT, DEFAULT_USER_AGENT, JiraClient, JiraConfig,
+    RequestInterceptor,
+};
+pub use clock::{Clock, FixedClock, SystemClock};
 pub use error::Error;
 pub use models::*;
+pub use retry::{RetryBudget, RetryPolicy};
 
 // Sync module re-exports
 pub use sync::{
-    ProjectSyncStats, SyncConfig, SyncResult, SyncService, SyncServiceStats, SyncState,
+    ProjectSyncStats, SyncConfig, SyncError, SyncResult, SyncService, SyncServiceStats, SyncState,
 };
 
+// JQL builder re-exports
+pub use jql::{IntoJql, JqlBuilder, OrderDirection};
+
 // Time filter module re-exports
 pub use time_filter::{TimeBasedFilter, TimeChunk, parse_jira_datetime};
 
 // Persistence module re-exports
 pub use persistence::{
-    DateRange, FilterConfig, IssueFilter, PersistenceStore, SortOrder, StorageStats,
+    CSV_EXPORT_COLUMNS, CustomFieldFilter, CustomFieldOperator, DateRange, FilterConfig, GroupBy,
+    IssueField, IssueFilter, IssueProjection, PersistenceStore, SHAREABLE_FILTER_VERSION,
+    SortOrder, StorageStats, UNASSIGNED_BUCKET,
 };
 
 // JSON store re-export
 pub use json_store::JsonStore;
 
 // DuckDB store re-export
-pub use duckdb_store::DuckDBStore;
+pub use duckdb_store::{
+    DEFAULT_OPTIMIZE_THRESHOLD, DEFAULT_READ_POOL_SIZE, DuckDBStore, MaintenanceReport,
+};
 
 // Config store re-exports
 pub use config_store::{AppConfig, ConfigStore, FileConfigStore};
 
 // History re-exports
 pub use history::{
-    ChangeType, HistoryAuthor, HistoryFilter, HistorySortOrder, HistoryStats, IssueHistory,
+    ChangeType, HistoryAuthor, HistoryFilter, HistorySortOrder, HistoryStats, INITIAL_STATUS,
+    IssueHistory, status_transition_graph,
 };
 
+// Issue diff re-exports
+pub use issue_diff::{FieldDiff, diff_issues};
+
 // Changelog parser re-export
 pub use changelog_parser::ChangelogParser;
 
+// Webhook re-exports
+pub use webhook::{WebhookEvent, WebhookEventType, parse_issue_event};
+
 // Memory management re-exports
 pub use memory::{
     DetailStatus, IssueLoader, IssueStream, IssueStreamLoader, LazyIssue, MemoryConfig, MemoryGC,
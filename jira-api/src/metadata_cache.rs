@@ -0,0 +1,94 @@
+//! メタデータ取得APIのレスポンスキャッシュ
+//!
+//! 優先度・課題タイプ・フィールド・ステータスカテゴリーはほとんど変更されないにもかかわらず、
+//! レポート作成のループなどで繰り返し取得されます。[`crate::JiraClient::with_metadata_cache`]で
+//! キャッシュを有効にすると、有効期限内はエンドポイントごとに保存したレスポンスを返し、
+//! 期限切れ後の呼び出しで再取得します。
+
+use chrono::{DateTime, Duration, Utc};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// エンドポイントをキーとするTTL付きキャッシュ（クローンしたクライアント間で共有）
+#[derive(Debug)]
+pub(crate) struct MetadataCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    expires_at: DateTime<Utc>,
+    value: Value,
+}
+
+impl MetadataCache {
+    /// 有効期限を指定してキャッシュを作成
+    pub(crate) fn new(ttl: std::time::Duration) -> Self {
+        Self {
+            ttl: Duration::from_std(ttl).unwrap_or(Duration::MAX),
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 有効期限内のレスポンスを取得
+    pub(crate) fn get(&self, endpoint: &str, now: DateTime<Utc>) -> Option<Value> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(endpoint) {
+            Some(entry) if now < entry.expires_at => Some(entry.value.clone()),
+            Some(_) => {
+                entries.remove(endpoint);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// レスポンスを保存
+    pub(crate) fn insert(&self, endpoint: &str, value: Value, now: DateTime<Utc>) {
+        let expires_at = now
+            .checked_add_signed(self.ttl)
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(endpoint.to_string(), CacheEntry { expires_at, value });
+    }
+
+    /// 全てのエントリを削除
+    pub(crate) fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    #[test]
+    fn test_metadata_cache_expiry() {
+        // 有効期限内はキャッシュを返し、期限切れ後は返さないことをテスト
+        let cache = MetadataCache::new(std::time::Duration::from_secs(60));
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        assert_eq!(cache.get("/rest/api/3/field", now), None);
+
+        cache.insert("/rest/api/3/field", json!([{"id": "summary"}]), now);
+        assert_eq!(
+            cache.get("/rest/api/3/field", now + Duration::seconds(59)),
+            Some(json!([{"id": "summary"}]))
+        );
+        assert_eq!(cache.get("/rest/api/3/priority", now), None);
+        assert_eq!(
+            cache.get("/rest/api/3/field", now + Duration::seconds(60)),
+            None
+        );
+
+        cache.insert("/rest/api/3/field", json!([]), now);
+        cache.clear();
+        assert_eq!(cache.get("/rest/api/3/field", now), None);
+    }
+}
@@ -0,0 +1,149 @@
+//! アジャイル指標の計算
+//!
+//! Issueと変更履歴から、リードタイム（作成→解決）とサイクルタイム（着手→解決）を計算します。
+
+use crate::history::ChangeType;
+use crate::{Issue, IssueHistory};
+use chrono::Duration;
+use std::collections::HashMap;
+
+/// 作業中（In Progress）を表すステータスカテゴリのキー
+pub const IN_PROGRESS_CATEGORY: &str = "indeterminate";
+
+/// Issueのリードタイム（作成から解決まで）を計算する
+///
+/// 未解決のIssueは`None`を返します。
+pub fn lead_time(issue: &Issue) -> Option<Duration> {
+    let resolved = issue.fields.resolution_date?;
+    Some(resolved - issue.fields.created)
+}
+
+/// Issueのサイクルタイム（最初に作業中になってから解決まで）を計算する
+///
+/// 変更履歴にはステータスのカテゴリが含まれないため、ステータス名またはIDから
+/// カテゴリキーへの対応表を`status_categories`で渡します（[`status_categories`]で作成できます）。
+/// `histories`に含まれる他のIssueの履歴は無視します。
+/// 未解決のIssue、または解決までに作業中カテゴリのステータスへ遷移していないIssueは`None`を返します。
+pub fn cycle_time(
+    issue: &Issue,
+    histories: &[IssueHistory],
+    status_categories: &HashMap<String, String>,
+) -> Option<Duration> {
+    let resolved = issue.fields.resolution_date?;
+
+    let started = histories
+        .iter()
+        .filter(|h| h.issue_key == issue.key && h.change_type() == ChangeType::StatusChange)
+        .filter(|h| h.change_timestamp <= resolved)
+        .filter(|h| {
+            [&h.to_display_value, &h.to_value]
+                .into_iter()
+                .flatten()
+                .any(|status| {
+                    status_categories
+                        .get(status)
+                        .is_some_and(|category| category == IN_PROGRESS_CATEGORY)
+                })
+        })
+        .map(|h| h.change_timestamp)
+        .min()?;
+
+    Some(resolved - started)
+}
+
+/// Issueの現在のステータスから、ステータス名とIDをカテゴリキーへ対応付ける表を作成する
+///
+/// 同期済みのIssueから作成すると、プロジェクトで使用中のステータスの分類が得られます。
+pub fn status_categories<'a>(
+    issues: impl IntoIterator<Item = &'a Issue>,
+) -> HashMap<String, String> {
+    let mut categories = HashMap::new();
+    for issue in issues {
+        let status = &issue.fields.status;
+        let category = status.status_category.key.clone();
+        categories.insert(status.name.clone(), category.clone());
+        categories.insert(status.id.clone(), category);
+    }
+    categories
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::sample_issue_json;
+    use chrono::{DateTime, TimeZone, Utc};
+    use serde_json::json;
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, day, hour, 0, 0).unwrap()
+    }
+
+    fn status_change(
+        key: &str,
+        change_id: &str,
+        timestamp: DateTime<Utc>,
+        from: &str,
+        to: &str,
+    ) -> IssueHistory {
+        IssueHistory::new(
+            "10001".to_string(),
+            key.to_string(),
+            change_id.to_string(),
+            timestamp,
+            "status".to_string(),
+        )
+        .with_field_change(
+            Some(from.to_string()),
+            Some(to.to_string()),
+            Some(from.to_string()),
+            Some(to.to_string()),
+        )
+    }
+
+    fn issue_with_status(key: &str, name: &str, category: &str) -> Issue {
+        let mut value = sample_issue_json("10001", key);
+        value["fields"]["status"]["id"] = json!(name);
+        value["fields"]["status"]["name"] = json!(name);
+        value["fields"]["status"]["statusCategory"]["key"] = json!(category);
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_lead_time_and_cycle_time() {
+        // 解決済みIssueはリードタイムとサイクルタイムが計算され、未解決のIssueはNoneになることをテスト
+        let categories = status_categories(&[
+            issue_with_status("CAT-1", "To Do", "new"),
+            issue_with_status("CAT-2", "In Progress", "indeterminate"),
+            issue_with_status("CAT-3", "Code Review", "indeterminate"),
+            issue_with_status("CAT-4", "Done", "done"),
+        ]);
+
+        // 2024-01-01 00:00作成、01-03 09:00に着手、01-05 09:00に解決
+        let mut resolved = issue_with_status("TEST-1", "Done", "done");
+        resolved.fields.resolution_date = Some(at(5, 9));
+
+        let histories = [
+            status_change("TEST-1", "1", at(2, 9), "To Do", "Blocked"),
+            status_change("TEST-1", "3", at(4, 9), "In Progress", "Code Review"),
+            status_change("TEST-1", "2", at(3, 9), "Blocked", "In Progress"),
+            status_change("TEST-1", "4", at(5, 9), "Code Review", "Done"),
+            // 他のIssueの履歴は無視される
+            status_change("OTHER-1", "5", at(1, 12), "To Do", "In Progress"),
+        ];
+
+        assert_eq!(lead_time(&resolved), Some(Duration::hours(4 * 24 + 9)));
+        assert_eq!(
+            cycle_time(&resolved, &histories, &categories),
+            Some(Duration::hours(2 * 24))
+        );
+
+        // 作業中を経ずに解決されたIssueはサイクルタイムが無い
+        let skipped = [status_change("TEST-1", "1", at(2, 9), "To Do", "Done")];
+        assert_eq!(cycle_time(&resolved, &skipped, &categories), None);
+
+        // 未解決のIssueはどちらもNone
+        let unresolved = issue_with_status("TEST-1", "In Progress", "indeterminate");
+        assert_eq!(lead_time(&unresolved), None);
+        assert_eq!(cycle_time(&unresolved, &histories, &categories), None);
+    }
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// アバター画像のサイズ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AvatarSize {
+    /// 16x16
+    Size16,
+    /// 24x24
+    Size24,
+    /// 32x32
+    Size32,
+    /// 48x48
+    Size48,
+}
+
+/// サイズ別のアバター画像URL（ユーザー・プロジェクト共通）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvatarUrls {
+    #[serde(rename = "48x48")]
+    pub size_48: String,
+    #[serde(rename = "24x24")]
+    pub size_24: String,
+    #[serde(rename = "16x16")]
+    pub size_16: String,
+    #[serde(rename = "32x32")]
+    pub size_32: String,
+}
+
+impl AvatarUrls {
+    /// 指定したサイズの画像URLを取得
+    pub fn url(&self, size: AvatarSize) -> &str {
+        match size {
+            AvatarSize::Size16 => &self.size_16,
+            AvatarSize::Size24 => &self.size_24,
+            AvatarSize::Size32 => &self.size_32,
+            AvatarSize::Size48 => &self.size_48,
+        }
+    }
+}
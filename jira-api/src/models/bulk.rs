@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+/// 一括操作で失敗した項目
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkFailure {
+    /// 対象のIssueキー
+    pub key: String,
+    /// エラーメッセージ
+    pub error: String,
+}
+
+/// 一括操作の結果
+///
+/// 一部の項目が失敗しても処理を中断せず、項目ごとの成否を記録します。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BulkResult {
+    /// 成功したIssueキー
+    pub succeeded: Vec<String>,
+    /// 失敗した項目
+    pub failed: Vec<BulkFailure>,
+}
+
+impl BulkResult {
+    /// 空の結果を作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 成功を記録
+    pub fn add_success(&mut self, key: impl Into<String>) {
+        self.succeeded.push(key.into());
+    }
+
+    /// 失敗を記録
+    pub fn add_failure(&mut self, key: impl Into<String>, error: impl Into<String>) {
+        self.failed.push(BulkFailure {
+            key: key.into(),
+            error: error.into(),
+        });
+    }
+
+    /// 処理した項目数
+    pub fn total(&self) -> usize {
+        self.succeeded.len() + self.failed.len()
+    }
+
+    /// すべての項目が成功したかどうか
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bulk_result_counts() {
+        let mut result = BulkResult::new();
+        assert!(result.is_success());
+
+        result.add_success("TEST-1");
+        result.add_failure("TEST-2", "API error: 409 - Conflict");
+
+        assert_eq!(result.total(), 2);
+        assert!(!result.is_success());
+        assert_eq!(result.succeeded, vec!["TEST-1"]);
+        assert_eq!(result.failed[0].key, "TEST-2");
+    }
+}
@@ -0,0 +1,107 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::User;
+
+/// Issueのコメント
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comment {
+    pub id: String,
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<User>,
+    /// 本文（ADF形式のオブジェクトまたは文字列）
+    #[serde(default)]
+    pub body: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated: Option<DateTime<Utc>>,
+    /// 公開範囲の制限（制限が無い場合はNone）
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<CommentVisibility>,
+}
+
+/// コメントの公開範囲の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VisibilityType {
+    /// プロジェクトロール
+    Role,
+    /// グループ
+    Group,
+}
+
+/// コメントの公開範囲
+///
+/// 指定したロールまたはグループのメンバーのみがコメントを閲覧できます。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommentVisibility {
+    #[serde(rename = "type")]
+    pub visibility_type: VisibilityType,
+    /// ロール名またはグループ名
+    pub value: String,
+}
+
+impl CommentVisibility {
+    /// プロジェクトロールに制限する
+    pub fn role(name: impl Into<String>) -> Self {
+        Self {
+            visibility_type: VisibilityType::Role,
+            value: name.into(),
+        }
+    }
+
+    /// グループに制限する
+    pub fn group(name: impl Into<String>) -> Self {
+        Self {
+            visibility_type: VisibilityType::Group,
+            value: name.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_comment_visibility_serialization() {
+        assert_eq!(
+            serde_json::to_value(CommentVisibility::role("Developers")).unwrap(),
+            json!({ "type": "role", "value": "Developers" })
+        );
+        assert_eq!(
+            serde_json::to_value(CommentVisibility::group("jira-admins")).unwrap(),
+            json!({ "type": "group", "value": "jira-admins" })
+        );
+    }
+
+    #[test]
+    fn test_comment_deserialization() {
+        let json_data = json!({
+            "id": "10100",
+            "self": "https://example.atlassian.net/rest/api/3/issue/10000/comment/10100",
+            "body": {
+                "type": "doc",
+                "version": 1,
+                "content": []
+            },
+            "created": "2024-01-01T00:00:00.000Z",
+            "visibility": { "type": "role", "value": "Administrators" }
+        });
+
+        let comment: Comment = serde_json::from_value(json_data).unwrap();
+
+        assert_eq!(comment.id, "10100");
+        assert!(comment.created.is_some());
+        assert_eq!(
+            comment.visibility,
+            Some(CommentVisibility::role("Administrators"))
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+
+/// Issueの説明
+///
+/// プレーンテキストは送信時にADF文書に変換し、ADF文書はそのまま送信します。
+#[derive(Debug, Clone, PartialEq)]
+pub enum IssueDescription {
+    /// プレーンテキスト
+    Text(String),
+    /// ADF（Atlassian Document Format）文書
+    Adf(Value),
+}
+
+impl IssueDescription {
+    /// リクエストボディに設定するADF文書を生成
+    pub fn to_adf(&self) -> Value {
+        match self {
+            IssueDescription::Text(text) => crate::adf::text_to_adf(text),
+            IssueDescription::Adf(doc) => doc.clone(),
+        }
+    }
+}
+
+impl From<&str> for IssueDescription {
+    fn from(text: &str) -> Self {
+        IssueDescription::Text(text.to_string())
+    }
+}
+
+impl From<String> for IssueDescription {
+    fn from(text: String) -> Self {
+        IssueDescription::Text(text)
+    }
+}
+
+impl From<Value> for IssueDescription {
+    fn from(doc: Value) -> Self {
+        IssueDescription::Adf(doc)
+    }
+}
+
+/// Issue作成の入力
+///
+/// `POST /rest/api/3/issue`の`fields`を組み立てます。説明はプレーンテキストまたはADF文書で指定します。
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateIssueInput {
+    /// プロジェクトキー
+    pub project_key: String,
+    /// 課題タイプ名（例: `Task`、`Bug`）
+    pub issue_type: String,
+    /// サマリー
+    pub summary: String,
+    /// 説明
+    pub description: Option<IssueDescription>,
+    /// 担当者のアカウントID
+    pub assignee_account_id: Option<String>,
+    /// 優先度名
+    pub priority: Option<String>,
+    /// ラベル
+    pub labels: Vec<String>,
+    /// カスタムフィールド（フィールドIDと値）
+    pub custom_fields: Map<String, Value>,
+}
+
+impl CreateIssueInput {
+    /// 必須項目を指定して入力を作成
+    pub fn new(
+        project_key: impl Into<String>,
+        issue_type: impl Into<String>,
+        summary: impl Into<String>,
+    ) -> Self {
+        Self {
+            project_key: project_key.into(),
+            issue_type: issue_type.into(),
+            summary: summary.into(),
+            description: None,
+            assignee_account_id: None,
+            priority: None,
+            labels: Vec::new(),
+            custom_fields: Map::new(),
+        }
+    }
+
+    /// 説明を設定
+    ///
+    /// 文字列はプレーンテキスト、`serde_json::Value`はADF文書として扱います。
+    pub fn description(mut self, description: impl Into<IssueDescription>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// 担当者を設定
+    pub fn assignee(mut self, account_id: impl Into<String>) -> Self {
+        self.assignee_account_id = Some(account_id.into());
+        self
+    }
+
+    /// 優先度を設定
+    pub fn priority(mut self, name: impl Into<String>) -> Self {
+        self.priority = Some(name.into());
+        self
+    }
+
+    /// ラベルを設定
+    pub fn labels(mut self, labels: Vec<String>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// カスタムフィールドの値を設定
+    pub fn custom_field(mut self, field_id: impl Into<String>, value: Value) -> Self {
+        self.custom_fields.insert(field_id.into(), value);
+        self
+    }
+
+    /// リクエストボディ（`{"fields": {...}}`）を生成
+    pub fn to_body(&self) -> Value {
+        let mut fields = self.custom_fields.clone();
+        fields.insert("project".to_string(), json!({ "key": self.project_key }));
+        fields.insert("issuetype".to_string(), json!({ "name": self.issue_type }));
+        fields.insert("summary".to_string(), json!(self.summary));
+        if let Some(description) = &self.description {
+            fields.insert("description".to_string(), description.to_adf());
+        }
+        if let Some(account_id) = &self.assignee_account_id {
+            fields.insert("assignee".to_string(), json!({ "accountId": account_id }));
+        }
+        if let Some(priority) = &self.priority {
+            fields.insert("priority".to_string(), json!({ "name": priority }));
+        }
+        if !self.labels.is_empty() {
+            fields.insert("labels".to_string(), json!(self.labels));
+        }
+
+        json!({ "fields": fields })
+    }
+}
+
+/// 作成された（または既存の）Issueへの参照
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreatedIssue {
+    pub id: String,
+    pub key: String,
+    #[serde(rename = "self")]
+    pub self_url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_issue_request_body() {
+        // 必須項目と任意項目がJIRAのfields形式に変換されることをテスト
+        let body = CreateIssueInput::new("TEST", "Task", "Nightly job failed")
+            .description("See logs")
+            .assignee("557058:abc")
+            .priority("High")
+            .labels(vec!["ops".to_string()])
+            .custom_field("customfield_10016", json!(3))
+            .to_body();
+
+        assert_eq!(body["fields"]["project"]["key"], "TEST");
+        assert_eq!(body["fields"]["issuetype"]["name"], "Task");
+        assert_eq!(body["fields"]["summary"], "Nightly job failed");
+        assert_eq!(body["fields"]["description"]["type"], "doc");
+        assert_eq!(body["fields"]["assignee"]["accountId"], "557058:abc");
+        assert_eq!(body["fields"]["priority"]["name"], "High");
+        assert_eq!(body["fields"]["labels"], json!(["ops"]));
+        assert_eq!(body["fields"]["customfield_10016"], 3);
+
+        let minimal = CreateIssueInput::new("TEST", "Bug", "Crash").to_body();
+        assert!(minimal["fields"].get("description").is_none());
+        assert!(minimal["fields"].get("labels").is_none());
+
+        // ADF文書は変換せずにそのまま送信される
+        let doc = crate::adf::markdown_to_adf("**Steps** to reproduce");
+        let adf = CreateIssueInput::new("TEST", "Bug", "Crash")
+            .description(doc.clone())
+            .to_body();
+        assert_eq!(adf["fields"]["description"], doc);
+    }
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// JIRAのエラーレスポンス
+///
+/// 400などのエラーでは`{ "errorMessages": [...], "errors": { "field": "message" } }`の形式で
+/// 全体のエラーとフィールドごとのエラーが返されます。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JiraErrorResponse {
+    #[serde(rename = "errorMessages")]
+    #[serde(default)]
+    pub error_messages: Vec<String>,
+    /// フィールド名ごとのエラーメッセージ
+    #[serde(default)]
+    pub errors: HashMap<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_error_response_deserialization() {
+        let json_data = json!({
+            "errorMessages": [],
+            "errors": {
+                "commentLevel": "You are currently not a member of the project role: Foo."
+            }
+        });
+
+        let response: JiraErrorResponse = serde_json::from_value(json_data).unwrap();
+
+        assert!(response.error_messages.is_empty());
+        assert_eq!(
+            response.errors.get("commentLevel").map(String::as_str),
+            Some("You are currently not a member of the project role: Foo.")
+        );
+    }
+}
@@ -36,6 +36,35 @@ pub struct FieldSchema {
     pub custom_id: Option<u64>,
 }
 
+/// カスタムフィールドのコンテキスト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldContext {
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "isGlobalContext")]
+    #[serde(default)]
+    pub is_global_context: bool,
+    #[serde(rename = "isAnyIssueType")]
+    #[serde(default)]
+    pub is_any_issue_type: bool,
+}
+
+/// 選択リスト型カスタムフィールドの選択肢
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldOption {
+    pub id: String,
+    pub value: String,
+    /// 無効化された選択肢は新しい値として選べない
+    #[serde(default)]
+    pub disabled: bool,
+    /// カスケード選択リストの子選択肢の場合は親の選択肢ID
+    #[serde(rename = "optionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option_id: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -67,4 +96,27 @@ mod tests {
         assert!(field.schema.is_some());
         assert_eq!(field.schema.unwrap().field_type, "number");
     }
+
+    #[test]
+    fn test_field_option_deserialization() {
+        let json_data = json!({
+            "id": "10001",
+            "value": "Backend",
+            "disabled": true
+        });
+
+        let option: FieldOption = serde_json::from_value(json_data).unwrap();
+
+        assert_eq!(option.id, "10001");
+        assert_eq!(option.value, "Backend");
+        assert!(option.disabled);
+        assert!(option.option_id.is_none());
+
+        // disabledが無い場合は有効として扱う
+        let option: FieldOption =
+            serde_json::from_value(json!({"id": "10002", "value": "Web", "optionId": "10001"}))
+                .unwrap();
+        assert!(!option.disabled);
+        assert_eq!(option.option_id.as_deref(), Some("10001"));
+    }
 }
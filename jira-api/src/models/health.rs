@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+/// 接続設定の健全性チェック結果
+///
+/// 認証・プロジェクト読み取り権限・サーバー情報の取得結果をまとめたもので、
+/// 一部のチェックが失敗しても他の結果は記録されます。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// サーバーに到達できたかどうか（HTTPレスポンスを受信できたか）
+    pub reachable: bool,
+    /// 認証に成功したかどうか
+    pub authenticated: bool,
+    /// プロジェクト一覧を読み取れたかどうか
+    pub can_read_projects: bool,
+    /// 認証ユーザーの表示名
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_display_name: Option<String>,
+    /// JIRAのバージョン
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jira_version: Option<String>,
+    /// 失敗したチェックのエラーメッセージ
+    pub errors: Vec<String>,
+}
+
+impl HealthReport {
+    /// すべてのチェックに成功したかどうか
+    pub fn is_healthy(&self) -> bool {
+        self.reachable && self.authenticated && self.can_read_projects && self.errors.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_report_is_healthy() {
+        let mut report = HealthReport {
+            reachable: true,
+            authenticated: true,
+            can_read_projects: true,
+            account_display_name: Some("Test User".to_string()),
+            jira_version: Some("1001.0.0".to_string()),
+            errors: Vec::new(),
+        };
+        assert!(report.is_healthy());
+
+        report.can_read_projects = false;
+        report
+            .errors
+            .push("project: API error: 403 - Forbidden".to_string());
+        assert!(!report.is_healthy());
+    }
+}
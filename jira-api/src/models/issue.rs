@@ -11,6 +11,11 @@ pub struct Issue {
     pub fields: IssueFields,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub changelog: Option<Changelog>,
+    /// サーバー側でHTMLにレンダリングされたフィールド（`expand=renderedFields`指定時のみ）
+    #[serde(rename = "renderedFields")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendered_fields: Option<HashMap<String, serde_json::Value>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,17 +33,148 @@ pub struct IssueFields {
     pub reporter: User,
     pub created: DateTime<Utc>,
     pub updated: DateTime<Utc>,
+    /// 解決状況（未解決の場合はNone）
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<super::Resolution>,
+    /// 時間管理情報（見積もりと作業時間）
+    #[serde(rename = "timetracking")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_tracking: Option<super::TimeTracking>,
+    /// 投票数と投票済みかどうか
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub votes: Option<super::Votes>,
+    /// ウォッチャー数とウォッチ中かどうか
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watches: Option<super::Watches>,
     #[serde(rename = "resolutiondate")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resolution_date: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<Project>,
+    /// 親Issue（サブタスクの親、次世代プロジェクトのエピックなど）
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<ParentRef>,
 
     // カスタムフィールドは動的に追加
     #[serde(flatten)]
     pub custom_fields: HashMap<String, serde_json::Value>,
 }
 
+/// 親Issueへの参照
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParentRef {
+    pub id: String,
+    pub key: String,
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_url: Option<String>,
+    #[serde(default)]
+    pub fields: ParentFields,
+}
+
+/// 親Issueの概要フィールド
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ParentFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    #[serde(rename = "issuetype")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<IssueType>,
+}
+
+impl ParentRef {
+    /// 親Issueのサマリー
+    pub fn summary(&self) -> Option<&str> {
+        self.fields.summary.as_deref()
+    }
+
+    /// 親Issueのステータス名
+    pub fn status_name(&self) -> Option<&str> {
+        self.fields.status.as_ref().map(|s| s.name.as_str())
+    }
+
+    /// 親Issueがエピックかどうか
+    pub fn is_epic(&self) -> bool {
+        self.fields
+            .issue_type
+            .as_ref()
+            .is_some_and(|t| t.name.eq_ignore_ascii_case("epic"))
+    }
+}
+
+impl Issue {
+    /// ステータスカテゴリのキー
+    ///
+    /// ワークフローごとのステータス名に関係なく、`new`（To Do）、`indeterminate`（In Progress）、
+    /// `done`（Done）のいずれかで分類できます。
+    pub fn status_category_key(&self) -> &str {
+        &self.fields.status.status_category.key
+    }
+
+    /// レンダリング済みの説明（HTML）
+    ///
+    /// 検索時に`expand=renderedFields`を指定しなかった場合や、説明が空の場合は`None`を返します。
+    pub fn rendered_description(&self) -> Option<&str> {
+        self.rendered_fields
+            .as_ref()
+            .and_then(|fields| fields.get("description"))
+            .and_then(|v| v.as_str())
+    }
+
+    /// 説明をプレーンテキストで取得する
+    ///
+    /// ADF形式の説明は[`crate::adf_to_plain_text`]で本文のみを取り出し、文字列の説明はそのまま返します。
+    /// 説明が無い場合は`None`を返します。
+    pub fn description_text(&self) -> Option<String> {
+        self.fields
+            .description
+            .as_ref()
+            .map(crate::adf::adf_to_plain_text)
+    }
+
+    /// ラベルの一覧
+    ///
+    /// `labels`フィールドはカスタムフィールドとして保持されています。ラベルが無い場合は空です。
+    pub fn labels(&self) -> Vec<&str> {
+        self.fields
+            .custom_fields
+            .get("labels")
+            .and_then(|v| v.as_array())
+            .map(|labels| labels.iter().filter_map(|label| label.as_str()).collect())
+            .unwrap_or_default()
+    }
+
+    /// 親Issueのキー
+    pub fn parent_key(&self) -> Option<&str> {
+        self.fields.parent.as_ref().map(|p| p.key.as_str())
+    }
+
+    /// 所属するエピックのキーを取得
+    ///
+    /// クラシックプロジェクトではエピックリンクのカスタムフィールド
+    /// （例: `customfield_10014`）の値を、次世代プロジェクトではエピック種別の親Issueを参照します。
+    pub fn epic_key(&self, epic_link_field_id: &str) -> Option<&str> {
+        self.fields
+            .custom_fields
+            .get(epic_link_field_id)
+            .and_then(|v| v.as_str())
+            .or_else(|| {
+                self.fields
+                    .parent
+                    .as_ref()
+                    .filter(|p| p.is_epic())
+                    .map(|p| p.key.as_str())
+            })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Changelog {
     #[serde(rename = "startAt")]
@@ -74,6 +210,58 @@ pub struct HistoryItem {
     pub to_string: Option<String>,
 }
 
+/// Issueを1件取得する際のパラメータ
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct IssueParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expand: Option<Vec<String>>,
+}
+
+impl IssueParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// `expand`に`changelog`を追加し、変更履歴を埋め込んで取得する
+    pub fn changelog(mut self) -> Self {
+        let expand = self.expand.get_or_insert_with(Vec::new);
+        if !expand.iter().any(|e| e == "changelog") {
+            expand.push("changelog".to_string());
+        }
+        self
+    }
+
+    /// クエリ文字列に変換する（パラメータが無い場合は空文字列）
+    pub fn to_query_string(&self) -> String {
+        let mut query_params = Vec::new();
+        if let Some(fields) = &self.fields {
+            query_params.push(format!("fields={}", urlencoding::encode(&fields.join(","))));
+        }
+        if let Some(expand) = &self.expand {
+            query_params.push(format!("expand={}", urlencoding::encode(&expand.join(","))));
+        }
+
+        if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        }
+    }
+}
+
 // Re-export dependent types that will be defined in other modules
 use super::{IssueType, Priority, Project, Status, User};
 
@@ -141,5 +329,198 @@ mod tests {
             issue.fields.custom_fields.get("customfield_10001").unwrap(),
             "Custom Value"
         );
+        assert!(issue.fields.parent.is_none());
+        assert_eq!(issue.status_category_key(), "new");
+    }
+
+    #[test]
+    fn test_issue_parent_and_epic_key() {
+        // parentフィールドとエピックキーの取得をテスト
+        let json_data = json!({
+            "id": "10001",
+            "key": "TEST-2",
+            "self": "https://example.atlassian.net/rest/api/3/issue/10001",
+            "fields": {
+                "summary": "Sub task",
+                "issuetype": {
+                    "id": "5",
+                    "name": "Sub-task",
+                    "self": "https://example.atlassian.net/rest/api/3/issuetype/5",
+                    "subtask": true
+                },
+                "status": {
+                    "id": "1",
+                    "name": "To Do",
+                    "self": "https://example.atlassian.net/rest/api/3/status/1",
+                    "statusCategory": {
+                        "id": 2,
+                        "key": "new",
+                        "name": "To Do",
+                        "colorName": "blue-gray"
+                    }
+                },
+                "reporter": {
+                    "accountId": "557058:f58131cb",
+                    "displayName": "Test User",
+                    "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:f58131cb"
+                },
+                "created": "2024-01-01T00:00:00.000Z",
+                "updated": "2024-01-02T00:00:00.000Z",
+                "parent": {
+                    "id": "10000",
+                    "key": "TEST-1",
+                    "self": "https://example.atlassian.net/rest/api/3/issue/10000",
+                    "fields": {
+                        "summary": "Parent story",
+                        "status": {
+                            "id": "3",
+                            "name": "In Progress",
+                            "self": "https://example.atlassian.net/rest/api/3/status/3",
+                            "statusCategory": {
+                                "id": 4,
+                                "key": "indeterminate",
+                                "name": "In Progress",
+                                "colorName": "yellow"
+                            }
+                        },
+                        "issuetype": {
+                            "id": "10",
+                            "name": "Story",
+                            "self": "https://example.atlassian.net/rest/api/3/issuetype/10"
+                        }
+                    }
+                },
+                "customfield_10014": "TEST-100"
+            }
+        });
+
+        let mut issue: Issue = serde_json::from_value(json_data).unwrap();
+
+        let parent = issue.fields.parent.as_ref().unwrap();
+        assert_eq!(issue.parent_key(), Some("TEST-1"));
+        assert_eq!(parent.summary(), Some("Parent story"));
+        assert_eq!(parent.status_name(), Some("In Progress"));
+        assert!(!parent.is_epic());
+        assert!(!issue.fields.custom_fields.contains_key("parent"));
+
+        // クラシックプロジェクトのエピックリンク
+        assert_eq!(issue.epic_key("customfield_10014"), Some("TEST-100"));
+        // エピックリンクが無く、親もエピックでない場合
+        assert_eq!(issue.epic_key("customfield_99999"), None);
+
+        // 次世代プロジェクトではエピック種別の親がエピックとなる
+        let parent = issue.fields.parent.as_mut().unwrap();
+        parent.fields.issue_type.as_mut().unwrap().name = "Epic".to_string();
+        assert_eq!(issue.epic_key("customfield_99999"), Some("TEST-1"));
+    }
+
+    #[test]
+    fn test_issue_votes_and_watches() {
+        // votes・watchesフィールドから件数と状態を取得でき、無い場合はNoneになることをテスト
+        let mut value = crate::testing::sample_issue_json("10001", "TEST-1");
+        value["fields"]["votes"] = json!({
+            "self": "https://example.atlassian.net/rest/api/3/issue/TEST-1/votes",
+            "votes": 3,
+            "hasVoted": true
+        });
+        value["fields"]["watches"] = json!({
+            "self": "https://example.atlassian.net/rest/api/3/issue/TEST-1/watchers",
+            "watchCount": 5,
+            "isWatching": false
+        });
+
+        let issue: Issue = serde_json::from_value(value).unwrap();
+        let votes = issue.fields.votes.as_ref().unwrap();
+        assert_eq!(votes.votes, 3);
+        assert!(votes.has_voted);
+        let watches = issue.fields.watches.as_ref().unwrap();
+        assert_eq!(watches.watch_count, 5);
+        assert!(!watches.is_watching);
+        assert!(!issue.fields.custom_fields.contains_key("votes"));
+        assert!(!issue.fields.custom_fields.contains_key("watches"));
+
+        // シリアライズしても同じ値に戻る
+        let restored: Issue =
+            serde_json::from_value(serde_json::to_value(&issue).unwrap()).unwrap();
+        assert_eq!(restored.fields.votes, issue.fields.votes);
+        assert_eq!(restored.fields.watches, issue.fields.watches);
+
+        let issue: Issue =
+            serde_json::from_value(crate::testing::sample_issue_json("10002", "TEST-2")).unwrap();
+        assert!(issue.fields.votes.is_none());
+        assert!(issue.fields.watches.is_none());
+    }
+
+    #[test]
+    fn test_issue_labels() {
+        // labelsフィールドの文字列のみが取得でき、フィールドが無い場合は空になることをテスト
+        let mut value = crate::testing::sample_issue_json("10001", "TEST-1");
+        let issue: Issue = serde_json::from_value(value.clone()).unwrap();
+        assert!(issue.labels().is_empty());
+
+        value["fields"]["labels"] = json!(["backend", 1, "urgent"]);
+        let issue: Issue = serde_json::from_value(value).unwrap();
+        assert_eq!(issue.labels(), vec!["backend", "urgent"]);
+    }
+
+    #[test]
+    fn test_issue_description_text() {
+        // ADF形式・文字列の説明がプレーンテキストで取得でき、説明が無い場合はNoneになることをテスト
+        let mut value = crate::testing::sample_issue_json("10001", "TEST-1");
+        value["fields"]["description"] = json!({
+            "type": "doc",
+            "version": 1,
+            "content": [
+                {
+                    "type": "paragraph",
+                    "content": [
+                        { "type": "text", "text": "Login fails" },
+                        { "type": "hardBreak" },
+                        { "type": "text", "text": "on Safari", "marks": [{ "type": "em" }] }
+                    ]
+                },
+                {
+                    "type": "bulletList",
+                    "content": [{
+                        "type": "listItem",
+                        "content": [{
+                            "type": "paragraph",
+                            "content": [{ "type": "text", "text": "clear cache" }]
+                        }]
+                    }]
+                }
+            ]
+        });
+        let issue: Issue = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(
+            issue.description_text().as_deref(),
+            Some("Login fails\non Safari\n\n- clear cache")
+        );
+
+        value["fields"]["description"] = json!("Plain description");
+        let issue: Issue = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(
+            issue.description_text().as_deref(),
+            Some("Plain description")
+        );
+
+        value["fields"]["description"] = serde_json::Value::Null;
+        let issue: Issue = serde_json::from_value(value).unwrap();
+        assert_eq!(issue.description_text(), None);
+    }
+
+    #[test]
+    fn test_issue_params_query_string() {
+        assert_eq!(IssueParams::new().to_query_string(), "");
+
+        let params = IssueParams::new()
+            .fields(vec!["summary".to_string(), "status".to_string()])
+            .changelog()
+            .changelog();
+        assert_eq!(params.expand, Some(vec!["changelog".to_string()]));
+        assert_eq!(
+            params.to_query_string(),
+            "?fields=summary%2Cstatus&expand=changelog"
+        );
     }
 }
@@ -1,17 +1,46 @@
+pub mod avatar;
+pub mod bulk;
+pub mod comment;
+pub mod create_issue;
+pub mod error_response;
 pub mod field;
+pub mod health;
 pub mod issue;
 pub mod issue_type;
+pub mod page;
 pub mod priority;
 pub mod project;
+pub mod resolution;
 pub mod search;
+pub mod server_info;
 pub mod status;
+pub mod time_tracking;
+pub mod transition;
 pub mod user;
+pub mod votes;
+pub mod watches;
+pub mod worklog;
 
+pub use crate::adf::adf_to_plain_text;
+pub use avatar::*;
+pub use bulk::*;
+pub use comment::*;
+pub use create_issue::*;
+pub use error_response::*;
 pub use field::*;
+pub use health::*;
 pub use issue::*;
 pub use issue_type::*;
+pub use page::*;
 pub use priority::*;
 pub use project::*;
+pub use resolution::*;
 pub use search::*;
+pub use server_info::*;
 pub use status::*;
+pub use time_tracking::*;
+pub use transition::*;
 pub use user::*;
+pub use votes::*;
+pub use watches::*;
+pub use worklog::*;
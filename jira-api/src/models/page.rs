@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+/// JIRAのページング形式レスポンス
+///
+/// コメント、ワークログ、バージョン、ボードなど多くのエンドポイントが
+/// `{ startAt, maxResults, total, isLast, values: [...] }`の形式で結果を返します。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Page<T> {
+    #[serde(rename = "startAt")]
+    pub start_at: u32,
+
+    #[serde(rename = "maxResults")]
+    pub max_results: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u32>,
+
+    #[serde(rename = "isLast")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_last: Option<bool>,
+
+    /// 取得した要素
+    ///
+    /// createmetaのIssueタイプ一覧は`issueTypes`、コメント一覧は`comments`、
+    /// ワークログ一覧は`worklogs`に要素を返すため、別名として受け付けます。
+    #[serde(default = "Vec::new")]
+    #[serde(alias = "issueTypes", alias = "comments", alias = "worklogs")]
+    pub values: Vec<T>,
+}
+
+impl<T> Page<T> {
+    /// このページが最後のページかどうか
+    ///
+    /// `total`が返された場合は次の開始位置が`total`に達したかどうかで判定します。
+    /// コメントやワークログのエンドポイントは途中のページでも`maxResults`より少ない件数を
+    /// 返すことがあるため、取得件数だけで最後と判断すると取りこぼしが発生します。
+    /// `total`が無い場合は`isLast`、それも無い場合は取得件数がページサイズ未満かどうかで判定します。
+    /// 空のページは無限ループを避けるため常に最後のページとして扱います。
+    pub fn is_last_page(&self) -> bool {
+        if self.values.is_empty() || self.is_last == Some(true) {
+            return true;
+        }
+
+        match (self.total, self.is_last) {
+            (Some(total), _) => self.next_start_at() >= total,
+            (None, Some(is_last)) => is_last,
+            (None, None) => (self.values.len() as u32) < self.max_results,
+        }
+    }
+
+    /// 次のページの開始位置
+    pub fn next_start_at(&self) -> u32 {
+        self.start_at + self.values.len() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_page_deserialization() {
+        let json_data = json!({
+            "startAt": 0,
+            "maxResults": 2,
+            "total": 3,
+            "isLast": false,
+            "values": [{"id": "1"}, {"id": "2"}]
+        });
+
+        let page: Page<serde_json::Value> = serde_json::from_value(json_data).unwrap();
+
+        assert_eq!(page.start_at, 0);
+        assert_eq!(page.max_results, 2);
+        assert_eq!(page.total, Some(3));
+        assert_eq!(page.is_last, Some(false));
+        assert_eq!(page.values.len(), 2);
+        assert!(!page.is_last_page());
+        assert_eq!(page.next_start_at(), 2);
+    }
+
+    #[test]
+    fn test_page_is_last_page_without_is_last() {
+        // isLastが無い場合はtotalと取得件数から判定する
+        let page: Page<u32> = serde_json::from_value(json!({
+            "startAt": 2,
+            "maxResults": 2,
+            "total": 3,
+            "values": [3]
+        }))
+        .unwrap();
+        assert!(page.is_last_page());
+
+        // totalも無い場合はページサイズ未満なら最後
+        let page: Page<u32> = serde_json::from_value(json!({
+            "startAt": 0,
+            "maxResults": 2,
+            "values": [1, 2]
+        }))
+        .unwrap();
+        assert!(!page.is_last_page());
+    }
+
+    #[test]
+    fn test_page_short_page_before_total_is_not_last() {
+        // totalに達していなければページサイズ未満でも最後ではない
+        let page: Page<u32> = serde_json::from_value(json!({
+            "startAt": 0,
+            "maxResults": 3,
+            "total": 5,
+            "comments": [1, 2]
+        }))
+        .unwrap();
+        assert_eq!(page.values, vec![1, 2]);
+        assert!(!page.is_last_page());
+        assert_eq!(page.next_start_at(), 2);
+    }
+}
@@ -14,7 +14,8 @@ pub struct Project {
     pub project_type_key: Option<String>,
     #[serde(rename = "avatarUrls")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub avatar_urls: Option<ProjectAvatarUrls>,
+    pub avatar_urls: Option<AvatarUrls>,
+    /// プロジェクトリーダー（`expand=lead`を指定した場合などに含まれる）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lead: Option<User>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -23,20 +24,23 @@ pub struct Project {
     pub simplified: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ProjectAvatarUrls {
-    #[serde(rename = "48x48")]
-    pub size_48: String,
-    #[serde(rename = "24x24")]
-    pub size_24: String,
-    #[serde(rename = "16x16")]
-    pub size_16: String,
-    #[serde(rename = "32x32")]
-    pub size_32: String,
+impl Project {
+    /// 指定したサイズのアバター画像URLを取得
+    pub fn avatar(&self, size: AvatarSize) -> Option<&str> {
+        self.avatar_urls.as_ref().map(|urls| urls.url(size))
+    }
+
+    /// プロジェクトリーダーのアカウントID
+    pub fn lead_account_id(&self) -> Option<&str> {
+        self.lead.as_ref().map(|lead| lead.account_id.as_str())
+    }
 }
 
+/// プロジェクトのアバター画像URL（[`AvatarUrls`]と同じ型）
+pub type ProjectAvatarUrls = AvatarUrls;
+
 // Re-export dependent types
-use super::User;
+use super::{AvatarSize, AvatarUrls, User};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ProjectParams {
@@ -129,4 +133,52 @@ mod tests {
         assert_eq!(json["recent"], 5);
         assert!(json.get("properties").is_none()); // None values should be omitted
     }
+
+    #[test]
+    fn test_project_avatar_and_lead() {
+        // avatarUrlsの各サイズとリーダーのアカウントIDを取得できることをテスト
+        let json_data = json!({
+            "id": "10000",
+            "key": "TEST",
+            "name": "Test Project",
+            "self": "https://example.atlassian.net/rest/api/3/project/10000",
+            "avatarUrls": {
+                "48x48": "https://example.atlassian.net/avatar/48",
+                "24x24": "https://example.atlassian.net/avatar/24",
+                "16x16": "https://example.atlassian.net/avatar/16",
+                "32x32": "https://example.atlassian.net/avatar/32"
+            },
+            "lead": {
+                "accountId": "557058:lead",
+                "displayName": "Project Lead",
+                "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:lead"
+            }
+        });
+
+        let project: Project = serde_json::from_value(json_data).unwrap();
+
+        for (size, suffix) in [
+            (AvatarSize::Size16, "16"),
+            (AvatarSize::Size24, "24"),
+            (AvatarSize::Size32, "32"),
+            (AvatarSize::Size48, "48"),
+        ] {
+            assert_eq!(
+                project.avatar(size),
+                Some(format!("https://example.atlassian.net/avatar/{}", suffix).as_str())
+            );
+        }
+        assert_eq!(project.lead_account_id(), Some("557058:lead"));
+
+        // アバター・リーダーが無い場合はNone
+        let minimal: Project = serde_json::from_value(json!({
+            "id": "10001",
+            "key": "MIN",
+            "name": "Minimal",
+            "self": "https://example.atlassian.net/rest/api/3/project/10001"
+        }))
+        .unwrap();
+        assert_eq!(minimal.avatar(AvatarSize::Size48), None);
+        assert_eq!(minimal.lead_account_id(), None);
+    }
 }
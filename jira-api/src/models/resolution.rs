@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+/// Issueの解決状況（Done、Won't Fixなど）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Resolution {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_resolution_deserialization() {
+        let json_data = json!({
+            "id": "10001",
+            "name": "Won't Fix",
+            "self": "https://example.atlassian.net/rest/api/3/resolution/10001",
+            "description": "This issue won't be actioned."
+        });
+
+        let resolution: Resolution = serde_json::from_value(json_data).unwrap();
+
+        assert_eq!(resolution.id, "10001");
+        assert_eq!(resolution.name, "Won't Fix");
+        assert!(resolution.self_url.is_some());
+    }
+}
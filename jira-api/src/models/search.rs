@@ -1,5 +1,6 @@
 use super::Issue;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SearchParams {
@@ -20,6 +21,15 @@ pub struct SearchParams {
     #[serde(rename = "validateQuery")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub validate_query: Option<bool>,
+
+    /// 並び順（検索時にJQLのORDER BY句として付与される）
+    #[serde(skip)]
+    pub order_by: Vec<(String, crate::jql::OrderDirection)>,
+
+    /// 次のページのトークン（トークン方式で検索する場合のみ使用）
+    #[serde(rename = "nextPageToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,11 +47,54 @@ pub struct SearchResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expand: Option<String>,
 
+    /// フィールドIDから表示名への対応（`expand=names`指定時のみ）
+    #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub names: Option<serde_json::Value>,
+    pub names: Option<HashMap<String, String>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub schema: Option<serde_json::Value>,
+
+    /// 次のページのトークン（トークン方式で検索した場合のみ）
+    #[serde(rename = "nextPageToken")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+}
+
+/// トークン方式のIssue検索（`/rest/api/3/search/jql`）のパラメータ
+///
+/// 次のページは前回の結果の[`JqlSearchResult::next_page_token`]を指定して取得します。
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct JqlSearchParams {
+    #[serde(rename = "nextPageToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+
+    #[serde(rename = "maxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<String>>,
+}
+
+/// トークン方式のIssue検索の結果
+///
+/// `/rest/api/3/search/jql`は`startAt`と`total`を返さないため、
+/// 続きがあるかどうかは`is_last`と`next_page_token`で判定します。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JqlSearchResult {
+    pub issues: Vec<Issue>,
+
+    #[serde(rename = "nextPageToken")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+
+    #[serde(rename = "isLast")]
+    #[serde(default)]
+    pub is_last: bool,
 }
 
 impl SearchParams {
@@ -69,10 +122,181 @@ impl SearchParams {
         self
     }
 
+    /// `expand`に`renderedFields`を追加し、HTMLレンダリング済みのフィールドを取得する
+    pub fn rendered_fields(mut self) -> Self {
+        let expand = self.expand.get_or_insert_with(Vec::new);
+        if !expand.iter().any(|e| e == "renderedFields") {
+            expand.push("renderedFields".to_string());
+        }
+        self
+    }
+
+    /// 並び順を追加する
+    ///
+    /// 検索時、JQLに既存のORDER BY句があれば取り除いてから指定した並び順を付与します。
+    /// 複数回呼び出すと、呼び出した順に並び替えのキーになります。
+    pub fn order_by(mut self, field: &str, direction: crate::jql::OrderDirection) -> Self {
+        self.order_by.push((field.to_string(), direction));
+        self
+    }
+
+    /// `expand`に`names`を追加し、フィールドIDと表示名の対応を取得する
+    pub fn names(mut self) -> Self {
+        let expand = self.expand.get_or_insert_with(Vec::new);
+        if !expand.iter().any(|e| e == "names") {
+            expand.push("names".to_string());
+        }
+        self
+    }
+
     pub fn validate_query(mut self, validate: bool) -> Self {
         self.validate_query = Some(validate);
         self
     }
+
+    pub fn next_page_token(mut self, token: impl Into<String>) -> Self {
+        self.next_page_token = Some(token.into());
+        self
+    }
+
+    /// `result`の次のページを取得するパラメータ
+    ///
+    /// 開始位置を進め、トークン方式の場合は次ページのトークンを引き継ぎます。
+    pub fn next_page(mut self, result: &SearchResult) -> Self {
+        self.start_at = Some(result.next_start_at());
+        self.next_page_token = result.next_page_token.clone();
+        self
+    }
+}
+
+impl SearchResult {
+    /// 次のページが存在するかどうか
+    ///
+    /// 取得件数が0の場合は、`total`に満たなくても次のページは無いものとして扱います。
+    /// トークン方式の場合は次ページのトークンの有無で判定します。
+    pub fn has_more(&self) -> bool {
+        !self.issues.is_empty()
+            && (self.next_page_token.is_some() || self.next_start_at() < self.total)
+    }
+
+    /// 次のページの開始位置
+    pub fn next_start_at(&self) -> u32 {
+        self.start_at + self.issues.len() as u32
+    }
+
+    /// フィールドIDの表示名
+    ///
+    /// 検索時に`expand=names`を指定しなかった場合や、対応が無いフィールドの場合は`None`を返します。
+    pub fn field_display_name(&self, field_id: &str) -> Option<&str> {
+        self.names
+            .as_ref()
+            .and_then(|names| names.get(field_id))
+            .map(|name| name.as_str())
+    }
+}
+
+impl JqlSearchParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn next_page_token(mut self, token: impl Into<String>) -> Self {
+        self.next_page_token = Some(token.into());
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+impl JqlSearchResult {
+    /// 次のページが存在するかどうか
+    ///
+    /// `isLast`が省略された場合も、次ページのトークンが無ければ最後のページとして扱います。
+    pub fn has_more(&self) -> bool {
+        !self.is_last && self.next_page_token.is_some()
+    }
+
+    /// オフセット方式の検索結果に変換する
+    ///
+    /// トークン方式では総件数が返されないため、`total`は`start_at`とこのページの件数の合計になります。
+    /// 続きがある場合は次ページのトークンを[`SearchResult::next_page_token`]に引き継ぎます。
+    pub fn into_search_result(self, start_at: u32, max_results: Option<u32>) -> SearchResult {
+        let next_page_token = if self.has_more() {
+            self.next_page_token
+        } else {
+            None
+        };
+        let count = self.issues.len() as u32;
+
+        SearchResult {
+            start_at,
+            max_results: max_results.unwrap_or(count),
+            total: start_at + count,
+            issues: self.issues,
+            expand: None,
+            names: None,
+            schema: None,
+            next_page_token,
+        }
+    }
+}
+
+/// 全件検索のオプション
+#[derive(Debug, Clone, Default)]
+pub struct SearchAllOptions {
+    /// 各ページの取得に失敗した場合の再試行ポリシー
+    pub retry_policy: crate::RetryPolicy,
+    /// 再試行しても取得できなかったページを飛ばして続行するかどうか
+    pub best_effort: bool,
+    /// ページの間で確認するキャンセルトークン
+    pub cancellation_token: Option<crate::CancellationToken>,
+}
+
+impl SearchAllOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn retry_policy(mut self, policy: crate::RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    pub fn best_effort(mut self, best_effort: bool) -> Self {
+        self.best_effort = best_effort;
+        self
+    }
+
+    pub fn cancellation_token(mut self, token: crate::CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+}
+
+/// 全件検索の結果
+#[derive(Debug, Clone, Default)]
+pub struct SearchAllResult {
+    /// 取得できたIssue
+    pub issues: Vec<Issue>,
+    /// 取得できなかったページの開始位置（best effortモードのみ）
+    pub failed_offsets: Vec<u32>,
+    /// キャンセルにより途中で終了したかどうか
+    pub cancelled: bool,
+}
+
+impl SearchAllResult {
+    /// 全てのページを取得できたかどうか
+    pub fn is_complete(&self) -> bool {
+        self.failed_offsets.is_empty() && !self.cancelled
+    }
 }
 
 #[cfg(test)]
@@ -96,6 +320,20 @@ mod tests {
         assert_eq!(params.validate_query, Some(true));
     }
 
+    #[test]
+    fn test_search_params_rendered_fields() {
+        // 既存のexpandを残したままrenderedFieldsが1回だけ追加されることをテスト
+        let params = SearchParams::new()
+            .expand(vec!["changelog".to_string()])
+            .rendered_fields()
+            .rendered_fields();
+
+        assert_eq!(
+            params.expand,
+            Some(vec!["changelog".to_string(), "renderedFields".to_string()])
+        );
+    }
+
     #[test]
     fn test_search_params_serialization() {
         let params = SearchParams::new().start_at(10).max_results(25);
@@ -157,4 +395,113 @@ mod tests {
         assert_eq!(result.issues.len(), 1);
         assert_eq!(result.issues[0].key, "TEST-1");
     }
+
+    fn search_result(start_at: u32, max_results: u32, total: u32, count: usize) -> SearchResult {
+        let issue: Issue = serde_json::from_value(json!({
+            "id": "10000",
+            "key": "TEST-1",
+            "self": "https://example.atlassian.net/rest/api/3/issue/10000",
+            "fields": {
+                "summary": "Test Issue",
+                "issuetype": {
+                    "id": "1",
+                    "name": "Bug",
+                    "self": "https://example.atlassian.net/rest/api/3/issuetype/1"
+                },
+                "status": {
+                    "id": "1",
+                    "name": "To Do",
+                    "self": "https://example.atlassian.net/rest/api/3/status/1",
+                    "statusCategory": {
+                        "id": 2,
+                        "key": "new",
+                        "name": "To Do",
+                        "colorName": "blue-gray"
+                    }
+                },
+                "reporter": {
+                    "accountId": "557058:f58131cb",
+                    "displayName": "Test User",
+                    "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:f58131cb"
+                },
+                "created": "2024-01-01T00:00:00.000Z",
+                "updated": "2024-01-02T00:00:00.000Z"
+            }
+        }))
+        .unwrap();
+
+        SearchResult {
+            start_at,
+            max_results,
+            total,
+            issues: vec![issue; count],
+            expand: None,
+            names: None,
+            schema: None,
+            next_page_token: None,
+        }
+    }
+
+    #[test]
+    fn test_search_result_has_more() {
+        // 途中のページでは次のページがある
+        let result = search_result(0, 2, 5, 2);
+        assert!(result.has_more());
+        assert_eq!(result.next_start_at(), 2);
+
+        // 件数がちょうど埋まった最終ページでは次のページが無い
+        let result = search_result(2, 3, 5, 3);
+        assert!(!result.has_more());
+        assert_eq!(result.next_start_at(), 5);
+
+        // 端数の最終ページ
+        let result = search_result(4, 2, 5, 1);
+        assert!(!result.has_more());
+        assert_eq!(result.next_start_at(), 5);
+
+        // サーバー側でmaxResultsが制限されても、totalに満たなければ続きがある
+        let result = search_result(0, 100, 250, 100);
+        assert!(result.has_more());
+        assert_eq!(result.next_start_at(), 100);
+    }
+
+    #[test]
+    fn test_search_result_has_more_empty_page() {
+        // 空のページは、totalが残っていても最後のページとして扱う
+        let result = search_result(10, 50, 20, 0);
+        assert!(!result.has_more());
+        assert_eq!(result.next_start_at(), 10);
+
+        let result = search_result(0, 50, 0, 0);
+        assert!(!result.has_more());
+    }
+
+    #[test]
+    fn test_jql_search_result_into_search_result() {
+        // 続きがある場合はトークンを引き継ぎ、totalに関わらず次のページがある
+        let page = JqlSearchResult {
+            issues: search_result(0, 2, 2, 2).issues,
+            next_page_token: Some("token-2".to_string()),
+            is_last: false,
+        };
+        let result = page.into_search_result(2, Some(2));
+        assert_eq!(result.start_at, 2);
+        assert_eq!(result.total, 4);
+        assert!(result.has_more());
+
+        let params = SearchParams::new().max_results(2).next_page(&result);
+        assert_eq!(params.start_at, Some(4));
+        assert_eq!(params.next_page_token.as_deref(), Some("token-2"));
+
+        // 最後のページではトークンが返されても引き継がない
+        let page = JqlSearchResult {
+            issues: search_result(0, 2, 1, 1).issues,
+            next_page_token: Some("token-3".to_string()),
+            is_last: true,
+        };
+        let result = page.into_search_result(4, Some(2));
+        assert_eq!(result.total, 5);
+        assert!(result.next_page_token.is_none());
+        assert!(!result.has_more());
+    }
 }
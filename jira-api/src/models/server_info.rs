@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+
+/// JIRAのデプロイ形態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeploymentType {
+    Cloud,
+    Server,
+    #[serde(rename = "Data Center", alias = "DataCenter")]
+    DataCenter,
+    /// 未知のデプロイ形態
+    #[serde(other)]
+    Unknown,
+}
+
+/// `/rest/api/3/serverInfo`のレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerInfo {
+    #[serde(rename = "baseUrl")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    pub version: String,
+    #[serde(rename = "versionNumbers")]
+    #[serde(default)]
+    pub version_numbers: Vec<u32>,
+    #[serde(rename = "deploymentType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployment_type: Option<DeploymentType>,
+    #[serde(rename = "buildNumber")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_number: Option<u64>,
+    #[serde(rename = "serverTitle")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_title: Option<String>,
+}
+
+/// Issue検索に使用するページング方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SearchStrategy {
+    /// `nextPageToken`によるページング（Cloudの`/rest/api/3/search/jql`）
+    TokenPaginated,
+    /// `startAt`によるページング（`/rest/api/3/search`）
+    OffsetPaginated,
+}
+
+impl SearchStrategy {
+    /// 検索に使用するエンドポイント
+    pub fn search_endpoint(&self) -> &'static str {
+        match self {
+            SearchStrategy::TokenPaginated => "/rest/api/3/search/jql",
+            SearchStrategy::OffsetPaginated => "/rest/api/3/search",
+        }
+    }
+}
+
+/// サーバー情報から判定したクライアントの利用可能機能
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServerCapabilities {
+    /// デプロイ形態
+    pub deployment_type: DeploymentType,
+    /// Issue検索のページング方式
+    pub search_strategy: SearchStrategy,
+}
+
+impl ServerInfo {
+    /// Cloudかどうか
+    pub fn is_cloud(&self) -> bool {
+        self.deployment_type == Some(DeploymentType::Cloud)
+    }
+
+    /// サーバー情報から利用可能な機能を判定
+    ///
+    /// トークン方式の検索はCloudのみで提供されるため、Server/Data Centerでは
+    /// バージョンに関わらずオフセット方式を使用します。
+    pub fn capabilities(&self) -> ServerCapabilities {
+        let search_strategy = if self.is_cloud() {
+            SearchStrategy::TokenPaginated
+        } else {
+            SearchStrategy::OffsetPaginated
+        };
+
+        ServerCapabilities {
+            deployment_type: self.deployment_type.unwrap_or(DeploymentType::Unknown),
+            search_strategy,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_server_info_cloud() {
+        let info: ServerInfo = serde_json::from_value(json!({
+            "baseUrl": "https://example.atlassian.net",
+            "version": "1001.0.0-SNAPSHOT",
+            "versionNumbers": [1001, 0, 0],
+            "deploymentType": "Cloud",
+            "buildNumber": 100242,
+            "buildDate": "2024-01-15T00:00:00.000+0000",
+            "serverTime": "2024-01-15T10:30:00.000+0000",
+            "scmInfo": "abc123",
+            "serverTitle": "Jira"
+        }))
+        .unwrap();
+
+        assert_eq!(info.version, "1001.0.0-SNAPSHOT");
+        assert_eq!(info.version_numbers, vec![1001, 0, 0]);
+        assert_eq!(info.deployment_type, Some(DeploymentType::Cloud));
+        assert_eq!(info.build_number, Some(100242));
+        assert!(info.is_cloud());
+
+        let capabilities = info.capabilities();
+        assert_eq!(capabilities.deployment_type, DeploymentType::Cloud);
+        assert_eq!(capabilities.search_strategy, SearchStrategy::TokenPaginated);
+    }
+
+    #[test]
+    fn test_server_info_server_and_data_center() {
+        let server: ServerInfo = serde_json::from_value(json!({
+            "baseUrl": "https://jira.example.com",
+            "version": "9.12.0",
+            "versionNumbers": [9, 12, 0],
+            "deploymentType": "Server",
+            "buildNumber": 9120000
+        }))
+        .unwrap();
+
+        assert_eq!(server.deployment_type, Some(DeploymentType::Server));
+        assert!(!server.is_cloud());
+        assert_eq!(
+            server.capabilities().search_strategy,
+            SearchStrategy::OffsetPaginated
+        );
+
+        let data_center: ServerInfo = serde_json::from_value(json!({
+            "version": "9.12.0",
+            "deploymentType": "Data Center"
+        }))
+        .unwrap();
+        assert_eq!(
+            data_center.deployment_type,
+            Some(DeploymentType::DataCenter)
+        );
+        assert_ne!(
+            data_center.capabilities().search_strategy,
+            SearchStrategy::TokenPaginated
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+/// Issueの時間管理情報（`timetracking`フィールド）
+///
+/// 見積もりと作業時間は表示用の文字列（`"1d 2h"`など）と秒数の両方で返されます。
+/// 値が設定されていない項目はJIRAのレスポンスに含まれないため、全て省略可能です。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TimeTracking {
+    #[serde(rename = "originalEstimate")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_estimate: Option<String>,
+
+    #[serde(rename = "remainingEstimate")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_estimate: Option<String>,
+
+    #[serde(rename = "timeSpent")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_spent: Option<String>,
+
+    /// 当初見積もり（秒）
+    #[serde(rename = "originalEstimateSeconds")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_estimate_seconds: Option<i64>,
+
+    /// 残り見積もり（秒）
+    #[serde(rename = "remainingEstimateSeconds")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_estimate_seconds: Option<i64>,
+
+    /// 作業時間（秒）
+    #[serde(rename = "timeSpentSeconds")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_spent_seconds: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_time_tracking_deserialization() {
+        let json_data = json!({
+            "originalEstimate": "1d",
+            "remainingEstimate": "4h",
+            "timeSpent": "4h",
+            "originalEstimateSeconds": 28800,
+            "remainingEstimateSeconds": 14400,
+            "timeSpentSeconds": 14400
+        });
+
+        let time_tracking: TimeTracking = serde_json::from_value(json_data).unwrap();
+
+        assert_eq!(time_tracking.original_estimate.as_deref(), Some("1d"));
+        assert_eq!(time_tracking.original_estimate_seconds, Some(28800));
+        assert_eq!(time_tracking.remaining_estimate_seconds, Some(14400));
+        assert_eq!(time_tracking.time_spent_seconds, Some(14400));
+
+        // 未設定の項目は省略される
+        let empty: TimeTracking = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(empty, TimeTracking::default());
+    }
+}
@@ -0,0 +1,49 @@
+use super::Status;
+use serde::{Deserialize, Serialize};
+
+/// Issueで実行可能なトランジション（ステータス遷移）
+///
+/// `GET /rest/api/3/issue/{key}/transitions`の`transitions`の各要素です。
+/// 実行するには`id`を[`crate::JiraClient::transition_issue`]に渡します。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transition {
+    pub id: String,
+    /// トランジション名（例: `Start Progress`）
+    pub name: String,
+    /// 遷移先のステータス
+    #[serde(rename = "to")]
+    pub to_status: Status,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_transition_deserialization() {
+        let json_data = json!({
+            "id": "21",
+            "name": "Start Progress",
+            "hasScreen": false,
+            "to": {
+                "id": "3",
+                "name": "In Progress",
+                "self": "https://example.atlassian.net/rest/api/3/status/3",
+                "statusCategory": {
+                    "id": 4,
+                    "key": "indeterminate",
+                    "name": "In Progress",
+                    "colorName": "yellow"
+                }
+            }
+        });
+
+        let transition: Transition = serde_json::from_value(json_data).unwrap();
+
+        assert_eq!(transition.id, "21");
+        assert_eq!(transition.name, "Start Progress");
+        assert_eq!(transition.to_status.name, "In Progress");
+        assert_eq!(transition.to_status.status_category.key, "indeterminate");
+    }
+}
@@ -1,3 +1,4 @@
+use super::AvatarUrls;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,18 +25,6 @@ pub struct User {
     pub account_type: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AvatarUrls {
-    #[serde(rename = "48x48")]
-    pub size_48: String,
-    #[serde(rename = "24x24")]
-    pub size_24: String,
-    #[serde(rename = "16x16")]
-    pub size_16: String,
-    #[serde(rename = "32x32")]
-    pub size_32: String,
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// Issueの投票状況（`votes`フィールド）
+///
+/// 投票者の一覧は含まず、件数と自分が投票済みかどうかのみを表します。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Votes {
+    #[serde(rename = "self")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_url: Option<String>,
+    /// 投票数
+    #[serde(default)]
+    pub votes: u64,
+    /// 現在のユーザーが投票済みかどうか
+    #[serde(rename = "hasVoted")]
+    #[serde(default)]
+    pub has_voted: bool,
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// Issueのウォッチ状況（`watches`フィールド）
+///
+/// ウォッチャーの一覧は含まず、件数と自分がウォッチ中かどうかのみを表します。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Watches {
+    #[serde(rename = "self")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_url: Option<String>,
+    /// ウォッチャー数
+    #[serde(rename = "watchCount")]
+    #[serde(default)]
+    pub watch_count: u64,
+    /// 現在のユーザーがウォッチ中かどうか
+    #[serde(rename = "isWatching")]
+    #[serde(default)]
+    pub is_watching: bool,
+}
@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::User;
+
+/// Issueの作業ログ
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Worklog {
+    pub id: String,
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<User>,
+    /// コメント（ADF形式のオブジェクトまたは文字列）
+    #[serde(default)]
+    pub comment: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started: Option<DateTime<Utc>>,
+    /// 作業時間の表示用文字列（`"3h 20m"`など）
+    #[serde(rename = "timeSpent")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_spent: Option<String>,
+    /// 作業時間（秒）
+    #[serde(rename = "timeSpentSeconds")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_spent_seconds: Option<i64>,
+}
@@ -1,8 +1,30 @@
 use crate::{Error, Issue};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures::Stream;
+use futures::future::BoxFuture;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
+use std::pin::Pin;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// NDJSON・CSVエクスポート時に1回で読み込むIssueの件数
+const EXPORT_PAGE_SIZE: usize = 500;
+
+/// CSVエクスポートの列
+pub const CSV_EXPORT_COLUMNS: [&str; 10] = [
+    "key",
+    "summary",
+    "status",
+    "priority",
+    "issue_type",
+    "project_key",
+    "assignee",
+    "reporter",
+    "created",
+    "updated",
+];
 
 /// データ永続化ストアの抽象化トレイト
 #[async_trait]
@@ -10,6 +32,12 @@ pub trait PersistenceStore: Send + Sync {
     /// Issueを保存
     async fn save_issues(&mut self, issues: &[Issue]) -> Result<usize, Error>;
 
+    /// Issueをキー単位で追加・置き換え
+    ///
+    /// 同じキーのIssueは渡されたもので置き換え、それ以外の既存のIssueはそのまま残します。
+    /// `save_issues`が既存のIssueを全て置き換えるストアでも、一部のIssueだけを書き込む場合に使用します。
+    async fn upsert_issues(&mut self, issues: &[Issue]) -> Result<usize, Error>;
+
     /// フィルター条件でIssueを読み込み
     async fn load_issues(&self, filter: &IssueFilter) -> Result<Vec<Issue>, Error>;
 
@@ -19,12 +47,104 @@ pub trait PersistenceStore: Send + Sync {
     /// Issueの件数を取得
     async fn count_issues(&self, filter: &IssueFilter) -> Result<usize, Error>;
 
+    /// フィルター条件に一致するIssueを指定の軸で集計
+    ///
+    /// 件数の多い順（同数の場合はキー順）に`(キー, 件数)`を返します。
+    /// フィルターの件数制限とオフセットは無視されます。
+    /// 未割り当てのIssueは[`UNASSIGNED_BUCKET`]に、その他の値が無いIssueは空文字列のキーに集計され、
+    /// [`StorageStats::issues_by_assignee`]と同じキーになります。
+    async fn group_counts(
+        &self,
+        filter: &IssueFilter,
+        by: GroupBy,
+    ) -> Result<Vec<(String, usize)>, Error>;
+
+    /// フィルター条件に一致するIssueの指定項目のみを読み込み
+    ///
+    /// レポートなどで一部の項目だけが必要な場合に、Issue全体を保持せずに済みます。
+    /// 並び順・件数制限・オフセットは`load_issues`と同じです。
+    async fn load_projection(
+        &self,
+        filter: &IssueFilter,
+        fields: &[IssueField],
+    ) -> Result<Vec<IssueProjection>, Error>;
+
+    /// フィルター条件に一致するIssueをステータスカテゴリで集計
+    ///
+    /// ワークフローごとに異なるステータス名を、`new`（To Do）、`indeterminate`（In Progress）、
+    /// `done`（Done）のカテゴリキーにまとめて数えます。並び順は`group_counts`と同じです。
+    async fn group_counts_by_category(
+        &self,
+        filter: &IssueFilter,
+    ) -> Result<Vec<(String, usize)>, Error> {
+        self.group_counts(filter, GroupBy::StatusCategory).await
+    }
+
     /// 指定されたIssueキーのIssueを削除
     async fn delete_issues(&mut self, issue_keys: &[String]) -> Result<usize, Error>;
 
+    /// トランザクションを開始
+    ///
+    /// 通常は[`PersistenceStore::transaction`]を使用してください。
+    /// 既にトランザクション中の場合は`InvalidInput`エラーを返します。
+    async fn begin_transaction(&mut self) -> Result<(), Error>;
+
+    /// トランザクションをコミット
+    async fn commit_transaction(&mut self) -> Result<(), Error>;
+
+    /// トランザクションをロールバックし、開始後の書き込みを全て破棄
+    async fn rollback_transaction(&mut self) -> Result<(), Error>;
+
+    /// 複数の書き込みを1つのトランザクションとして実行
+    ///
+    /// IssueとIssue履歴を続けて保存する場合など、途中で失敗したときに
+    /// 片方だけが書き込まれた状態を残さないために使用します。
+    /// `f`がエラーを返した場合はロールバックしてそのエラーを返し、
+    /// 成功した場合はコミットして`f`の戻り値を返します。
+    ///
+    /// ```rust,no_run
+    /// # use jira_api::{DuckDBStore, Error, PersistenceStore};
+    /// # async fn example(store: &mut DuckDBStore, issues: Vec<jira_api::Issue>, histories: Vec<jira_api::IssueHistory>) -> Result<(), Error> {
+    /// store
+    ///     .transaction(|store| {
+    ///         Box::pin(async move {
+    ///             store.save_issues(&issues).await?;
+    ///             store.save_issue_history(&histories).await?;
+    ///             Ok(())
+    ///         })
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn transaction<F, T>(&mut self, f: F) -> Result<T, Error>
+    where
+        Self: Sized,
+        F: for<'a> FnOnce(&'a mut Self) -> BoxFuture<'a, Result<T, Error>> + Send,
+        T: Send,
+    {
+        self.begin_transaction().await?;
+
+        match f(self).await {
+            Ok(value) => {
+                self.commit_transaction().await?;
+                Ok(value)
+            }
+            Err(e) => {
+                let _ = self.rollback_transaction().await;
+                Err(e)
+            }
+        }
+    }
+
     /// ストレージを最適化（インデックス再構築、圧縮など）
     async fn optimize(&mut self) -> Result<(), Error>;
 
+    /// 保留中のデータをディスクに書き出す
+    ///
+    /// 短時間で終了するCLIやファイルを検証するテストで、終了前に確実に永続化するために使用します。
+    async fn flush(&mut self) -> Result<(), Error>;
+
     /// ストレージの統計情報を取得
     async fn get_stats(&self) -> Result<StorageStats, Error>;
 
@@ -34,6 +154,35 @@ pub trait PersistenceStore: Send + Sync {
     /// フィルター設定を読み込み
     async fn load_filter_config(&self) -> Result<Option<FilterConfig>, Error>;
 
+    /// 保存済みフィルター設定の使用を記録
+    ///
+    /// 使用回数を1増やし、最終使用日時を現在時刻に更新します。
+    /// 指定IDの設定が無い場合は`NotFound`エラーを返します。
+    async fn touch_filter_config(&mut self, id: &str) -> Result<(), Error> {
+        let mut config = self
+            .load_filter_config()
+            .await?
+            .filter(|c| c.id == id)
+            .ok_or_else(|| Error::NotFound(format!("Filter config not found: {}", id)))?;
+
+        config.increment_usage();
+        self.save_filter_config(&config).await
+    }
+
+    /// 保存済みフィルター設定でIssueを読み込み、使用を記録
+    async fn load_issues_with_saved_filter(&mut self, id: &str) -> Result<Vec<Issue>, Error> {
+        self.touch_filter_config(id).await?;
+
+        // 使用記録で更新日時が最新になるため、最新の設定として読み込まれる
+        let config = self
+            .load_filter_config()
+            .await?
+            .filter(|c| c.id == id)
+            .ok_or_else(|| Error::NotFound(format!("Filter config not found: {}", id)))?;
+
+        self.load_issues(&config.filter).await
+    }
+
     /// 履歴データを保存
     async fn save_issue_history(&mut self, history: &[crate::IssueHistory])
     -> Result<usize, Error>;
@@ -44,11 +193,162 @@ pub trait PersistenceStore: Send + Sync {
         filter: &crate::HistoryFilter,
     ) -> Result<Vec<crate::IssueHistory>, Error>;
 
+    /// 履歴データをストリームとして取得
+    ///
+    /// `load_issue_history`と同じ条件・同じ順序でレコードを返しますが、
+    /// 全件を一度にメモリへ読み込まずに少しずつ取り出せます。
+    fn stream_issue_history(
+        &self,
+        filter: crate::HistoryFilter,
+    ) -> Pin<Box<dyn Stream<Item = Result<crate::IssueHistory, Error>> + Send + '_>>;
+
     /// 履歴統計情報を取得
     async fn get_history_stats(&self) -> Result<crate::HistoryStats, Error>;
 
     /// 指定課題キーの履歴を削除
     async fn delete_issue_history(&mut self, issue_keys: &[String]) -> Result<usize, Error>;
+
+    /// エクスポート時に1回で読み込むIssueの件数
+    ///
+    /// `None`の場合はページングせず、フィルターに一致するIssueを一度に読み込みます。
+    /// 読み込みのたびに全件を読み直すストアでは、ページングすると全体の処理量が
+    /// 件数の2乗に比例するため`None`を返します。
+    fn export_page_size(&self) -> Option<usize> {
+        Some(EXPORT_PAGE_SIZE)
+    }
+
+    /// フィルター条件に一致するIssueをNDJSON形式で書き出し
+    ///
+    /// 1行に1つのIssueをJSONオブジェクトとして書き出し、書き出した件数を返します。
+    /// 全件をメモリに保持しないよう、フィルターのオフセットと件数制限を尊重しつつ
+    /// [`PersistenceStore::export_page_size`]件ずつページングして読み込みます。
+    async fn export_ndjson(
+        &self,
+        filter: &IssueFilter,
+        writer: &mut (dyn AsyncWrite + Unpin + Send),
+    ) -> Result<usize, Error> {
+        let mut exported = 0;
+
+        while let Some((issues, has_more)) = load_export_page(self, filter, exported).await? {
+            for issue in &issues {
+                let mut line = serde_json::to_vec(issue)
+                    .map_err(|e| Error::SerializationError(e.to_string()))?;
+                line.push(b'\n');
+                writer.write_all(&line).await?;
+            }
+
+            exported += issues.len();
+            if !has_more {
+                break;
+            }
+        }
+
+        writer.flush().await?;
+        Ok(exported)
+    }
+
+    /// フィルター条件に一致するIssueをCSVファイルに書き出し
+    ///
+    /// 列は[`CSV_EXPORT_COLUMNS`]の順で、担当者と報告者は表示名、日時はRFC 3339形式です。
+    /// カンマや改行、ダブルクォートを含む値はクオートされるため、Excelやpandasでそのまま読み込めます。
+    /// 既存のファイルは上書きし、一致するIssueが無い場合もヘッダー行のみのファイルを作成します。
+    /// 書き出した行数（ヘッダーを除く）を返します。
+    async fn export_csv(&self, filter: &IssueFilter, path: &Path) -> Result<usize, Error> {
+        let mut file = tokio::fs::File::create(path).await?;
+        file.write_all(&csv_bytes([CSV_EXPORT_COLUMNS])?).await?;
+        let mut exported = 0;
+
+        while let Some((issues, has_more)) = load_export_page(self, filter, exported).await? {
+            file.write_all(&csv_bytes(issues.iter().map(csv_record))?)
+                .await?;
+
+            exported += issues.len();
+            if !has_more {
+                break;
+            }
+        }
+
+        file.flush().await?;
+        Ok(exported)
+    }
+}
+
+/// エクスポート用に、フィルターのオフセットと件数制限を尊重しながら次のページを読み込む
+///
+/// `exported`はこれまでに書き出した件数です。件数制限に達した場合は`None`を返し、
+/// それ以外は読み込んだIssueと、続きのページがあり得るかどうかを返します。
+async fn load_export_page<S: PersistenceStore + ?Sized>(
+    store: &S,
+    filter: &IssueFilter,
+    exported: usize,
+) -> Result<Option<(Vec<Issue>, bool)>, Error> {
+    let remaining = filter.limit.map(|limit| limit.saturating_sub(exported));
+    let page_size = match (store.export_page_size(), remaining) {
+        (Some(size), Some(remaining)) => Some(size.min(remaining)),
+        (size, remaining) => size.or(remaining),
+    };
+    if page_size == Some(0) {
+        return Ok(None);
+    }
+
+    let mut page_filter = filter.clone();
+    page_filter.offset = Some(filter.offset.unwrap_or(0) + exported);
+    page_filter.limit = page_size;
+
+    let issues = store.load_issues(&page_filter).await?;
+    let has_more = page_size.is_some_and(|size| issues.len() == size);
+    Ok(Some((issues, has_more)))
+}
+
+/// IssueをCSVエクスポートの1行に変換する（列は[`CSV_EXPORT_COLUMNS`]の順）
+fn csv_record(issue: &Issue) -> [String; 10] {
+    let fields = &issue.fields;
+    [
+        issue.key.clone(),
+        fields.summary.clone(),
+        fields.status.name.clone(),
+        fields
+            .priority
+            .as_ref()
+            .map(|p| p.name.clone())
+            .unwrap_or_default(),
+        fields.issue_type.name.clone(),
+        fields
+            .project
+            .as_ref()
+            .map(|p| p.key.clone())
+            .unwrap_or_default(),
+        fields
+            .assignee
+            .as_ref()
+            .map(|u| u.display_name.clone())
+            .unwrap_or_default(),
+        fields.reporter.display_name.clone(),
+        fields.created.to_rfc3339(),
+        fields.updated.to_rfc3339(),
+    ]
+}
+
+/// レコードをCSV形式のバイト列に変換する
+///
+/// ページごとに変換してファイルへ書き出すことで、全件をメモリに保持せずにエクスポートします。
+fn csv_bytes<I, R>(records: I) -> Result<Vec<u8>, Error>
+where
+    I: IntoIterator<Item = R>,
+    R: IntoIterator,
+    R::Item: AsRef<[u8]>,
+{
+    let mut csv_writer = csv::Writer::from_writer(Vec::new());
+    for record in records {
+        csv_writer.write_record(record).map_err(csv_error)?;
+    }
+    csv_writer
+        .into_inner()
+        .map_err(|e| Error::SerializationError(format!("CSV write error: {}", e)))
+}
+
+fn csv_error(error: csv::Error) -> Error {
+    Error::SerializationError(format!("CSV write error: {}", error))
 }
 
 /// Issue検索フィルター
@@ -66,16 +366,36 @@ pub struct IssueFilter {
     pub reporters: Vec<String>,
     /// 担当者のユーザー名
     pub assignees: Vec<String>,
+    /// 未割り当てのIssueで絞り込むかどうか
+    ///
+    /// `Some(true)`は担当者が無いIssueに一致し、`assignees`と併用した場合は
+    /// いずれかに一致すればよい。`Some(false)`は担当者があるIssueのみに一致する。
+    #[serde(default)]
+    pub assignee_unassigned: Option<bool>,
     /// 作成日時の範囲
     pub created_range: Option<DateRange>,
     /// 更新日時の範囲
     pub updated_range: Option<DateRange>,
     /// サマリー検索（部分一致）
     pub summary_contains: Option<String>,
-    /// 説明検索（部分一致）
+    /// 説明検索（部分一致、大文字小文字を区別しない）
+    ///
+    /// `text_search`と同じく、ADF形式の説明はプレーンテキストに変換してから照合します。
+    /// 保存されたJSONの構造（`paragraph`などのノード種別）には一致しません。
     pub description_contains: Option<String>,
-    /// ラベル
+    /// サマリーまたは説明の本文検索（部分一致、大文字小文字を区別しない）
+    ///
+    /// ADF形式の説明はプレーンテキストに変換してから照合するため、ノード種別などの構造は一致しません。
+    #[serde(default)]
+    pub text_search: Option<String>,
+    /// ラベル（いずれかのラベルを持つIssueに一致）
     pub labels: Vec<String>,
+    /// 親Issueのキー
+    #[serde(default)]
+    pub parent_keys: Vec<String>,
+    /// カスタムフィールドの条件（すべてに一致するものに絞り込む）
+    #[serde(default)]
+    pub custom_field_filters: Vec<CustomFieldFilter>,
     /// 最大取得件数
     pub limit: Option<usize>,
     /// スキップする件数（オフセット）
@@ -94,11 +414,15 @@ impl IssueFilter {
             issue_types: Vec::new(),
             reporters: Vec::new(),
             assignees: Vec::new(),
+            assignee_unassigned: None,
             created_range: None,
             updated_range: None,
             summary_contains: None,
             description_contains: None,
+            text_search: None,
             labels: Vec::new(),
+            parent_keys: Vec::new(),
+            custom_field_filters: Vec::new(),
             limit: None,
             offset: None,
             sort_order: SortOrder::CreatedDesc,
@@ -141,6 +465,12 @@ impl IssueFilter {
         self
     }
 
+    /// 未割り当てかどうかでフィルタ
+    pub fn assignee_unassigned(mut self, unassigned: bool) -> Self {
+        self.assignee_unassigned = Some(unassigned);
+        self
+    }
+
     /// 作成日時範囲でフィルタ
     pub fn created_range(mut self, range: DateRange) -> Self {
         self.created_range = Some(range);
@@ -159,18 +489,36 @@ impl IssueFilter {
         self
     }
 
-    /// 説明検索
+    /// 説明検索（ADF形式の説明はプレーンテキストとして照合）
     pub fn description_contains(mut self, text: String) -> Self {
         self.description_contains = Some(text);
         self
     }
 
+    /// サマリーと説明の本文検索
+    pub fn text_search(mut self, text: String) -> Self {
+        self.text_search = Some(text);
+        self
+    }
+
     /// ラベルでフィルタ
     pub fn labels(mut self, labels: Vec<String>) -> Self {
         self.labels = labels;
         self
     }
 
+    /// 親Issueのキーでフィルタ
+    pub fn parent_keys(mut self, parent_keys: Vec<String>) -> Self {
+        self.parent_keys = parent_keys;
+        self
+    }
+
+    /// カスタムフィールドの条件を追加
+    pub fn custom_field_filter(mut self, filter: CustomFieldFilter) -> Self {
+        self.custom_field_filters.push(filter);
+        self
+    }
+
     /// 最大取得件数を設定
     pub fn limit(mut self, limit: usize) -> Self {
         self.limit = Some(limit);
@@ -197,11 +545,15 @@ impl IssueFilter {
             && self.issue_types.is_empty()
             && self.reporters.is_empty()
             && self.assignees.is_empty()
+            && self.assignee_unassigned.is_none()
             && self.created_range.is_none()
             && self.updated_range.is_none()
             && self.summary_contains.is_none()
             && self.description_contains.is_none()
+            && self.text_search.is_none()
             && self.labels.is_empty()
+            && self.parent_keys.is_empty()
+            && self.custom_field_filters.is_empty()
     }
 
     /// Issueがフィルター条件に一致するかチェック
@@ -246,14 +598,36 @@ impl IssueFilter {
             }
         }
 
-        // 担当者でフィルタ
-        if !self.assignees.is_empty() {
-            if let Some(ref assignee) = issue.fields.assignee {
-                if !self.assignees.contains(&assignee.display_name) {
-                    return false;
-                }
-            } else {
-                return false;
+        // 担当者でフィルタ（未割り当ての指定とはORで組み合わせる）
+        let matches_assignee = match issue.fields.assignee {
+            Some(ref assignee) => match self.assignee_unassigned {
+                Some(true) => self.assignees.contains(&assignee.display_name),
+                _ => self.assignees.is_empty() || self.assignees.contains(&assignee.display_name),
+            },
+            None => match self.assignee_unassigned {
+                Some(unassigned) => unassigned,
+                None => self.assignees.is_empty(),
+            },
+        };
+        if !matches_assignee {
+            return false;
+        }
+
+        // ラベルでフィルタ（いずれかのラベルを持つIssueに一致）
+        if !self.labels.is_empty()
+            && !issue
+                .labels()
+                .iter()
+                .any(|label| self.labels.iter().any(|l| l == label))
+        {
+            return false;
+        }
+
+        // 親Issueでフィルタ
+        if !self.parent_keys.is_empty() {
+            match issue.parent_key() {
+                Some(parent_key) if self.parent_keys.iter().any(|k| k == parent_key) => {}
+                _ => return false,
             }
         }
 
@@ -273,35 +647,39 @@ impl IssueFilter {
 
         // サマリー検索
         if let Some(ref text) = self.summary_contains {
-            if !issue
-                .fields
-                .summary
-                .to_lowercase()
-                .contains(&text.to_lowercase())
-            {
+            if !contains_ignore_case(&issue.fields.summary, text) {
                 return false;
             }
         }
 
         // 説明検索
         if let Some(ref text) = self.description_contains {
-            if let Some(ref description) = issue.fields.description {
-                let description_text = match description {
-                    serde_json::Value::String(s) => s.clone(),
-                    serde_json::Value::Object(_) => description.to_string(), // ADF形式の場合は文字列化
-                    _ => description.to_string(),
-                };
-                if !description_text
-                    .to_lowercase()
-                    .contains(&text.to_lowercase())
-                {
-                    return false;
-                }
-            } else {
+            match issue.description_text() {
+                Some(description) if contains_ignore_case(&description, text) => {}
+                _ => return false,
+            }
+        }
+
+        // 本文検索（サマリーまたは説明のプレーンテキスト）
+        if let Some(ref text) = self.text_search {
+            let in_summary = contains_ignore_case(&issue.fields.summary, text);
+            let in_description = issue
+                .description_text()
+                .is_some_and(|d| contains_ignore_case(&d, text));
+            if !in_summary && !in_description {
                 return false;
             }
         }
 
+        // カスタムフィールドでフィルタ
+        if !self
+            .custom_field_filters
+            .iter()
+            .all(|f| f.matches(&issue.fields.custom_fields))
+        {
+            return false;
+        }
+
         true
     }
 }
@@ -312,12 +690,100 @@ impl Default for IssueFilter {
     }
 }
 
+/// カスタムフィールド条件の演算子
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CustomFieldOperator {
+    /// 等しい（選択肢の場合は`value`または`name`で比較）
+    Eq,
+    /// より大きい（数値のみ）
+    Gt,
+    /// より小さい（数値のみ）
+    Lt,
+    /// 含む（文字列は部分一致、配列はいずれかの要素が等しい）
+    Contains,
+}
+
+/// カスタムフィールドの値による絞り込み条件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomFieldFilter {
+    /// フィールドID（例: `customfield_10016`）
+    pub field_id: String,
+    /// 演算子
+    pub operator: CustomFieldOperator,
+    /// 比較する値
+    pub value: serde_json::Value,
+}
+
+impl CustomFieldFilter {
+    /// 新しい条件を作成
+    pub fn new(
+        field_id: impl Into<String>,
+        operator: CustomFieldOperator,
+        value: serde_json::Value,
+    ) -> Self {
+        Self {
+            field_id: field_id.into(),
+            operator,
+            value,
+        }
+    }
+
+    /// カスタムフィールドの値が条件に一致するかチェック
+    ///
+    /// フィールドが存在しない場合は一致しません。
+    pub fn matches(&self, custom_fields: &HashMap<String, serde_json::Value>) -> bool {
+        let Some(actual) = custom_fields.get(&self.field_id) else {
+            return false;
+        };
+
+        match self.operator {
+            CustomFieldOperator::Eq => custom_values_equal(actual, &self.value),
+            CustomFieldOperator::Gt => match (actual.as_f64(), self.value.as_f64()) {
+                (Some(a), Some(b)) => a > b,
+                _ => false,
+            },
+            CustomFieldOperator::Lt => match (actual.as_f64(), self.value.as_f64()) {
+                (Some(a), Some(b)) => a < b,
+                _ => false,
+            },
+            CustomFieldOperator::Contains => match actual {
+                serde_json::Value::Array(items) => items
+                    .iter()
+                    .any(|item| custom_values_equal(item, &self.value)),
+                serde_json::Value::String(text) => {
+                    self.value.as_str().is_some_and(|v| text.contains(v))
+                }
+                _ => false,
+            },
+        }
+    }
+}
+
+/// カスタムフィールドの値を比較する
+///
+/// 数値は数値として比較し（`5`と`5.0`は等しい）、選択肢オブジェクトは`value`または`name`で比較します。
+fn custom_values_equal(actual: &serde_json::Value, expected: &serde_json::Value) -> bool {
+    if let (Some(a), Some(b)) = (actual.as_f64(), expected.as_f64()) {
+        return a == b;
+    }
+    if actual == expected {
+        return true;
+    }
+    ["value", "name"]
+        .iter()
+        .any(|key| actual.get(key).is_some_and(|v| v == expected))
+}
+
 /// 日時範囲
+///
+/// 開始日時を含み終了日時を含まない半開区間`[start, end)`です。
+/// 隣接する範囲（前の範囲の`end`と次の範囲の`start`が同じ）で同じ日時が重複して一致しません。
+/// JsonStoreとDuckDBStoreのどちらでも同じ境界で絞り込まれます。
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DateRange {
-    /// 開始日時
+    /// 開始日時（この日時を含む）
     pub start: DateTime<Utc>,
-    /// 終了日時
+    /// 終了日時（この日時を含まない）
     pub end: DateTime<Utc>,
 }
 
@@ -337,9 +803,9 @@ impl DateRange {
         Ok(())
     }
 
-    /// 指定した日時が範囲に含まれるかチェック
+    /// 指定した日時が範囲に含まれるかチェック（`start <= datetime < end`）
     pub fn contains(&self, datetime: &DateTime<Utc>) -> bool {
-        datetime >= &self.start && datetime <= &self.end
+        datetime >= &self.start && datetime < &self.end
     }
 
     /// 最近N日間の範囲を作成
@@ -376,6 +842,22 @@ pub enum SortOrder {
     PriorityAsc,
     /// 優先度降順（優先度が低い順）
     PriorityDesc,
+    /// 担当者昇順（未割り当ては末尾）
+    AssigneeAsc,
+    /// 担当者降順（未割り当ては末尾）
+    AssigneeDesc,
+    /// 報告者昇順
+    ReporterAsc,
+    /// 報告者降順
+    ReporterDesc,
+    /// ステータス昇順
+    StatusAsc,
+    /// ステータス降順
+    StatusDesc,
+    /// Issue種別昇順
+    TypeAsc,
+    /// Issue種別降順
+    TypeDesc,
 }
 
 impl Default for SortOrder {
@@ -384,6 +866,147 @@ impl Default for SortOrder {
     }
 }
 
+/// 大文字小文字を区別せずに部分一致を判定
+fn contains_ignore_case(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(&needle.to_lowercase())
+}
+
+/// 集計の軸
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GroupBy {
+    /// ステータス名
+    Status,
+    /// プロジェクトキー
+    Project,
+    /// Issue種別名
+    Type,
+    /// 優先度名
+    Priority,
+    /// 担当者の表示名
+    Assignee,
+    /// ステータスカテゴリのキー（`new`, `indeterminate`, `done`）
+    StatusCategory,
+}
+
+impl GroupBy {
+    /// Issueから集計キーを取得
+    pub fn key_of(&self, issue: &Issue) -> String {
+        let fields = &issue.fields;
+        match self {
+            GroupBy::Status => fields.status.name.clone(),
+            GroupBy::Project => fields
+                .project
+                .as_ref()
+                .map(|p| p.key.clone())
+                .unwrap_or_default(),
+            GroupBy::Type => fields.issue_type.name.clone(),
+            GroupBy::Priority => fields
+                .priority
+                .as_ref()
+                .map(|p| p.name.clone())
+                .unwrap_or_default(),
+            GroupBy::Assignee => fields
+                .assignee
+                .as_ref()
+                .map_or_else(|| UNASSIGNED_BUCKET.to_string(), |a| a.display_name.clone()),
+            GroupBy::StatusCategory => issue.status_category_key().to_string(),
+        }
+    }
+}
+
+/// 射影で取得するIssueの項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IssueField {
+    /// Issue ID
+    Id,
+    /// Issueキー
+    Key,
+    /// サマリー
+    Summary,
+    /// ステータス名
+    Status,
+    /// 優先度名
+    Priority,
+    /// Issue種別名
+    IssueType,
+    /// プロジェクトキー
+    Project,
+    /// 報告者の表示名
+    Reporter,
+    /// 担当者の表示名
+    Assignee,
+    /// 作成日時
+    Created,
+    /// 更新日時
+    Updated,
+    /// 解決状況名
+    Resolution,
+}
+
+/// 指定した項目のみを持つ軽量なIssue
+///
+/// 要求されなかった項目と、Issueに値が無い項目は`None`になります。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IssueProjection {
+    pub id: Option<String>,
+    pub key: Option<String>,
+    pub summary: Option<String>,
+    pub status: Option<String>,
+    pub priority: Option<String>,
+    pub issue_type: Option<String>,
+    pub project_key: Option<String>,
+    pub reporter: Option<String>,
+    pub assignee: Option<String>,
+    pub created: Option<DateTime<Utc>>,
+    pub updated: Option<DateTime<Utc>>,
+    pub resolution: Option<String>,
+}
+
+impl IssueProjection {
+    /// Issueから指定した項目を取り出す
+    pub fn from_issue(issue: &Issue, fields: &[IssueField]) -> Self {
+        let mut projection = Self::default();
+        let issue_fields = &issue.fields;
+
+        for field in fields {
+            match field {
+                IssueField::Id => projection.id = Some(issue.id.clone()),
+                IssueField::Key => projection.key = Some(issue.key.clone()),
+                IssueField::Summary => projection.summary = Some(issue_fields.summary.clone()),
+                IssueField::Status => projection.status = Some(issue_fields.status.name.clone()),
+                IssueField::Priority => {
+                    projection.priority = issue_fields.priority.as_ref().map(|p| p.name.clone())
+                }
+                IssueField::IssueType => {
+                    projection.issue_type = Some(issue_fields.issue_type.name.clone())
+                }
+                IssueField::Project => {
+                    projection.project_key = issue_fields.project.as_ref().map(|p| p.key.clone())
+                }
+                IssueField::Reporter => {
+                    projection.reporter = Some(issue_fields.reporter.display_name.clone())
+                }
+                IssueField::Assignee => {
+                    projection.assignee = issue_fields
+                        .assignee
+                        .as_ref()
+                        .map(|a| a.display_name.clone())
+                }
+                IssueField::Created => projection.created = Some(issue_fields.created),
+                IssueField::Updated => projection.updated = Some(issue_fields.updated),
+                IssueField::Resolution => {
+                    projection.resolution = issue_fields.resolution.as_ref().map(|r| r.name.clone())
+                }
+            }
+        }
+
+        projection
+    }
+}
+
+/// 担当者別統計で未割り当てのIssueを数える際のキー
+pub const UNASSIGNED_BUCKET: &str = "(unassigned)";
+
 /// ストレージ統計情報
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StorageStats {
@@ -395,6 +1018,15 @@ pub struct StorageStats {
     pub issues_by_status: HashMap<String, usize>,
     /// Issue種別別件数
     pub issues_by_type: HashMap<String, usize>,
+    /// 解決状況別件数（未解決のIssueは含まない）
+    #[serde(default)]
+    pub issues_by_resolution: HashMap<String, usize>,
+    /// 担当者の表示名別件数（未割り当ては[`UNASSIGNED_BUCKET`]に集計）
+    #[serde(default)]
+    pub issues_by_assignee: HashMap<String, usize>,
+    /// 優先度名別件数（優先度の無いIssueは含まない）
+    #[serde(default)]
+    pub issues_by_priority: HashMap<String, usize>,
     /// ストレージサイズ（バイト）
     pub storage_size_bytes: u64,
     /// 最後の更新日時
@@ -413,6 +1045,9 @@ impl StorageStats {
             issues_by_project: HashMap::new(),
             issues_by_status: HashMap::new(),
             issues_by_type: HashMap::new(),
+            issues_by_resolution: HashMap::new(),
+            issues_by_assignee: HashMap::new(),
+            issues_by_priority: HashMap::new(),
             storage_size_bytes: 0,
             last_updated: Utc::now(),
             index_count: 0,
@@ -427,6 +1062,21 @@ impl Default for StorageStats {
     }
 }
 
+/// 共有用フィルター文書の形式バージョン
+pub const SHAREABLE_FILTER_VERSION: u32 = 1;
+
+/// インスタンス間で共有するフィルター文書
+///
+/// IDや使用状況などインスタンス固有の情報は含めません。
+#[derive(Debug, Serialize, Deserialize)]
+struct ShareableFilter {
+    version: u32,
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    filter: IssueFilter,
+}
+
 /// フィルター設定の永続化
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FilterConfig {
@@ -482,6 +1132,49 @@ impl FilterConfig {
         self.filter = filter;
         self.updated_at = Utc::now();
     }
+
+    /// 他のインスタンスと共有できるJSON文書に変換
+    ///
+    /// 名前・説明・フィルター条件と形式バージョンのみを含み、IDや使用回数などは含めません。
+    pub fn to_shareable_json(&self) -> String {
+        let document = ShareableFilter {
+            version: SHAREABLE_FILTER_VERSION,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            filter: self.filter.clone(),
+        };
+        serde_json::to_string_pretty(&document).expect("filter document is always serializable")
+    }
+
+    /// 共有用のJSON文書からフィルター設定を作成
+    ///
+    /// 新しいIDを割り当て、使用回数と日時は初期状態になります。
+    /// 未対応の形式バージョンの場合は`InvalidInput`エラーを返します。
+    pub fn from_shareable_json(s: &str) -> Result<Self, Error> {
+        let document: ShareableFilter = serde_json::from_str(s).map_err(|e| {
+            Error::DeserializationError(format!("Invalid shared filter document: {}", e))
+        })?;
+        if document.version == 0 || document.version > SHAREABLE_FILTER_VERSION {
+            return Err(Error::InvalidInput(format!(
+                "Unsupported shared filter version: {}",
+                document.version
+            )));
+        }
+
+        let mut config = Self::new(generate_filter_id(), document.name, document.filter);
+        config.description = document.description;
+        Ok(config)
+    }
+}
+
+/// インポートしたフィルター設定用の新しいIDを生成
+fn generate_filter_id() -> String {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let nanos = Utc::now().timestamp_nanos_opt().unwrap_or_default();
+    let sequence = COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("filter-{:x}-{:x}", nanos, sequence)
 }
 
 #[cfg(test)]
@@ -505,6 +1198,7 @@ mod tests {
         assert!(filter.summary_contains.is_none());
         assert!(filter.description_contains.is_none());
         assert!(filter.labels.is_empty());
+        assert!(filter.parent_keys.is_empty());
         assert!(filter.limit.is_none());
         assert!(filter.offset.is_none());
         assert!(matches!(filter.sort_order, SortOrder::CreatedDesc));
@@ -535,6 +1229,74 @@ mod tests {
         assert!(!filter.is_empty());
     }
 
+    #[test]
+    fn test_custom_field_filter_matches() {
+        // 演算子ごとにカスタムフィールドの値が正しく評価されることをテスト
+        let fields: HashMap<String, serde_json::Value> = [
+            (
+                "customfield_10020".to_string(),
+                serde_json::json!({"id": "1", "value": "High"}),
+            ),
+            ("customfield_10016".to_string(), serde_json::json!(5.0)),
+            (
+                "customfield_10030".to_string(),
+                serde_json::json!(["backend", "api"]),
+            ),
+            (
+                "customfield_10040".to_string(),
+                serde_json::json!("Release notes"),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let check = |field: &str, operator, value| {
+            CustomFieldFilter::new(field, operator, value).matches(&fields)
+        };
+
+        assert!(check(
+            "customfield_10020",
+            CustomFieldOperator::Eq,
+            serde_json::json!("High")
+        ));
+        assert!(!check(
+            "customfield_10020",
+            CustomFieldOperator::Eq,
+            serde_json::json!("Low")
+        ));
+        assert!(check(
+            "customfield_10016",
+            CustomFieldOperator::Eq,
+            serde_json::json!(5)
+        ));
+        assert!(check(
+            "customfield_10016",
+            CustomFieldOperator::Gt,
+            serde_json::json!(4)
+        ));
+        assert!(!check(
+            "customfield_10016",
+            CustomFieldOperator::Lt,
+            serde_json::json!(5)
+        ));
+        assert!(check(
+            "customfield_10030",
+            CustomFieldOperator::Contains,
+            serde_json::json!("api")
+        ));
+        assert!(check(
+            "customfield_10040",
+            CustomFieldOperator::Contains,
+            serde_json::json!("notes")
+        ));
+        // 存在しないフィールドは一致しない
+        assert!(!check(
+            "customfield_99999",
+            CustomFieldOperator::Eq,
+            serde_json::json!("High")
+        ));
+    }
+
     #[test]
     fn test_date_range_new() {
         // DateRange::new()で正しく作成されることをテスト
@@ -566,9 +1328,10 @@ mod tests {
         let after = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
         assert!(!range.contains(&after));
 
-        // 境界値
+        // 境界値（開始は含み、終了は含まない）
         assert!(range.contains(&start));
-        assert!(range.contains(&end));
+        assert!(!range.contains(&end));
+        assert!(range.contains(&(end - chrono::Duration::milliseconds(1))));
     }
 
     #[test]
@@ -614,6 +1377,8 @@ mod tests {
         assert!(stats.issues_by_project.is_empty());
         assert!(stats.issues_by_status.is_empty());
         assert!(stats.issues_by_type.is_empty());
+        assert!(stats.issues_by_assignee.is_empty());
+        assert!(stats.issues_by_priority.is_empty());
         assert_eq!(stats.storage_size_bytes, 0);
         assert_eq!(stats.index_count, 0);
         assert_eq!(stats.compression_ratio, 0.0);
@@ -637,6 +1402,56 @@ mod tests {
         assert!(config.last_used_at.is_none());
     }
 
+    #[test]
+    fn test_filter_config_shareable_json_round_trip() {
+        // エクスポート→インポートで条件が保持され、IDと使用状況がリセットされることをテスト
+        let filter = IssueFilter::new()
+            .project_keys(vec!["TEST".to_string()])
+            .statuses(vec!["Open".to_string()])
+            .summary_contains("login".to_string())
+            .sort_order(SortOrder::UpdatedDesc);
+        let mut config = FilterConfig::new(
+            "local-id".to_string(),
+            "Open login bugs".to_string(),
+            filter,
+        )
+        .description("Shared with QA".to_string());
+        config.increment_usage();
+        config.increment_usage();
+
+        let json = config.to_shareable_json();
+        let document: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(document["version"], SHAREABLE_FILTER_VERSION);
+        assert!(document.get("id").is_none());
+        assert!(document.get("usage_count").is_none());
+
+        let imported = FilterConfig::from_shareable_json(&json).unwrap();
+        assert_ne!(imported.id, "local-id");
+        assert_eq!(imported.name, "Open login bugs");
+        assert_eq!(imported.description.as_deref(), Some("Shared with QA"));
+        assert_eq!(imported.usage_count, 0);
+        assert!(imported.last_used_at.is_none());
+        assert_eq!(
+            serde_json::to_value(&imported.filter).unwrap(),
+            serde_json::to_value(&config.filter).unwrap()
+        );
+
+        // 2回インポートすると別のIDになる
+        let again = FilterConfig::from_shareable_json(&json).unwrap();
+        assert_ne!(again.id, imported.id);
+
+        // 未対応のバージョンや不正なJSONはエラー
+        let future = json.replacen("\"version\": 1", "\"version\": 99", 1);
+        assert!(matches!(
+            FilterConfig::from_shareable_json(&future),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            FilterConfig::from_shareable_json("not json"),
+            Err(Error::DeserializationError(_))
+        ));
+    }
+
     #[test]
     fn test_filter_config_increment_usage() {
         // FilterConfig::increment_usage()が正しく動作することをテスト
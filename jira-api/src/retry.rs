@@ -0,0 +1,185 @@
+//! 一時的な失敗に対する再試行ポリシー
+//!
+//! サーバーエラー（5xx）やレート制限（429）、通信エラーは時間をおいて再送すると
+//! 成功することが多いため、[`RetryPolicy`]で再試行回数と指数バックオフの待機時間を指定します。
+
+use crate::Error;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 再試行ポリシー
+///
+/// `attempt`回目（0始まり）の再試行までの待機時間は
+/// `initial_backoff * 2^attempt`で、`max_backoff`を上限とします。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// 最大再試行回数（最初の試行は含まない）
+    pub max_retries: u32,
+    /// 最初の再試行までの待機時間
+    pub initial_backoff: Duration,
+    /// 待機時間の上限
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// デフォルトのポリシーを作成（3回まで、500msから最大10秒）
+    pub fn new() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+
+    /// 再試行しないポリシーを作成
+    pub fn no_retry() -> Self {
+        Self::new().max_retries(0)
+    }
+
+    /// 最大再試行回数を設定
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// 最初の再試行までの待機時間を設定
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// 待機時間の上限を設定
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// `attempt`回目（0始まり）の再試行までの待機時間
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    /// 再試行によって成功する可能性があるエラーかどうか
+    ///
+    /// 判定は[`Error::is_retryable`]に委ねます。
+    pub fn is_retryable(&self, error: &Error) -> bool {
+        error.is_retryable()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// クライアント全体で共有する再試行の予算（トークンバケット）
+///
+/// 再試行のたびにトークンを1つ消費し、トークンは一定の速度で`capacity`まで補充されます。
+/// 多数のタスクが同時に失敗しても、再試行の総数がこの速度に制限されるため、
+/// 不調なインスタンスに再送が集中するのを防げます。予算が尽きた場合、エラーは再試行せずに返されます。
+#[derive(Debug)]
+pub struct RetryBudget {
+    capacity: f64,
+    refill_per_second: f64,
+    state: Mutex<BudgetState>,
+}
+
+#[derive(Debug)]
+struct BudgetState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RetryBudget {
+    /// 予算を作成
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - 同時に使える再試行の最大数（初期状態は満杯）
+    /// * `refill_per_second` - 1秒あたりに補充される再試行の数
+    pub fn new(capacity: u32, refill_per_second: f64) -> Self {
+        Self {
+            capacity: capacity as f64,
+            refill_per_second: refill_per_second.max(0.0),
+            state: Mutex::new(BudgetState {
+                tokens: capacity as f64,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// 再試行1回分のトークンを取得する
+    ///
+    /// 予算が尽きている場合は`false`を返します。
+    pub fn try_acquire(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        state.last_refill = now;
+
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// 現在使用できる再試行の数
+    pub fn available(&self) -> u32 {
+        self.state.lock().unwrap().tokens.floor() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_policy_delay_and_retryable() {
+        // 待機時間が指数的に増えて上限で止まり、一時的なエラーのみ再試行対象になることをテスト
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(350));
+
+        assert_eq!(policy.delay_for(0), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(1), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(2), Duration::from_millis(350));
+        assert_eq!(policy.delay_for(40), Duration::from_millis(350));
+
+        let server_error = Error::ApiError {
+            status: 503,
+            message: "Service Unavailable".to_string(),
+        };
+        let client_error = Error::ApiError {
+            status: 400,
+            message: "Bad Request".to_string(),
+        };
+        assert!(policy.is_retryable(&server_error));
+        assert!(policy.is_retryable(&Error::RateLimited { retry_after: None }));
+        assert!(!policy.is_retryable(&client_error));
+        assert_eq!(RetryPolicy::no_retry().max_retries, 0);
+    }
+
+    #[test]
+    fn test_retry_budget_exhausts_and_refills() {
+        // 予算を使い切ると取得できなくなり、補充されないことをテスト
+        let budget = RetryBudget::new(2, 0.0);
+        assert!(budget.try_acquire());
+        assert!(budget.try_acquire());
+        assert!(!budget.try_acquire());
+        assert_eq!(budget.available(), 0);
+
+        // 補充速度が十分に大きければすぐに再び取得できる
+        let budget = RetryBudget::new(1, 1_000_000.0);
+        assert!(budget.try_acquire());
+        std::thread::sleep(Duration::from_millis(1));
+        assert!(budget.try_acquire());
+    }
+}
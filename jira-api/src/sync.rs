@@ -1,4 +1,10 @@
-use crate::{Error, Issue, JiraClient, SearchParams, TimeBasedFilter};
+use crate::cancel::CancellationToken;
+use crate::clock::{Clock, SystemClock};
+use crate::jql::JqlBuilder;
+use crate::{
+    ConfigStore, Error, Issue, IssueFilter, JiraClient, PersistenceStore, SearchParams,
+    TimeBasedFilter,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -21,6 +27,9 @@ pub struct SyncConfig {
     pub target_projects: Vec<String>,
     /// 除外するフィールド
     pub excluded_fields: Vec<String>,
+    /// ドライラン（件数のみ取得し、保存や履歴の更新を行わない）
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 impl SyncConfig {
@@ -33,6 +42,7 @@ impl SyncConfig {
             concurrent_sync_count: 3,
             target_projects: Vec::new(),
             excluded_fields: Vec::new(),
+            dry_run: false,
         }
     }
 
@@ -71,6 +81,16 @@ impl SyncConfig {
         self.excluded_fields = fields;
         self
     }
+
+    /// ドライランを設定
+    ///
+    /// 有効にすると各プロジェクトのJQLを`maxResults=0`で実行して`total`のみを読み取り、
+    /// Issue本体の取得・ウォーターマークの保存・同期履歴の更新を行いません。
+    /// 新規/更新の内訳はIssueを取得しないと判別できないため、件数は`synced_issues_count`にのみ反映されます。
+    pub fn dry_run(mut self, enabled: bool) -> Self {
+        self.dry_run = enabled;
+        self
+    }
 }
 
 impl Default for SyncConfig {
@@ -100,17 +120,27 @@ pub struct SyncResult {
     pub project_stats: HashMap<String, ProjectSyncStats>,
     /// エラーメッセージ一覧
     pub error_messages: Vec<String>,
+    /// プロジェクト単位の同期エラー（失敗したプロジェクトの再同期に使用）
+    #[serde(default)]
+    pub errors: Vec<SyncError>,
     /// 同期が成功したかどうか
     pub is_success: bool,
+    /// キャンセルにより途中で終了したかどうか
+    #[serde(default)]
+    pub cancelled: bool,
 }
 
 impl SyncResult {
     /// 新しい同期結果を作成
     pub fn new() -> Self {
-        let now = Utc::now();
+        Self::started_at(Utc::now())
+    }
+
+    /// 指定した開始時刻で新しい同期結果を作成
+    pub fn started_at(start_time: DateTime<Utc>) -> Self {
         Self {
-            start_time: now,
-            end_time: now,
+            start_time,
+            end_time: start_time,
             synced_issues_count: 0,
             new_issues_count: 0,
             updated_issues_count: 0,
@@ -118,14 +148,23 @@ impl SyncResult {
             error_count: 0,
             project_stats: HashMap::new(),
             error_messages: Vec::new(),
+            errors: Vec::new(),
             is_success: false,
+            cancelled: false,
         }
     }
 
     /// 同期終了を記録
     pub fn finish(&mut self) {
-        self.end_time = Utc::now();
-        self.is_success = self.error_count == 0;
+        self.finish_at(Utc::now());
+    }
+
+    /// 指定した終了時刻で同期終了を記録
+    ///
+    /// キャンセルされた同期は取得漏れがあるため成功として扱いません。
+    pub fn finish_at(&mut self, end_time: DateTime<Utc>) {
+        self.end_time = end_time;
+        self.is_success = self.error_count == 0 && !self.cancelled;
     }
 
     /// エラーを追加
@@ -134,6 +173,29 @@ impl SyncResult {
         self.error_messages.push(message);
     }
 
+    /// プロジェクト単位の同期エラーを追加
+    ///
+    /// 表示用のメッセージも[`SyncResult::error_messages`]に追加します。
+    pub fn add_sync_error(&mut self, error: SyncError) {
+        self.add_error(error.to_string());
+        self.errors.push(error);
+    }
+
+    /// 表示用のエラーメッセージ一覧を取得
+    pub fn error_messages(&self) -> &[String] {
+        &self.error_messages
+    }
+
+    /// 同期に失敗したプロジェクトのキー一覧を取得（キー順、重複なし）
+    ///
+    /// [`SyncService::sync_projects`]に渡すと失敗したプロジェクトのみを再同期できます。
+    pub fn failed_projects(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.errors.iter().map(|e| e.project_key.clone()).collect();
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+
     /// プロジェクト統計を追加
     pub fn add_project_stats(&mut self, project_key: String, stats: ProjectSyncStats) {
         self.project_stats.insert(project_key, stats);
@@ -143,6 +205,105 @@ impl SyncResult {
     pub fn duration_seconds(&self) -> f64 {
         (self.end_time - self.start_time).num_milliseconds() as f64 / 1000.0
     }
+
+    /// 同期結果の1行サマリーを取得
+    ///
+    /// 例: `Synced 120 issues (30 new, 90 updated, 2 errors) in 4.3s across 3 projects`
+    pub fn summary(&self) -> String {
+        self.to_string()
+    }
+
+    /// プロジェクト別の統計とエラーメッセージを含む詳細レポートを取得
+    ///
+    /// プロジェクトはキー順に並べます。
+    pub fn detailed_report(&self) -> String {
+        let mut lines = vec![self.summary()];
+
+        let mut project_keys: Vec<&String> = self.project_stats.keys().collect();
+        project_keys.sort();
+        if !project_keys.is_empty() {
+            lines.push("Projects:".to_string());
+            for key in project_keys {
+                let stats = &self.project_stats[key];
+                lines.push(format!(
+                    "  {}: {} synced ({} new, {} updated, {} errors)",
+                    key,
+                    stats.synced_count,
+                    stats.new_count,
+                    stats.updated_count,
+                    stats.error_count
+                ));
+            }
+        }
+
+        if !self.error_messages.is_empty() {
+            lines.push("Errors:".to_string());
+            for message in &self.error_messages {
+                lines.push(format!("  - {}", message));
+            }
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// プロジェクト単位の同期エラー
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncError {
+    /// 失敗したプロジェクトのキー
+    pub project_key: String,
+    /// 実行したJQL（JQLの構築に失敗した場合は空）
+    pub jql: String,
+    /// エラー内容
+    pub message: String,
+    /// APIが返したHTTPステータス（通信エラーなどの場合は`None`）
+    pub status: Option<u16>,
+}
+
+impl SyncError {
+    /// エラーから同期エラーを作成
+    pub fn from_error(project_key: &str, jql: &str, error: &Error) -> Self {
+        let status = match error {
+            Error::ApiError { status, .. } => Some(*status),
+            Error::RateLimited { .. } => Some(429),
+            _ => None,
+        };
+        Self {
+            project_key: project_key.to_string(),
+            jql: jql.to_string(),
+            message: error.to_string(),
+            status,
+        }
+    }
+}
+
+impl std::fmt::Display for SyncError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "プロジェクト {} の同期エラー: {}",
+            self.project_key, self.message
+        )
+    }
+}
+
+impl std::fmt::Display for SyncResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Synced {} issues ({} new, {} updated, {} errors) in {:.1}s across {} projects",
+            self.synced_issues_count,
+            self.new_issues_count,
+            self.updated_issues_count,
+            self.error_count,
+            self.duration_seconds(),
+            self.project_stats.len()
+        )?;
+        if self.cancelled {
+            write!(f, " (cancelled)")?;
+        }
+        Ok(())
+    }
 }
 
 impl Default for SyncResult {
@@ -166,6 +327,9 @@ pub struct ProjectSyncStats {
     pub error_count: usize,
     /// 最後の同期時刻
     pub last_sync_time: DateTime<Utc>,
+    /// 同期したIssueのうち最も新しい更新日時
+    #[serde(default)]
+    pub latest_updated: Option<DateTime<Utc>>,
 }
 
 impl ProjectSyncStats {
@@ -178,6 +342,7 @@ impl ProjectSyncStats {
             updated_count: 0,
             error_count: 0,
             last_sync_time: Utc::now(),
+            latest_updated: None,
         }
     }
 }
@@ -235,6 +400,10 @@ pub struct SyncService {
     last_successful_sync: Arc<Mutex<Option<DateTime<Utc>>>>,
     /// 並行処理制御用セマフォ
     concurrency_limiter: Arc<Semaphore>,
+    /// 同期ウォーターマークの永続化先
+    config_store: Option<Arc<Mutex<dyn ConfigStore>>>,
+    /// 現在時刻の取得元
+    clock: Arc<dyn Clock>,
 }
 
 impl SyncService {
@@ -247,9 +416,110 @@ impl SyncService {
             current_state: Arc::new(Mutex::new(SyncState::Idle)),
             sync_history: Arc::new(Mutex::new(Vec::new())),
             last_successful_sync: Arc::new(Mutex::new(None)),
+            config_store: None,
+            clock: Arc::new(SystemClock),
         }
     }
 
+    /// 現在時刻の取得元を設定
+    ///
+    /// 同期間隔の判定や同期結果の時刻記録に使われます。デフォルトはシステム時刻です。
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 同期ウォーターマークを永続化する設定ストアを設定
+    ///
+    /// 設定すると、プロジェクトごとに最後に同期したIssueの`updated`を保存し、
+    /// 次回の増分同期ではその時刻以降に更新されたIssueのみを取得します。
+    /// プロセスを再起動しても同じストアを渡せば前回の続きから同期できます。
+    pub fn with_config_store(mut self, store: Arc<Mutex<dyn ConfigStore>>) -> Self {
+        self.config_store = Some(store);
+        self
+    }
+
+    /// 永続化されたプロジェクトの同期ウォーターマークを取得
+    pub async fn load_sync_watermark(
+        &self,
+        project_key: &str,
+    ) -> Result<Option<DateTime<Utc>>, Error> {
+        match &self.config_store {
+            Some(store) => store.lock().await.load_sync_watermark(project_key).await,
+            None => Ok(None),
+        }
+    }
+
+    /// プロジェクトの同期ウォーターマークを永続化
+    async fn save_sync_watermark(
+        &self,
+        project_key: &str,
+        timestamp: DateTime<Utc>,
+    ) -> Result<(), Error> {
+        match &self.config_store {
+            Some(store) => {
+                store
+                    .lock()
+                    .await
+                    .save_sync_watermark(project_key, timestamp)
+                    .await
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// プロジェクトの同期統計の推移を取得（古い順）
+    ///
+    /// メモリ上の同期履歴から該当プロジェクトの統計を抜き出します。
+    /// 保持される件数は`max_history_count`までです。
+    pub async fn project_history(&self, project_key: &str) -> Vec<ProjectSyncStats> {
+        self.sync_history
+            .lock()
+            .await
+            .iter()
+            .filter_map(|result| result.project_stats.get(project_key).cloned())
+            .collect()
+    }
+
+    /// 永続化されたプロジェクトの同期統計の推移を取得（古い順）
+    ///
+    /// 設定ストアが設定されていない場合は空のリストを返します。
+    pub async fn load_project_history(
+        &self,
+        project_key: &str,
+    ) -> Result<Vec<ProjectSyncStats>, Error> {
+        match &self.config_store {
+            Some(store) => {
+                store
+                    .lock()
+                    .await
+                    .load_project_sync_history(project_key)
+                    .await
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// プロジェクトの同期統計を永続化された履歴に追加
+    ///
+    /// 履歴は`max_history_count`件を超えた分を古い順に削除します。
+    async fn append_project_history(&self, stats: &ProjectSyncStats) -> Result<(), Error> {
+        let Some(store) = &self.config_store else {
+            return Ok(());
+        };
+
+        let mut store = store.lock().await;
+        let mut history = store.load_project_sync_history(&stats.project_key).await?;
+        history.push(stats.clone());
+        if history.len() > self.config.max_history_count {
+            let excess = history.len() - self.config.max_history_count;
+            history.drain(..excess);
+        }
+        store
+            .save_project_sync_history(&stats.project_key, &history)
+            .await
+    }
+
     /// 現在の同期状態を取得
     pub async fn current_state(&self) -> SyncState {
         self.current_state.lock().await.clone()
@@ -287,6 +557,19 @@ impl SyncService {
 
     /// 同期結果を履歴に追加
     pub(crate) async fn add_sync_result(&self, result: SyncResult) {
+        // 成功した同期の場合、最終成功時刻を更新
+        if result.is_success {
+            *self.last_successful_sync.lock().await = Some(result.end_time);
+        }
+
+        self.push_sync_history(result).await;
+    }
+
+    /// 最終成功時刻（増分同期の基準）を変えずに同期結果を履歴に追加
+    ///
+    /// 一部のIssueのみを対象とした処理の結果に使用します。最終成功時刻を進めると、
+    /// 対象外のプロジェクトの更新が次回の増分同期で取得されなくなるためです。
+    async fn push_sync_history(&self, result: SyncResult) {
         let mut history = self.sync_history.lock().await;
 
         // 最大履歴数を超えた場合、古いものを削除
@@ -294,11 +577,6 @@ impl SyncService {
             history.remove(0);
         }
 
-        // 成功した同期の場合、最終成功時刻を更新
-        if result.is_success {
-            *self.last_successful_sync.lock().await = Some(result.end_time);
-        }
-
         history.push(result);
     }
 
@@ -322,6 +600,64 @@ impl SyncService {
         &self,
         client: &JiraClient,
         existing_issues: &[Issue],
+    ) -> Result<SyncResult, Error> {
+        self.sync_incremental_with_cancellation(client, existing_issues, &CancellationToken::new())
+            .await
+    }
+
+    /// キャンセル可能な増分同期を実行
+    ///
+    /// 各プロジェクトはページの間でトークンを確認し、キャンセルされていれば次のページを要求しません。
+    /// キャンセルされた同期は`cancelled: true`の結果を返し、取得漏れを防ぐため
+    /// ウォーターマークと最終成功時刻は更新しません。同期状態はアイドルに戻ります。
+    pub async fn sync_incremental_with_cancellation(
+        &self,
+        client: &JiraClient,
+        existing_issues: &[Issue],
+        cancellation_token: &CancellationToken,
+    ) -> Result<SyncResult, Error> {
+        self.run_sync(client, existing_issues, None, cancellation_token)
+            .await
+    }
+
+    /// 指定したプロジェクトのみを対象に増分同期を実行
+    ///
+    /// 設定の`target_projects`を今回の実行に限り`projects`で置き換えます。設定自体は変更しません。
+    /// ウォーターマークや重複除外などの挙動は[`SyncService::sync_incremental`]と同じです。
+    ///
+    /// 対象外のプロジェクトの更新を取りこぼさないよう、結果は同期履歴に残しますが
+    /// 増分同期の基準となる最終成功時刻は進めません。対象プロジェクトの進捗は
+    /// 設定ストアのウォーターマークにのみ記録されます。
+    pub async fn sync_projects(
+        &self,
+        client: &JiraClient,
+        existing_issues: &[Issue],
+        projects: &[String],
+    ) -> Result<SyncResult, Error> {
+        if projects.is_empty() {
+            return Err(Error::InvalidInput(
+                "同期対象のプロジェクトが指定されていません".to_string(),
+            ));
+        }
+
+        self.run_sync(
+            client,
+            existing_issues,
+            Some(projects),
+            &CancellationToken::new(),
+        )
+        .await
+    }
+
+    /// 同期処理の本体
+    ///
+    /// `projects`が指定された場合は設定の`target_projects`の代わりにそのプロジェクトを同期します。
+    async fn run_sync(
+        &self,
+        client: &JiraClient,
+        existing_issues: &[Issue],
+        projects: Option<&[String]>,
+        cancellation_token: &CancellationToken,
     ) -> Result<SyncResult, Error> {
         // 同期中でないことを確認
         if !self.can_sync().await {
@@ -330,24 +666,26 @@ impl SyncService {
 
         // 同期開始
         self.set_state(SyncState::Syncing).await;
-        let mut result = SyncResult::new();
+        let mut result = SyncResult::started_at(self.clock.now());
 
         // 最後の同期時刻以降のフィルターを作成
         let filter = if let Some(last_sync) = self.last_successful_sync().await {
-            TimeBasedFilter::incremental_since(last_sync)
+            TimeBasedFilter::incremental_since_with_clock(last_sync, self.clock.as_ref())
                 .excluded_issue_keys(existing_issues.iter().map(|i| i.key.clone()).collect())
         } else {
             // 初回同期の場合は最近24時間分を取得
-            TimeBasedFilter::last_hours(24)
+            TimeBasedFilter::last_hours_with_clock(24, self.clock.as_ref())
         };
 
         // フィルター妥当性チェック
         if let Err(e) = filter.is_valid() {
             result.add_error(format!("フィルター設定エラー: {}", e));
-            result.finish();
+            result.finish_at(self.clock.now());
             self.set_state(SyncState::Error(format!("フィルター設定エラー: {}", e)))
                 .await;
-            self.add_sync_result(result.clone()).await;
+            if !self.config.dry_run {
+                self.add_sync_result(result.clone()).await;
+            }
             return Ok(result);
         }
 
@@ -356,19 +694,23 @@ impl SyncService {
             existing_issues.iter().map(|i| i.key.clone()).collect();
 
         // プロジェクト別同期実行
-        let projects_to_sync = if self.config.target_projects.is_empty() {
+        let projects_to_sync = if let Some(projects) = projects {
+            projects.to_vec()
+        } else if self.config.target_projects.is_empty() {
             // 全プロジェクト対象の場合、プロジェクト一覧を取得
             match client.get_projects().await {
                 Ok(projects) => projects.into_iter().map(|p| p.key).collect(),
                 Err(e) => {
                     result.add_error(format!("プロジェクト一覧取得エラー: {}", e));
-                    result.finish();
+                    result.finish_at(self.clock.now());
                     self.set_state(SyncState::Error(format!(
                         "プロジェクト一覧取得エラー: {}",
                         e
                     )))
                     .await;
-                    self.add_sync_result(result.clone()).await;
+                    if !self.config.dry_run {
+                        self.add_sync_result(result.clone()).await;
+                    }
                     return Ok(result);
                 }
             }
@@ -381,12 +723,29 @@ impl SyncService {
         let mut join_set = JoinSet::new();
 
         for project_key in projects_to_sync {
+            // ウォーターマークがあるプロジェクトはその時刻以降の更新分のみ取得
+            let filter = match self.load_sync_watermark(&project_key).await {
+                Ok(Some(watermark)) => {
+                    TimeBasedFilter::incremental_since_with_clock(watermark, self.clock.as_ref())
+                        .filter_by_created(false)
+                }
+                Ok(None) => filter.clone(),
+                Err(e) => {
+                    result.add_error(format!(
+                        "プロジェクト {} のウォーターマーク読み込みエラー: {}",
+                        project_key, e
+                    ));
+                    filter.clone()
+                }
+            };
+
             let client = client.clone();
             let config = self.config.clone();
-            let filter = filter.clone();
             let existing_keys = existing_keys.clone();
             let tx = tx.clone();
             let semaphore = Arc::clone(&self.concurrency_limiter);
+            let clock = Arc::clone(&self.clock);
+            let cancellation_token = cancellation_token.clone();
 
             join_set.spawn(async move {
                 let _permit = semaphore.acquire().await.expect("セマフォ取得失敗");
@@ -397,6 +756,8 @@ impl SyncService {
                     &project_key,
                     &filter,
                     &existing_keys,
+                    clock.as_ref(),
+                    &cancellation_token,
                 )
                 .await;
 
@@ -413,12 +774,46 @@ impl SyncService {
                     result.synced_issues_count += synced_count;
                     result.new_issues_count += new_count;
                     result.updated_issues_count += updated_count;
+
+                    // キャンセル時は途中までしか取得していない可能性があるため進めない
+                    if !self.config.dry_run
+                        && !cancellation_token.is_cancelled()
+                        && let Some(latest_updated) = stats.latest_updated
+                        && let Err(e) = self.save_sync_watermark(&project_key, latest_updated).await
+                    {
+                        result.add_error(format!(
+                            "プロジェクト {} のウォーターマーク保存エラー: {}",
+                            project_key, e
+                        ));
+                    }
+
+                    if !self.config.dry_run
+                        && let Err(e) = self.append_project_history(&stats).await
+                    {
+                        result.add_error(format!(
+                            "プロジェクト {} の同期統計保存エラー: {}",
+                            project_key, e
+                        ));
+                    }
+
                     result.add_project_stats(project_key, stats);
                 }
-                Err((project_key, error_msg)) => {
-                    result.add_error(error_msg);
+                Err(error) => {
+                    let project_key = error.project_key.clone();
+                    result.add_sync_error(error);
                     let mut error_stats = ProjectSyncStats::new(project_key.clone());
                     error_stats.error_count = 1;
+                    error_stats.last_sync_time = self.clock.now();
+
+                    if !self.config.dry_run
+                        && let Err(e) = self.append_project_history(&error_stats).await
+                    {
+                        result.add_error(format!(
+                            "プロジェクト {} の同期統計保存エラー: {}",
+                            project_key, e
+                        ));
+                    }
+
                     result.add_project_stats(project_key, error_stats);
                 }
             }
@@ -521,10 +916,13 @@ impl SyncService {
         */
 
         // 同期完了処理
-        result.finish();
+        result.cancelled = cancellation_token.is_cancelled();
+        result.finish_at(self.clock.now());
 
         if result.is_success {
             self.set_state(SyncState::Completed).await;
+        } else if result.cancelled {
+            self.set_state(SyncState::Idle).await;
         } else {
             self.set_state(SyncState::Error(format!(
                 "同期中に {} 件のエラーが発生しました",
@@ -533,10 +931,152 @@ impl SyncService {
             .await;
         }
 
-        self.add_sync_result(result.clone()).await;
+        // ドライランは後続の増分同期に影響しないよう履歴に残さない。
+        // 対象を絞った同期は最終成功時刻を進めず、進捗はウォーターマークにのみ記録する
+        if !self.config.dry_run {
+            if projects.is_some() {
+                self.push_sync_history(result.clone()).await;
+            } else {
+                self.add_sync_result(result.clone()).await;
+            }
+        }
+        Ok(result)
+    }
+
+    /// ストアの内容をJQLに一致するIssueと完全に一致させる
+    ///
+    /// JQLに一致するIssueを全件取得し、ストア内の全てのIssueと比較します。
+    /// ストアに無いIssueは新規、`updated`が異なるIssueは更新として保存し、
+    /// JQLに一致しなくなったIssueは削除します。変更の無いIssueは書き込みません。
+    /// ストアをJQLのミラーとして使う場合のためのもので、JQL外のIssueも保持する
+    /// ストアでは[`SyncService::reconcile_in_scope`]を使用してください。
+    ///
+    /// 書き込みは1つのトランザクションで行い、失敗した場合はロールバックしてエラーを返します。
+    /// 一致するIssueの取得に失敗した場合も、誤って削除しないようストアを変更せずにエラーを返します。
+    /// 結果は同期履歴に残しますが、増分同期の基準となる最終成功時刻は変更しません。
+    pub async fn reconcile<S>(
+        &self,
+        client: &JiraClient,
+        store: &mut S,
+        jql: &str,
+    ) -> Result<SyncResult, Error>
+    where
+        S: PersistenceStore + ?Sized,
+    {
+        self.reconcile_in_scope(client, store, jql, &IssueFilter::new())
+            .await
+    }
+
+    /// ストア内の`scope`に一致するIssueを、JQLに一致するIssueと完全に一致させる
+    ///
+    /// [`SyncService::reconcile`]と同様ですが、比較と削除の対象を`scope`に一致するIssueに限定します。
+    /// JQLはストアの条件に変換できないため、複数のプロジェクトを保持するストアなどでは
+    /// JQLと同じ範囲を表す[`IssueFilter`]を`scope`に指定します。
+    ///
+    /// ストアが条件の一部を絞り込みに使えない場合に備え、読み込んだIssueは
+    /// [`IssueFilter::matches`]でも確認し、`scope`外のIssueは削除しません。
+    pub async fn reconcile_in_scope<S>(
+        &self,
+        client: &JiraClient,
+        store: &mut S,
+        jql: &str,
+        scope: &IssueFilter,
+    ) -> Result<SyncResult, Error>
+    where
+        S: PersistenceStore + ?Sized,
+    {
+        if !self.can_sync().await {
+            return Err(Error::InvalidInput("同期が既に実行中です".to_string()));
+        }
+
+        self.set_state(SyncState::Syncing).await;
+        let mut result = SyncResult::started_at(self.clock.now());
+
+        if let Err(e) = Self::reconcile_store(client, store, jql, scope, &mut result).await {
+            self.set_state(SyncState::Error(format!("リコンサイルエラー: {}", e)))
+                .await;
+            return Err(e);
+        }
+
+        result.finish_at(self.clock.now());
+        self.set_state(SyncState::Completed).await;
+        self.push_sync_history(result.clone()).await;
         Ok(result)
     }
 
+    /// リコンサイルの差分計算と書き込み
+    async fn reconcile_store<S>(
+        client: &JiraClient,
+        store: &mut S,
+        jql: &str,
+        scope: &IssueFilter,
+        result: &mut SyncResult,
+    ) -> Result<(), Error>
+    where
+        S: PersistenceStore + ?Sized,
+    {
+        let remote_issues = client.search_all_issues(jql, SearchParams::new()).await?;
+
+        // 件数制限とオフセットは比較対象から外す
+        let mut scope = scope.clone();
+        scope.limit = None;
+        scope.offset = None;
+        let stored_updated: HashMap<String, DateTime<Utc>> = store
+            .load_issues(&scope)
+            .await?
+            .into_iter()
+            .filter(|issue| scope.matches(issue))
+            .map(|issue| (issue.key, issue.fields.updated))
+            .collect();
+
+        let remote_keys: HashSet<&str> = remote_issues.iter().map(|i| i.key.as_str()).collect();
+        let mut stale_keys: Vec<String> = stored_updated
+            .keys()
+            .filter(|key| !remote_keys.contains(key.as_str()))
+            .cloned()
+            .collect();
+        stale_keys.sort();
+
+        let mut changed = Vec::new();
+        for issue in &remote_issues {
+            match stored_updated.get(&issue.key) {
+                None => result.new_issues_count += 1,
+                Some(updated) if *updated != issue.fields.updated => {
+                    result.updated_issues_count += 1
+                }
+                Some(_) => continue,
+            }
+            changed.push(issue.clone());
+        }
+        result.synced_issues_count = remote_issues.len();
+
+        store.begin_transaction().await?;
+        let written = async {
+            // save_issuesは既存のIssueを全て置き換えるストアがあるため、変更分だけをupsertする
+            if !changed.is_empty() {
+                store.upsert_issues(&changed).await?;
+            }
+            if stale_keys.is_empty() {
+                Ok(0)
+            } else {
+                store.delete_issues(&stale_keys).await
+            }
+        }
+        .await;
+
+        match written {
+            Ok(deleted) => {
+                store.commit_transaction().await?;
+                result.deleted_issues_count = deleted;
+                Ok(())
+            }
+            Err(e) => {
+                let _ = store.rollback_transaction().await;
+                Err(e)
+            }
+        }
+    }
+
     /// 初回同期を実行（全データを取得）
     pub async fn sync_full(&self, client: &JiraClient) -> Result<SyncResult, Error> {
         self.sync_incremental(client, &[]).await
@@ -544,11 +1084,23 @@ impl SyncService {
 
     /// 重複除外処理を実行
     pub fn deduplicate_issues(&self, issues: Vec<Issue>) -> Vec<Issue> {
+        self.deduplicate_by(issues, |issue| issue.key.clone())
+    }
+
+    /// 任意のキーで重複除外処理を実行
+    ///
+    /// 複数のインスタンスから集めたIssueはキーが衝突し得るため、`id`や`self_url`、
+    /// それらを組み合わせた値など、呼び出し側が重複判定のキーを選べます。
+    /// 同じキーのIssueは最初に現れたものを残します。
+    pub fn deduplicate_by<F>(&self, issues: Vec<Issue>, key_fn: F) -> Vec<Issue>
+    where
+        F: Fn(&Issue) -> String,
+    {
         let mut seen_keys = HashSet::new();
         let mut deduplicated = Vec::new();
 
         for issue in issues {
-            if seen_keys.insert(issue.key.clone()) {
+            if seen_keys.insert(key_fn(&issue)) {
                 deduplicated.push(issue);
             }
         }
@@ -556,6 +1108,31 @@ impl SyncService {
         deduplicated
     }
 
+    /// 重複除外処理を実行（更新日時が最新のものを残す）
+    ///
+    /// 同じキーのIssueが複数ある場合は`fields.updated`が最も新しいものを残します。
+    /// 結果の並び順は各キーが最初に現れた位置のままです。
+    pub fn deduplicate_keep_latest(&self, issues: Vec<Issue>) -> Vec<Issue> {
+        let mut index_by_key: HashMap<String, usize> = HashMap::new();
+        let mut deduplicated: Vec<Issue> = Vec::new();
+
+        for issue in issues {
+            match index_by_key.get(&issue.key) {
+                Some(&index) => {
+                    if issue.fields.updated > deduplicated[index].fields.updated {
+                        deduplicated[index] = issue;
+                    }
+                }
+                None => {
+                    index_by_key.insert(issue.key.clone(), deduplicated.len());
+                    deduplicated.push(issue);
+                }
+            }
+        }
+
+        deduplicated
+    }
+
     /// 同期の必要性をチェック
     pub async fn should_sync(&self) -> bool {
         if self.current_state().await.is_syncing() {
@@ -564,7 +1141,7 @@ impl SyncService {
 
         // 最後の成功した同期から設定された間隔が経過している場合
         if let Some(last_sync) = self.last_successful_sync().await {
-            let now = Utc::now();
+            let now = self.clock.now();
             let elapsed_minutes = (now - last_sync).num_minutes();
             elapsed_minutes >= self.config.interval_minutes as i64
         } else {
@@ -587,18 +1164,35 @@ impl SyncService {
         project_key: &str,
         filter: &TimeBasedFilter,
         existing_keys: &HashSet<String>,
-    ) -> Result<(String, ProjectSyncStats, usize, usize, usize), (String, String)> {
+        clock: &dyn Clock,
+        cancellation_token: &CancellationToken,
+    ) -> Result<(String, ProjectSyncStats, usize, usize, usize), SyncError> {
         let mut project_stats = ProjectSyncStats::new(project_key.to_string());
 
         // プロジェクト固有のJQLクエリを構築
-        let base_jql = format!("project = {}", project_key);
-        let time_condition = filter.to_jql_time_condition();
-
-        let jql = if let Some(time_cond) = time_condition {
-            format!("{} AND ({})", base_jql, time_cond)
-        } else {
-            base_jql
-        };
+        let mut builder = JqlBuilder::new().project(project_key);
+        if let Some(time_cond) = filter.to_jql_time_condition() {
+            builder = builder.condition(&time_cond);
+        }
+        let jql = builder.build().map_err(|e| SyncError {
+            project_key: project_key.to_string(),
+            jql: String::new(),
+            message: format!("JQL構築エラー: {}", e),
+            status: None,
+        })?;
+
+        // ドライランの場合は件数のみ取得
+        if config.dry_run {
+            return match client.count_matching(&jql).await {
+                Ok(total) => {
+                    let total = total as usize;
+                    project_stats.synced_count = total;
+                    project_stats.last_sync_time = clock.now();
+                    Ok((project_key.to_string(), project_stats, total, 0, 0))
+                }
+                Err(e) => Err(SyncError::from_error(project_key, &jql, &e)),
+            };
+        }
 
         // 検索パラメータ設定
         let mut search_params = SearchParams::new()
@@ -627,14 +1221,14 @@ impl SyncService {
         }
 
         // ページネーションで全Issues取得
-        let mut start_at = 0u32;
-        let max_results = 1000u32;
         let mut total_synced = 0;
         let mut total_new = 0;
         let mut total_updated = 0;
 
         loop {
-            search_params = search_params.start_at(start_at).max_results(max_results);
+            if cancellation_token.is_cancelled() {
+                break;
+            }
 
             match client.search_issues(&jql, search_params.clone()).await {
                 Ok(search_result) => {
@@ -647,6 +1241,13 @@ impl SyncService {
                         } else {
                             new_issues += 1;
                         }
+
+                        if project_stats
+                            .latest_updated
+                            .is_none_or(|latest| issue.fields.updated > latest)
+                        {
+                            project_stats.latest_updated = Some(issue.fields.updated);
+                        }
                     }
 
                     // 統計更新
@@ -659,22 +1260,17 @@ impl SyncService {
                     total_updated += updated_issues;
 
                     // 次のページがない場合は終了
-                    if (search_result.issues.len() as u32) < max_results
-                        || start_at + (search_result.issues.len() as u32) >= search_result.total
-                    {
+                    if !search_result.has_more() {
                         break;
                     }
 
-                    start_at += max_results;
-                }
-                Err(e) => {
-                    let error_msg = format!("プロジェクト {} の同期エラー: {}", project_key, e);
-                    return Err((project_key.to_string(), error_msg));
+                    search_params = search_params.next_page(&search_result);
                 }
+                Err(e) => return Err(SyncError::from_error(project_key, &jql, &e)),
             }
         }
 
-        project_stats.last_sync_time = Utc::now();
+        project_stats.last_sync_time = clock.now();
         Ok((
             project_key.to_string(),
             project_stats,
@@ -741,6 +1337,7 @@ mod tests {
         assert_eq!(config.concurrent_sync_count, 3);
         assert!(config.target_projects.is_empty());
         assert!(config.excluded_fields.is_empty());
+        assert!(!config.dry_run);
     }
 
     #[test]
@@ -955,11 +1552,68 @@ mod tests {
         assert!((duration - 1.0).abs() < 0.1); // 約1秒
     }
 
+    #[test]
+    fn test_sync_result_summary_and_detailed_report() {
+        // SyncResultのサマリーと詳細レポートが集計値とエラーを含むことをテスト
+        use chrono::TimeZone;
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let mut result = SyncResult::started_at(start);
+        result.synced_issues_count = 120;
+        result.new_issues_count = 30;
+        result.updated_issues_count = 90;
+
+        let mut web = ProjectSyncStats::new("WEB".to_string());
+        web.synced_count = 80;
+        web.new_count = 20;
+        web.updated_count = 60;
+        let mut api = ProjectSyncStats::new("API".to_string());
+        api.synced_count = 40;
+        api.new_count = 10;
+        api.updated_count = 30;
+        let mut ops = ProjectSyncStats::new("OPS".to_string());
+        ops.error_count = 1;
+        result.add_project_stats("WEB".to_string(), web);
+        result.add_project_stats("API".to_string(), api);
+        result.add_project_stats("OPS".to_string(), ops);
+
+        result.add_error("プロジェクト OPS の同期エラー: timeout".to_string());
+        result.add_error("プロジェクト OPS の同期統計保存エラー: disk full".to_string());
+        result.finish_at(start + chrono::Duration::milliseconds(4300));
+
+        let expected_summary =
+            "Synced 120 issues (30 new, 90 updated, 2 errors) in 4.3s across 3 projects";
+        assert_eq!(result.summary(), expected_summary);
+        assert_eq!(result.to_string(), expected_summary);
+
+        let expected_report = [
+            expected_summary,
+            "Projects:",
+            "  API: 40 synced (10 new, 30 updated, 0 errors)",
+            "  OPS: 0 synced (0 new, 0 updated, 1 errors)",
+            "  WEB: 80 synced (20 new, 60 updated, 0 errors)",
+            "Errors:",
+            "  - プロジェクト OPS の同期エラー: timeout",
+            "  - プロジェクト OPS の同期統計保存エラー: disk full",
+        ]
+        .join("\n");
+        assert_eq!(result.detailed_report(), expected_report);
+
+        // キャンセルされた同期はサマリーに明示される
+        result.cancelled = true;
+        assert!(result.summary().ends_with("across 3 projects (cancelled)"));
+    }
+
     #[tokio::test]
     async fn test_sync_service_should_sync() {
         // SyncService::should_sync()が正しく動作することをテスト
+        use crate::clock::FixedClock;
+        use chrono::TimeZone;
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let clock = FixedClock::new(start);
         let config = SyncConfig::new().interval_minutes(60);
-        let service = SyncService::new(config);
+        let service = SyncService::new(config).with_clock(Arc::new(clock.clone()));
 
         // 初回同期の場合は常にtrue
         assert!(service.should_sync().await);
@@ -968,15 +1622,20 @@ mod tests {
         service.set_state(SyncState::Syncing).await;
         assert!(!service.should_sync().await);
 
-        // 最後の同期から十分時間が経過している場合はtrue
+        // 同期が成功した直後はfalse
         service.set_state(SyncState::Idle).await;
-        *service.last_successful_sync.lock().await = Some(Utc::now() - chrono::Duration::hours(2));
-        assert!(service.should_sync().await);
+        let mut result = SyncResult::started_at(clock.now());
+        result.finish_at(clock.now());
+        service.add_sync_result(result).await;
+        assert!(!service.should_sync().await);
 
-        // 最近同期した場合はfalse
-        *service.last_successful_sync.lock().await =
-            Some(Utc::now() - chrono::Duration::minutes(30));
+        // 間隔に満たない場合はfalse
+        clock.advance(chrono::Duration::minutes(59));
         assert!(!service.should_sync().await);
+
+        // 間隔が経過した場合はtrue
+        clock.advance(chrono::Duration::minutes(1));
+        assert!(service.should_sync().await);
     }
 
     #[tokio::test]
@@ -1000,6 +1659,703 @@ mod tests {
         assert!(service.current_state().await.is_idle());
     }
 
+    /// 1件のIssueを含む検索レスポンスを作成
+    fn mock_search_response(total: u32, updated: &str) -> serde_json::Value {
+        serde_json::json!({
+            "startAt": 0,
+            "maxResults": 1000,
+            "total": total,
+            "issues": [{
+                "id": "10000",
+                "key": "TEST-1",
+                "self": "https://example.atlassian.net/rest/api/3/issue/10000",
+                "fields": {
+                    "summary": "Test Issue",
+                    "issuetype": {
+                        "id": "1",
+                        "name": "Bug",
+                        "self": "https://example.atlassian.net/rest/api/3/issuetype/1"
+                    },
+                    "status": {
+                        "id": "1",
+                        "name": "To Do",
+                        "self": "https://example.atlassian.net/rest/api/3/status/1",
+                        "statusCategory": {
+                            "id": 2,
+                            "key": "new",
+                            "name": "To Do",
+                            "colorName": "blue-gray"
+                        }
+                    },
+                    "reporter": {
+                        "accountId": "557058:f58131cb",
+                        "displayName": "Test User",
+                        "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:f58131cb"
+                    },
+                    "created": "2024-01-01T00:00:00.000Z",
+                    "updated": updated
+                }
+            }]
+        })
+    }
+
+    /// モックサーバー向けのクライアントを作成
+    fn mock_client(base_url: String) -> JiraClient {
+        JiraClient::new(crate::JiraConfig {
+            base_url,
+            auth: crate::Auth::Basic {
+                username: "test@example.com".to_string(),
+                api_token: "test_token".to_string(),
+            },
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            max_retries: 3,
+            user_agent: None,
+            default_search_fields: None,
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_sync_watermark_advances_and_resumes() {
+        // 同期後にウォーターマークが進み、再起動後はその時刻から同期が再開されることをテスト
+        use crate::FileConfigStore;
+        use tempfile::TempDir;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(mock_search_response(1, "2024-01-02T03:04:00.000Z")),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(mock_server.uri());
+
+        let temp_dir = TempDir::new().unwrap();
+        let store: Arc<Mutex<dyn ConfigStore>> =
+            Arc::new(Mutex::new(FileConfigStore::new(temp_dir.path())));
+        let config = SyncConfig::new().target_projects(vec!["TEST".to_string()]);
+
+        // 1回目の同期でウォーターマークが保存される
+        let service = SyncService::new(config.clone()).with_config_store(Arc::clone(&store));
+        let result = service.sync_full(&client).await.unwrap();
+        assert!(result.is_success);
+
+        let expected = chrono::DateTime::parse_from_rfc3339("2024-01-02T03:04:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            service.load_sync_watermark("TEST").await.unwrap(),
+            Some(expected)
+        );
+
+        // 再起動相当: 新しいストア・サービスでもウォーターマークから再開する
+        let reopened: Arc<Mutex<dyn ConfigStore>> =
+            Arc::new(Mutex::new(FileConfigStore::new(temp_dir.path())));
+        let restarted = SyncService::new(config).with_config_store(reopened);
+        let result = restarted.sync_full(&client).await.unwrap();
+        assert!(result.is_success);
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let last_body: serde_json::Value =
+            serde_json::from_slice(&requests.last().unwrap().body).unwrap();
+        let jql = last_body["jql"].as_str().unwrap();
+        assert!(jql.contains("updated >= '2024-01-02 03:04'"));
+        assert!(!jql.contains("created"));
+    }
+
+    #[tokio::test]
+    async fn test_sync_project_history_after_three_runs() {
+        // 3回同期した後、プロジェクトの統計履歴が3件・実行順で取得できることをテスト
+        use crate::FileConfigStore;
+        use crate::clock::FixedClock;
+        use chrono::TimeZone;
+        use tempfile::TempDir;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(mock_search_response(1, "2024-01-02T03:04:00.000Z")),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(mock_server.uri());
+
+        let temp_dir = TempDir::new().unwrap();
+        let store: Arc<Mutex<dyn ConfigStore>> =
+            Arc::new(Mutex::new(FileConfigStore::new(temp_dir.path())));
+        let start = Utc.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap();
+        let clock = FixedClock::new(start);
+        let service = SyncService::new(SyncConfig::new().target_projects(vec!["TEST".to_string()]))
+            .with_config_store(Arc::clone(&store))
+            .with_clock(Arc::new(clock.clone()));
+
+        let mut expected_times = Vec::new();
+        for _ in 0..3 {
+            expected_times.push(clock.now());
+            let result = service.sync_full(&client).await.unwrap();
+            assert!(result.is_success);
+            clock.advance(chrono::Duration::hours(1));
+        }
+
+        // メモリ上の履歴
+        let history = service.project_history("TEST").await;
+        let times: Vec<DateTime<Utc>> = history.iter().map(|s| s.last_sync_time).collect();
+        assert_eq!(times, expected_times);
+        assert!(service.project_history("OTHER").await.is_empty());
+
+        // 永続化された履歴（再起動相当の新しいサービスから取得）
+        let reopened: Arc<Mutex<dyn ConfigStore>> =
+            Arc::new(Mutex::new(FileConfigStore::new(temp_dir.path())));
+        let restarted = SyncService::new(SyncConfig::new()).with_config_store(reopened);
+        let persisted = restarted.load_project_history("TEST").await.unwrap();
+        let times: Vec<DateTime<Utc>> = persisted.iter().map(|s| s.last_sync_time).collect();
+        assert_eq!(times, expected_times);
+    }
+
+    #[tokio::test]
+    async fn test_sync_cancellation_stops_page_requests() {
+        // 同期中にキャンセルすると次のページを要求せず、途中までの結果を返すことをテスト
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+        /// 1ページ目を返すと同時にキャンセルするレスポンダー
+        struct CancelOnFirstPage(CancellationToken);
+
+        impl Respond for CancelOnFirstPage {
+            fn respond(&self, _request: &Request) -> ResponseTemplate {
+                self.0.cancel();
+                // total=5のため、キャンセルされなければ次のページを要求し続ける
+                ResponseTemplate::new(200)
+                    .set_body_json(mock_search_response(5, "2024-01-02T03:04:00.000Z"))
+            }
+        }
+
+        let token = CancellationToken::new();
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(CancelOnFirstPage(token.clone()))
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(mock_server.uri());
+        let service = SyncService::new(SyncConfig::new().target_projects(vec!["TEST".to_string()]));
+
+        let result = service
+            .sync_incremental_with_cancellation(&client, &[], &token)
+            .await
+            .unwrap();
+
+        assert!(result.cancelled);
+        assert!(!result.is_success);
+        assert_eq!(result.synced_issues_count, 1);
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+
+        // キャンセルされた同期は最終成功時刻を更新せず、再度同期できる
+        assert!(service.last_successful_sync().await.is_none());
+        assert!(service.current_state().await.is_idle());
+    }
+
+    #[tokio::test]
+    async fn test_sync_dry_run_counts_without_saving() {
+        // ドライランではmaxResults=0で件数のみ取得し、ストアに何も書き込まないことをテスト
+        use crate::FileConfigStore;
+        use tempfile::TempDir;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(mock_search_response(42, "2024-01-02T03:04:00.000Z")),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(mock_server.uri());
+        let temp_dir = TempDir::new().unwrap();
+        let store: Arc<Mutex<dyn ConfigStore>> =
+            Arc::new(Mutex::new(FileConfigStore::new(temp_dir.path())));
+
+        let config = SyncConfig::new()
+            .target_projects(vec!["TEST".to_string()])
+            .dry_run(true);
+        let service = SyncService::new(config).with_config_store(store);
+
+        let result = service.sync_full(&client).await.unwrap();
+        assert!(result.is_success);
+        assert_eq!(result.synced_issues_count, 42);
+        assert_eq!(result.project_stats["TEST"].synced_count, 42);
+
+        // 件数取得のためmaxResults=0で1回だけ問い合わせる
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["maxResults"], 0);
+
+        // ウォーターマークも同期履歴も保存されない
+        assert!(service.load_sync_watermark("TEST").await.unwrap().is_none());
+        assert!(!temp_dir.path().join("sync_watermarks.json").exists());
+        assert!(service.sync_history().await.is_empty());
+        assert!(service.last_successful_sync().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sync_dry_run_project_list_error_not_recorded() {
+        // ドライランでプロジェクト一覧の取得に失敗しても同期履歴に残らないことをテスト
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(mock_server.uri());
+        let service = SyncService::new(SyncConfig::new().dry_run(true));
+
+        let result = service.sync_full(&client).await.unwrap();
+        assert!(!result.is_success);
+        assert!(
+            result
+                .error_messages
+                .iter()
+                .any(|e| e.contains("プロジェクト一覧取得エラー"))
+        );
+        assert!(service.current_state().await.is_error());
+
+        assert!(service.sync_history().await.is_empty());
+        assert!(service.last_successful_sync().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sync_projects_overrides_target_projects() {
+        // sync_projectsは設定を変更せず、指定したプロジェクトのみを問い合わせることをテスト
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(mock_search_response(1, "2024-01-02T03:04:00.000Z")),
+            )
+            .mount(&mock_server)
+            .await;
+        // プロジェクト一覧は取得しない
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(mock_server.uri());
+        let service =
+            SyncService::new(SyncConfig::new().target_projects(vec!["OTHER".to_string()]));
+
+        let projects = vec!["TEST".to_string(), "DEMO".to_string()];
+        let result = service
+            .sync_projects(&client, &[], &projects)
+            .await
+            .unwrap();
+
+        assert!(result.is_success);
+        let mut synced: Vec<&String> = result.project_stats.keys().collect();
+        synced.sort();
+        assert_eq!(synced, ["DEMO", "TEST"]);
+
+        // 各検索は指定したプロジェクトのいずれかのみを対象とする
+        let mut queried = Vec::new();
+        for request in mock_server.received_requests().await.unwrap() {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            let jql = body["jql"].as_str().unwrap().to_string();
+            assert!(!jql.contains("OTHER"), "unexpected project in {}", jql);
+            let project = projects
+                .iter()
+                .find(|p| jql.contains(p.as_str()))
+                .unwrap_or_else(|| panic!("no target project in {}", jql));
+            queried.push(project.clone());
+        }
+        queried.sort();
+        queried.dedup();
+        assert_eq!(queried, ["DEMO", "TEST"]);
+
+        // 設定の対象プロジェクトはそのまま
+        assert_eq!(service.config().target_projects, vec!["OTHER".to_string()]);
+
+        // 空の指定はエラー
+        assert!(service.sync_projects(&client, &[], &[]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sync_projects_keeps_incremental_baseline() {
+        // 対象を絞った同期の後も、対象外のプロジェクトは前回の増分同期の時刻から取得されることをテスト
+        use crate::clock::FixedClock;
+        use crate::testing::sample_search_response;
+        use crate::time_filter::format_jira_datetime;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(sample_search_response(vec![])))
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(mock_server.uri());
+        let start = "2024-03-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let clock = FixedClock::new(start);
+        let service = SyncService::new(
+            SyncConfig::new().target_projects(vec!["A".to_string(), "B".to_string()]),
+        )
+        .with_clock(Arc::new(clock.clone()));
+
+        service.sync_incremental(&client, &[]).await.unwrap();
+        assert_eq!(service.last_successful_sync().await, Some(start));
+
+        // Aのみを同期しても最終成功時刻は進まない
+        clock.advance(chrono::Duration::hours(2));
+        let result = service
+            .sync_projects(&client, &[], &["A".to_string()])
+            .await
+            .unwrap();
+        assert!(result.is_success);
+        assert_eq!(service.last_successful_sync().await, Some(start));
+        assert_eq!(service.sync_history().await.len(), 2);
+
+        // 次の増分同期では、Bは最初の同期の時刻以降の更新を取得する
+        clock.advance(chrono::Duration::hours(2));
+        mock_server.reset().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(sample_search_response(vec![])))
+            .mount(&mock_server)
+            .await;
+        service.sync_incremental(&client, &[]).await.unwrap();
+
+        let since = format!("updated >= '{}'", format_jira_datetime(&start));
+        let jqls: Vec<String> = mock_server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| {
+                let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+                body["jql"].as_str().unwrap().to_string()
+            })
+            .collect();
+        let b_jql = jqls
+            .iter()
+            .find(|jql| jql.contains("project = \"B\""))
+            .unwrap_or_else(|| panic!("no request for B in {:?}", jqls));
+        assert!(b_jql.contains(&since), "{}", b_jql);
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_deletes_stale_issues() {
+        // JQLに一致しなくなったIssueが削除され、新規・更新・削除の件数が正しく集計されることをテスト
+        use crate::DuckDBStore;
+        use crate::testing::{sample_issue_json, sample_search_response};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let issue_json = |key: &str, updated: &str| {
+            let mut value = sample_issue_json(&key.replace('-', ""), key);
+            value["fields"]["updated"] = serde_json::json!(updated);
+            value
+        };
+        let issue = |key: &str, updated: &str| -> Issue {
+            serde_json::from_value(issue_json(key, updated)).unwrap()
+        };
+
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+        store
+            .save_issues(&[
+                // 変更なし
+                issue("TEST-1", "2024-01-02T00:00:00.000Z"),
+                // リモートで更新済み
+                issue("TEST-2", "2024-01-02T00:00:00.000Z"),
+                // JQLに一致しなくなった
+                issue("TEST-9", "2024-01-02T00:00:00.000Z"),
+                // 範囲外のプロジェクトは変更しない
+                issue("OTHER-1", "2024-01-02T00:00:00.000Z"),
+            ])
+            .await
+            .unwrap();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(sample_search_response(vec![
+                    issue_json("TEST-1", "2024-01-02T00:00:00.000Z"),
+                    issue_json("TEST-2", "2024-01-05T00:00:00.000Z"),
+                    issue_json("TEST-3", "2024-01-05T00:00:00.000Z"),
+                ])),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(mock_server.uri());
+        let service = SyncService::new(SyncConfig::new());
+        let scope = IssueFilter::new().project_keys(vec!["TEST".to_string()]);
+
+        let result = service
+            .reconcile_in_scope(&client, &mut store, "project = TEST", &scope)
+            .await
+            .unwrap();
+
+        assert!(result.is_success);
+        assert_eq!(result.synced_issues_count, 3);
+        assert_eq!(result.new_issues_count, 1);
+        assert_eq!(result.updated_issues_count, 1);
+        assert_eq!(result.deleted_issues_count, 1);
+
+        let mut keys: Vec<String> = store
+            .load_all_issues()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|i| i.key)
+            .collect();
+        keys.sort();
+        assert_eq!(keys, vec!["OTHER-1", "TEST-1", "TEST-2", "TEST-3"]);
+
+        let updated = store
+            .load_issues(&IssueFilter::new().project_keys(vec!["TEST".to_string()]))
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|i| i.key == "TEST-2")
+            .unwrap();
+        assert_eq!(
+            updated.fields.updated,
+            "2024-01-05T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_keeps_issues_outside_label_and_priority_scope() {
+        // 優先度・ラベルで絞った範囲外のIssueは削除されず、最終成功時刻も変わらないことをテスト
+        use crate::DuckDBStore;
+        use crate::testing::{sample_issue_json, sample_search_response};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let issue = |key: &str, priority: &str, labels: Vec<&str>| -> Issue {
+            let mut value = sample_issue_json(&key.replace('-', ""), key);
+            value["fields"]["priority"] = serde_json::json!({
+                "id": "1",
+                "name": priority,
+                "self": "https://example.atlassian.net/rest/api/3/priority/1"
+            });
+            value["fields"]["labels"] = serde_json::json!(labels);
+            serde_json::from_value(value).unwrap()
+        };
+
+        let mut store = DuckDBStore::new_in_memory().unwrap();
+        store.initialize().await.unwrap();
+        store
+            .save_issues(&[
+                // 範囲内でJQLに一致しなくなった
+                issue("TEST-1", "High", vec!["backend"]),
+                // ラベルが範囲外
+                issue("TEST-2", "High", vec!["frontend"]),
+                // 優先度が範囲外
+                issue("TEST-3", "Low", vec!["backend"]),
+                issue("TEST-4", "Low", vec![]),
+            ])
+            .await
+            .unwrap();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(sample_search_response(vec![])))
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(mock_server.uri());
+        let service = SyncService::new(SyncConfig::new());
+        let scope = IssueFilter::new()
+            .project_keys(vec!["TEST".to_string()])
+            .priorities(vec!["High".to_string()])
+            .labels(vec!["backend".to_string()]);
+
+        let result = service
+            .reconcile_in_scope(
+                &client,
+                &mut store,
+                "project = TEST AND priority = High AND labels = backend",
+                &scope,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.deleted_issues_count, 1);
+
+        let mut keys: Vec<String> = store
+            .load_all_issues()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|i| i.key)
+            .collect();
+        keys.sort();
+        assert_eq!(keys, vec!["TEST-2", "TEST-3", "TEST-4"]);
+
+        // 履歴には残るが、増分同期の基準は変わらない
+        assert_eq!(service.sync_history().await.len(), 1);
+        assert_eq!(service.last_successful_sync().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_json_store_keeps_unchanged_issues() {
+        // JsonStoreでも変更の無いIssueと範囲外のIssueが残り、ミラーとしてのリコンサイルでは範囲外も削除されることをテスト
+        use crate::JsonStore;
+        use crate::testing::{sample_issue_json, sample_search_response};
+        use tempfile::TempDir;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let issue_json = |key: &str, updated: &str| {
+            let mut value = sample_issue_json(&key.replace('-', ""), key);
+            value["fields"]["updated"] = serde_json::json!(updated);
+            value
+        };
+        let issue = |key: &str, updated: &str| -> Issue {
+            serde_json::from_value(issue_json(key, updated)).unwrap()
+        };
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = JsonStore::new(temp_dir.path());
+        store.initialize().await.unwrap();
+        store
+            .save_issues(&[
+                issue("TEST-1", "2024-01-02T00:00:00.000Z"),
+                issue("TEST-2", "2024-01-02T00:00:00.000Z"),
+                issue("TEST-9", "2024-01-02T00:00:00.000Z"),
+                issue("OTHER-1", "2024-01-02T00:00:00.000Z"),
+            ])
+            .await
+            .unwrap();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(sample_search_response(vec![
+                    issue_json("TEST-1", "2024-01-02T00:00:00.000Z"),
+                    issue_json("TEST-2", "2024-01-05T00:00:00.000Z"),
+                    issue_json("TEST-3", "2024-01-05T00:00:00.000Z"),
+                ])),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(mock_server.uri());
+        let service = SyncService::new(SyncConfig::new());
+        let scope = IssueFilter::new().project_keys(vec!["TEST".to_string()]);
+
+        let result = service
+            .reconcile_in_scope(&client, &mut store, "project = TEST", &scope)
+            .await
+            .unwrap();
+        assert_eq!(result.new_issues_count, 1);
+        assert_eq!(result.updated_issues_count, 1);
+        assert_eq!(result.deleted_issues_count, 1);
+
+        let stored_keys = |issues: Vec<Issue>| {
+            let mut keys: Vec<String> = issues.into_iter().map(|i| i.key).collect();
+            keys.sort();
+            keys
+        };
+        assert_eq!(
+            stored_keys(store.load_all_issues().await.unwrap()),
+            vec!["OTHER-1", "TEST-1", "TEST-2", "TEST-3"]
+        );
+
+        // ミラーとしてのリコンサイルではJQLに一致しないIssueは全て削除される
+        let result = service
+            .reconcile(&client, &mut store, "project = TEST")
+            .await
+            .unwrap();
+        assert_eq!(result.new_issues_count, 0);
+        assert_eq!(result.updated_issues_count, 0);
+        assert_eq!(result.deleted_issues_count, 1);
+        assert_eq!(
+            stored_keys(store.load_all_issues().await.unwrap()),
+            vec!["TEST-1", "TEST-2", "TEST-3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_error_captures_project_and_status() {
+        // 失敗したプロジェクトの同期エラーにキー・JQL・HTTPステータスが記録されることをテスト
+        use wiremock::matchers::{body_string_contains, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_string_contains("BROKEN"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "errorMessages": ["You do not have permission to view this project"],
+                "errors": {}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(mock_search_response(1, "2024-01-02T03:04:00.000Z")),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = mock_client(mock_server.uri());
+        let service = SyncService::new(
+            SyncConfig::new().target_projects(vec!["TEST".to_string(), "BROKEN".to_string()]),
+        );
+
+        let result = service.sync_full(&client).await.unwrap();
+
+        assert!(!result.is_success);
+        assert_eq!(result.error_count, 1);
+        assert_eq!(result.errors.len(), 1);
+
+        let error = &result.errors[0];
+        assert_eq!(error.project_key, "BROKEN");
+        assert!(error.jql.contains("BROKEN"), "{}", error.jql);
+        assert_eq!(error.status, Some(403));
+        assert_eq!(result.failed_projects(), vec!["BROKEN".to_string()]);
+
+        // 表示用のメッセージにも同じエラーが含まれる
+        assert_eq!(result.error_messages(), [error.to_string()]);
+        assert!(result.error_messages()[0].contains("BROKEN"));
+    }
+
     #[test]
     fn test_sync_service_deduplicate_issues() {
         // SyncService::deduplicate_issues()が正しく動作することをテスト
@@ -1012,4 +2368,55 @@ mod tests {
 
         assert_eq!(deduplicated.len(), 0);
     }
+
+    #[test]
+    fn test_sync_service_deduplicate_keep_latest() {
+        // 同じキーのIssueのうち更新日時が新しいものが残ることをテスト
+        let service = SyncService::new(SyncConfig::new());
+
+        let issue_with_updated = |updated: &str| -> Issue {
+            serde_json::from_value(mock_search_response(1, updated)["issues"][0].clone()).unwrap()
+        };
+        let newer = issue_with_updated("2024-01-03T00:00:00.000Z");
+        let older = issue_with_updated("2024-01-02T00:00:00.000Z");
+
+        // 新しいものが後から現れる場合
+        let deduplicated = service.deduplicate_keep_latest(vec![older.clone(), newer.clone()]);
+        assert_eq!(deduplicated.len(), 1);
+        assert_eq!(deduplicated[0].fields.updated, newer.fields.updated);
+
+        // 新しいものが先に現れる場合も古いもので上書きされない
+        let deduplicated = service.deduplicate_keep_latest(vec![newer.clone(), older]);
+        assert_eq!(deduplicated.len(), 1);
+        assert_eq!(deduplicated[0].fields.updated, newer.fields.updated);
+    }
+
+    #[test]
+    fn test_sync_service_deduplicate_by_custom_key() {
+        // 異なるインスタンスで同じキーを持つIssueが、IDで重複除外すると両方残ることをテスト
+        let service = SyncService::new(SyncConfig::new());
+
+        let issue: Issue = serde_json::from_value(
+            mock_search_response(1, "2024-01-02T03:04:00.000Z")["issues"][0].clone(),
+        )
+        .unwrap();
+        let mut other_instance = issue.clone();
+        other_instance.id = "20000".to_string();
+        other_instance.self_url = "https://other.atlassian.net/rest/api/3/issue/20000".to_string();
+        let issues = vec![issue.clone(), other_instance, issue];
+
+        // キーでは衝突して1件になる
+        let by_key = service.deduplicate_issues(issues.clone());
+        assert_eq!(by_key.len(), 1);
+        assert_eq!(by_key[0].id, "10000");
+
+        // IDでは別のIssueとして扱われ、完全な重複のみ除外される
+        let by_id = service.deduplicate_by(issues.clone(), |i| i.id.clone());
+        let ids: Vec<&str> = by_id.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, ["10000", "20000"]);
+
+        // 複合キー（self URL）でも同じ結果になる
+        let by_url = service.deduplicate_by(issues, |i| format!("{}#{}", i.self_url, i.key));
+        assert_eq!(by_url.len(), 2);
+    }
 }
@@ -0,0 +1,264 @@
+//! テスト用の共通ヘルパー
+//!
+//! モックサーバーに接続する[`JiraClient`]の作成と、JIRA REST APIのレスポンス形式に沿った
+//! サンプルJSONの組み立てを提供します。エンドポイントのテストでは
+//! `MockServer`を起動してレスポンスを登録し、[`mock_client`]で作成したクライアントから呼び出します。
+
+use crate::{Auth, Issue, JiraClient, JiraConfig};
+use serde_json::{Value, json};
+use wiremock::MockServer;
+
+/// [`mock_client`]が使用するユーザー名
+pub(crate) const TEST_USERNAME: &str = "test@example.com";
+
+/// [`mock_client`]が使用するAPIトークン
+pub(crate) const TEST_API_TOKEN: &str = "test_token";
+
+/// モックサーバーに接続するBasic認証のクライアントを作成
+pub(crate) fn mock_client(server: &MockServer) -> JiraClient {
+    let config = JiraConfig::new_unchecked(
+        server.uri(),
+        Auth::Basic {
+            username: TEST_USERNAME.to_string(),
+            api_token: TEST_API_TOKEN.to_string(),
+        },
+    );
+
+    JiraClient::new(config).expect("failed to create mock client")
+}
+
+/// 必須フィールドを持つIssueのJSONを作成
+///
+/// プロジェクトキーはIssueキーの`-`より前の部分から決まります。
+pub(crate) fn sample_issue_json(id: &str, key: &str) -> Value {
+    let project_key = key.split('-').next().unwrap_or(key);
+
+    json!({
+        "id": id,
+        "key": key,
+        "self": format!("https://example.atlassian.net/rest/api/3/issue/{}", id),
+        "fields": {
+            "summary": format!("Test Issue {}", key),
+            "issuetype": {
+                "id": "1",
+                "name": "Bug",
+                "self": "https://example.atlassian.net/rest/api/3/issuetype/1"
+            },
+            "status": sample_status_json("1", "To Do", "new"),
+            "project": sample_project_json("10000", project_key, &format!("Project {}", project_key)),
+            "reporter": {
+                "accountId": "557058:f58131cb",
+                "displayName": "Test User",
+                "emailAddress": "test@example.com",
+                "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:f58131cb"
+            },
+            "created": "2024-01-01T00:00:00.000Z",
+            "updated": "2024-01-02T00:00:00.000Z"
+        }
+    })
+}
+
+/// ステータスのJSONを作成
+///
+/// ステータスカテゴリーのIDと色は`category_key`（`new`・`indeterminate`・`done`）から決まります。
+pub(crate) fn sample_status_json(id: &str, name: &str, category_key: &str) -> Value {
+    let (category_id, category_name, color_name) = match category_key {
+        "indeterminate" => (4, "In Progress", "yellow"),
+        "done" => (3, "Done", "green"),
+        _ => (2, "To Do", "blue-gray"),
+    };
+
+    json!({
+        "id": id,
+        "name": name,
+        "self": format!("https://example.atlassian.net/rest/api/3/status/{}", id),
+        "statusCategory": {
+            "id": category_id,
+            "key": category_key,
+            "name": category_name,
+            "colorName": color_name
+        }
+    })
+}
+
+/// プロジェクトのJSONを作成
+pub(crate) fn sample_project_json(id: &str, key: &str, name: &str) -> Value {
+    json!({
+        "id": id,
+        "key": key,
+        "name": name,
+        "self": format!("https://example.atlassian.net/rest/api/3/project/{}", id),
+        "projectTypeKey": "software",
+        "simplified": false
+    })
+}
+
+/// Issueの配列から`/rest/api/3/search`のレスポンスJSONを作成
+pub(crate) fn sample_search_response(issues: Vec<Value>) -> Value {
+    json!({
+        "startAt": 0,
+        "maxResults": 50,
+        "total": issues.len(),
+        "issues": issues
+    })
+}
+
+/// 並び替えと担当者の絞り込みのテストに使う3件のIssueを作成
+///
+/// | キー | ステータス | 種別 | 担当者 | 報告者 |
+/// |------|------------|------|--------|--------|
+/// | SORT-1 | Open | Task | Bob | Carol |
+/// | SORT-2 | Done | Bug | 未割り当て | Alice |
+/// | SORT-3 | In Progress | Story | Alice | Bob |
+///
+/// 担当者と報告者のアカウントIDは表示名を小文字にしたものです。
+pub(crate) fn sample_sort_issues() -> Vec<Issue> {
+    let user = |name: &str| {
+        json!({
+            "accountId": name.to_lowercase(),
+            "displayName": name,
+            "self": "http://example.com",
+            "active": true
+        })
+    };
+    let issue = |id: &str, key: &str, status: &str, issue_type: &str| {
+        let mut value = sample_issue_json(id, key);
+        value["fields"]["status"] = sample_status_json("1", status, "new");
+        value["fields"]["issuetype"]["name"] = json!(issue_type);
+        value
+    };
+
+    let mut issue1 = issue("10001", "SORT-1", "Open", "Task");
+    issue1["fields"]["assignee"] = user("Bob");
+    issue1["fields"]["reporter"] = user("Carol");
+
+    let mut issue2 = issue("10002", "SORT-2", "Done", "Bug");
+    issue2["fields"]["reporter"] = user("Alice");
+
+    let mut issue3 = issue("10003", "SORT-3", "In Progress", "Story");
+    issue3["fields"]["assignee"] = user("Alice");
+    issue3["fields"]["reporter"] = user("Bob");
+
+    [issue1, issue2, issue3]
+        .into_iter()
+        .map(|value| serde_json::from_value(value).expect("invalid sample issue"))
+        .collect()
+}
+
+/// 解決状況の集計のテストに使う4件のIssueを作成
+///
+/// | キー | ステータス | 解決状況 |
+/// |------|------------|----------|
+/// | RES-1 | Done | Done |
+/// | RES-2 | Done | Done |
+/// | RES-3 | Closed | Won't Fix |
+/// | RES-4 | Open | 未解決 |
+pub(crate) fn sample_resolution_issues() -> Vec<Issue> {
+    let issue = |id: &str, key: &str, status: &str, resolution: Option<&str>| {
+        let mut value = sample_issue_json(id, key);
+        value["fields"]["status"] = sample_status_json("1", status, "new");
+        if let Some(name) = resolution {
+            value["fields"]["resolution"] = json!({"id": name, "name": name});
+        }
+        value
+    };
+
+    [
+        issue("30001", "RES-1", "Done", Some("Done")),
+        issue("30002", "RES-2", "Done", Some("Done")),
+        issue("30003", "RES-3", "Closed", Some("Won't Fix")),
+        issue("30004", "RES-4", "Open", None),
+    ]
+    .into_iter()
+    .map(|value| serde_json::from_value(value).expect("invalid sample issue"))
+    .collect()
+}
+
+/// カスタムフィールドの絞り込みのテストに使う4件のIssueを作成
+///
+/// | キー | 選択リスト（`customfield_10020`） | ストーリーポイント（`customfield_10016`） |
+/// |------|------------------------------------|--------------------------------------------|
+/// | CF-1 | High | 3.0 |
+/// | CF-2 | Low | 5.0 |
+/// | CF-3 | High | 8.0 |
+/// | CF-4 | なし | なし |
+pub(crate) fn sample_custom_field_issues() -> Vec<Issue> {
+    let issue = |id: &str, key: &str, option: Option<(&str, &str, f64)>| {
+        let mut value = sample_issue_json(id, key);
+        if let Some((option_id, option_value, story_points)) = option {
+            value["fields"]["customfield_10020"] = json!({"id": option_id, "value": option_value});
+            value["fields"]["customfield_10016"] = json!(story_points);
+        }
+        value
+    };
+
+    [
+        issue("20001", "CF-1", Some(("1", "High", 3.0))),
+        issue("20002", "CF-2", Some(("2", "Low", 5.0))),
+        issue("20003", "CF-3", Some(("1", "High", 8.0))),
+        // カスタムフィールドを持たないIssue
+        issue("20004", "CF-4", None),
+    ]
+    .into_iter()
+    .map(|value| serde_json::from_value(value).expect("invalid sample issue"))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Issue, Project, SearchResult, Status};
+
+    #[test]
+    fn test_sample_json_deserializes() {
+        // サンプルJSONがモデルとしてデシリアライズできることをテスト
+        let issue: Issue = serde_json::from_value(sample_issue_json("10001", "TEST-1")).unwrap();
+        assert_eq!(issue.id, "10001");
+        assert_eq!(issue.key, "TEST-1");
+        assert_eq!(issue.fields.project.as_ref().unwrap().key, "TEST");
+
+        let project: Project =
+            serde_json::from_value(sample_project_json("10000", "DEMO", "Demo Project")).unwrap();
+        assert_eq!(project.key, "DEMO");
+        assert_eq!(project.name, "Demo Project");
+
+        let status: Status =
+            serde_json::from_value(sample_status_json("10001", "Done", "done")).unwrap();
+        assert_eq!(status.name, "Done");
+        assert_eq!(status.status_category.key, "done");
+
+        let result: SearchResult = serde_json::from_value(sample_search_response(vec![
+            sample_issue_json("10001", "TEST-1"),
+            sample_issue_json("10002", "TEST-2"),
+        ]))
+        .unwrap();
+        assert_eq!(result.total, 2);
+        assert_eq!(result.issues.len(), 2);
+    }
+
+    #[test]
+    fn test_sample_sort_issues() {
+        // 並び替え用のIssueが表の通りの担当者と報告者を持つことをテスト
+        let issues = sample_sort_issues();
+        let keys: Vec<&str> = issues.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys, vec!["SORT-1", "SORT-2", "SORT-3"]);
+
+        let assignees: Vec<Option<&str>> = issues
+            .iter()
+            .map(|i| i.fields.assignee.as_ref().map(|u| u.display_name.as_str()))
+            .collect();
+        assert_eq!(assignees, vec![Some("Bob"), None, Some("Alice")]);
+        assert_eq!(issues[0].fields.reporter.display_name, "Carol");
+        assert_eq!(issues[1].fields.issue_type.name, "Bug");
+        assert_eq!(issues[2].fields.status.name, "In Progress");
+    }
+
+    #[tokio::test]
+    async fn test_mock_client_targets_server() {
+        // mock_client()がモックサーバーに接続することをテスト
+        let server = MockServer::start().await;
+        let client = mock_client(&server);
+
+        assert_eq!(client.config().base_url, server.uri());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::clock::{Clock, SystemClock};
 use chrono::{DateTime, Duration, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -84,7 +85,12 @@ impl TimeBasedFilter {
 
     /// 最近N時間のフィルターを作成
     pub fn last_hours(hours: u32) -> Self {
-        let now = Utc::now();
+        Self::last_hours_with_clock(hours, &SystemClock)
+    }
+
+    /// 指定したクロックの現在時刻を基準に最近N時間のフィルターを作成
+    pub fn last_hours_with_clock(hours: u32, clock: &dyn Clock) -> Self {
+        let now = clock.now();
         let since = now - Duration::hours(hours as i64);
 
         Self::new().since(since).until(now).granularity_hours(1)
@@ -92,7 +98,12 @@ impl TimeBasedFilter {
 
     /// 最近N日のフィルターを作成
     pub fn last_days(days: u32) -> Self {
-        let now = Utc::now();
+        Self::last_days_with_clock(days, &SystemClock)
+    }
+
+    /// 指定したクロックの現在時刻を基準に最近N日のフィルターを作成
+    pub fn last_days_with_clock(days: u32, clock: &dyn Clock) -> Self {
+        let now = clock.now();
         let since = now - Duration::days(days as i64);
 
         Self::new().since(since).until(now).granularity_hours(24)
@@ -108,7 +119,12 @@ impl TimeBasedFilter {
 
     /// 増分取得用のフィルターを作成（最後の同期時刻以降）
     pub fn incremental_since(last_sync_time: DateTime<Utc>) -> Self {
-        let now = Utc::now();
+        Self::incremental_since_with_clock(last_sync_time, &SystemClock)
+    }
+
+    /// 指定したクロックの現在時刻までを範囲とする増分取得用のフィルターを作成
+    pub fn incremental_since_with_clock(last_sync_time: DateTime<Utc>, clock: &dyn Clock) -> Self {
+        let now = clock.now();
 
         Self::new()
             .since(last_sync_time)
@@ -224,8 +240,14 @@ impl TimeBasedFilter {
 
     /// 時間範囲を時間粒度で分割
     pub fn split_into_chunks(&self) -> Vec<TimeChunk> {
-        let mut chunks = Vec::new();
+        self.chunks().collect()
+    }
 
+    /// 時間範囲を粒度ごとのチャンクとして順に返すイテレーター
+    ///
+    /// 各チャンクは開始を含み終了を含まない半開区間`[start, end)`で、
+    /// 前のチャンクの終了時刻が次のチャンクの開始時刻になります。
+    pub fn chunks(&self) -> impl Iterator<Item = TimeChunk> + use<> {
         let (start, end) = match (&self.since, &self.until) {
             (Some(s), Some(e)) => (*s, *e),
             (Some(s), None) => (*s, Utc::now()),
@@ -239,16 +261,21 @@ impl TimeBasedFilter {
         let chunk_duration = Duration::hours(self.granularity_hours as i64);
         let mut current = start;
 
-        while current < end {
-            let chunk_end = std::cmp::min(current + chunk_duration, end);
-            chunks.push(TimeChunk {
-                start: current,
-                end: chunk_end,
-            });
-            current = chunk_end;
-        }
+        std::iter::from_fn(move || {
+            if current >= end {
+                return None;
+            }
 
-        chunks
+            // 粒度が0の場合は範囲全体を1つのチャンクにする
+            let chunk_end = if chunk_duration > Duration::zero() {
+                std::cmp::min(current + chunk_duration, end)
+            } else {
+                end
+            };
+            let chunk = TimeChunk::new(current, chunk_end);
+            current = chunk_end;
+            Some(chunk)
+        })
     }
 }
 
@@ -259,11 +286,14 @@ impl Default for TimeBasedFilter {
 }
 
 /// 時間チャンク（時間粒度で分割された時間範囲）
+///
+/// 開始時刻を含み、終了時刻を含まない半開区間`[start, end)`です。
+/// 隣接するチャンクは境界を共有するため、境界ちょうどの時刻のIssueは後のチャンクにのみ含まれます。
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimeChunk {
     /// チャンクの開始時刻
     pub start: DateTime<Utc>,
-    /// チャンクの終了時刻
+    /// チャンクの終了時刻（この時刻は含まない）
     pub end: DateTime<Utc>,
 }
 
@@ -283,29 +313,42 @@ impl TimeChunk {
         self.duration_seconds() as f64 / 3600.0
     }
 
+    /// 指定した時刻がチャンクに含まれるかどうか（`start <= datetime < end`）
+    pub fn contains(&self, datetime: &DateTime<Utc>) -> bool {
+        datetime >= &self.start && datetime < &self.end
+    }
+
+    /// 指定したフィールドがこのチャンクに含まれる条件のJQLを生成
+    ///
+    /// `field >= 'start' AND field < 'end'`を生成するため、連続するチャンクの条件は
+    /// 重複も欠落も無くタイムラインを分割します。JQLの日時は分単位のため、
+    /// チャンクの境界は分単位に揃えてください。
+    pub fn to_jql(&self, field: &str) -> String {
+        format!(
+            "{} >= '{}' AND {} < '{}'",
+            field,
+            format_jira_datetime(&self.start),
+            field,
+            format_jira_datetime(&self.end)
+        )
+    }
+
     /// このチャンク用のJQL時間条件を生成
+    ///
+    /// 作成日時・更新日時の条件は[`TimeChunk::to_jql`]で生成し、両方を指定した場合はORで結合します。
     pub fn to_jql_condition(&self, filter_by_created: bool, filter_by_updated: bool) -> String {
-        let start_str = format_jira_datetime(&self.start);
-        let end_str = format_jira_datetime(&self.end);
-
         let mut conditions = Vec::new();
 
         if filter_by_created {
-            conditions.push(format!(
-                "created >= '{}' AND created <= '{}'",
-                start_str, end_str
-            ));
+            conditions.push(self.to_jql("created"));
         }
 
         if filter_by_updated {
-            conditions.push(format!(
-                "updated >= '{}' AND updated <= '{}'",
-                start_str, end_str
-            ));
+            conditions.push(self.to_jql("updated"));
         }
 
         if conditions.is_empty() {
-            format!("created >= '{}' AND created <= '{}'", start_str, end_str)
+            self.to_jql("created")
         } else {
             format!("({})", conditions.join(" OR "))
         }
@@ -313,7 +356,7 @@ impl TimeChunk {
 }
 
 /// DateTime<Utc>をJIRA用の日時文字列にフォーマット
-fn format_jira_datetime(dt: &DateTime<Utc>) -> String {
+pub(crate) fn format_jira_datetime(dt: &DateTime<Utc>) -> String {
     // JIRA APIでは "YYYY-MM-DD HH:mm" フォーマットを使用
     dt.format("%Y-%m-%d %H:%M").to_string()
 }
@@ -386,6 +429,26 @@ mod tests {
         assert!(until <= now);
     }
 
+    #[test]
+    fn test_time_based_filter_with_fixed_clock() {
+        // クロックを指定した場合、その時刻を基準に範囲が決まることをテスト
+        let now = Utc.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+        let clock = crate::clock::FixedClock::new(now);
+
+        let filter = TimeBasedFilter::last_hours_with_clock(6, &clock);
+        assert_eq!(filter.since, Some(now - Duration::hours(6)));
+        assert_eq!(filter.until, Some(now));
+
+        let filter = TimeBasedFilter::last_days_with_clock(2, &clock);
+        assert_eq!(filter.since, Some(now - Duration::days(2)));
+        assert_eq!(filter.until, Some(now));
+
+        let last_sync = now - Duration::minutes(15);
+        let filter = TimeBasedFilter::incremental_since_with_clock(last_sync, &clock);
+        assert_eq!(filter.since, Some(last_sync));
+        assert_eq!(filter.until, Some(now));
+    }
+
     #[test]
     fn test_time_based_filter_last_days() {
         // TimeBasedFilter::last_days()が正しく動作することをテスト
@@ -507,7 +570,7 @@ mod tests {
         // 作成時刻のみでフィルタ
         let jql_created = chunk.to_jql_condition(true, false);
         assert!(jql_created.contains("created >= '2024-01-01 12:00'"));
-        assert!(jql_created.contains("created <= '2024-01-01 13:00'"));
+        assert!(jql_created.contains("created < '2024-01-01 13:00'"));
         assert!(!jql_created.contains("updated"));
 
         // 作成時刻と更新時刻でフィルタ
@@ -539,6 +602,37 @@ mod tests {
         assert_eq!(chunks[2].end, end);
     }
 
+    #[test]
+    fn test_adjacent_chunks_partition_timeline() {
+        // 隣接するチャンクのJQLと範囲が重複も欠落も無くタイムラインを分割することをテスト
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 1, 4, 0, 0).unwrap();
+
+        let filter = TimeBasedFilter::new()
+            .since(start)
+            .until(end)
+            .granularity_hours(2);
+        let chunks: Vec<TimeChunk> = filter.chunks().collect();
+        assert_eq!(chunks.len(), 2);
+
+        assert_eq!(
+            chunks[0].to_jql("updated"),
+            "updated >= '2024-01-01 00:00' AND updated < '2024-01-01 02:00'"
+        );
+        assert_eq!(
+            chunks[1].to_jql("updated"),
+            "updated >= '2024-01-01 02:00' AND updated < '2024-01-01 04:00'"
+        );
+
+        // 境界を含む各時刻がちょうど1つのチャンクに含まれる
+        for minutes in [0, 1, 119, 120, 121, 239] {
+            let t = start + Duration::minutes(minutes);
+            let matching = chunks.iter().filter(|c| c.contains(&t)).count();
+            assert_eq!(matching, 1, "{}", t);
+        }
+        assert!(!chunks.iter().any(|c| c.contains(&end)));
+    }
+
     #[test]
     fn test_format_and_parse_jira_datetime() {
         // JIRA日時フォーマットが正しく動作することをテスト
@@ -0,0 +1,257 @@
+//! JIRA Webhookペイロードの解析
+//!
+//! Webhookで受信したIssueイベントを[`Issue`]と[`IssueHistory`]に変換します。
+//! 変換結果はポーリング同期で取得したデータと同じ形式のため、
+//! そのまま[`crate::PersistenceStore`]に保存できます。
+
+use crate::{ChangelogParser, Error, Issue, IssueHistory};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Webhookイベントの種類
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum WebhookEventType {
+    /// Issue作成（`jira:issue_created`）
+    IssueCreated,
+    /// Issue更新（`jira:issue_updated`）
+    IssueUpdated,
+    /// Issue削除（`jira:issue_deleted`）
+    IssueDeleted,
+}
+
+impl WebhookEventType {
+    /// `webhookEvent`の値からイベント種別を判定
+    pub fn from_event_name(name: &str) -> Option<Self> {
+        match name {
+            "jira:issue_created" => Some(Self::IssueCreated),
+            "jira:issue_updated" => Some(Self::IssueUpdated),
+            "jira:issue_deleted" => Some(Self::IssueDeleted),
+            _ => None,
+        }
+    }
+}
+
+/// 解析済みのWebhookイベント
+#[derive(Debug, Clone)]
+pub struct WebhookEvent {
+    /// イベントの種類
+    pub event_type: WebhookEventType,
+    /// イベント発生日時（ペイロードに含まれる場合）
+    pub timestamp: Option<DateTime<Utc>>,
+    /// 対象のIssue
+    pub issue: Issue,
+    /// changelogから変換した変更履歴（changelogが無い場合は空）
+    pub history: Vec<IssueHistory>,
+}
+
+/// IssueイベントのWebhookペイロードを解析
+///
+/// `webhookEvent`でイベント種別を判定し、`issue`を[`Issue`]に変換します。
+/// `changelog`が含まれる場合は、イベントの`timestamp`と`user`を変更日時・変更者として
+/// [`ChangelogParser`]で[`IssueHistory`]に変換します。
+pub fn parse_issue_event(payload: &Value) -> Result<WebhookEvent, Error> {
+    let event_name = payload
+        .get("webhookEvent")
+        .and_then(|e| e.as_str())
+        .ok_or_else(|| Error::InvalidData("Missing webhookEvent in payload".to_string()))?;
+
+    let event_type = WebhookEventType::from_event_name(event_name)
+        .ok_or_else(|| Error::InvalidData(format!("Unsupported webhook event: {}", event_name)))?;
+
+    let issue_json = payload
+        .get("issue")
+        .ok_or_else(|| Error::InvalidData("Missing issue in payload".to_string()))?;
+    let issue: Issue = serde_json::from_value(issue_json.clone())
+        .map_err(|e| Error::DeserializationError(format!("Invalid issue in payload: {}", e)))?;
+
+    let timestamp = payload
+        .get("timestamp")
+        .and_then(|t| t.as_i64())
+        .and_then(DateTime::<Utc>::from_timestamp_millis);
+
+    let history = match payload.get("changelog") {
+        Some(changelog) => {
+            let changed_at = timestamp.unwrap_or(issue.fields.updated);
+            parse_webhook_changelog(&issue, changelog, payload.get("user"), changed_at)?
+        }
+        None => Vec::new(),
+    };
+
+    Ok(WebhookEvent {
+        event_type,
+        timestamp,
+        issue,
+        history,
+    })
+}
+
+/// Webhookのchangelogを履歴データに変換
+///
+/// Webhookのchangelogは`{ id, items }`のみを持つため、
+/// REST APIの`histories`形式に組み立て直してから解析します。
+fn parse_webhook_changelog(
+    issue: &Issue,
+    changelog: &Value,
+    user: Option<&Value>,
+    changed_at: DateTime<Utc>,
+) -> Result<Vec<IssueHistory>, Error> {
+    // changelog.idは文字列または数値で送られてくる
+    let change_id = match changelog.get("id") {
+        Some(Value::String(id)) => id.clone(),
+        Some(Value::Number(id)) => id.to_string(),
+        _ => return Err(Error::InvalidData("Missing changelog id".to_string())),
+    };
+
+    let mut history_entry = json!({
+        "id": change_id,
+        "created": changed_at.to_rfc3339(),
+        "items": changelog.get("items").cloned().unwrap_or_else(|| json!([])),
+    });
+    if let Some(user) = user {
+        history_entry["author"] = user.clone();
+    }
+
+    ChangelogParser::parse_changelog(
+        &issue.id,
+        &issue.key,
+        &json!({ "histories": [history_entry] }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue_updated_payload() -> Value {
+        json!({
+            "timestamp": 1705314600000i64,
+            "webhookEvent": "jira:issue_updated",
+            "issue_event_type_name": "issue_generic",
+            "user": {
+                "accountId": "557058:f58131cb",
+                "displayName": "Test User",
+                "emailAddress": "test@example.com"
+            },
+            "issue": {
+                "id": "10000",
+                "key": "TEST-1",
+                "self": "https://example.atlassian.net/rest/api/3/issue/10000",
+                "fields": {
+                    "summary": "Test Issue",
+                    "issuetype": {
+                        "id": "1",
+                        "name": "Bug",
+                        "self": "https://example.atlassian.net/rest/api/3/issuetype/1"
+                    },
+                    "status": {
+                        "id": "3",
+                        "name": "In Progress",
+                        "self": "https://example.atlassian.net/rest/api/3/status/3",
+                        "statusCategory": {
+                            "id": 4,
+                            "key": "indeterminate",
+                            "name": "In Progress",
+                            "colorName": "yellow"
+                        }
+                    },
+                    "reporter": {
+                        "accountId": "557058:f58131cb",
+                        "displayName": "Test User",
+                        "self": "https://example.atlassian.net/rest/api/3/user?accountId=557058:f58131cb"
+                    },
+                    "created": "2024-01-01T00:00:00.000Z",
+                    "updated": "2024-01-15T10:30:00.000Z"
+                }
+            },
+            "changelog": {
+                "id": "10100",
+                "items": [
+                    {
+                        "field": "status",
+                        "fieldtype": "jira",
+                        "fieldId": "status",
+                        "from": "1",
+                        "fromString": "To Do",
+                        "to": "3",
+                        "toString": "In Progress"
+                    },
+                    {
+                        "field": "assignee",
+                        "fieldtype": "jira",
+                        "fieldId": "assignee",
+                        "from": null,
+                        "fromString": null,
+                        "to": "557058:f58131cb",
+                        "toString": "Test User"
+                    }
+                ]
+            }
+        })
+    }
+
+    #[test]
+    fn test_parse_issue_updated_event() {
+        // jira:issue_updatedのペイロードからIssueと履歴が生成されることをテスト
+        let event = parse_issue_event(&issue_updated_payload()).unwrap();
+
+        assert_eq!(event.event_type, WebhookEventType::IssueUpdated);
+        assert_eq!(event.issue.key, "TEST-1");
+        assert_eq!(event.issue.fields.status.name, "In Progress");
+
+        let timestamp = DateTime::<Utc>::from_timestamp_millis(1705314600000).unwrap();
+        assert_eq!(event.timestamp, Some(timestamp));
+
+        assert_eq!(event.history.len(), 2);
+        let status_change = &event.history[0];
+        assert_eq!(status_change.issue_id, "10000");
+        assert_eq!(status_change.issue_key, "TEST-1");
+        assert_eq!(status_change.change_id, "10100");
+        assert_eq!(status_change.change_timestamp, timestamp);
+        assert_eq!(status_change.field_name, "status");
+        assert_eq!(status_change.from_display_value.as_deref(), Some("To Do"));
+        assert_eq!(
+            status_change.to_display_value.as_deref(),
+            Some("In Progress")
+        );
+        assert_eq!(
+            status_change
+                .author
+                .as_ref()
+                .map(|a| a.display_name.as_str()),
+            Some("Test User")
+        );
+
+        let assignee_change = &event.history[1];
+        assert_eq!(assignee_change.field_name, "assignee");
+        assert_eq!(assignee_change.from_value, None);
+        assert_eq!(
+            assignee_change.to_display_value.as_deref(),
+            Some("Test User")
+        );
+    }
+
+    #[test]
+    fn test_parse_event_without_changelog() {
+        // changelogを含まないイベントでは履歴が空になることをテスト
+        let mut payload = issue_updated_payload();
+        payload["webhookEvent"] = json!("jira:issue_created");
+        payload.as_object_mut().unwrap().remove("changelog");
+
+        let event = parse_issue_event(&payload).unwrap();
+        assert_eq!(event.event_type, WebhookEventType::IssueCreated);
+        assert!(event.history.is_empty());
+    }
+
+    #[test]
+    fn test_parse_unsupported_event() {
+        // Issue以外のイベントはエラーになることをテスト
+        let mut payload = issue_updated_payload();
+        payload["webhookEvent"] = json!("comment_created");
+
+        assert!(matches!(
+            parse_issue_event(&payload),
+            Err(Error::InvalidData(_))
+        ));
+    }
+}
@@ -120,8 +120,13 @@ fn create_concurrent_test_issues(start_id: usize, count: usize) -> Vec<Issue> {
             reporter,
             created: Utc::now() - Duration::minutes(issue_id as i64),
             updated: Utc::now() - Duration::seconds((issue_id * 10) as i64),
+            resolution: None,
+            time_tracking: None,
+            votes: None,
+            watches: None,
             resolution_date: None,
             project: Some(project),
+            parent: None,
             custom_fields: HashMap::new(),
         };
 
@@ -131,6 +136,7 @@ fn create_concurrent_test_issues(start_id: usize, count: usize) -> Vec<Issue> {
             fields,
             self_url: format!("https://example.com/issue/{}", 50000 + issue_id),
             changelog: None,
+            rendered_fields: None,
         };
 
         issues.push(issue);
@@ -104,8 +104,13 @@ fn create_mock_issues(count: usize) -> Vec<Issue> {
             reporter,
             created: Utc::now() - Duration::days(i as i64),
             updated: Utc::now() - Duration::hours(i as i64),
+            resolution: None,
+            time_tracking: None,
+            votes: None,
+            watches: None,
             resolution_date: None,
             project: Some(project),
+            parent: None,
             custom_fields: HashMap::new(),
         };
 
@@ -115,6 +120,7 @@ fn create_mock_issues(count: usize) -> Vec<Issue> {
             fields,
             self_url: "http://example.com".to_string(),
             changelog: None,
+            rendered_fields: None,
         };
 
         issues.push(issue);
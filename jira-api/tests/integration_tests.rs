@@ -161,12 +161,17 @@ fn create_mock_issues_for_integration_test(count: usize) -> Vec<Issue> {
             reporter,
             created: Utc::now() - Duration::days(i as i64),
             updated: Utc::now() - Duration::hours(i as i64),
+            resolution: None,
+            time_tracking: None,
+            votes: None,
+            watches: None,
             resolution_date: if i % 3 == 0 {
                 Some(Utc::now() - Duration::hours((i / 2) as i64))
             } else {
                 None
             },
             project: Some(project),
+            parent: None,
             custom_fields: {
                 let mut fields = HashMap::new();
                 fields.insert(
@@ -183,6 +188,7 @@ fn create_mock_issues_for_integration_test(count: usize) -> Vec<Issue> {
             fields,
             self_url: format!("https://mock-jira.example.com/issue/{}", 20000 + i),
             changelog: None,
+            rendered_fields: None,
         };
 
         issues.push(issue);
@@ -145,12 +145,17 @@ fn generate_large_test_dataset(count: usize) -> Vec<Issue> {
             reporter,
             created: Utc::now() - Duration::days((i % 365) as i64),
             updated: Utc::now() - Duration::hours((i % 24) as i64),
+            resolution: None,
+            time_tracking: None,
+            votes: None,
+            watches: None,
             resolution_date: if *status_name == "Done" || *status_name == "Closed" {
                 Some(Utc::now() - Duration::hours((i % 48) as i64))
             } else {
                 None
             },
             project: Some(project),
+            parent: None,
             custom_fields,
         };
 
@@ -160,6 +165,7 @@ fn generate_large_test_dataset(count: usize) -> Vec<Issue> {
             fields,
             self_url: format!("http://example.com/issue/{}", 50000 + i),
             changelog: None,
+            rendered_fields: None,
         };
 
         issues.push(issue);